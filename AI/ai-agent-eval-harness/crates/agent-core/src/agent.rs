@@ -1,20 +1,43 @@
-use crate::{config::EvaluationConfig,
+use crate::{config::{EvaluationConfig,
+                     ToolCallingMode},
             llm_client::{LlmClient,
+                         LlmResponse,
                          ResolvedPromptSet,
-                         resolve_prompt_set}};
-use agent_models::{base_agent::{AgentMetadata,
+                         resolve_prompt_set,
+                         tool_definitions}};
+use agent_models::{approval::{self,
+                              ApprovalDecision,
+                              ApprovalRequest},
+                   base_agent::{AgentMetadata,
                                 BaseAgent},
                    domain_config::DomainConfig,
                    models::{AgentState,
                             PpaStage,
                             PpaStep,
+                            TokenUsage,
                             ToolCall,
-                            Trajectory}};
+                            Trajectory},
+                   progress::{self,
+                              ProgressEvent}};
+use execution_fault_injection::fault_injector::FaultInjector;
 use execution_tools::registry::ToolRegistry;
 use std::{collections::HashMap,
           sync::{Arc,
                  Mutex}};
 
+/// SPEC-040: `from` 번째 이후 궤적에 추가된 단계를 진행 이벤트로 보고한다.
+/// 단계 함수가 오류로 단계를 남기지 않았으면 아무것도 보내지 않는다.
+fn report_steps_since(trajectory: &Trajectory, from: usize) {
+    for step in trajectory.steps.iter().skip(from) {
+        progress::emit(ProgressEvent::Step {
+            task_id: trajectory.task_id.clone(),
+            stage: step.stage.clone(),
+            iteration: step.iteration,
+            duration_ms: step.duration_ms,
+        });
+    }
+}
+
 pub struct PpaAgent {
     llm: Arc<LlmClient>,
     tools: Mutex<ToolRegistry>,
@@ -36,6 +59,51 @@ fn block_on_future<F: std::future::Future>(fut: F) -> F::Output {
     }
 }
 
+/// SPEC-034: native 모드에서 Policy system 프롬프트 끝에 붙이는 안내문.
+const NATIVE_TOOL_CALLING_NOTE: &str = "\n\n[도구 호출 방식]\n\
+     위 JSON 출력 형식 대신, 필요한 도구는 제공된 function(tool) 호출로 직접 요청하세요.\n\
+     모든 필요한 도구 호출이 끝났으면 도구를 호출하지 말고 결과를 요약해 답하세요.";
+
+/// SPEC-034: native 응답을 prompt 모드와 같은 모양의 policy 출력과 계획
+/// 행동 목록으로 변환한다. 도구 호출이 없으면 작업 완료로 본다.
+///
+/// @trace SPEC: SPEC-034
+/// @trace FR: PRD-034/FR-2
+fn native_policy(response: &LlmResponse) -> (HashMap<String, serde_json::Value>, Vec<serde_json::Value>) {
+    let planned: Vec<serde_json::Value> = response
+        .tool_calls
+        .iter()
+        .map(|tc| {
+            serde_json::json!({
+                "selected_tool": tc.name,
+                "tool_parameters": tc.arguments,
+                "next_step": null,
+            })
+        })
+        .collect();
+    let first = response.tool_calls.first();
+
+    let mut policy_data = HashMap::new();
+    policy_data.insert("reasoning".into(), serde_json::json!(response.content));
+    policy_data.insert("selected_tool".into(), serde_json::json!(first.map(|tc| &tc.name)));
+    policy_data.insert(
+        "tool_parameters".into(),
+        serde_json::json!(first.map(|tc| tc.arguments.clone()).unwrap_or_default()),
+    );
+    policy_data.insert("tool_calls".into(), serde_json::json!(planned));
+    policy_data.insert("task_completed".into(), serde_json::json!(response.tool_calls.is_empty()));
+    policy_data.insert("tool_calling_mode".into(), serde_json::json!(ToolCallingMode::Native.as_str()));
+    (policy_data, planned)
+}
+
+/// SPEC-033: LLM 호출 1회의 토큰 사용량을 궤적 합계에 더한다. provider 가
+/// 사용량을 보고하지 않으면 궤적 값은 그대로 둔다.
+fn record_usage(trajectory: &mut Trajectory, usage: Option<TokenUsage>) {
+    if let Some(u) = usage {
+        trajectory.token_usage.get_or_insert_with(TokenUsage::default).add(&u);
+    }
+}
+
 impl PpaAgent {
     pub fn new(llm: LlmClient, config: EvaluationConfig) -> Self {
         Self {
@@ -57,8 +125,13 @@ impl PpaAgent {
             trajectory.prompt_set_id = prompt_set_id;
         }
 
-        match block_on_future(self.llm.invoke(messages)) {
-            | Ok(response) => {
+        match block_on_future(self.llm.invoke_with_usage(messages)) {
+            | Ok(LlmResponse {
+                content: response,
+                usage,
+                ..
+            }) => {
+                record_usage(trajectory, usage);
                 let parsed = LlmClient::parse_json_response(&response);
                 let duration = start.elapsed().as_millis() as f64;
 
@@ -75,6 +148,7 @@ impl PpaAgent {
                     output_data,
                     tool_calls: Vec::new(),
                     duration_ms: Some(duration),
+                    token_usage: usage,
                 });
 
                 state.current_stage = PpaStage::Policy;
@@ -88,22 +162,46 @@ impl PpaAgent {
         }
     }
 
-    fn policy_step(&self, bundle: &ResolvedPromptSet, domain: &str, state: &mut AgentState, trajectory: &mut Trajectory) {
+    fn policy_step(&self, bundle: &ResolvedPromptSet, domain: &str, tools: &ToolRegistry, state: &mut AgentState, trajectory: &mut Trajectory) {
         let start = std::time::Instant::now();
 
-        let tools_meta = self.tools.lock().unwrap().get_tools_metadata();
+        let tools_meta = tools.get_tools_metadata();
         let mut ctx_map = HashMap::new();
         ctx_map.insert("iteration".into(), serde_json::json!(state.iteration));
 
-        let (messages, prompt_set_id) =
+        let (mut messages, prompt_set_id) =
             LlmClient::create_policy_prompt(bundle, domain, &state.task_description, &state.perceived_info, &tools_meta, Some(&ctx_map));
         if trajectory.prompt_set_id.is_none() {
             trajectory.prompt_set_id = prompt_set_id;
         }
 
-        match block_on_future(self.llm.invoke(messages)) {
+        // SPEC-034: native 모드는 같은 프롬프트에 도구 정의를 `tools` 로 함께
+        // 보내고, JSON 출력 대신 tool_calls 로 답하도록 안내문을 덧붙인다.
+        let result = match self.config.tool_calling_mode {
+            | ToolCallingMode::Prompt => block_on_future(self.llm.invoke_with_usage(messages)),
+            | ToolCallingMode::Native => {
+                if let Some(system) = messages.first_mut() {
+                    system.content.push_str(NATIVE_TOOL_CALLING_NOTE);
+                }
+                block_on_future(self.llm.invoke_with_tools(messages, &tool_definitions(&tools_meta)))
+            },
+        };
+
+        match result {
             | Ok(response) => {
-                let policy_data = LlmClient::parse_json_response(&response);
+                record_usage(trajectory, response.usage);
+                let (policy_data, planned_actions) = match self.config.tool_calling_mode {
+                    | ToolCallingMode::Prompt => {
+                        let policy_data = LlmClient::parse_json_response(&response.content);
+                        let planned = serde_json::json!({
+                            "selected_tool": policy_data.get("selected_tool"),
+                            "tool_parameters": policy_data.get("tool_parameters").cloned().unwrap_or(serde_json::json!({})),
+                            "next_step": policy_data.get("next_step"),
+                        });
+                        (policy_data, vec![planned])
+                    },
+                    | ToolCallingMode::Native => native_policy(&response),
+                };
                 let duration = start.elapsed().as_millis() as f64;
 
                 let mut input_data = HashMap::new();
@@ -119,16 +217,11 @@ impl PpaAgent {
                     output_data,
                     tool_calls: Vec::new(),
                     duration_ms: Some(duration),
+                    token_usage: response.usage,
                 });
 
                 state.current_stage = PpaStage::Action;
-
-                let planned = serde_json::json!({
-                    "selected_tool": policy_data.get("selected_tool"),
-                    "tool_parameters": policy_data.get("tool_parameters").cloned().unwrap_or(serde_json::json!({})),
-                    "next_step": policy_data.get("next_step"),
-                });
-                state.planned_actions = vec![planned];
+                state.planned_actions = planned_actions;
 
                 if policy_data.get("task_completed").and_then(|v| v.as_bool()).unwrap_or(false) {
                     state.is_complete = true;
@@ -140,7 +233,9 @@ impl PpaAgent {
         }
     }
 
-    fn action_step(&self, state: &mut AgentState, trajectory: &mut Trajectory) {
+    /// `tools` 는 이 실행 전용 레지스트리다 (폴트 주입이 켜져 있으면
+    /// SPEC-031 래퍼로 감싼 것).
+    fn action_step(&self, tools: &ToolRegistry, state: &mut AgentState, trajectory: &mut Trajectory) {
         let start = std::time::Instant::now();
         let mut tool_calls = Vec::new();
 
@@ -154,11 +249,25 @@ impl PpaAgent {
 
                 let mut tc = ToolCall::new(tool_name.to_string(), tool_params.clone());
 
-                let tools = self.tools.lock().unwrap();
                 if let Some(tool) = tools.get_tool(tool_name) {
-                    if !tool.validate_parameters(&tool_params) {
+                    // SPEC-044: 위험 도구는 현재 승인 게이트의 결정을 받는다.
+                    // 거부되면 실행하지 않고 실패 호출로 궤적에 남긴다.
+                    let valid = tool.validate_parameters(&tool_params);
+                    if valid && tool.metadata().requires_approval {
+                        tc.approval = Some(approval::request(&ApprovalRequest {
+                            task_id: trajectory.task_id.clone(),
+                            iteration: state.iteration,
+                            tool_name: tool_name.to_string(),
+                            parameters: tool_params.clone(),
+                        }));
+                    }
+                    if !valid {
                         tc.success = false;
                         tc.error = Some("파라미터 유효성 검사 실패".into());
+                    } else if let Some(record) = tc.approval.as_ref().filter(|r| r.decision == ApprovalDecision::Denied) {
+                        tc.success = false;
+                        tc.error = Some(format!("승인 거부 ({})", record.policy));
+                        state.perceived_info.insert("last_tool_error".into(), serde_json::json!(tc.error));
                     } else {
                         let result = tool.execute(&tool_params);
                         tc.success = result.get("success").and_then(|v| v.as_bool()).unwrap_or(false);
@@ -178,6 +287,13 @@ impl PpaAgent {
                     tc.error = Some(format!("도구를 찾을 수 없음: {}", tool_name));
                 }
 
+                // SPEC-040: 도구 호출은 Action 단계가 끝나기 전에 하나씩
+                // 보고한다.
+                progress::emit(ProgressEvent::ToolCall {
+                    task_id: trajectory.task_id.clone(),
+                    iteration: state.iteration,
+                    call: tc.clone(),
+                });
                 tool_calls.push(tc);
             }
         }
@@ -198,6 +314,7 @@ impl PpaAgent {
             output_data,
             tool_calls: tool_calls.clone(),
             duration_ms: Some(duration),
+            token_usage: None,
         });
 
         state.current_stage = PpaStage::Perceive;
@@ -233,17 +350,27 @@ impl BaseAgent for PpaAgent {
         let task_id = uuid::Uuid::new_v4().to_string();
         let started_at = std::time::Instant::now();
         println!("▶ PPA 실행 시작: task=\"{}\" (task_id={})", task_description, task_id);
+        // SPEC-036: 라우팅은 실행당 한 번만 한다 (임베딩 라우터는 task 임베딩
+        // 호출이 필요). 결정은 궤적에 기록되어 라우팅 정확도 채점에 쓰인다.
+        // 라우터가 꺼져 있으면 주 도메인만 키워드로 고른다 (임베딩 호출 없음).
+        let top_k = self.config.domain_router_top_k;
+        let routing = if top_k > 0 {
+            crate::domain_router::route(task_description, top_k)
+        } else {
+            crate::domain_router::route_by_keywords(task_description, 1)
+        };
         // SPEC-020 Stage 3: 도메인 라우터가 활성화되면 task 에 맞는 상위 K
         // 도메인의 도구만 남기고 나머지는 제외하여 컨텍스트 토큰/오탐을 줄인다.
-        if self.config.domain_router_top_k > 0 {
-            self.load_tools_with_router(task_description, self.config.domain_router_top_k);
-        }
+        // SPEC-028: 동시 실행되는 다른 task 와 공유 레지스트리를 다투지 않도록
+        // 이 실행 전용 레지스트리를 만든다.
+        let task_tools = if top_k > 0 {
+            self.tools_for_domains(&routing.domains)
+        } else {
+            self.tools.lock().unwrap().clone()
+        };
         // SPEC-025: 이 실행의 "주 도메인" 을 라우터 top-1 로 해석한다.
         // 라우터가 비활성이거나 매칭 실패 시 "general" 로 폴백.
-        let primary_domain = crate::domain_router::select_domains(task_description, 1)
-            .into_iter()
-            .next()
-            .unwrap_or_else(|| "general".to_string());
+        let primary_domain = routing.domains.first().cloned().unwrap_or_else(|| "general".to_string());
 
         // SPEC-025: 주 도메인의 활성 PromptSet 을 DB 에서 해석한다. 실패 시
         // `general` → bootstrap 폴백 (resolve_prompt_set 내부에서 처리).
@@ -260,23 +387,44 @@ impl BaseAgent for PpaAgent {
             success: false,
             total_iterations: 0,
             prompt_set_id: None,
+            injected_faults: Vec::new(),
+            token_usage: None,
+            tool_calling_mode: Some(self.config.tool_calling_mode.as_str().to_string()),
+            llm_model: Some(self.llm.config().model().to_string()),
+            domain_routing: Some(routing),
         };
 
+        // SPEC-031: 폴트 주입이 켜져 있으면 이 실행 전용 주입기로 레지스트리를
+        // 감싼다. 실행마다 새 주입기이므로 호출 순번은 0 부터 센다.
+        let injector = self.config.fault_injection.clone().filter(|c| c.enabled).map(FaultInjector::new);
+        let fault_tools = injector.as_ref().map(|inj| inj.wrap_registry(&task_tools));
+        let tools = fault_tools.as_ref().unwrap_or(&task_tools);
+
         let mut state = AgentState::new(task_description.to_string()).with_environment(initial_environment.unwrap_or_default());
 
         let mut repeated_action_count = 0u32;
         let mut last_action = String::new();
 
         while !state.is_complete && state.iteration < self.config.max_iterations {
+            // SPEC-040: 취소 요청은 반복 경계에서만 확인한다 (진행 중인
+            // LLM·도구 호출은 끝까지 기다린다).
+            if progress::is_cancelled() {
+                state.error_message = Some("실행 취소".into());
+                break;
+            }
             state.iteration += 1;
             println!("  [Iteration {}/{}]", state.iteration, self.config.max_iterations);
 
+            let before = trajectory.steps.len();
             self.perceive_step(&bundle, &primary_domain, &mut state, &mut trajectory);
+            report_steps_since(&trajectory, before);
             if state.error_message.is_some() {
                 break;
             }
 
-            self.policy_step(&bundle, &primary_domain, &mut state, &mut trajectory);
+            let before = trajectory.steps.len();
+            self.policy_step(&bundle, &primary_domain, tools, &mut state, &mut trajectory);
+            report_steps_since(&trajectory, before);
             if state.error_message.is_some() {
                 break;
             }
@@ -285,7 +433,9 @@ impl BaseAgent for PpaAgent {
                 break;
             }
 
-            self.action_step(&mut state, &mut trajectory);
+            let before = trajectory.steps.len();
+            self.action_step(tools, &mut state, &mut trajectory);
+            report_steps_since(&trajectory, before);
             if state.error_message.is_some() {
                 break;
             }
@@ -306,6 +456,9 @@ impl BaseAgent for PpaAgent {
         trajectory.end_time = Some(chrono::Utc::now());
         trajectory.success = state.is_complete && state.error_message.is_none();
         trajectory.total_iterations = state.iteration;
+        if let Some(injector) = &injector {
+            trajectory.injected_faults = injector.injected_faults();
+        }
         let elapsed = started_at.elapsed().as_secs_f64();
         let status = if trajectory.success { "성공" } else { "실패" };
         let err_suffix = state.error_message.as_deref().map(|m| format!(" (사유: {})", m)).unwrap_or_default();
@@ -340,25 +493,34 @@ impl PpaAgent {
         // SPEC-023: DB 에 등록된 외부 HTTP 도구도 함께 로드. store 가 install
         // 안 된 환경(테스트)에서는 조용히 skip.
         crate::external_tools::register_external_tools_from_db(&mut registry);
+        // SPEC-037: DB 에 등록된 MCP 서버의 도구. 접속은 캐시되어 재사용된다.
+        crate::mcp_tools::register_mcp_tools_from_db(&mut registry);
     }
 
-    /// 키워드 기반 pre-filter. `task_description` 에서 도메인 키워드 빈도를
-    /// 집계해 상위 `top_k` 도메인만 남기고 다른 도메인 도구는 제외한
+    /// 도메인 pre-filter. 라우터(SPEC-036: 임베딩 유사도, 없으면 키워드
+    /// 빈도)가 고른 상위 `top_k` 도메인만 남기고 다른 도메인 도구는 제외한
     /// 레지스트리를 구성한다. `top_k == 0` 또는 매칭이 전혀 없으면 전체
     /// 도구를 사용한다. `general` 도메인(기본 파일 도구)은 항상 포함된다.
     ///
     /// @trace SPEC: SPEC-020
     /// @trace FR: PRD-020/FR-3
     pub fn load_tools_with_router(&self, task_description: &str, top_k: usize) {
-        self.load_all_tools();
         if top_k == 0 {
+            self.load_all_tools();
             return;
         }
-        let keep: Vec<String> = crate::domain_router::select_domains(task_description, top_k);
+        self.load_all_tools();
+        let filtered = self.tools_for_domains(&crate::domain_router::select_domains(task_description, top_k));
+        *self.tools.lock().unwrap() = filtered;
+    }
+
+    /// 공유 레지스트리에서 라우터가 고른 `keep` 도메인의 도구만 남긴 사본을
+    /// 만든다. 비어 있으면 전체 도구의 사본. 공유 레지스트리는 바꾸지 않는다.
+    fn tools_for_domains(&self, keep: &[String]) -> ToolRegistry {
+        let registry = self.tools.lock().unwrap();
         if keep.is_empty() {
-            return;
+            return registry.clone();
         }
-        let mut registry = self.tools.lock().unwrap();
         let all_keys: Vec<String> = registry.get_tool_names();
         let mut new_reg = execution_tools::registry::ToolRegistry::new();
         for key in all_keys {
@@ -374,6 +536,236 @@ impl PpaAgent {
                 }
             }
         }
-        *registry = new_reg;
+        new_reg
+    }
+}
+
+#[cfg(test)]
+mod spec034_tests {
+    // =============================================================================
+    // @trace SPEC-034
+    // @trace PRD: PRD-034
+    // @trace FR: PRD-034/FR-2, PRD-034/FR-3
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use crate::config::OpenAiCompatibleConfig;
+    use test_support::spawn_json_server;
+
+    fn content(text: &str) -> serde_json::Value {
+        serde_json::json!({
+            "choices": [{"message": {"role": "assistant", "content": text}}],
+            "usage": {"prompt_tokens": 10, "completion_tokens": 2, "total_tokens": 12}
+        })
+    }
+
+    /// native 모드: Policy 가 tool_calls 로 도구를 고르고, 도구 호출 없는
+    /// 응답에서 완료된다. 궤적에 모드와 토큰 합계가 기록된다.
+    ///
+    /// @trace TC: SPEC-034/TC-5
+    /// @trace FR: PRD-034/FR-2, PRD-034/FR-3
+    #[test]
+    fn spec034_tc_5_native_mode_executes_tool_calls() {
+        let tool_call = serde_json::json!({
+            "choices": [{"message": {
+                "role": "assistant",
+                "content": null,
+                "tool_calls": [{"id": "c1", "type": "function", "function": {
+                    "name": "financial__calculate_simple_interest",
+                    "arguments": "{\"principal\": 1000000, \"rate\": 0.05, \"time\": 1}"
+                }}]
+            }}],
+            "usage": {"prompt_tokens": 10, "completion_tokens": 2, "total_tokens": 12}
+        });
+        let (url, requests) = spawn_json_server(vec![content("{}"), tool_call, content("{}"), content("이자는 50000원입니다.")]);
+
+        let llm = LlmClient::new(OpenAiCompatibleConfig {
+            base_url: url,
+            api_key: None,
+            model: "local".into(),
+            temperature: 0.0,
+            max_tokens: None,
+        });
+        let config = EvaluationConfig {
+            tool_calling_mode: ToolCallingMode::Native,
+            ..EvaluationConfig::default()
+        };
+        let agent = PpaAgent::new(llm, config);
+        agent.load_all_tools();
+
+        let trajectory = agent.execute_task("100만원 5% 1년 단리 이자 계산", None);
+        let requests: Vec<String> = requests.iter().map(|r| r.body).collect();
+
+        assert!(
+            trajectory.success,
+            "{:?}",
+            trajectory.final_state.as_ref().and_then(|s| s.error_message.clone())
+        );
+        assert_eq!(trajectory.tool_calling_mode.as_deref(), Some("native"));
+        assert_eq!(trajectory.token_usage.map(|u| u.total_tokens), Some(48));
+
+        let calls: Vec<&ToolCall> = trajectory.steps.iter().flat_map(|s| s.tool_calls.iter()).collect();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].tool_name, "financial__calculate_simple_interest");
+        assert!(calls[0].success);
+
+        // Policy 요청(2번째)에만 도구 정의가 실린다.
+        let policy_req: serde_json::Value = serde_json::from_str(&requests[1]).unwrap();
+        assert!(policy_req["tools"].as_array().is_some_and(|t| !t.is_empty()));
+        let perceive_req: serde_json::Value = serde_json::from_str(&requests[0]).unwrap();
+        assert!(perceive_req.get("tools").is_none());
+    }
+
+    /// 라우터 필터는 실행 전용 사본을 만들고 공유 레지스트리는 그대로 둔다.
+    /// 동시 실행되는 다른 task 의 도구 목록이 바뀌지 않아야 한다.
+    ///
+    /// @trace SPEC: SPEC-028
+    #[test]
+    fn spec028_domain_filter_does_not_touch_shared_registry() {
+        let agent = native_agent("http://127.0.0.1:9".into());
+        let before = agent.tools.lock().unwrap().get_tool_names().len();
+
+        let filtered = agent.tools_for_domains(&["financial".to_string()]);
+        assert!(filtered.get_tool("financial__calculate_simple_interest").is_some());
+        assert!(filtered.get_tool_names().len() < before);
+        assert_eq!(agent.tools.lock().unwrap().get_tool_names().len(), before);
+    }
+
+    /// SPEC-040 테스트용 진행 이벤트 수집기. `cancelled` 면 처음부터 취소.
+    struct Collect {
+        events: Mutex<Vec<ProgressEvent>>,
+        cancelled: bool,
+    }
+
+    impl progress::ProgressSink for Collect {
+        fn emit(&self, event: ProgressEvent) { self.events.lock().unwrap().push(event); }
+
+        fn is_cancelled(&self) -> bool { self.cancelled }
+    }
+
+    fn native_agent(base_url: String) -> PpaAgent {
+        let llm = LlmClient::new(OpenAiCompatibleConfig {
+            base_url,
+            api_key: None,
+            model: "local".into(),
+            temperature: 0.0,
+            max_tokens: None,
+        });
+        let agent = PpaAgent::new(
+            llm,
+            EvaluationConfig {
+                tool_calling_mode: ToolCallingMode::Native,
+                ..EvaluationConfig::default()
+            },
+        );
+        agent.load_all_tools();
+        agent
+    }
+
+    /// 도구 호출은 Action 단계 이벤트보다 먼저, 단계는 궤적 순서대로 보고된다.
+    ///
+    /// @trace TC: SPEC-040/TC-2
+    /// @trace FR: PRD-040/FR-2
+    #[test]
+    fn spec040_tc_2_steps_and_tool_calls_are_reported_live() {
+        let tool_call = serde_json::json!({
+            "choices": [{"message": {
+                "role": "assistant",
+                "content": null,
+                "tool_calls": [{"id": "c1", "type": "function", "function": {
+                    "name": "financial__calculate_simple_interest",
+                    "arguments": "{\"principal\": 1000000, \"rate\": 0.05, \"time\": 1}"
+                }}]
+            }}]
+        });
+        let (url, _requests) = spawn_json_server(vec![content("{}"), tool_call, content("{}"), content("이자는 50000원입니다.")]);
+        let agent = native_agent(url);
+        let sink = Arc::new(Collect {
+            events: Mutex::new(Vec::new()),
+            cancelled: false,
+        });
+
+        let trajectory = progress::scoped(Some(sink.clone()), || agent.execute_task("100만원 5% 1년 단리 이자 계산", None));
+        assert!(trajectory.success);
+
+        let labels: Vec<String> = sink
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|e| match e {
+                | ProgressEvent::Step {
+                    task_id,
+                    stage,
+                    iteration,
+                    ..
+                } => {
+                    assert_eq!(task_id, &trajectory.task_id);
+                    format!("{stage:?}{iteration}")
+                },
+                | ProgressEvent::ToolCall {
+                    call, ..
+                } => format!("tool:{}", call.tool_name),
+                | other => panic!("unexpected {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                "Perceive1",
+                "Policy1",
+                "tool:financial__calculate_simple_interest",
+                "Action1",
+                "Perceive2",
+                "Policy2"
+            ]
+        );
+    }
+
+    /// @trace TC: SPEC-040/TC-3
+    /// @trace FR: PRD-040/FR-4
+    #[test]
+    fn spec040_tc_3_cancelled_run_stops_before_next_iteration() {
+        // 취소된 실행은 LLM 을 부르지 않으므로 mock 서버가 필요 없다.
+        let agent = native_agent("http://127.0.0.1:9".into());
+        let sink = Arc::new(Collect {
+            events: Mutex::new(Vec::new()),
+            cancelled: true,
+        });
+        let trajectory = progress::scoped(Some(sink.clone()), || agent.execute_task("이자 계산", None));
+        assert!(!trajectory.success);
+        assert_eq!(trajectory.total_iterations, 0);
+        assert_eq!(trajectory.final_state.unwrap().error_message.as_deref(), Some("실행 취소"));
+        assert!(sink.events.lock().unwrap().is_empty());
+    }
+
+    /// 거부된 위험 도구 호출은 실행되지 않고 승인 기록과 함께 실패로 남는다.
+    ///
+    /// @trace TC: SPEC-044/TC-2
+    /// @trace FR: PRD-044/FR-1, PRD-044/FR-3
+    #[test]
+    fn spec044_tc_2_denied_tool_call_is_recorded_not_executed() {
+        let target = std::env::temp_dir().join(format!("spec044-{}.txt", uuid::Uuid::new_v4()));
+        let arguments = serde_json::json!({"file_path": target.to_string_lossy(), "content": "x"}).to_string();
+        let tool_call = serde_json::json!({
+            "choices": [{"message": {
+                "role": "assistant",
+                "content": null,
+                "tool_calls": [{"id": "c1", "type": "function", "function": {"name": "write_file", "arguments": arguments}}]
+            }}]
+        });
+        let (url, _requests) = spawn_json_server(vec![content("{}"), tool_call, content("{}"), content("쓰기가 거부되었습니다.")]);
+        let agent = native_agent(url);
+
+        let gate = Arc::new(approval::ApprovalGate::new(approval::ApprovalPolicy::AutoDeny));
+        let trajectory = approval::scoped(Some(gate), || agent.execute_task("out.txt 에 x 를 써줘", None));
+
+        let calls: Vec<&ToolCall> = trajectory.steps.iter().flat_map(|s| s.tool_calls.iter()).collect();
+        assert_eq!(calls.len(), 1);
+        assert!(!calls[0].success);
+        assert_eq!(calls[0].error.as_deref(), Some("승인 거부 (auto_deny)"));
+        assert_eq!(calls[0].approval.as_ref().map(|r| r.decision), Some(ApprovalDecision::Denied));
+        assert!(!target.exists(), "거부된 호출은 실행되지 않는다");
     }
 }
@@ -1,3 +1,4 @@
+use execution_fault_injection::models::FaultInjectionConfig;
 use serde::{Deserialize,
             Serialize};
 
@@ -28,6 +29,235 @@ impl AzureOpenAiConfig {
     }
 }
 
+/// SPEC-033: LLM 백엔드 종류. `LLM_PROVIDER` 환경변수나 에이전트 이름
+/// 접미사(`ppa-openai` 등)로 선택한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LlmProvider {
+    AzureOpenAi,
+    OpenAi,
+    Ollama,
+}
+
+impl LlmProvider {
+    pub const ALL: [LlmProvider; 3] = [LlmProvider::AzureOpenAi, LlmProvider::OpenAi, LlmProvider::Ollama];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            | LlmProvider::AzureOpenAi => "azure",
+            | LlmProvider::OpenAi => "openai",
+            | LlmProvider::Ollama => "ollama",
+        }
+    }
+
+    /// `LLM_PROVIDER` 환경변수. 없거나 알 수 없는 값이면 Azure.
+    pub fn from_env() -> Self {
+        dotenvy::dotenv().ok();
+        std::env::var("LLM_PROVIDER")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(LlmProvider::AzureOpenAi)
+    }
+}
+
+impl std::str::FromStr for LlmProvider {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            | "azure" | "azure_openai" => Ok(LlmProvider::AzureOpenAi),
+            | "openai" | "openai_compatible" => Ok(LlmProvider::OpenAi),
+            | "ollama" => Ok(LlmProvider::Ollama),
+            | other => anyhow::bail!("알 수 없는 LLM provider: {other} (azure | openai | ollama)"),
+        }
+    }
+}
+
+/// OpenAI 호환 서버 설정 (`/v1/chat/completions`, Bearer 인증).
+/// vLLM, LM Studio, llama.cpp server 등 로컬 서버도 이 형식을 쓴다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAiCompatibleConfig {
+    /// 서버 루트 URL (예: `http://localhost:8000`). `/v1` 은 붙이지 않는다.
+    pub base_url: String,
+    /// 로컬 서버는 인증이 없는 경우가 많아 선택.
+    pub api_key: Option<String>,
+    pub model: String,
+    pub temperature: f64,
+    pub max_tokens: Option<u32>,
+}
+
+impl OpenAiCompatibleConfig {
+    pub fn from_env() -> anyhow::Result<Self> {
+        dotenvy::dotenv().ok();
+        Ok(Self {
+            base_url: std::env::var("OPENAI_BASE_URL").unwrap_or_else(|_| "https://api.openai.com".into()),
+            api_key: std::env::var("OPENAI_API_KEY").ok().filter(|v| !v.is_empty()),
+            model: std::env::var("OPENAI_MODEL")?,
+            temperature: std::env::var("OPENAI_TEMPERATURE").ok().and_then(|v| v.parse().ok()).unwrap_or(1.0),
+            max_tokens: std::env::var("OPENAI_MAX_TOKENS").ok().and_then(|v| v.parse().ok()).or(Some(4096)),
+        })
+    }
+}
+
+/// Ollama 네이티브 API(`/api/chat`) 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaConfig {
+    pub host: String,
+    pub model: String,
+    pub temperature: f64,
+    pub max_tokens: Option<u32>,
+}
+
+impl OllamaConfig {
+    pub fn from_env() -> anyhow::Result<Self> {
+        dotenvy::dotenv().ok();
+        Ok(Self {
+            host: std::env::var("OLLAMA_HOST").unwrap_or_else(|_| "http://localhost:11434".into()),
+            model: std::env::var("OLLAMA_MODEL")?,
+            temperature: std::env::var("OLLAMA_TEMPERATURE").ok().and_then(|v| v.parse().ok()).unwrap_or(0.2),
+            max_tokens: std::env::var("OLLAMA_MAX_TOKENS").ok().and_then(|v| v.parse().ok()),
+        })
+    }
+}
+
+/// SPEC-033: `LlmClient` 가 사용할 백엔드 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+pub enum LlmConfig {
+    AzureOpenAi(AzureOpenAiConfig),
+    OpenAi(OpenAiCompatibleConfig),
+    Ollama(OllamaConfig),
+}
+
+impl LlmConfig {
+    /// 지정 provider 의 환경변수 설정을 읽는다. 필수 변수가 없으면 Err.
+    ///
+    /// @trace SPEC: SPEC-033
+    /// @trace FR: PRD-033/FR-1
+    pub fn from_env(provider: LlmProvider) -> anyhow::Result<Self> {
+        Ok(match provider {
+            | LlmProvider::AzureOpenAi => LlmConfig::AzureOpenAi(AzureOpenAiConfig::from_env()?),
+            | LlmProvider::OpenAi => LlmConfig::OpenAi(OpenAiCompatibleConfig::from_env()?),
+            | LlmProvider::Ollama => LlmConfig::Ollama(OllamaConfig::from_env()?),
+        })
+    }
+
+    /// `LLM_PROVIDER` 로 지정된 기본 provider 의 설정.
+    pub fn from_env_default() -> anyhow::Result<Self> { Self::from_env(LlmProvider::from_env()) }
+
+    pub fn provider(&self) -> LlmProvider {
+        match self {
+            | LlmConfig::AzureOpenAi(_) => LlmProvider::AzureOpenAi,
+            | LlmConfig::OpenAi(_) => LlmProvider::OpenAi,
+            | LlmConfig::Ollama(_) => LlmProvider::Ollama,
+        }
+    }
+
+    /// 모델(Azure 는 배포) 이름.
+    pub fn model(&self) -> &str {
+        match self {
+            | LlmConfig::AzureOpenAi(c) => &c.azure_openai_deployment,
+            | LlmConfig::OpenAi(c) => &c.model,
+            | LlmConfig::Ollama(c) => &c.model,
+        }
+    }
+}
+
+impl From<AzureOpenAiConfig> for LlmConfig {
+    fn from(c: AzureOpenAiConfig) -> Self { LlmConfig::AzureOpenAi(c) }
+}
+
+impl From<OpenAiCompatibleConfig> for LlmConfig {
+    fn from(c: OpenAiCompatibleConfig) -> Self { LlmConfig::OpenAi(c) }
+}
+
+impl From<OllamaConfig> for LlmConfig {
+    fn from(c: OllamaConfig) -> Self { LlmConfig::Ollama(c) }
+}
+
+/// SPEC-036: 도메인 라우터가 쓰는 임베딩 모델 설정. 연결 정보는 같은
+/// provider 의 LLM 환경변수를 재사용하고 모델만 따로 지정한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddingConfig {
+    pub provider: LlmProvider,
+    /// Azure endpoint, OpenAI 호환 base URL, Ollama host 중 하나.
+    pub base_url: String,
+    pub api_key: Option<String>,
+    /// 모델(Azure 는 배포) 이름.
+    pub model: String,
+    /// Azure 전용.
+    pub api_version: Option<String>,
+}
+
+impl EmbeddingConfig {
+    /// `EMBEDDING_PROVIDER`(없으면 `LLM_PROVIDER`) 의 임베딩 모델 변수
+    /// (`AZURE_OPENAI_EMBEDDING_DEPLOYMENT` | `OPENAI_EMBEDDING_MODEL` |
+    /// `OLLAMA_EMBEDDING_MODEL`) 를 읽는다. 모델 변수가 없으면 `Ok(None)`
+    /// 이며 라우터는 키워드 방식으로 동작한다.
+    ///
+    /// @trace SPEC: SPEC-036
+    /// @trace FR: PRD-036/FR-1
+    pub fn from_env() -> anyhow::Result<Option<Self>> {
+        dotenvy::dotenv().ok();
+        let provider = match std::env::var("EMBEDDING_PROVIDER") {
+            | Ok(v) if !v.trim().is_empty() => v.parse()?,
+            | _ => LlmProvider::from_env(),
+        };
+        let model_var = match provider {
+            | LlmProvider::AzureOpenAi => "AZURE_OPENAI_EMBEDDING_DEPLOYMENT",
+            | LlmProvider::OpenAi => "OPENAI_EMBEDDING_MODEL",
+            | LlmProvider::Ollama => "OLLAMA_EMBEDDING_MODEL",
+        };
+        let Some(model) = std::env::var(model_var).ok().filter(|v| !v.trim().is_empty()) else {
+            return Ok(None);
+        };
+        let config = match provider {
+            | LlmProvider::AzureOpenAi => Self {
+                provider,
+                base_url: std::env::var("AZURE_OPENAI_ENDPOINT")?,
+                api_key: Some(std::env::var("AZURE_OPENAI_API_KEY")?),
+                model,
+                api_version: Some(std::env::var("AZURE_OPENAI_API_VERSION").unwrap_or_else(|_| "2024-12-01-preview".into())),
+            },
+            | LlmProvider::OpenAi => Self {
+                provider,
+                base_url: std::env::var("OPENAI_BASE_URL").unwrap_or_else(|_| "https://api.openai.com".into()),
+                api_key: std::env::var("OPENAI_API_KEY").ok().filter(|v| !v.is_empty()),
+                model,
+                api_version: None,
+            },
+            | LlmProvider::Ollama => Self {
+                provider,
+                base_url: std::env::var("OLLAMA_HOST").unwrap_or_else(|_| "http://localhost:11434".into()),
+                api_key: None,
+                model,
+                api_version: None,
+            },
+        };
+        Ok(Some(config))
+    }
+}
+
+/// SPEC-034: Policy 단계가 도구 선택을 받아오는 방식.
+/// `Prompt` 는 프롬프트로 JSON 출력을 요청해 파싱하고, `Native` 는 도구
+/// 정의를 `tools` 로 보내 응답의 `tool_calls` 를 그대로 사용한다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolCallingMode {
+    #[default]
+    Prompt,
+    Native,
+}
+
+impl ToolCallingMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            | ToolCallingMode::Prompt => "prompt",
+            | ToolCallingMode::Native => "native",
+        }
+    }
+}
+
 /// 평가 시스템 설정
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvaluationConfig {
@@ -44,6 +274,14 @@ pub struct EvaluationConfig {
     /// 파일 도구)은 항상 포함.
     #[serde(default)]
     pub domain_router_top_k: usize,
+    /// SPEC-031: 설정 시 각 실행마다 도구 레지스트리를 폴트 주입 래퍼로 감싸
+    /// 실행하고, 주입 기록을 `Trajectory::injected_faults` 에 남긴다.
+    #[serde(default)]
+    pub fault_injection: Option<FaultInjectionConfig>,
+    /// SPEC-034: 기본 `prompt`. 실행 모드는 `Trajectory::tool_calling_mode`
+    /// 에 기록된다.
+    #[serde(default)]
+    pub tool_calling_mode: ToolCallingMode,
 }
 
 impl Default for EvaluationConfig {
@@ -57,6 +295,8 @@ impl Default for EvaluationConfig {
             safety_check_enabled: true,
             enable_llm_judge: false,
             domain_router_top_k: 0,
+            fault_injection: None,
+            tool_calling_mode: ToolCallingMode::Prompt,
         }
     }
 }
@@ -19,9 +19,26 @@
 //
 // 단위 테스트나 store 가 install 되지 않은 환경에서는 `default_keywords()` 가
 // 폴백으로 사용된다.
+//
+// SPEC-036: 임베딩 라우터. `install_embedder` 로 임베더가 설치되면 도메인
+// 설명과 시나리오 작업 설명(예시)의 벡터를 SQLite `domain_embeddings` 에
+// 모델별로 저장해 두고, task 벡터와의 코사인 유사도(도메인별 최댓값)로
+// 순위를 매긴다. 임베더가 없거나 벡터가 없거나 호출이 실패하면 키워드
+// 방식으로 폴백한다. 벡터 캐시도 `invalidate_cache()` 로 함께 비워지며,
+// 다시 채울 때 바뀐 텍스트만 새로 임베딩한다.
 
+use crate::embedding::{Embedder,
+                       cosine_similarity};
+use agent_models::models::DomainRouting;
+use data_scenarios::sqlite_store::{DomainEmbeddingRow,
+                                   SqliteStore};
 use std::{collections::HashMap,
-          sync::RwLock};
+          sync::{Arc,
+                 RwLock}};
+
+/// SPEC-036: 코사인 유사도가 이 값보다 낮은 도메인은 고르지 않는다. 모두
+/// 미달이면 키워드 라우터의 "매칭 0건" 과 같이 빈 결과를 돌려준다.
+pub const MIN_EMBEDDING_SIMILARITY: f32 = 0.3;
 
 /// 부트스트랩 키워드. v5 마이그레이션 시 이 값들이 DB 에 시드되며, store 가
 /// 없는 환경(테스트 등)에서는 폴백으로 사용된다.
@@ -104,14 +121,81 @@ pub fn default_keywords_flat() -> Vec<(String, String)> {
 /// 캐시. None 이면 다음 호출 시 lazy load.
 static KEYWORD_CACHE: RwLock<Option<HashMap<String, Vec<String>>>> = RwLock::new(None);
 
-/// 캐시를 비운다. 도메인/키워드 CRUD 직후 호출.
+/// SPEC-036: 설치된 임베더. None 이면 키워드 라우팅만 사용.
+static EMBEDDER: RwLock<Option<Arc<dyn Embedder>>> = RwLock::new(None);
+
+/// SPEC-036: 도메인 → 설명/예시 벡터 캐시. None 이면 다음 호출 시 lazy load.
+static VECTOR_CACHE: RwLock<Option<HashMap<String, Vec<Vec<f32>>>>> = RwLock::new(None);
+
+/// 캐시를 비운다. 도메인/키워드/시나리오 CRUD 직후 호출.
 ///
-/// @trace SPEC: SPEC-022
+/// @trace SPEC: SPEC-022, SPEC-036
 /// @trace FR: PRD-022/FR-3
 pub fn invalidate_cache() {
     if let Ok(mut guard) = KEYWORD_CACHE.write() {
         *guard = None;
     }
+    if let Ok(mut guard) = VECTOR_CACHE.write() {
+        *guard = None;
+    }
+}
+
+/// 임베더를 설치(또는 None 으로 해제)한다. 벡터 캐시는 모델이 바뀔 수
+/// 있으므로 비운다.
+///
+/// @trace SPEC: SPEC-036
+/// @trace FR: PRD-036/FR-1
+pub fn install_embedder(embedder: Option<Arc<dyn Embedder>>) {
+    if let Ok(mut guard) = EMBEDDER.write() {
+        *guard = embedder;
+    }
+    if let Ok(mut guard) = VECTOR_CACHE.write() {
+        *guard = None;
+    }
+}
+
+pub fn installed_embedder() -> Option<Arc<dyn Embedder>> { EMBEDDER.read().ok().and_then(|g| g.clone()) }
+
+/// 환경변수(`EmbeddingConfig::from_env`)에 임베딩 모델이 있으면 HTTP
+/// 임베더를 설치하고 모델 이름을 반환한다. 이미 설치돼 있으면 그대로 둔다.
+///
+/// @trace SPEC: SPEC-036
+/// @trace FR: PRD-036/FR-1
+pub fn install_embedder_from_env() -> Option<String> {
+    if let Some(embedder) = installed_embedder() {
+        return Some(embedder.model().to_string());
+    }
+    match crate::config::EmbeddingConfig::from_env() {
+        | Ok(Some(config)) => {
+            let model = config.model.clone();
+            install_embedder(Some(Arc::new(crate::embedding::HttpEmbedder::new(config))));
+            Some(model)
+        },
+        | Ok(None) => None,
+        | Err(e) => {
+            eprintln!("[warn] 임베딩 설정 로드 실패: {e} — 키워드 라우팅 사용");
+            None
+        },
+    }
+}
+
+/// 벡터 캐시를 직접 주입(테스트용).
+pub fn prime_vector_cache(map: HashMap<String, Vec<Vec<f32>>>) {
+    if let Ok(mut guard) = VECTOR_CACHE.write() {
+        *guard = Some(map);
+    }
+}
+
+/// 동기 컨텍스트에서 store 의 async 메서드를 실행한다.
+fn block_on<F: std::future::Future>(fut: F) -> F::Output {
+    match tokio::runtime::Handle::try_current() {
+        | Ok(handle) => tokio::task::block_in_place(|| handle.block_on(fut)),
+        | Err(_) => tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build tokio runtime")
+            .block_on(fut),
+    }
 }
 
 /// 외부에서 캐시를 직접 주입(테스트·기동 시점 prime). 일반 코드는 lazy load 에
@@ -126,19 +210,7 @@ pub fn prime_cache(map: HashMap<String, Vec<String>>) {
 fn load_into_cache() -> HashMap<String, Vec<String>> {
     if let Some(store) = data_scenarios::loader::try_installed_store() {
         // SqliteStore 메서드는 async 이므로 동기 컨텍스트에서 block_on.
-        let result = match tokio::runtime::Handle::try_current() {
-            | Ok(handle) => {
-                let store = store.clone();
-                tokio::task::block_in_place(|| handle.block_on(async move { store.list_all_domain_keywords().await }))
-            },
-            | Err(_) => tokio::runtime::Builder::new_current_thread()
-                .enable_all()
-                .build()
-                .ok()
-                .map(|rt| rt.block_on(async move { store.list_all_domain_keywords().await }))
-                .unwrap_or_else(|| Ok(HashMap::new())),
-        };
-        if let Ok(map) = result {
+        if let Ok(map) = block_on(store.list_all_domain_keywords()) {
             if !map.is_empty() {
                 return map;
             }
@@ -148,9 +220,158 @@ fn load_into_cache() -> HashMap<String, Vec<String>> {
     default_keywords().into_iter().collect()
 }
 
+/// 도메인 설명과 시나리오 작업 설명의 `embedder` 벡터를 store 와 맞춘 뒤
+/// `domain → 벡터 목록` 으로 반환한다. 이미 저장된 (모델, 텍스트) 벡터는
+/// 재사용하고, 새 텍스트만 임베딩하며, 사라진 텍스트의 행은 지운다.
+///
+/// @trace SPEC: SPEC-036
+/// @trace FR: PRD-036/FR-2
+pub fn sync_domain_embeddings(store: &SqliteStore, embedder: &dyn Embedder) -> anyhow::Result<HashMap<String, Vec<Vec<f32>>>> {
+    let model = embedder.model().to_string();
+    let domains = block_on(store.load_all_domains())?;
+    let existing = block_on(store.list_domain_embeddings(&model))?;
+    let mut stored_count: HashMap<String, usize> = HashMap::new();
+    let mut known: HashMap<(String, String, String), Vec<f32>> = HashMap::new();
+    for row in existing {
+        *stored_count.entry(row.domain.clone()).or_default() += 1;
+        known.insert((row.domain, row.kind, row.text), row.vector);
+    }
+
+    let mut out = HashMap::new();
+    for domain in domains {
+        let mut wanted: Vec<(String, String)> = Vec::new();
+        if !domain.description.trim().is_empty() {
+            wanted.push(("description".into(), domain.description.clone()));
+        }
+        for scenario in &domain.scenarios {
+            if !scenario.task_description.trim().is_empty() {
+                wanted.push(("example".into(), scenario.task_description.clone()));
+            }
+        }
+        wanted.sort();
+        wanted.dedup();
+        if wanted.is_empty() {
+            // 텍스트가 모두 비워졌으면 남은 벡터로 라우팅되지 않게 지운다.
+            if stored_count.get(&domain.name).is_some_and(|n| *n > 0) {
+                block_on(store.replace_domain_embeddings(&domain.name, &model, &[]))?;
+            }
+            continue;
+        }
+
+        let missing: Vec<String> = wanted
+            .iter()
+            .filter(|(kind, text)| !known.contains_key(&(domain.name.clone(), kind.clone(), text.clone())))
+            .map(|(_, text)| text.clone())
+            .collect();
+        let mut fresh = embedder.embed(&missing)?.into_iter();
+        let rows: Vec<DomainEmbeddingRow> = wanted
+            .into_iter()
+            .filter_map(|(kind, text)| {
+                let vector = known.remove(&(domain.name.clone(), kind.clone(), text.clone())).or_else(|| fresh.next())?;
+                Some(DomainEmbeddingRow {
+                    domain: domain.name.clone(),
+                    kind,
+                    text,
+                    vector,
+                })
+            })
+            .collect();
+        if !missing.is_empty() || stored_count.get(&domain.name).copied().unwrap_or(0) != rows.len() {
+            block_on(store.replace_domain_embeddings(&domain.name, &model, &rows))?;
+        }
+        out.insert(domain.name, rows.into_iter().map(|r| r.vector).collect());
+    }
+    Ok(out)
+}
+
+/// 도메인별 최대 코사인 유사도를 내림차순(동률은 이름순)으로 반환한다.
+/// 벡터가 하나도 없으면 빈 Vec.
+fn rank_by_embedding(embedder: &dyn Embedder, task_description: &str) -> anyhow::Result<Vec<(String, f32)>> {
+    let cached: Option<HashMap<String, Vec<Vec<f32>>>> = VECTOR_CACHE.read().ok().and_then(|g| g.clone());
+    let vectors = match cached {
+        | Some(v) => v,
+        | None => {
+            let loaded = match data_scenarios::loader::try_installed_store() {
+                | Some(store) => sync_domain_embeddings(&store, embedder)?,
+                | None => HashMap::new(),
+            };
+            if let Ok(mut guard) = VECTOR_CACHE.write() {
+                *guard = Some(loaded.clone());
+            }
+            loaded
+        },
+    };
+    if vectors.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let query = embedder
+        .embed(&[task_description.to_string()])?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("task 임베딩 응답이 비어 있음"))?;
+    let mut scored: Vec<(String, f32)> = vectors
+        .into_iter()
+        .filter(|(_, vs)| !vs.is_empty())
+        .map(|(domain, vs)| {
+            let best = vs.iter().map(|v| cosine_similarity(&query, v)).fold(f32::MIN, f32::max);
+            (domain, best)
+        })
+        .collect();
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    Ok(scored)
+}
+
+/// task 에 맞는 상위 `top_k` 도메인을 고르고 그 방식과 점수를 함께
+/// 반환한다. 임베더가 설치돼 있으면 코사인 유사도를, 아니면(또는 벡터가
+/// 없거나 호출이 실패하면) 키워드 매칭 건수를 쓴다.
+///
+/// @trace SPEC: SPEC-036
+/// @trace FR: PRD-036/FR-3
+pub fn route(task_description: &str, top_k: usize) -> DomainRouting {
+    if top_k > 0 {
+        if let Some(embedder) = installed_embedder() {
+            match rank_by_embedding(embedder.as_ref(), task_description) {
+                | Ok(scored) if !scored.is_empty() => {
+                    let (domains, scores) = scored
+                        .into_iter()
+                        .filter(|(_, score)| *score >= MIN_EMBEDDING_SIMILARITY)
+                        .take(top_k)
+                        .map(|(d, score)| (d, score as f64))
+                        .unzip();
+                    return DomainRouting {
+                        method: "embedding".into(),
+                        domains,
+                        scores,
+                    };
+                },
+                | Ok(_) => {},
+                | Err(e) => eprintln!("[warn] 임베딩 라우팅 실패: {e} — 키워드 라우팅으로 폴백"),
+            }
+        }
+    }
+    route_by_keywords(task_description, top_k)
+}
+
+/// 임베딩 없이 키워드 매칭 건수만으로 고르는 `route`. 라우터가 꺼져 있어도
+/// 주 도메인은 필요하므로, 이 경로는 임베딩 HTTP 호출 없이 쓸 수 있다.
+///
+/// @trace SPEC: SPEC-036
+pub fn route_by_keywords(task_description: &str, top_k: usize) -> DomainRouting {
+    let (domains, scores) = keyword_scores(task_description, top_k).into_iter().map(|(d, hits)| (d, hits as f64)).unzip();
+    DomainRouting {
+        method: "keyword".into(),
+        domains,
+        scores,
+    }
+}
+
+/// 상위 `top_k` 도메인 이름만 반환하는 `route` 축약형. 매칭이 없으면 빈 Vec.
+pub fn select_domains(task_description: &str, top_k: usize) -> Vec<String> { route(task_description, top_k).domains }
+
 /// task 설명에서 각 도메인의 키워드 매칭 건수를 센 뒤 상위 `top_k` 개만
 /// 반환한다. 동률은 도메인 이름 정렬(stable). 매칭 0건이면 빈 Vec.
-pub fn select_domains(task_description: &str, top_k: usize) -> Vec<String> {
+fn keyword_scores(task_description: &str, top_k: usize) -> Vec<(String, usize)> {
     if top_k == 0 {
         return Vec::new();
     }
@@ -179,7 +400,8 @@ pub fn select_domains(task_description: &str, top_k: usize) -> Vec<String> {
         .collect();
     // hits 내림차순, 동률이면 도메인 이름 사전순(결정론적)
     scored.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
-    scored.into_iter().take(top_k).map(|(d, _)| d).collect()
+    scored.truncate(top_k);
+    scored
 }
 
 #[cfg(test)]
@@ -266,4 +488,149 @@ mod tests {
         let r2 = select_domains("환자 처방전", 1);
         assert!(r2.is_empty(), "갱신된 키워드 반영");
     }
+
+    /// 개념 단어 수로 2차원 벡터를 만드는 결정론적 임베더.
+    /// [금융 개념 수, 고객응대 개념 수].
+    struct ConceptEmbedder {
+        fail: bool,
+        embedded: std::sync::atomic::AtomicUsize,
+    }
+
+    impl ConceptEmbedder {
+        fn new(fail: bool) -> Arc<Self> {
+            Arc::new(Self {
+                fail,
+                embedded: Default::default(),
+            })
+        }
+    }
+
+    impl Embedder for ConceptEmbedder {
+        fn model(&self) -> &str { "concept-test" }
+
+        fn embed(&self, texts: &[String]) -> anyhow::Result<Vec<Vec<f32>>> {
+            if self.fail {
+                anyhow::bail!("embedding endpoint down");
+            }
+            self.embedded.fetch_add(texts.len(), std::sync::atomic::Ordering::SeqCst);
+            let count = |t: &str, words: &[&str]| words.iter().filter(|w| t.contains(*w)).count() as f32;
+            Ok(texts
+                .iter()
+                .map(|t| {
+                    let t = t.to_lowercase();
+                    vec![
+                        count(&t, &["savings", "grow", "저축", "이자", "금리", "대출"]),
+                        count(&t, &["package", "broken", "배송", "환불", "고객"]),
+                    ]
+                })
+                .collect())
+        }
+    }
+
+    /// 테스트 끝에서 임베더와 캐시를 원복한다.
+    struct ResetEmbedder;
+
+    impl Drop for ResetEmbedder {
+        fn drop(&mut self) {
+            install_embedder(None);
+            invalidate_cache();
+        }
+    }
+
+    /// @trace TC: SPEC-036/TC-4
+    #[test]
+    fn spec036_tc_4_paraphrase_routes_by_embedding() {
+        let _g = TEST_LOCK.lock().unwrap();
+        let _reset = ResetEmbedder;
+        prime_with_defaults();
+        // 키워드가 하나도 없는 영어 의역 task
+        let task = "How much will my savings grow over three years?";
+        assert!(select_domains(task, 1).is_empty());
+
+        install_embedder(Some(ConceptEmbedder::new(false)));
+        prime_vector_cache(HashMap::from([
+            ("financial".to_string(), vec![vec![1.0, 0.0]]),
+            ("customer_service".to_string(), vec![vec![0.0, 1.0]]),
+        ]));
+        let r = route(task, 2);
+        assert_eq!(r.method, "embedding");
+        assert_eq!(r.domains, vec!["financial".to_string()], "유사도 미달 도메인은 제외");
+        assert!(r.scores[0] > 0.9);
+
+        assert!(route("오늘 날씨가 좋네요", 2).domains.is_empty());
+    }
+
+    /// 라우터가 꺼진 실행의 주 도메인 선택은 임베딩을 호출하지 않는다.
+    ///
+    /// @trace TC: SPEC-036/TC-4
+    #[test]
+    fn spec036_keyword_route_skips_embedder() {
+        let _g = TEST_LOCK.lock().unwrap();
+        let _reset = ResetEmbedder;
+        prime_with_defaults();
+        let embedder = ConceptEmbedder::new(false);
+        install_embedder(Some(embedder.clone()));
+        prime_vector_cache(HashMap::from([("financial".to_string(), vec![vec![1.0, 0.0]])]));
+
+        let r = route_by_keywords("대출 이자 계산", 1);
+        assert_eq!(r.method, "keyword");
+        assert_eq!(r.domains, vec!["financial".to_string()]);
+        assert_eq!(embedder.embedded.load(std::sync::atomic::Ordering::SeqCst), 0);
+    }
+
+    /// @trace TC: SPEC-036/TC-5
+    #[test]
+    fn spec036_tc_5_embedding_failure_falls_back_to_keywords() {
+        let _g = TEST_LOCK.lock().unwrap();
+        let _reset = ResetEmbedder;
+        prime_with_defaults();
+        install_embedder(Some(ConceptEmbedder::new(true)));
+        prime_vector_cache(HashMap::from([("financial".to_string(), vec![vec![1.0, 0.0]])]));
+
+        let r = route("고객이 주문한 상품 환불을 요청했습니다", 1);
+        assert_eq!(r.method, "keyword");
+        assert_eq!(r.domains, vec!["customer_service".to_string()]);
+        assert_eq!(r.scores.len(), 1);
+
+        // 벡터가 없으면(빈 캐시) 역시 키워드
+        install_embedder(Some(ConceptEmbedder::new(false)));
+        prime_vector_cache(HashMap::new());
+        assert_eq!(route("대출 이자 계산", 1).method, "keyword");
+    }
+
+    /// @trace TC: SPEC-036/TC-6
+    #[tokio::test(flavor = "multi_thread")]
+    async fn spec036_tc_6_sync_reuses_stored_vectors() {
+        let store = SqliteStore::open_in_memory_for_loader().await.unwrap();
+        store.seed_from_embedded().await.unwrap();
+        let embedder = ConceptEmbedder::new(false);
+
+        let first = sync_domain_embeddings(&store, embedder.as_ref()).unwrap();
+        let embedded = embedder.embedded.load(std::sync::atomic::Ordering::SeqCst);
+        assert!(embedded > 0);
+        assert!(first.contains_key("financial") && first.contains_key("customer_service"));
+
+        // 변경이 없으면 다시 임베딩하지 않는다.
+        let second = sync_domain_embeddings(&store, embedder.as_ref()).unwrap();
+        assert_eq!(embedder.embedded.load(std::sync::atomic::Ordering::SeqCst), embedded);
+        assert_eq!(second["financial"].len(), first["financial"].len());
+
+        // 설명이 바뀐 도메인은 바뀐 텍스트 하나만 임베딩하고 이전 행은 지운다.
+        store.update_domain("financial", "예금과 대출 이자 계산").await.unwrap();
+        let third = sync_domain_embeddings(&store, embedder.as_ref()).unwrap();
+        assert_eq!(embedder.embedded.load(std::sync::atomic::Ordering::SeqCst), embedded + 1);
+        assert_eq!(third["financial"].len(), first["financial"].len());
+        let rows = store.list_domain_embeddings("concept-test").await.unwrap();
+        assert!(rows.iter().any(|r| r.kind == "description" && r.text == "예금과 대출 이자 계산"));
+        assert_eq!(rows.iter().filter(|r| r.domain == "financial" && r.kind == "description").count(), 1);
+
+        // 텍스트가 모두 비워진 도메인의 행은 지워진다.
+        store.insert_domain("scratch", "임시 도메인").await.unwrap();
+        assert!(sync_domain_embeddings(&store, embedder.as_ref()).unwrap().contains_key("scratch"));
+        store.update_domain("scratch", "").await.unwrap();
+        let fourth = sync_domain_embeddings(&store, embedder.as_ref()).unwrap();
+        assert!(!fourth.contains_key("scratch"));
+        let rows = store.list_domain_embeddings("concept-test").await.unwrap();
+        assert!(rows.iter().all(|r| r.domain != "scratch"));
+    }
 }
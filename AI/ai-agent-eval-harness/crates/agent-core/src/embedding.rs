@@ -0,0 +1,205 @@
+// =============================================================================
+// @trace SPEC-036
+// @trace PRD: PRD-036
+// @trace FR: PRD-036/FR-1, PRD-036/FR-2
+// @trace file-type: impl
+// =============================================================================
+//
+// 도메인 라우터용 텍스트 임베딩. `Embedder` 트레이트는 동기 호출이며
+// (라우터가 동기 `select_domains` 경로에서 부른다), HTTP 구현은
+// Azure OpenAI / OpenAI 호환 서버 / Ollama 의 임베딩 API 를 지원한다.
+
+use crate::config::{EmbeddingConfig,
+                    LlmProvider};
+use anyhow::Result;
+use serde::Deserialize;
+
+/// 텍스트 목록을 같은 순서의 벡터 목록으로 변환한다.
+pub trait Embedder: Send + Sync {
+    /// 벡터를 저장할 때 함께 기록하는 모델 이름. 모델이 바뀌면 저장된
+    /// 벡터는 재사용하지 않는다.
+    fn model(&self) -> &str;
+
+    fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>>;
+}
+
+/// 코사인 유사도. 길이가 다르거나 영벡터면 0.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let (mut dot, mut na, mut nb) = (0.0f32, 0.0f32, 0.0f32);
+    for (x, y) in a.iter().zip(b) {
+        dot += x * y;
+        na += x * x;
+        nb += y * y;
+    }
+    if na == 0.0 || nb == 0.0 { 0.0 } else { dot / (na.sqrt() * nb.sqrt()) }
+}
+
+#[derive(Deserialize)]
+struct OpenAiEmbeddingResponse {
+    data: Vec<OpenAiEmbedding>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiEmbedding {
+    #[serde(default)]
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+#[derive(Deserialize)]
+struct OllamaEmbedResponse {
+    embeddings: Vec<Vec<f32>>,
+}
+
+/// 임베딩 API 클라이언트.
+pub struct HttpEmbedder {
+    config: EmbeddingConfig,
+    http: reqwest::Client,
+}
+
+impl HttpEmbedder {
+    pub fn new(config: EmbeddingConfig) -> Self {
+        Self {
+            config,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// 설정된 provider 의 임베딩 API 를 한 번 호출한다.
+    ///
+    /// @trace SPEC: SPEC-036
+    /// @trace FR: PRD-036/FR-1
+    pub async fn embed_async(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let base = self.config.base_url.trim_end_matches('/');
+        let (url, builder) = match self.config.provider {
+            | LlmProvider::AzureOpenAi => {
+                let url = format!(
+                    "{}/openai/deployments/{}/embeddings?api-version={}",
+                    base,
+                    self.config.model,
+                    self.config.api_version.as_deref().unwrap_or("2024-12-01-preview"),
+                );
+                let builder = self
+                    .http
+                    .post(&url)
+                    .header("api-key", self.config.api_key.as_deref().unwrap_or_default())
+                    .json(&serde_json::json!({ "input": texts }));
+                (url, builder)
+            },
+            | LlmProvider::OpenAi => {
+                let url = format!("{base}/v1/embeddings");
+                let mut builder = self.http.post(&url).json(&serde_json::json!({ "model": self.config.model, "input": texts }));
+                if let Some(key) = &self.config.api_key {
+                    builder = builder.bearer_auth(key);
+                }
+                (url, builder)
+            },
+            | LlmProvider::Ollama => {
+                let url = format!("{base}/api/embed");
+                let builder = self.http.post(&url).json(&serde_json::json!({ "model": self.config.model, "input": texts }));
+                (url, builder)
+            },
+        };
+
+        let resp = builder.send().await?;
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            anyhow::bail!("embedding {} at {}: {}", status, url, body);
+        }
+        let vectors = match self.config.provider {
+            | LlmProvider::Ollama => resp.json::<OllamaEmbedResponse>().await?.embeddings,
+            | _ => {
+                let mut data = resp.json::<OpenAiEmbeddingResponse>().await?.data;
+                data.sort_by_key(|d| d.index);
+                data.into_iter().map(|d| d.embedding).collect()
+            },
+        };
+        if vectors.len() != texts.len() {
+            anyhow::bail!("embedding 응답 개수 불일치: 요청 {} / 응답 {}", texts.len(), vectors.len());
+        }
+        Ok(vectors)
+    }
+}
+
+impl Embedder for HttpEmbedder {
+    fn model(&self) -> &str { &self.config.model }
+
+    fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        match tokio::runtime::Handle::try_current() {
+            | Ok(handle) => tokio::task::block_in_place(|| handle.block_on(self.embed_async(texts))),
+            | Err(_) => tokio::runtime::Builder::new_current_thread().enable_all().build()?.block_on(self.embed_async(texts)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-036
+    // @trace PRD: PRD-036
+    // @trace FR: PRD-036/FR-1
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use test_support::spawn_json_server;
+
+    fn config(provider: LlmProvider, base_url: String) -> EmbeddingConfig {
+        EmbeddingConfig {
+            provider,
+            base_url,
+            api_key: Some("sk-local".into()),
+            model: "text-embedding-3-small".into(),
+            api_version: None,
+        }
+    }
+
+    /// @trace TC: SPEC-036/TC-1
+    #[tokio::test]
+    async fn spec036_tc_1_openai_embeddings_ordered_by_index() {
+        let (url, rx) = spawn_json_server(vec![serde_json::json!({
+            "data": [
+                {"index": 1, "embedding": [0.0, 1.0]},
+                {"index": 0, "embedding": [1.0, 0.0]}
+            ]
+        })]);
+        let embedder = HttpEmbedder::new(config(LlmProvider::OpenAi, url));
+
+        let v = embedder.embed_async(&["a".into(), "b".into()]).await.unwrap();
+        assert_eq!(v, vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+
+        let req = rx.recv().unwrap().raw;
+        assert!(req.starts_with("POST /v1/embeddings "), "{req}");
+        assert!(req.to_ascii_lowercase().contains("authorization: bearer sk-local"));
+        assert!(req.contains("\"model\":\"text-embedding-3-small\""));
+    }
+
+    /// @trace TC: SPEC-036/TC-2
+    #[tokio::test]
+    async fn spec036_tc_2_ollama_embed_and_count_mismatch() {
+        let (url, rx) = spawn_json_server(vec![serde_json::json!({"embeddings": [[0.5, 0.5]]})]);
+        let embedder = HttpEmbedder::new(config(LlmProvider::Ollama, url));
+        let v = embedder.embed_async(&["a".into()]).await.unwrap();
+        assert_eq!(v, vec![vec![0.5, 0.5]]);
+        assert!(rx.recv().unwrap().raw.starts_with("POST /api/embed "));
+
+        let (url, _rx) = spawn_json_server(vec![serde_json::json!({"embeddings": [[0.5, 0.5]]})]);
+        let embedder = HttpEmbedder::new(config(LlmProvider::Ollama, url));
+        assert!(embedder.embed_async(&["a".into(), "b".into()]).await.is_err());
+    }
+
+    #[test]
+    fn cosine_similarity_basics() {
+        assert!((cosine_similarity(&[1.0, 0.0], &[2.0, 0.0]) - 1.0).abs() < 1e-6);
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[0.0, 3.0]), 0.0);
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), 0.0);
+        assert_eq!(cosine_similarity(&[1.0], &[1.0, 0.0]), 0.0);
+    }
+}
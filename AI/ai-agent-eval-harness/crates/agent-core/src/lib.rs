@@ -1,5 +1,7 @@
 pub mod agent;
 pub mod config;
+pub mod embedding;
 pub mod domain_router;
 pub mod external_tools;
 pub mod llm_client;
+pub mod mcp_tools;
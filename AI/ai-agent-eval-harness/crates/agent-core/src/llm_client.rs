@@ -1,4 +1,8 @@
-use crate::config::AzureOpenAiConfig;
+use crate::config::{AzureOpenAiConfig,
+                    LlmConfig,
+                    OllamaConfig,
+                    OpenAiCompatibleConfig};
+use agent_models::models::TokenUsage;
 use anyhow::Result;
 use serde::{Deserialize,
             Serialize};
@@ -185,77 +189,312 @@ impl Message {
     }
 }
 
+/// Azure / OpenAI 호환 chat-completions 요청 본문.
 #[derive(Debug, Serialize)]
-struct ChatRequest {
+struct ChatRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<&'a str>,
     messages: Vec<Message>,
     #[serde(skip_serializing_if = "Option::is_none")]
     temperature: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     max_completion_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<&'a [serde_json::Value]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<&'static str>,
 }
 
 #[derive(Debug, Deserialize)]
 struct ChatResponse {
     choices: Vec<Choice>,
+    #[serde(default)]
+    usage: Option<ChatUsage>,
 }
 
 #[derive(Debug, Deserialize)]
 struct Choice {
-    message: Message,
+    message: ResponseMessage,
+}
+
+/// 응답 메시지. 도구 호출만 있는 응답은 `content` 가 null 이다.
+#[derive(Debug, Deserialize)]
+struct ResponseMessage {
+    #[serde(default)]
+    content: Option<String>,
+    #[serde(default)]
+    tool_calls: Vec<RawToolCall>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawToolCall {
+    function: RawFunction,
+}
+
+/// OpenAI 는 `arguments` 를 JSON 문자열로, Ollama 는 객체로 준다.
+#[derive(Debug, Deserialize)]
+struct RawFunction {
+    name: String,
+    #[serde(default)]
+    arguments: serde_json::Value,
 }
 
-/// Azure OpenAI LLM 클라이언트
+impl From<RawToolCall> for NativeToolCall {
+    fn from(raw: RawToolCall) -> Self {
+        let args = match raw.function.arguments {
+            | serde_json::Value::String(s) => serde_json::from_str(&s).unwrap_or(serde_json::Value::Null),
+            | v => v,
+        };
+        NativeToolCall {
+            name: raw.function.name,
+            arguments: args
+                .as_object()
+                .map(|o| o.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatUsage {
+    #[serde(default)]
+    prompt_tokens: u64,
+    #[serde(default)]
+    completion_tokens: u64,
+    #[serde(default)]
+    total_tokens: u64,
+}
+
+/// Ollama `/api/chat` 요청 본문 (스트리밍 비활성).
+#[derive(Debug, Serialize)]
+struct OllamaChatRequest<'a> {
+    model: &'a str,
+    messages: Vec<Message>,
+    stream: bool,
+    options: OllamaOptions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<&'a [serde_json::Value]>,
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaOptions {
+    temperature: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_predict: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaChatResponse {
+    message: ResponseMessage,
+    #[serde(default)]
+    prompt_eval_count: Option<u64>,
+    #[serde(default)]
+    eval_count: Option<u64>,
+}
+
+/// SPEC-033: 응답 본문과 provider 가 보고한 토큰 사용량.
+/// SPEC-034: `tool_calls` 는 도구 정의를 함께 보낸 요청에서만 채워진다.
+#[derive(Debug, Clone)]
+pub struct LlmResponse {
+    pub content: String,
+    pub usage: Option<TokenUsage>,
+    pub tool_calls: Vec<NativeToolCall>,
+}
+
+/// SPEC-034: 모델이 구조화된 `tool_calls` 로 요청한 도구 호출 1건.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NativeToolCall {
+    pub name: String,
+    pub arguments: HashMap<String, serde_json::Value>,
+}
+
+/// `ToolRegistry::get_tools_metadata` 항목을 chat-completions `tools`
+/// 정의(`{"type": "function", "function": {...}}`)로 변환한다.
+///
+/// @trace SPEC: SPEC-034
+/// @trace FR: PRD-034/FR-1
+pub fn tool_definitions(tools_metadata: &[serde_json::Value]) -> Vec<serde_json::Value> {
+    tools_metadata
+        .iter()
+        .filter_map(|meta| {
+            let name = meta.get("name").and_then(|v| v.as_str())?;
+            let parameters = meta
+                .get("parameters_schema")
+                .filter(|v| v.is_object())
+                .cloned()
+                .unwrap_or_else(|| serde_json::json!({"type": "object", "properties": {}}));
+            Some(serde_json::json!({
+                "type": "function",
+                "function": {
+                    "name": name,
+                    "description": meta.get("description").and_then(|v| v.as_str()).unwrap_or(""),
+                    "parameters": parameters,
+                }
+            }))
+        })
+        .collect()
+}
+
+/// LLM 클라이언트. Azure OpenAI, OpenAI 호환 서버, Ollama 를 지원한다.
 pub struct LlmClient {
-    config: AzureOpenAiConfig,
+    config: LlmConfig,
     http: reqwest::Client,
 }
 
 impl LlmClient {
-    pub fn new(config: AzureOpenAiConfig) -> Self {
+    pub fn new(config: impl Into<LlmConfig>) -> Self {
         Self {
-            config,
+            config: config.into(),
             http: reqwest::Client::new(),
         }
     }
 
-    pub async fn invoke(&self, messages: Vec<Message>) -> Result<String> {
+    pub fn config(&self) -> &LlmConfig { &self.config }
+
+    pub async fn invoke(&self, messages: Vec<Message>) -> Result<String> { Ok(self.invoke_with_usage(messages).await?.content) }
+
+    /// 설정된 provider 로 요청을 보내고 응답과 토큰 사용량을 함께 반환한다.
+    ///
+    /// @trace SPEC: SPEC-033
+    /// @trace FR: PRD-033/FR-1, PRD-033/FR-3
+    pub async fn invoke_with_usage(&self, messages: Vec<Message>) -> Result<LlmResponse> { self.send(messages, None).await }
+
+    /// 네이티브 도구 호출 요청. `tools` 는 `tool_definitions` 결과이며,
+    /// 모델이 고른 호출은 `LlmResponse::tool_calls` 로 돌려준다.
+    ///
+    /// @trace SPEC: SPEC-034
+    /// @trace FR: PRD-034/FR-1, PRD-034/FR-2
+    pub async fn invoke_with_tools(&self, messages: Vec<Message>, tools: &[serde_json::Value]) -> Result<LlmResponse> { self.send(messages, Some(tools)).await }
+
+    async fn send(&self, messages: Vec<Message>, tools: Option<&[serde_json::Value]>) -> Result<LlmResponse> {
+        match &self.config {
+            | LlmConfig::AzureOpenAi(c) => self.invoke_azure(c, messages, tools).await,
+            | LlmConfig::OpenAi(c) => self.invoke_openai(c, messages, tools).await,
+            | LlmConfig::Ollama(c) => self.invoke_ollama(c, messages, tools).await,
+        }
+    }
+
+    async fn invoke_azure(&self, config: &AzureOpenAiConfig, messages: Vec<Message>, tools: Option<&[serde_json::Value]>) -> Result<LlmResponse> {
         let url = format!(
             "{}/openai/deployments/{}/chat/completions?api-version={}",
-            self.config.azure_openai_endpoint.trim_end_matches('/'),
-            self.config.azure_openai_deployment,
-            self.config.azure_openai_api_version,
+            config.azure_openai_endpoint.trim_end_matches('/'),
+            config.azure_openai_deployment,
+            config.azure_openai_api_version,
         );
 
         // gpt-5.x 계열 배포는 temperature가 기본값(1.0)일 때만 허용되므로, 기본값이면
         // 필드 자체를 생략한다.
-        let temperature = if (self.config.temperature - 1.0).abs() < f64::EPSILON {
+        let temperature = if (config.temperature - 1.0).abs() < f64::EPSILON {
             None
         } else {
-            Some(self.config.temperature)
+            Some(config.temperature)
         };
 
         let req = ChatRequest {
+            model: None,
             messages,
             temperature,
-            max_completion_tokens: self.config.max_tokens,
+            max_completion_tokens: config.max_tokens,
+            max_tokens: None,
+            tools,
+            tool_choice: tools.map(|_| "auto"),
         };
 
-        let resp = self
-            .http
-            .post(&url)
-            .header("api-key", &self.config.azure_openai_api_key)
-            .json(&req)
-            .send()
-            .await?;
+        let resp = self.http.post(&url).header("api-key", &config.azure_openai_api_key).json(&req).send().await?;
+        Self::read_chat_response("Azure OpenAI", &url, resp).await
+    }
+
+    async fn invoke_openai(&self, config: &OpenAiCompatibleConfig, messages: Vec<Message>, tools: Option<&[serde_json::Value]>) -> Result<LlmResponse> {
+        let url = format!("{}/v1/chat/completions", config.base_url.trim_end_matches('/'));
 
+        // 로컬 서버 호환을 위해 max_completion_tokens 대신 max_tokens 사용.
+        let req = ChatRequest {
+            model: Some(&config.model),
+            messages,
+            temperature: Some(config.temperature),
+            max_completion_tokens: None,
+            max_tokens: config.max_tokens,
+            tools,
+            tool_choice: tools.map(|_| "auto"),
+        };
+
+        let mut builder = self.http.post(&url).json(&req);
+        if let Some(key) = &config.api_key {
+            builder = builder.bearer_auth(key);
+        }
+        let resp = builder.send().await?;
+        Self::read_chat_response("OpenAI", &url, resp).await
+    }
+
+    async fn invoke_ollama(&self, config: &OllamaConfig, messages: Vec<Message>, tools: Option<&[serde_json::Value]>) -> Result<LlmResponse> {
+        let url = format!("{}/api/chat", config.host.trim_end_matches('/'));
+        let req = OllamaChatRequest {
+            model: &config.model,
+            messages,
+            stream: false,
+            options: OllamaOptions {
+                temperature: config.temperature,
+                num_predict: config.max_tokens,
+            },
+            tools,
+        };
+
+        let resp = self.http.post(&url).json(&req).send().await?;
         let status = resp.status();
         if !status.is_success() {
             let body = resp.text().await.unwrap_or_default();
-            anyhow::bail!("Azure OpenAI {} at {}: {}", status, url, body);
+            anyhow::bail!("Ollama {} at {}: {}", status, url, body);
+        }
+
+        let parsed: OllamaChatResponse = resp.json().await?;
+        // Ollama 는 prompt/eval 카운트를 따로 주므로 합계를 직접 계산한다.
+        let usage = match (parsed.prompt_eval_count, parsed.eval_count) {
+            | (None, None) => None,
+            | (p, c) => {
+                let (p, c) = (p.unwrap_or(0), c.unwrap_or(0));
+                Some(TokenUsage {
+                    prompt_tokens: p,
+                    completion_tokens: c,
+                    total_tokens: p + c,
+                })
+            },
+        };
+        Ok(LlmResponse {
+            content: parsed.message.content.unwrap_or_default(),
+            usage,
+            tool_calls: parsed.message.tool_calls.into_iter().map(NativeToolCall::from).collect(),
+        })
+    }
+
+    async fn read_chat_response(label: &str, url: &str, resp: reqwest::Response) -> Result<LlmResponse> {
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            anyhow::bail!("{} {} at {}: {}", label, status, url, body);
         }
 
         let parsed: ChatResponse = resp.json().await?;
-        Ok(parsed.choices.into_iter().next().map(|c| c.message.content).unwrap_or_default())
+        let usage = parsed.usage.map(|u| TokenUsage {
+            prompt_tokens: u.prompt_tokens,
+            completion_tokens: u.completion_tokens,
+            total_tokens: if u.total_tokens > 0 {
+                u.total_tokens
+            } else {
+                u.prompt_tokens + u.completion_tokens
+            },
+        });
+        let message = parsed.choices.into_iter().next().map(|c| c.message);
+        let (content, tool_calls) = match message {
+            | Some(m) => (m.content.unwrap_or_default(), m.tool_calls.into_iter().map(NativeToolCall::from).collect()),
+            | None => (String::new(), Vec::new()),
+        };
+        Ok(LlmResponse {
+            content,
+            usage,
+            tool_calls,
+        })
     }
 
     /// LLM 응답에서 JSON 파싱
@@ -542,3 +781,243 @@ mod spec025_tests {
         assert_eq!(bundle.perceive_system, BOOTSTRAP_PERCEIVE_SYSTEM);
     }
 }
+
+#[cfg(test)]
+mod spec033_tests {
+    // =============================================================================
+    // @trace SPEC-033
+    // @trace PRD: PRD-033
+    // @trace FR: PRD-033/FR-1, PRD-033/FR-3
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use crate::config::LlmProvider;
+    use test_support::spawn_json_server;
+
+    /// @trace TC: SPEC-033/TC-1
+    /// @trace FR: PRD-033/FR-1, PRD-033/FR-3
+    #[tokio::test]
+    async fn spec033_tc_1_openai_compatible_uses_v1_path_and_bearer() {
+        let (url, rx) = spawn_json_server(vec![serde_json::json!({
+            "choices": [{"message": {"role": "assistant", "content": "hi"}}],
+            "usage": {"prompt_tokens": 12, "completion_tokens": 3, "total_tokens": 15}
+        })]);
+        let client = LlmClient::new(OpenAiCompatibleConfig {
+            base_url: format!("{url}/"),
+            api_key: Some("sk-local".into()),
+            model: "qwen2.5".into(),
+            temperature: 0.0,
+            max_tokens: Some(64),
+        });
+
+        let resp = client.invoke_with_usage(vec![Message::user("hello")]).await.unwrap();
+        assert_eq!(resp.content, "hi");
+        assert_eq!(
+            resp.usage,
+            Some(TokenUsage {
+                prompt_tokens: 12,
+                completion_tokens: 3,
+                total_tokens: 15,
+            })
+        );
+
+        let req = rx.recv().unwrap().raw;
+        assert!(req.starts_with("POST /v1/chat/completions "), "{req}");
+        assert!(req.to_ascii_lowercase().contains("authorization: bearer sk-local"));
+        assert!(req.contains(r#""model":"qwen2.5""#));
+        assert!(req.contains(r#""max_tokens":64"#));
+    }
+
+    /// @trace TC: SPEC-033/TC-2
+    /// @trace FR: PRD-033/FR-1, PRD-033/FR-3
+    #[tokio::test]
+    async fn spec033_tc_2_ollama_native_chat_and_eval_counts() {
+        let (url, rx) = spawn_json_server(vec![serde_json::json!({
+            "model": "llama3.1",
+            "message": {"role": "assistant", "content": "{\"ok\": true}"},
+            "done": true,
+            "prompt_eval_count": 20,
+            "eval_count": 7
+        })]);
+        let client = LlmClient::new(OllamaConfig {
+            host: url,
+            model: "llama3.1".into(),
+            temperature: 0.2,
+            max_tokens: None,
+        });
+
+        let resp = client.invoke_with_usage(vec![Message::user("hello")]).await.unwrap();
+        assert_eq!(resp.content, "{\"ok\": true}");
+        assert_eq!(resp.usage.map(|u| u.total_tokens), Some(27));
+
+        let req = rx.recv().unwrap().raw;
+        assert!(req.starts_with("POST /api/chat "), "{req}");
+        assert!(req.contains(r#""stream":false"#));
+        assert!(!req.to_ascii_lowercase().contains("authorization:"));
+    }
+
+    /// Azure 경로/헤더는 그대로이고, usage 가 없으면 None.
+    ///
+    /// @trace TC: SPEC-033/TC-3
+    /// @trace FR: PRD-033/FR-1
+    #[tokio::test]
+    async fn spec033_tc_3_azure_keeps_deployment_path_and_api_key() {
+        let (url, rx) = spawn_json_server(vec![serde_json::json!({
+            "choices": [{"message": {"role": "assistant", "content": "ok"}}]
+        })]);
+        let client = LlmClient::new(AzureOpenAiConfig {
+            azure_openai_endpoint: url,
+            azure_openai_api_key: "az-key".into(),
+            azure_openai_deployment: "gpt-4o-mini".into(),
+            azure_openai_api_version: "2024-12-01-preview".into(),
+            azure_openai_region: "local".into(),
+            temperature: 1.0,
+            max_tokens: None,
+        });
+
+        let resp = client.invoke_with_usage(vec![Message::user("hello")]).await.unwrap();
+        assert_eq!(resp.content, "ok");
+        assert!(resp.usage.is_none());
+
+        let req = rx.recv().unwrap().raw;
+        assert!(req.starts_with("POST /openai/deployments/gpt-4o-mini/chat/completions?api-version=2024-12-01-preview "));
+        assert!(req.to_ascii_lowercase().contains("api-key: az-key"));
+    }
+
+    /// @trace TC: SPEC-033/TC-4
+    /// @trace FR: PRD-033/FR-2
+    #[test]
+    fn spec033_tc_4_provider_names_roundtrip() {
+        for p in LlmProvider::ALL {
+            assert_eq!(p.as_str().parse::<LlmProvider>().unwrap(), p);
+        }
+        assert_eq!("OpenAI".parse::<LlmProvider>().unwrap(), LlmProvider::OpenAi);
+        assert!("bedrock".parse::<LlmProvider>().is_err());
+    }
+}
+
+#[cfg(test)]
+mod spec034_tests {
+    // =============================================================================
+    // @trace SPEC-034
+    // @trace PRD: PRD-034
+    // @trace FR: PRD-034/FR-1, PRD-034/FR-2
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use test_support::spawn_json_server;
+
+    fn interest_tool_meta() -> Vec<serde_json::Value> {
+        vec![serde_json::json!({
+            "name": "financial__calculate_simple_interest",
+            "description": "단리 계산",
+            "domain": "financial",
+            "parameters_schema": {
+                "type": "object",
+                "properties": {"principal": {"type": "number"}},
+                "required": ["principal"]
+            }
+        })]
+    }
+
+    /// @trace TC: SPEC-034/TC-1
+    /// @trace FR: PRD-034/FR-1
+    #[test]
+    fn spec034_tc_1_tool_definitions_from_metadata() {
+        let mut meta = interest_tool_meta();
+        meta.push(serde_json::json!({"name": "no_schema", "description": "스키마 없음"}));
+        meta.push(serde_json::json!({"description": "이름 없음"}));
+
+        let defs = tool_definitions(&meta);
+        assert_eq!(defs.len(), 2, "이름 없는 항목은 제외");
+        assert_eq!(defs[0]["type"], "function");
+        assert_eq!(defs[0]["function"]["name"], "financial__calculate_simple_interest");
+        assert_eq!(defs[0]["function"]["parameters"]["required"][0], "principal");
+        assert_eq!(defs[1]["function"]["parameters"]["type"], "object");
+    }
+
+    /// OpenAI 형식: `arguments` 는 JSON 문자열이고, 도구 호출 응답의
+    /// content 는 null.
+    ///
+    /// @trace TC: SPEC-034/TC-2
+    /// @trace FR: PRD-034/FR-1, PRD-034/FR-2
+    #[tokio::test]
+    async fn spec034_tc_2_openai_tool_calls_parsed_from_string_arguments() {
+        let (url, rx) = spawn_json_server(vec![serde_json::json!({
+            "choices": [{"message": {
+                "role": "assistant",
+                "content": null,
+                "tool_calls": [{
+                    "id": "call_1",
+                    "type": "function",
+                    "function": {"name": "financial__calculate_simple_interest", "arguments": "{\"principal\": 1000000}"}
+                }]
+            }}]
+        })]);
+        let client = LlmClient::new(OpenAiCompatibleConfig {
+            base_url: url,
+            api_key: None,
+            model: "qwen2.5".into(),
+            temperature: 0.0,
+            max_tokens: None,
+        });
+
+        let defs = tool_definitions(&interest_tool_meta());
+        let resp = client.invoke_with_tools(vec![Message::user("이자 계산")], &defs).await.unwrap();
+        assert_eq!(resp.content, "");
+        assert_eq!(
+            resp.tool_calls,
+            vec![NativeToolCall {
+                name: "financial__calculate_simple_interest".into(),
+                arguments: HashMap::from([("principal".to_string(), serde_json::json!(1000000))]),
+            }]
+        );
+
+        let body: serde_json::Value = serde_json::from_str(&rx.recv().unwrap().body).unwrap();
+        assert_eq!(body["tools"][0]["function"]["name"], "financial__calculate_simple_interest");
+        assert_eq!(body["tool_choice"], "auto");
+    }
+
+    /// Ollama 형식: `arguments` 는 객체. 도구 없이 보낸 요청에는 `tools`
+    /// 키가 없다.
+    ///
+    /// @trace TC: SPEC-034/TC-3
+    /// @trace FR: PRD-034/FR-2
+    #[tokio::test]
+    async fn spec034_tc_3_ollama_tool_calls_parsed_from_object_arguments() {
+        let (url, rx) = spawn_json_server(vec![serde_json::json!({
+            "message": {
+                "role": "assistant",
+                "content": "",
+                "tool_calls": [{"function": {"name": "financial__calculate_simple_interest", "arguments": {"principal": 5}}}]
+            },
+            "done": true
+        })]);
+        let client = LlmClient::new(OllamaConfig {
+            host: url,
+            model: "llama3.1".into(),
+            temperature: 0.0,
+            max_tokens: None,
+        });
+        let defs = tool_definitions(&interest_tool_meta());
+        let resp = client.invoke_with_tools(vec![Message::user("x")], &defs).await.unwrap();
+        assert_eq!(resp.tool_calls.len(), 1);
+        assert_eq!(resp.tool_calls[0].arguments["principal"], serde_json::json!(5));
+        let body: serde_json::Value = serde_json::from_str(&rx.recv().unwrap().body).unwrap();
+        assert!(body["tools"].is_array());
+
+        let (url, rx) = spawn_json_server(vec![serde_json::json!({"message": {"role": "assistant", "content": "done"}})]);
+        let client = LlmClient::new(OllamaConfig {
+            host: url,
+            model: "llama3.1".into(),
+            temperature: 0.0,
+            max_tokens: None,
+        });
+        let resp = client.invoke_with_usage(vec![Message::user("x")]).await.unwrap();
+        assert!(resp.tool_calls.is_empty());
+        let body: serde_json::Value = serde_json::from_str(&rx.recv().unwrap().body).unwrap();
+        assert!(body.get("tools").is_none());
+    }
+}
@@ -0,0 +1,264 @@
+// =============================================================================
+// @trace SPEC-037
+// @trace PRD: PRD-037
+// @trace FR: PRD-037/FR-4, PRD-037/FR-5
+// @trace file-type: impl
+// =============================================================================
+//
+// SPEC-037: 전역 SqliteStore 의 mcp_servers 행마다 MCP 서버에 접속해 도구
+// 목록을 받고, 각 도구를 McpTool 로 ToolRegistry 에 등록한다.
+// PpaAgent::load_all_tools 가 로드마다 호출하므로 접속한 클라이언트는 행
+// 내용을 키로 캐시한다. 행이 바뀌면 다시 접속하고, 사라진 행의 클라이언트는
+// 정리된다(stdio 서버 프로세스도 함께 종료). 접속 실패도 백오프와 함께
+// 캐시해, 죽은 서버에 로드마다 다시 접속하지 않는다.
+
+use data_scenarios::sqlite_store::McpServerRow;
+use execution_tools::{base::BaseTool,
+                      mcp_tool::{McpClient,
+                                 McpTool,
+                                 McpTransport},
+                      registry::ToolRegistry};
+use std::{collections::HashMap,
+          sync::{Arc,
+                 Mutex,
+                 OnceLock},
+          time::{Duration,
+                 Instant}};
+
+/// 접속 실패 후 첫 재시도까지의 대기. 실패가 이어질 때마다 두 배로 늘린다.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(30);
+const MAX_BACKOFF: Duration = Duration::from_secs(600);
+
+/// 한 서버의 캐시 상태. 실패도 캐시해 백오프가 끝나기 전에는 다시 접속하지
+/// 않는다 (도구 로드마다 죽은 서버의 타임아웃을 기다리지 않도록).
+enum Entry {
+    Connected(Vec<Arc<McpTool>>),
+    Failed { retry_at: Instant, backoff: Duration },
+}
+
+/// (domain, name) → (접속 당시의 행, 상태).
+type ClientCache = HashMap<(String, String), (McpServerRow, Entry)>;
+
+static CLIENTS: OnceLock<Mutex<ClientCache>> = OnceLock::new();
+
+fn clients() -> &'static Mutex<ClientCache> { CLIENTS.get_or_init(|| Mutex::new(HashMap::new())) }
+
+fn next_backoff(previous: Option<Duration>) -> Duration { previous.map_or(INITIAL_BACKOFF, |b| (b * 2).min(MAX_BACKOFF)) }
+
+/// 전역 store 가 install 되어 있으면 모든 MCP 서버의 도구를 registry 에
+/// 등록. 없으면 조용히 반환. 접속 실패한 서버는 경고 후 건너뛴다.
+///
+/// @trace SPEC: SPEC-037
+/// @trace FR: PRD-037/FR-5
+pub fn register_mcp_tools_from_db(registry: &mut ToolRegistry) {
+    let Some(store) = data_scenarios::loader::try_installed_store() else {
+        return;
+    };
+    let result = match tokio::runtime::Handle::try_current() {
+        | Ok(handle) => {
+            let store = store.clone();
+            tokio::task::block_in_place(|| handle.block_on(async move { store.list_mcp_servers().await }))
+        },
+        | Err(_) => tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .ok()
+            .map(|rt| rt.block_on(async move { store.list_mcp_servers().await }))
+            .unwrap_or_else(|| Ok(Vec::new())),
+    };
+    let rows = match result {
+        | Ok(r) => r,
+        | Err(e) => {
+            eprintln!("[warn] mcp_servers 로드 실패: {e}");
+            return;
+        },
+    };
+    register_rows(registry, &rows, connect_row);
+}
+
+/// `rows` 의 도구를 캐시에서 꺼내 등록한다. 캐시에 없거나 백오프가 끝난
+/// 서버만 `connect` 로 접속하며, 접속은 전역 캐시 락 밖에서 한다.
+fn register_rows(registry: &mut ToolRegistry, rows: &[McpServerRow], connect: impl Fn(&McpServerRow) -> Result<Vec<Arc<McpTool>>, String>) {
+    let now = Instant::now();
+    let pending: Vec<(&McpServerRow, Option<Duration>)> = {
+        let mut cache = clients().lock().unwrap();
+        cache.retain(|key, (cached, _)| rows.iter().any(|r| (&r.domain, &r.name) == (&key.0, &key.1) && r == cached));
+        rows.iter()
+            .filter_map(|row| match cache.get(&(row.domain.clone(), row.name.clone())) {
+                | None => Some((row, None)),
+                | Some((
+                    _,
+                    Entry::Failed {
+                        retry_at,
+                        backoff,
+                    },
+                )) if *retry_at <= now => Some((row, Some(*backoff))),
+                | Some(_) => None,
+            })
+            .collect()
+    };
+
+    let connected: Vec<_> = pending.into_iter().map(|(row, backoff)| (row, backoff, connect(row))).collect();
+
+    let mut cache = clients().lock().unwrap();
+    for (row, previous, result) in connected {
+        let entry = match result {
+            | Ok(tools) => Entry::Connected(tools),
+            | Err(e) => {
+                let backoff = next_backoff(previous);
+                eprintln!(
+                    "[warn] MCP 서버 '{}/{}' 연결 실패: {} ({}초 후 재시도)",
+                    row.domain,
+                    row.name,
+                    e,
+                    backoff.as_secs()
+                );
+                Entry::Failed {
+                    retry_at: Instant::now() + backoff,
+                    backoff,
+                }
+            },
+        };
+        cache.insert((row.domain.clone(), row.name.clone()), (row.clone(), entry));
+    }
+    for row in rows {
+        if let Some((_, Entry::Connected(tools))) = cache.get(&(row.domain.clone(), row.name.clone())) {
+            for tool in tools {
+                registry.register_with_domain(tool.clone() as Arc<dyn BaseTool>, &row.domain);
+            }
+        }
+    }
+}
+
+fn connect_row(row: &McpServerRow) -> Result<Vec<Arc<McpTool>>, String> {
+    let transport = transport_from_row(row)?;
+    let client = Arc::new(McpClient::connect(&transport, row.timeout_ms as u64)?);
+    Ok(McpTool::discover(client)?.into_iter().map(Arc::new).collect())
+}
+
+/// 행을 접속 설정으로 변환. 필드 누락·JSON 파싱 실패는 Err.
+pub fn transport_from_row(row: &McpServerRow) -> Result<McpTransport, String> {
+    if row.timeout_ms <= 0 {
+        return Err("timeout_ms must be > 0".into());
+    }
+    match row.transport.as_str() {
+        | "stdio" => {
+            let command = row
+                .command
+                .as_deref()
+                .filter(|c| !c.trim().is_empty())
+                .ok_or("stdio transport 는 command 가 필요")?;
+            let args: Vec<String> = serde_json::from_str(&row.args_json).map_err(|e| format!("args_json parse: {e}"))?;
+            let env: HashMap<String, String> = match row.env_json.as_deref() {
+                | Some(s) if !s.trim().is_empty() => serde_json::from_str(s).map_err(|e| format!("env_json parse: {e}"))?,
+                | _ => HashMap::new(),
+            };
+            Ok(McpTransport::Stdio {
+                command: command.to_string(),
+                args,
+                env,
+            })
+        },
+        | "http" => {
+            let url = row.url.as_deref().filter(|u| !u.trim().is_empty()).ok_or("http transport 는 url 이 필요")?;
+            Ok(McpTransport::Http {
+                url: url.to_string(),
+            })
+        },
+        | other => Err(format!("unknown transport '{other}' (stdio|http)")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_row() -> McpServerRow {
+        McpServerRow {
+            name: "fx".into(),
+            domain: "financial".into(),
+            transport: "stdio".into(),
+            command: Some("npx".into()),
+            args_json: r#"["-y","fx-mcp"]"#.into(),
+            env_json: Some(r#"{"FX_API_KEY":"k"}"#.into()),
+            url: None,
+            timeout_ms: 5000,
+        }
+    }
+
+    /// @trace TC: SPEC-037/TC-5
+    #[test]
+    fn spec037_tc_5_transport_from_row() {
+        let McpTransport::Stdio {
+            command,
+            args,
+            env,
+        } = transport_from_row(&sample_row()).unwrap()
+        else {
+            panic!("stdio 여야 함");
+        };
+        assert_eq!(command, "npx");
+        assert_eq!(args, vec!["-y", "fx-mcp"]);
+        assert_eq!(env["FX_API_KEY"], "k");
+
+        let mut http = sample_row();
+        http.transport = "http".into();
+        assert!(transport_from_row(&http).is_err(), "url 누락");
+        http.url = Some("http://127.0.0.1:8931/mcp".into());
+        assert_eq!(
+            transport_from_row(&http).unwrap(),
+            McpTransport::Http {
+                url: "http://127.0.0.1:8931/mcp".into()
+            }
+        );
+
+        let mut bad = sample_row();
+        bad.args_json = "not-json".into();
+        assert!(transport_from_row(&bad).is_err());
+        bad.transport = "sse".into();
+        assert!(transport_from_row(&bad).is_err());
+
+        let mut zero = sample_row();
+        zero.timeout_ms = 0;
+        assert!(transport_from_row(&zero).is_err(), "timeout_ms 0 은 거부");
+    }
+
+    /// 실패한 서버는 백오프 동안 다시 접속하지 않고, 행이 바뀌면 즉시 다시
+    /// 시도한다.
+    ///
+    /// @trace TC: SPEC-037/TC-5
+    #[test]
+    fn spec037_failed_server_is_not_retried_during_backoff() {
+        use std::sync::atomic::{AtomicUsize,
+                                Ordering};
+
+        let attempts = AtomicUsize::new(0);
+        let failing = |_: &McpServerRow| {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err::<Vec<Arc<McpTool>>, _>("connection refused".to_string())
+        };
+        let mut row = sample_row();
+        row.domain = "backoff_test".into();
+
+        let mut reg = ToolRegistry::new();
+        register_rows(&mut reg, std::slice::from_ref(&row), failing);
+        register_rows(&mut reg, std::slice::from_ref(&row), failing);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1, "백오프 중에는 재접속하지 않는다");
+
+        row.timeout_ms = 1000;
+        register_rows(&mut reg, std::slice::from_ref(&row), failing);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2, "행이 바뀌면 다시 시도");
+
+        assert_eq!(next_backoff(None), INITIAL_BACKOFF);
+        assert_eq!(next_backoff(Some(INITIAL_BACKOFF)), INITIAL_BACKOFF * 2);
+        assert_eq!(next_backoff(Some(MAX_BACKOFF)), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn register_skips_when_no_store() {
+        let mut reg = ToolRegistry::new();
+        let before = reg.get_tool_names().len();
+        register_mcp_tools_from_db(&mut reg);
+        assert_eq!(before, reg.get_tool_names().len(), "store 없으면 변화 없음");
+    }
+}
@@ -0,0 +1,262 @@
+// =============================================================================
+// @trace SPEC-044
+// @trace PRD: PRD-044
+// @trace FR: PRD-044/FR-1, PRD-044/FR-2, PRD-044/FR-3
+// @trace file-type: impl
+// =============================================================================
+//
+// `requires_approval` 도구의 승인 게이트. 러너가 시나리오 실행을 `scoped` 로
+// 감싸 현재 스레드에 게이트를 설치하면, 에이전트는 위험 도구를 실행하기
+// 전에 `request` 로 결정을 받는다. `interactive` 정책은 현재 스레드의
+// `ProgressSink::request_approval` (TUI/웹) 에 묻고, 물을 곳이 없으면
+// 거부한다. 게이트가 없으면 기존 동작대로 승인한다.
+
+use crate::progress;
+use serde::{Deserialize,
+            Serialize};
+use std::{cell::RefCell,
+          collections::HashMap,
+          sync::{Arc,
+                 Mutex}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApprovalDecision {
+    Approved,
+    Denied,
+}
+
+fn denied() -> ApprovalDecision { ApprovalDecision::Denied }
+
+/// 승인 정책. 시나리오 YAML 의 `approval` 또는 실행 옵션으로 지정한다.
+///
+/// ```yaml
+/// approval:
+///   mode: scripted
+///   decisions:
+///     write_file: [denied, approved]   # 호출 순서대로 소비
+///   default: denied
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum ApprovalPolicy {
+    #[default]
+    AutoApprove,
+    AutoDeny,
+    /// 도구별 결정 목록. 키는 레지스트리 키 또는 `<domain>__<tool>` 의
+    /// `<tool>`. 목록을 다 쓰면 마지막 결정을 반복하고, 키가 없으면
+    /// `default`.
+    Scripted {
+        #[serde(default)]
+        decisions: HashMap<String, Vec<ApprovalDecision>>,
+        #[serde(default = "denied")]
+        default: ApprovalDecision,
+    },
+    /// TUI/웹에서 사람이 결정한다.
+    Interactive,
+}
+
+impl ApprovalPolicy {
+    pub fn mode(&self) -> &'static str {
+        match self {
+            | Self::AutoApprove => "auto_approve",
+            | Self::AutoDeny => "auto_deny",
+            | Self::Scripted {
+                ..
+            } => "scripted",
+            | Self::Interactive => "interactive",
+        }
+    }
+}
+
+impl std::str::FromStr for ApprovalPolicy {
+    type Err = String;
+
+    /// CLI 용. `scripted` 는 시나리오 YAML 에서만 지정할 수 있다.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.replace('-', "_").as_str() {
+            | "auto_approve" => Ok(Self::AutoApprove),
+            | "auto_deny" => Ok(Self::AutoDeny),
+            | "interactive" => Ok(Self::Interactive),
+            | _ => Err(format!("unknown approval policy: {s} (auto-approve | auto-deny | interactive)")),
+        }
+    }
+}
+
+/// 승인 요청 한 건. `tool_name` 은 레지스트리 키.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApprovalRequest {
+    pub task_id: String,
+    pub iteration: u32,
+    pub tool_name: String,
+    pub parameters: HashMap<String, serde_json::Value>,
+}
+
+/// 궤적의 `ToolCall` 에 남는 승인 기록. `policy` 는 결정한 정책의 mode.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApprovalRecord {
+    pub decision: ApprovalDecision,
+    pub policy: String,
+}
+
+/// 실행(시나리오) 1회의 게이트. scripted 정책의 도구별 소비 위치를 갖는다.
+#[derive(Debug, Default)]
+pub struct ApprovalGate {
+    policy: ApprovalPolicy,
+    cursors: Mutex<HashMap<String, usize>>,
+}
+
+impl ApprovalGate {
+    pub fn new(policy: ApprovalPolicy) -> Self {
+        Self {
+            policy,
+            cursors: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn policy(&self) -> &ApprovalPolicy { &self.policy }
+
+    /// @trace SPEC: SPEC-044
+    /// @trace FR: PRD-044/FR-1
+    pub fn decide(&self, request: &ApprovalRequest) -> ApprovalRecord {
+        let decision = match &self.policy {
+            | ApprovalPolicy::AutoApprove => ApprovalDecision::Approved,
+            | ApprovalPolicy::AutoDeny => ApprovalDecision::Denied,
+            | ApprovalPolicy::Scripted {
+                decisions,
+                default,
+            } => {
+                let short = request.tool_name.rsplit_once("__").map(|(_, t)| t);
+                let found = decisions
+                    .get_key_value(&request.tool_name)
+                    .or_else(|| short.and_then(|s| decisions.get_key_value(s)));
+                match found {
+                    | Some((key, script)) if !script.is_empty() => {
+                        let mut cursors = self.cursors.lock().unwrap();
+                        let idx = cursors.entry(key.clone()).or_insert(0);
+                        let decision = script[(*idx).min(script.len() - 1)];
+                        *idx += 1;
+                        decision
+                    },
+                    | _ => *default,
+                }
+            },
+            | ApprovalPolicy::Interactive => progress::current()
+                .and_then(|sink| sink.request_approval(request))
+                .unwrap_or(ApprovalDecision::Denied),
+        };
+        ApprovalRecord {
+            decision,
+            policy: self.policy.mode().to_string(),
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<ApprovalGate>>> = const { RefCell::new(None) };
+}
+
+/// 이전 게이트를 되돌리는 guard. `f` 가 패닉해도 복원된다.
+struct Restore(Option<Arc<ApprovalGate>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        CURRENT.with(|c| *c.borrow_mut() = previous);
+    }
+}
+
+/// 현재 스레드에 `gate` 를 설치한 채로 `f` 를 실행한다.
+///
+/// @trace SPEC: SPEC-044
+/// @trace FR: PRD-044/FR-1
+pub fn scoped<R>(gate: Option<Arc<ApprovalGate>>, f: impl FnOnce() -> R) -> R {
+    let previous = CURRENT.with(|c| std::mem::replace(&mut *c.borrow_mut(), gate));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// 현재 스레드의 게이트에 결정을 요청한다. 게이트가 없으면 승인.
+///
+/// @trace SPEC: SPEC-044
+/// @trace FR: PRD-044/FR-1, PRD-044/FR-2
+pub fn request(request: &ApprovalRequest) -> ApprovalRecord {
+    match CURRENT.with(|c| c.borrow().clone()) {
+        | Some(gate) => gate.decide(request),
+        | None => ApprovalGate::default().decide(request),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-044
+    // @trace PRD: PRD-044
+    // @trace FR: PRD-044/FR-1
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use crate::progress::{ProgressEvent,
+                          ProgressSink};
+
+    fn req(tool: &str) -> ApprovalRequest {
+        ApprovalRequest {
+            task_id: "t".into(),
+            iteration: 1,
+            tool_name: tool.into(),
+            parameters: HashMap::new(),
+        }
+    }
+
+    struct Human(ApprovalDecision);
+
+    impl ProgressSink for Human {
+        fn emit(&self, _event: ProgressEvent) {}
+
+        fn request_approval(&self, _request: &ApprovalRequest) -> Option<ApprovalDecision> { Some(self.0) }
+    }
+
+    /// @trace TC: SPEC-044/TC-1
+    /// @trace FR: PRD-044/FR-1
+    #[test]
+    fn spec044_tc_1_policies_decide_per_call() {
+        assert_eq!(request(&req("write_file")).decision, ApprovalDecision::Approved, "게이트가 없으면 승인");
+
+        let policy: ApprovalPolicy = serde_json::from_value(serde_json::json!({
+            "mode": "scripted",
+            "decisions": {
+                "write_file": ["denied", "approved"],
+                "customer_service__process_refund": ["approved"],
+            },
+        }))
+        .unwrap();
+        let gate = Arc::new(ApprovalGate::new(policy));
+        let decisions: Vec<ApprovalDecision> = scoped(Some(gate), || {
+            ["write_file", "write_file", "write_file", "customer_service__process_refund", "other__delete"]
+                .iter()
+                .map(|t| request(&req(t)).decision)
+                .collect()
+        });
+        use ApprovalDecision::*;
+        assert_eq!(decisions, vec![Denied, Approved, Approved, Approved, Denied]);
+
+        let deny = Arc::new(ApprovalGate::new(ApprovalPolicy::AutoDeny));
+        let record = scoped(Some(deny), || request(&req("write_file")));
+        assert_eq!(
+            record,
+            ApprovalRecord {
+                decision: Denied,
+                policy: "auto_deny".into(),
+            }
+        );
+
+        // interactive: sink 에 묻고, sink 가 없으면 거부.
+        let interactive = Arc::new(ApprovalGate::new(ApprovalPolicy::Interactive));
+        assert_eq!(scoped(Some(interactive.clone()), || request(&req("write_file"))).decision, Denied);
+        let human: Arc<dyn ProgressSink> = Arc::new(Human(Approved));
+        let got = progress::scoped(Some(human), || scoped(Some(interactive), || request(&req("write_file"))));
+        assert_eq!(got.decision, Approved);
+        assert_eq!("auto-deny".parse::<ApprovalPolicy>(), Ok(ApprovalPolicy::AutoDeny));
+    }
+}
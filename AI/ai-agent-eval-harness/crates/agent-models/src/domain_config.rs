@@ -1,6 +1,8 @@
+use crate::approval::ApprovalPolicy;
 use serde::{Deserialize,
             Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap,
+                       HashMap};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolConfig {
@@ -18,6 +20,111 @@ pub struct ScenarioConfig {
     pub expected_tools: Vec<String>,
     pub success_criteria: HashMap<String, serde_json::Value>,
     pub difficulty: String,
+    /// SPEC-032: 있으면 멀티턴 시나리오. `task_description` 은 대화 전체의
+    /// 요약이자, 스크립트 턴이 비어 있을 때 첫 사용자 발화로 쓰인다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conversation: Option<ConversationConfig>,
+    /// SPEC-044: 이 시나리오의 `requires_approval` 도구 승인 정책. 없으면
+    /// 실행 옵션의 정책(기본 auto_approve)을 따른다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approval: Option<ApprovalPolicy>,
+    /// SPEC-045: 실행마다 만드는 작업 공간의 fixture 와 기대 파일. 없어도
+    /// 빈 작업 공간에서 실행한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<WorkspaceConfig>,
+}
+
+/// 시나리오 작업 공간 정의. 경로는 작업 공간 루트 기준 상대 경로.
+///
+/// ```yaml
+/// workspace:
+///   fixtures:
+///     input/orders.csv: "id,amount\n1,100\n"
+///   expected_files:
+///     out/summary.txt: "total: 100"
+///     input/orders.csv.bak: null   # 없어야 함
+/// ```
+///
+/// @trace SPEC: SPEC-045
+/// @trace FR: PRD-045/FR-1, PRD-045/FR-3
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceConfig {
+    #[serde(default)]
+    pub fixtures: BTreeMap<String, String>,
+    /// 실행 후 기대 내용(앞뒤 공백 무시). `null` 이면 파일이 없어야 한다.
+    #[serde(default)]
+    pub expected_files: BTreeMap<String, Option<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_file_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_bytes: Option<u64>,
+}
+
+/// 멀티턴 시나리오 정의. 스크립트 턴을 순서대로 보낸 뒤, `simulated_user`
+/// 가 있으면 직전 턴 결과에 반응하는 규칙으로 다음 발화를 이어간다.
+///
+/// @trace SPEC: SPEC-032
+/// @trace FR: PRD-032/FR-1, PRD-032/FR-2
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConversationConfig {
+    #[serde(default)]
+    pub turns: Vec<ConversationTurnConfig>,
+    #[serde(default)]
+    pub simulated_user: Option<SimulatedUserConfig>,
+    /// 대화 전체 성공 조건 (`min_success_rate`, `all_turns_success`).
+    /// 비어 있으면 모든 턴 성공.
+    #[serde(default)]
+    pub success_criteria: HashMap<String, serde_json::Value>,
+}
+
+/// 사용자 턴 하나와 그 턴의 기대 도구/성공 조건.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConversationTurnConfig {
+    pub user_input: String,
+    #[serde(default)]
+    pub initial_environment: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub expected_tools: Vec<String>,
+    #[serde(default)]
+    pub success_criteria: HashMap<String, serde_json::Value>,
+    /// 이 턴 종료 시 대화 컨텍스트에 남아 있어야 할 키.
+    #[serde(default)]
+    pub expected_context_keys: Vec<String>,
+}
+
+fn default_max_turns() -> u32 { 5 }
+
+/// 규칙 기반 가상 사용자. 위에서부터 처음 맞는 규칙의 `reply` 를 보내고,
+/// 맞는 규칙이 없거나 전체 턴 수가 `max_turns` 에 이르면 대화를 끝낸다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulatedUserConfig {
+    #[serde(default = "default_max_turns")]
+    pub max_turns: u32,
+    pub rules: Vec<SimulatedUserRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulatedUserRule {
+    pub when: UserRuleCondition,
+    pub reply: ConversationTurnConfig,
+}
+
+/// 직전 턴 궤적에 대한 조건.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UserRuleCondition {
+    Always,
+    LastTurnSucceeded,
+    LastTurnFailed,
+    /// 성공한 호출 중 도구 이름이 같거나 `<domain>__<tool>` 의 `<tool>` 이
+    /// 같은 것이 있으면 참.
+    ToolCalled {
+        tool: String,
+    },
+    /// 성공한 도구 결과 또는 최종 인지 정보의 JSON 에 `text` 가 있으면 참.
+    OutputContains {
+        text: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
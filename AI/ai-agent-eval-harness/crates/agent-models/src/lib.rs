@@ -1,3 +1,5 @@
+pub mod approval;
 pub mod base_agent;
 pub mod domain_config;
 pub mod models;
+pub mod progress;
@@ -1,3 +1,4 @@
+use crate::approval::ApprovalRecord;
 use chrono::{DateTime,
              Utc};
 use serde::{Deserialize,
@@ -47,6 +48,9 @@ impl AgentState {
 }
 
 /// 도구 호출 정보
+///
+/// SPEC-044: `approval` 은 `requires_approval` 도구에 대한 게이트 결정.
+/// 거부된 호출은 실행되지 않고 `success = false` 로 남는다.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolCall {
     pub tool_name: String,
@@ -55,6 +59,8 @@ pub struct ToolCall {
     pub success: bool,
     pub result: Option<serde_json::Value>,
     pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approval: Option<ApprovalRecord>,
 }
 
 impl ToolCall {
@@ -66,11 +72,55 @@ impl ToolCall {
             success: true,
             result: None,
             error: None,
+            approval: None,
         }
     }
 }
 
+/// 폴트 주입으로 결과가 조작된 도구 호출 한 건.
+///
+/// SPEC-031: 평가기가 `ToolCall` 과 대조해 복구/재시도/무비판 수용 여부를
+/// 채점한다. `tool_name` 은 `ToolCall::tool_name` 과 같은 레지스트리 키,
+/// `failure_mode` 는 snake_case 모드 이름(`timeout`, `stale_data` 등).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InjectedFault {
+    pub call_index: u64,
+    pub tool_name: String,
+    pub failure_mode: String,
+    pub parameters: HashMap<String, serde_json::Value>,
+}
+
+/// SPEC-033: LLM 응답의 토큰 사용량. provider 가 보고하지 않으면 기록되지
+/// 않는다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub total_tokens: u64,
+}
+
+impl TokenUsage {
+    pub fn add(&mut self, other: &TokenUsage) {
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.total_tokens += other.total_tokens;
+    }
+}
+
+/// SPEC-036: 실행 시작 시 도메인 라우터가 내린 결정. `domains` 는 점수
+/// 내림차순이며 `scores` 와 같은 길이다. `method` 는 `embedding`(코사인
+/// 유사도) 또는 `keyword`(키워드 매칭 건수).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DomainRouting {
+    pub method: String,
+    pub domains: Vec<String>,
+    pub scores: Vec<f64>,
+}
+
 /// PPA 루프의 단일 단계
+///
+/// SPEC-035: `token_usage` 는 이 단계의 LLM 호출 사용량 (Action 단계 등
+/// LLM 을 부르지 않는 단계는 None).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PpaStep {
     pub stage: PpaStage,
@@ -80,12 +130,20 @@ pub struct PpaStep {
     pub output_data: HashMap<String, serde_json::Value>,
     pub tool_calls: Vec<ToolCall>,
     pub duration_ms: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_usage: Option<TokenUsage>,
 }
 
 /// Agent의 전체 궤적
 ///
 /// SPEC-025: `prompt_set_id` 는 이 실행에 사용된 PromptSet 의 DB id.
 /// 구버전 JSON (`prompt_set_id` 키 없음) 역직렬화 호환을 위해 `serde(default)`.
+/// SPEC-031: `injected_faults` 는 폴트 주입이 켜진 실행에서만 채워진다.
+/// SPEC-033: `token_usage` 는 실행 중 모든 LLM 호출의 토큰 합계.
+/// SPEC-034: `tool_calling_mode` 는 LLM 에이전트의 도구 선택 방식
+/// (`prompt` | `native`). LLM 을 쓰지 않는 에이전트는 비워 둔다.
+/// SPEC-035: `llm_model` 은 비용 계산에 쓰는 모델(배포) 이름.
+/// SPEC-036: `domain_routing` 은 라우터를 쓰는 에이전트만 기록한다.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Trajectory {
     pub task_id: String,
@@ -98,6 +156,16 @@ pub struct Trajectory {
     pub total_iterations: u32,
     #[serde(default)]
     pub prompt_set_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub injected_faults: Vec<InjectedFault>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_usage: Option<TokenUsage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_calling_mode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llm_model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain_routing: Option<DomainRouting>,
 }
 
 
@@ -141,6 +209,11 @@ mod spec025_tests {
             success: false,
             total_iterations: 0,
             prompt_set_id: None,
+            injected_faults: Vec::new(),
+            token_usage: None,
+            tool_calling_mode: None,
+            llm_model: None,
+            domain_routing: None,
         };
         // 첫 스텝에서 id=42 주입
         if t.prompt_set_id.is_none() {
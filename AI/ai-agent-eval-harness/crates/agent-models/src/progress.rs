@@ -0,0 +1,232 @@
+// =============================================================================
+// @trace SPEC-040
+// @trace PRD: PRD-040
+// @trace FR: PRD-040/FR-2, PRD-040/FR-4
+// @trace file-type: impl
+// =============================================================================
+//
+// 실행 진행 이벤트. 러너가 시나리오 실행을 `scoped` 로 감싸 현재 스레드에
+// sink 를 설치하면, 에이전트는 `BaseAgent` 시그니처를 바꾸지 않고도
+// `emit` 으로 PPA 단계·도구 호출을 실시간 보고하고 `is_cancelled` 로 취소
+// 요청을 확인할 수 있다. sink 가 없으면 모두 no-op 이다.
+
+use crate::{approval::{ApprovalDecision,
+                       ApprovalRequest},
+            models::{PpaStage,
+                     ToolCall}};
+use serde::{Deserialize,
+            Serialize};
+use std::{cell::RefCell,
+          sync::{Arc,
+                 Mutex}};
+
+/// 진행 이벤트. JSON 에서는 `type` 필드로 구분한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ProgressEvent {
+    /// `index` 는 전체 실행(시나리오 × 시행) 중 0 부터의 순번.
+    ScenarioStarted {
+        index: usize,
+        total: usize,
+        domain: String,
+        scenario_id: String,
+        name: String,
+    },
+    ScenarioFinished {
+        index: usize,
+        total: usize,
+        domain: String,
+        scenario_id: String,
+        task_id: Option<String>,
+        success: bool,
+        duration_ms: f64,
+    },
+    /// 궤적에 PPA 단계 하나가 추가됨.
+    Step {
+        task_id: String,
+        stage: PpaStage,
+        iteration: u32,
+        duration_ms: Option<f64>,
+    },
+    /// 도구 호출 한 건이 끝남 (Action 단계가 끝나기 전에 보고된다).
+    ToolCall { task_id: String, iteration: u32, call: ToolCall },
+}
+
+/// 진행 이벤트 수신자. 취소를 지원하는 sink 는 `is_cancelled` 를 구현한다.
+pub trait ProgressSink: Send + Sync {
+    fn emit(&self, event: ProgressEvent);
+
+    fn is_cancelled(&self) -> bool { false }
+
+    /// SPEC-044: `interactive` 승인 정책에서 사람에게 묻는다. 결정이 날
+    /// 때까지 블로킹하며, 물을 수 없는 sink 는 None (거부로 처리된다).
+    fn request_approval(&self, _request: &ApprovalRequest) -> Option<ApprovalDecision> { None }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<dyn ProgressSink>>> = const { RefCell::new(None) };
+}
+
+/// 이전 sink 를 되돌리는 guard. `f` 가 패닉해도 복원된다.
+struct Restore(Option<Arc<dyn ProgressSink>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        CURRENT.with(|c| *c.borrow_mut() = previous);
+    }
+}
+
+/// 현재 스레드에 `sink` 를 설치한 채로 `f` 를 실행한다. 다른 스레드로
+/// 넘어가는 작업은 `current()` 로 꺼내 그 스레드에서 다시 감싸야 한다.
+///
+/// @trace SPEC: SPEC-040
+/// @trace FR: PRD-040/FR-2
+pub fn scoped<R>(sink: Option<Arc<dyn ProgressSink>>, f: impl FnOnce() -> R) -> R {
+    let previous = CURRENT.with(|c| std::mem::replace(&mut *c.borrow_mut(), sink));
+    let _restore = Restore(previous);
+    f()
+}
+
+pub fn current() -> Option<Arc<dyn ProgressSink>> { CURRENT.with(|c| c.borrow().clone()) }
+
+/// 현재 스레드의 sink 로 이벤트를 보낸다. sink 가 없으면 무시.
+pub fn emit(event: ProgressEvent) {
+    if let Some(sink) = current() {
+        sink.emit(event);
+    }
+}
+
+/// 현재 스레드의 실행에 취소가 요청됐는지.
+///
+/// @trace SPEC: SPEC-040
+/// @trace FR: PRD-040/FR-4
+pub fn is_cancelled() -> bool { current().is_some_and(|s| s.is_cancelled()) }
+
+/// 바깥 sink 를 감싸 이 scope 만 따로 취소할 수 있게 하는 sink. 러너가
+/// 제한 시간을 넘긴 실행 스레드를 버릴 때 `cancel` 하면, 그 스레드는 다음
+/// 반복 경계에서 멈추고 그 뒤의 이벤트는 바깥 sink 에 전달되지 않는다.
+///
+/// @trace SPEC: SPEC-028
+/// @trace FR: PRD-028/FR-2
+pub struct CancelScope {
+    inner: Option<Arc<dyn ProgressSink>>,
+    /// 취소 여부. `emit` 은 이 락을 잡은 채 전달하므로 `cancel` 이 돌아온
+    /// 뒤에는 어떤 이벤트도 바깥 sink 에 닿지 않는다.
+    cancelled: Mutex<bool>,
+}
+
+impl CancelScope {
+    pub fn new(inner: Option<Arc<dyn ProgressSink>>) -> Arc<Self> {
+        Arc::new(Self {
+            inner,
+            cancelled: Mutex::new(false),
+        })
+    }
+
+    pub fn cancel(&self) { *self.cancelled.lock().unwrap() = true; }
+
+    fn is_scope_cancelled(&self) -> bool { *self.cancelled.lock().unwrap() }
+}
+
+impl ProgressSink for CancelScope {
+    fn emit(&self, event: ProgressEvent) {
+        let cancelled = self.cancelled.lock().unwrap();
+        if let (false, Some(inner)) = (*cancelled, &self.inner) {
+            inner.emit(event);
+        }
+    }
+
+    fn is_cancelled(&self) -> bool { self.is_scope_cancelled() || self.inner.as_ref().is_some_and(|s| s.is_cancelled()) }
+
+    fn request_approval(&self, request: &ApprovalRequest) -> Option<ApprovalDecision> {
+        if self.is_scope_cancelled() {
+            return None;
+        }
+        self.inner.as_ref().and_then(|s| s.request_approval(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Mutex,
+                    atomic::{AtomicBool,
+                             Ordering}};
+
+    #[derive(Default)]
+    struct Collect {
+        events: Mutex<Vec<ProgressEvent>>,
+        cancelled: AtomicBool,
+    }
+
+    impl ProgressSink for Collect {
+        fn emit(&self, event: ProgressEvent) { self.events.lock().unwrap().push(event); }
+
+        fn is_cancelled(&self) -> bool { self.cancelled.load(Ordering::SeqCst) }
+    }
+
+    fn step(iteration: u32) -> ProgressEvent {
+        ProgressEvent::Step {
+            task_id: "t".into(),
+            stage: PpaStage::Perceive,
+            iteration,
+            duration_ms: None,
+        }
+    }
+
+    /// @trace TC: SPEC-040/TC-1
+    #[test]
+    fn spec040_tc_1_scoped_sink_receives_events_and_restores() {
+        emit(step(0));
+        assert!(!is_cancelled(), "sink 없으면 no-op");
+
+        let sink = Arc::new(Collect::default());
+        scoped(Some(sink.clone()), || {
+            emit(step(1));
+            // 중첩 scope 는 안쪽 sink 를 쓰고 끝나면 바깥으로 돌아온다.
+            scoped(None, || emit(step(2)));
+            emit(step(3));
+            sink.cancelled.store(true, Ordering::SeqCst);
+            assert!(is_cancelled());
+        });
+        assert!(current().is_none());
+        let iterations: Vec<u32> = sink
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|e| match e {
+                | ProgressEvent::Step {
+                    iteration, ..
+                } => *iteration,
+                | other => panic!("unexpected {other:?}"),
+            })
+            .collect();
+        assert_eq!(iterations, vec![1, 3]);
+
+        let r = std::panic::catch_unwind(|| scoped(Some(sink.clone()), || panic!("boom")));
+        assert!(r.is_err());
+        assert!(current().is_none(), "패닉 후에도 복원");
+
+        let json = serde_json::to_value(step(4)).unwrap();
+        assert_eq!(json["type"], "step");
+        assert_eq!(json["stage"], "perceive");
+    }
+
+    /// @trace SPEC: SPEC-028
+    #[test]
+    fn cancel_scope_silences_and_cancels_only_its_scope() {
+        let outer = Arc::new(Collect::default());
+        let scope = CancelScope::new(Some(outer.clone()));
+        scoped(Some(scope.clone()), || {
+            emit(step(1));
+            assert!(!is_cancelled());
+            scope.cancel();
+            assert!(is_cancelled());
+            emit(step(2));
+        });
+        assert_eq!(outer.events.lock().unwrap().len(), 1);
+        assert!(!outer.is_cancelled(), "바깥 sink 는 취소되지 않는다");
+    }
+}
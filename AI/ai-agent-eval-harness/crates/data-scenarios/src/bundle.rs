@@ -0,0 +1,960 @@
+// =============================================================================
+// @trace SPEC-043
+// @trace PRD: PRD-043
+// @trace FR: PRD-043/FR-1, PRD-043/FR-2, PRD-043/FR-3, PRD-043/FR-4
+// @trace file-type: impl
+// =============================================================================
+//
+// 도메인 1개를 머신 간에 옮기기 위한 번들. 한 도메인에 속한 domains /
+// domain_tools / domain_keywords / eval_scenarios / golden_sets /
+// external_tools / mcp_servers / prompt_sets 행을 `DomainBundle` 로 모으고,
+// git 에서 리뷰할 수 있도록 디렉토리 하나에 YAML/JSON 파일로 쓴다.
+//
+//   bundle.yaml          manifest (format_version, domain, keywords)
+//   scenarios.yaml       DomainConfig — seed/scenarios/*.yaml 과 같은 형식
+//   golden_sets.json     GoldenSetFile — seed/goldens/*.json 과 같은 형식
+//   external_tools.json  외부 HTTP 도구 (JSON 문자열 컬럼은 구조로 풀어 씀)
+//   mcp_servers.json     MCP 서버
+//   prompt_sets.yaml     PromptSet 버전 목록
+//
+// import 는 먼저 현재 DB 와 비교해 계획(생성/수정/충돌)을 세우고, 정책에
+// 따라 적용한다. 충돌 = 같은 키의 행이 이미 있고 내용이 다른 경우.
+
+use crate::{models::{GoldenSetEntry,
+                     GoldenSetFile},
+            sqlite_store::{self,
+                           ExternalToolRow,
+                           McpServerRow,
+                           PromptSetInsert,
+                           SqliteStore,
+                           StoreError}};
+use agent_models::domain_config::{DomainConfig,
+                                  ScenarioConfig};
+use serde::{Deserialize,
+            Serialize};
+use serde_json::Value;
+use std::{collections::{BTreeSet,
+                        HashMap},
+          path::{Path,
+                 PathBuf}};
+
+/// 현재 번들 형식 버전. 더 높은 버전의 번들은 거부한다.
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_FILE: &str = "bundle.yaml";
+const SCENARIOS_FILE: &str = "scenarios.yaml";
+const GOLDEN_SETS_FILE: &str = "golden_sets.json";
+const EXTERNAL_TOOLS_FILE: &str = "external_tools.json";
+const MCP_SERVERS_FILE: &str = "mcp_servers.json";
+const PROMPT_SETS_FILE: &str = "prompt_sets.yaml";
+
+#[derive(Debug, thiserror::Error)]
+pub enum BundleError {
+    #[error(transparent)]
+    Store(#[from] StoreError),
+    #[error("io error ({path}): {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("parse error ({path}): {message}")]
+    Parse { path: PathBuf, message: String },
+    #[error("invalid bundle: {0}")]
+    Invalid(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleManifest {
+    pub format_version: u32,
+    pub domain: String,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+/// 외부 HTTP 도구. `headers`/`params_schema` 는 JSON 문자열 대신 구조로
+/// 저장해 diff 를 읽을 수 있게 한다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleExternalTool {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub method: String,
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Value>,
+    pub body_template: String,
+    pub params_schema: Value,
+    pub timeout_ms: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleMcpServer {
+    pub name: String,
+    pub transport: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub timeout_ms: i64,
+}
+
+/// PromptSet 버전 1개. 가져올 때 버전 번호는 대상 DB 에서 새로 매겨지고,
+/// 내용이 같은 버전이 이미 있으면 건너뛴다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundlePromptSet {
+    pub version: i64,
+    pub perceive_system: String,
+    pub perceive_user: String,
+    pub policy_system: String,
+    pub policy_user: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub is_active: bool,
+}
+
+impl BundlePromptSet {
+    fn same_templates(&self, other: &BundlePromptSet) -> bool {
+        self.perceive_system == other.perceive_system
+            && self.perceive_user == other.perceive_user
+            && self.policy_system == other.policy_system
+            && self.policy_user == other.policy_user
+    }
+}
+
+/// 도메인 1개의 이식용 스냅샷. API 는 이 구조를 JSON 으로 주고받는다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DomainBundle {
+    pub manifest: BundleManifest,
+    pub domain: DomainConfig,
+    pub golden_sets: GoldenSetFile,
+    #[serde(default)]
+    pub external_tools: Vec<BundleExternalTool>,
+    #[serde(default)]
+    pub mcp_servers: Vec<BundleMcpServer>,
+    #[serde(default)]
+    pub prompt_sets: Vec<BundlePromptSet>,
+}
+
+/// 충돌 처리 정책. `fail` 은 충돌이 하나라도 있으면 아무것도 쓰지 않는다.
+/// `merge` 는 기존 행을 유지하고 없는 행만 추가하며, 키워드/도구 목록은
+/// 합친다. `overwrite` 는 번들 내용으로 덮어쓰고 번들의 활성 PromptSet 을
+/// 활성화한다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportPolicy {
+    #[default]
+    Fail,
+    Merge,
+    Overwrite,
+}
+
+impl std::str::FromStr for ImportPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            | "fail" => Ok(Self::Fail),
+            | "merge" => Ok(Self::Merge),
+            | "overwrite" => Ok(Self::Overwrite),
+            | other => Err(format!("unknown import policy: {other} (fail | merge | overwrite)")),
+        }
+    }
+}
+
+/// 충돌 1건. `key` 는 `scenario:fin_001` 처럼 종류와 식별자로 이뤄진다.
+/// `resolution` 은 `unresolved`(fail), `kept`(merge), `overwritten`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BundleConflict {
+    pub key: String,
+    pub resolution: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportReport {
+    pub domain: String,
+    pub policy: ImportPolicy,
+    pub dry_run: bool,
+    /// 실제로 DB 에 썼는지. dry-run 이거나 fail 정책에서 충돌이 있으면 false.
+    pub applied: bool,
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    pub unchanged: usize,
+    pub conflicts: Vec<BundleConflict>,
+}
+
+// --------------------------------------------------------------------------
+// export
+// --------------------------------------------------------------------------
+
+fn parse_json_column(value: &str, what: &str) -> Result<Value, BundleError> {
+    serde_json::from_str(value).map_err(|e| BundleError::Invalid(format!("{what}: {e}")))
+}
+
+fn external_to_bundle(row: ExternalToolRow) -> Result<BundleExternalTool, BundleError> {
+    Ok(BundleExternalTool {
+        headers: row.headers_json.as_deref().map(|h| parse_json_column(h, "headers_json")).transpose()?,
+        params_schema: parse_json_column(&row.params_schema, "params_schema")?,
+        name: row.name,
+        description: row.description,
+        method: row.method,
+        url: row.url,
+        body_template: row.body_template,
+        timeout_ms: row.timeout_ms,
+    })
+}
+
+fn external_to_row(domain: &str, t: &BundleExternalTool) -> ExternalToolRow {
+    ExternalToolRow {
+        name: t.name.clone(),
+        domain: domain.to_string(),
+        description: t.description.clone(),
+        method: t.method.clone(),
+        url: t.url.clone(),
+        headers_json: t.headers.as_ref().map(Value::to_string),
+        body_template: t.body_template.clone(),
+        params_schema: t.params_schema.to_string(),
+        timeout_ms: t.timeout_ms,
+    }
+}
+
+fn mcp_to_bundle(row: McpServerRow) -> Result<BundleMcpServer, BundleError> {
+    Ok(BundleMcpServer {
+        args: serde_json::from_str(&row.args_json).map_err(|e| BundleError::Invalid(format!("args_json: {e}")))?,
+        env: row.env_json.as_deref().map(|e| parse_json_column(e, "env_json")).transpose()?,
+        name: row.name,
+        transport: row.transport,
+        command: row.command,
+        url: row.url,
+        timeout_ms: row.timeout_ms,
+    })
+}
+
+fn mcp_to_row(domain: &str, s: &BundleMcpServer) -> McpServerRow {
+    McpServerRow {
+        name: s.name.clone(),
+        domain: domain.to_string(),
+        transport: s.transport.clone(),
+        command: s.command.clone(),
+        args_json: serde_json::to_string(&s.args).unwrap_or_else(|_| "[]".into()),
+        env_json: s.env.as_ref().map(Value::to_string),
+        url: s.url.clone(),
+        timeout_ms: s.timeout_ms,
+    }
+}
+
+/// 스토어에서 도메인 1개를 번들로 모은다. 없으면 `NotFound`.
+///
+/// @trace SPEC: SPEC-043
+/// @trace FR: PRD-043/FR-1
+pub async fn export_domain(store: &SqliteStore, name: &str) -> Result<DomainBundle, BundleError> {
+    let summary = store
+        .get_domain_summary(name)
+        .await?
+        .ok_or_else(|| StoreError::NotFound(format!("domain ({name})")))?;
+    let domain = store
+        .load_all_domains()
+        .await?
+        .into_iter()
+        .find(|d| d.name == name)
+        .unwrap_or_else(|| DomainConfig {
+            name: name.to_string(),
+            description: summary.description.clone(),
+            tools: Vec::new(),
+            scenarios: Vec::new(),
+        });
+    let external_tools = store
+        .list_external_tools_by_domain(name)
+        .await?
+        .into_iter()
+        .map(external_to_bundle)
+        .collect::<Result<_, _>>()?;
+    let mcp_servers = store
+        .list_mcp_servers_by_domain(name)
+        .await?
+        .into_iter()
+        .map(mcp_to_bundle)
+        .collect::<Result<_, _>>()?;
+    let mut prompt_sets: Vec<BundlePromptSet> = store
+        .list_prompt_sets(name)
+        .await?
+        .into_iter()
+        .map(|p| BundlePromptSet {
+            version: p.version,
+            perceive_system: p.perceive_system,
+            perceive_user: p.perceive_user,
+            policy_system: p.policy_system,
+            policy_user: p.policy_user,
+            notes: p.notes,
+            is_active: p.is_active,
+        })
+        .collect();
+    prompt_sets.sort_by_key(|p| p.version);
+    Ok(DomainBundle {
+        manifest: BundleManifest {
+            format_version: BUNDLE_FORMAT_VERSION,
+            domain: name.to_string(),
+            keywords: summary.keywords,
+        },
+        domain,
+        golden_sets: store.load_golden_sets_by_domain(name).await?,
+        external_tools,
+        mcp_servers,
+        prompt_sets,
+    })
+}
+
+fn io_err(path: &Path) -> impl FnOnce(std::io::Error) -> BundleError + '_ {
+    move |source| BundleError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+fn parse_err(path: &Path, message: impl ToString) -> BundleError {
+    BundleError::Parse {
+        path: path.to_path_buf(),
+        message: message.to_string(),
+    }
+}
+
+fn write_yaml<T: Serialize>(dir: &Path, file: &str, value: &T) -> Result<(), BundleError> {
+    let path = dir.join(file);
+    let body = serde_yaml::to_string(value).map_err(|e| parse_err(&path, e))?;
+    std::fs::write(&path, body).map_err(io_err(&path))
+}
+
+fn write_json<T: Serialize>(dir: &Path, file: &str, value: &T) -> Result<(), BundleError> {
+    let path = dir.join(file);
+    let body = serde_json::to_string_pretty(value).map_err(|e| parse_err(&path, e))?;
+    std::fs::write(&path, body + "\n").map_err(io_err(&path))
+}
+
+/// 번들을 `dir` 에 파일로 쓴다. 디렉토리가 없으면 만든다.
+///
+/// @trace SPEC: SPEC-043
+/// @trace FR: PRD-043/FR-2
+pub fn write_bundle_dir(bundle: &DomainBundle, dir: &Path) -> Result<(), BundleError> {
+    std::fs::create_dir_all(dir).map_err(io_err(dir))?;
+    write_yaml(dir, MANIFEST_FILE, &bundle.manifest)?;
+    write_yaml(dir, SCENARIOS_FILE, &bundle.domain)?;
+    write_json(dir, GOLDEN_SETS_FILE, &bundle.golden_sets)?;
+    write_json(dir, EXTERNAL_TOOLS_FILE, &bundle.external_tools)?;
+    write_json(dir, MCP_SERVERS_FILE, &bundle.mcp_servers)?;
+    write_yaml(dir, PROMPT_SETS_FILE, &bundle.prompt_sets)?;
+    Ok(())
+}
+
+fn read_optional(dir: &Path, file: &str) -> Result<Option<(PathBuf, String)>, BundleError> {
+    let path = dir.join(file);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let body = std::fs::read_to_string(&path).map_err(io_err(&path))?;
+    Ok(Some((path, body)))
+}
+
+fn read_required(dir: &Path, file: &str) -> Result<(PathBuf, String), BundleError> {
+    read_optional(dir, file)?.ok_or_else(|| BundleError::Invalid(format!("{} 가 없습니다", dir.join(file).display())))
+}
+
+/// `dir` 의 번들 파일을 읽는다. 도구·서버·PromptSet 파일은 생략할 수 있다.
+///
+/// @trace SPEC: SPEC-043
+/// @trace FR: PRD-043/FR-2
+pub fn read_bundle_dir(dir: &Path) -> Result<DomainBundle, BundleError> {
+    let (path, body) = read_required(dir, MANIFEST_FILE)?;
+    let manifest: BundleManifest = serde_yaml::from_str(&body).map_err(|e| parse_err(&path, e))?;
+    let (path, body) = read_required(dir, SCENARIOS_FILE)?;
+    let domain: DomainConfig = serde_yaml::from_str(&body).map_err(|e| parse_err(&path, e))?;
+    let golden_sets = match read_optional(dir, GOLDEN_SETS_FILE)? {
+        | Some((path, body)) => serde_json::from_str(&body).map_err(|e| parse_err(&path, e))?,
+        | None => GoldenSetFile {
+            domain: manifest.domain.clone(),
+            version: "1.0".into(),
+            golden_sets: Vec::new(),
+        },
+    };
+    let external_tools = match read_optional(dir, EXTERNAL_TOOLS_FILE)? {
+        | Some((path, body)) => serde_json::from_str(&body).map_err(|e| parse_err(&path, e))?,
+        | None => Vec::new(),
+    };
+    let mcp_servers = match read_optional(dir, MCP_SERVERS_FILE)? {
+        | Some((path, body)) => serde_json::from_str(&body).map_err(|e| parse_err(&path, e))?,
+        | None => Vec::new(),
+    };
+    let prompt_sets = match read_optional(dir, PROMPT_SETS_FILE)? {
+        | Some((path, body)) => serde_yaml::from_str(&body).map_err(|e| parse_err(&path, e))?,
+        | None => Vec::new(),
+    };
+    Ok(DomainBundle {
+        manifest,
+        domain,
+        golden_sets,
+        external_tools,
+        mcp_servers,
+        prompt_sets,
+    })
+}
+
+// --------------------------------------------------------------------------
+// import
+// --------------------------------------------------------------------------
+
+/// 번들 자체의 일관성 검사 (형식 버전, 도메인 이름, 골든셋의 시나리오 참조,
+/// MCP 서버 타임아웃).
+fn validate(bundle: &DomainBundle, existing_ids: &BTreeSet<String>) -> Result<(), BundleError> {
+    let m = &bundle.manifest;
+    if m.format_version == 0 || m.format_version > BUNDLE_FORMAT_VERSION {
+        return Err(BundleError::Invalid(format!(
+            "unsupported format_version {} (supported: {BUNDLE_FORMAT_VERSION})",
+            m.format_version
+        )));
+    }
+    if m.domain.is_empty() || bundle.domain.name != m.domain || bundle.golden_sets.domain != m.domain {
+        return Err(BundleError::Invalid(format!(
+            "domain name mismatch: manifest={}, scenarios={}, golden_sets={}",
+            m.domain, bundle.domain.name, bundle.golden_sets.domain
+        )));
+    }
+    for g in &bundle.golden_sets.golden_sets {
+        if !existing_ids.contains(&g.scenario_id) && !bundle.domain.scenarios.iter().any(|s| s.id == g.scenario_id) {
+            return Err(BundleError::Invalid(format!("golden set {} has no scenario", g.scenario_id)));
+        }
+    }
+    if let Some(s) = bundle.mcp_servers.iter().find(|s| s.timeout_ms <= 0) {
+        return Err(BundleError::Invalid(format!("mcp_server {}: timeout_ms must be > 0", s.name)));
+    }
+    Ok(())
+}
+
+fn same<T: Serialize>(a: &T, b: &T) -> bool { serde_json::to_value(a).ok() == serde_json::to_value(b).ok() }
+
+enum Action {
+    CreateDomain,
+    UpdateDescription,
+    ReplaceTools(Vec<String>),
+    ReplaceKeywords(Vec<String>),
+    InsertScenario(ScenarioConfig),
+    UpdateScenario(ScenarioConfig),
+    InsertGolden(GoldenSetEntry),
+    UpdateGolden(GoldenSetEntry),
+    UpsertExternalTool(ExternalToolRow),
+    UpsertMcpServer(McpServerRow),
+    InsertPromptSet(BundlePromptSet),
+    /// 내용이 같은 기존 버전을 활성화.
+    ActivatePromptSet(i64),
+}
+
+struct Plan {
+    actions: Vec<Action>,
+    report: ImportReport,
+}
+
+impl Plan {
+    /// 키 `key` 의 비교 결과를 기록하고, 적용할 동작이 있으면 추가한다.
+    fn item(&mut self, key: String, existing: bool, differs: bool, policy: ImportPolicy, create: Action, update: Action) {
+        match (existing, differs) {
+            | (false, _) => {
+                self.report.created.push(key);
+                self.actions.push(create);
+            },
+            | (true, false) => self.report.unchanged += 1,
+            | (true, true) => self.conflict(key, policy, update),
+        }
+    }
+
+    fn conflict(&mut self, key: String, policy: ImportPolicy, update: Action) {
+        let resolution = match policy {
+            | ImportPolicy::Fail => "unresolved",
+            | ImportPolicy::Merge => "kept",
+            | ImportPolicy::Overwrite => {
+                self.report.updated.push(key.clone());
+                self.actions.push(update);
+                "overwritten"
+            },
+        };
+        self.report.conflicts.push(BundleConflict {
+            key,
+            resolution: resolution.into(),
+        });
+    }
+
+    /// 도구/키워드 같은 집합 필드. merge 는 합집합, overwrite 는 교체.
+    fn set_field(&mut self, key: &str, current: &[String], incoming: &[String], policy: ImportPolicy, make: fn(Vec<String>) -> Action) {
+        let cur: BTreeSet<&String> = current.iter().collect();
+        let inc: BTreeSet<&String> = incoming.iter().collect();
+        if cur == inc {
+            self.report.unchanged += 1;
+            return;
+        }
+        if current.is_empty() {
+            self.report.created.push(key.to_string());
+            self.actions.push(make(incoming.to_vec()));
+            return;
+        }
+        if policy == ImportPolicy::Merge {
+            let mut union = current.to_vec();
+            union.extend(incoming.iter().filter(|i| !cur.contains(i)).cloned());
+            if union.len() > current.len() {
+                self.report.updated.push(key.to_string());
+                self.actions.push(make(union));
+            }
+        }
+        self.conflict(key.to_string(), policy, make(incoming.to_vec()));
+    }
+}
+
+async fn plan_import(store: &SqliteStore, bundle: &DomainBundle, policy: ImportPolicy, dry_run: bool) -> Result<Plan, BundleError> {
+    let name = bundle.manifest.domain.as_str();
+    let summary = store.get_domain_summary(name).await?;
+    let current = store.load_all_domains().await?.into_iter().find(|d| d.name == name);
+    let current_scenarios: HashMap<&str, &ScenarioConfig> = current
+        .as_ref()
+        .map(|d| d.scenarios.iter().map(|s| (s.id.as_str(), s)).collect())
+        .unwrap_or_default();
+    validate(bundle, &current_scenarios.keys().map(|k| k.to_string()).collect())?;
+
+    let mut plan = Plan {
+        actions: Vec::new(),
+        report: ImportReport {
+            domain: name.to_string(),
+            policy,
+            dry_run,
+            applied: false,
+            created: Vec::new(),
+            updated: Vec::new(),
+            unchanged: 0,
+            conflicts: Vec::new(),
+        },
+    };
+
+    let incoming_tools: Vec<String> = bundle.domain.tools.iter().map(|t| t.class_name.clone()).collect();
+    match &summary {
+        | None => {
+            plan.report.created.push(format!("domain:{name}"));
+            plan.actions.push(Action::CreateDomain);
+            if !incoming_tools.is_empty() {
+                plan.actions.push(Action::ReplaceTools(incoming_tools));
+            }
+            if !bundle.manifest.keywords.is_empty() {
+                plan.actions.push(Action::ReplaceKeywords(bundle.manifest.keywords.clone()));
+            }
+        },
+        | Some(s) => {
+            if s.description != bundle.domain.description {
+                plan.conflict("domain:description".into(), policy, Action::UpdateDescription);
+            } else {
+                plan.report.unchanged += 1;
+            }
+            plan.set_field("domain:tools", &s.tool_class_names, &incoming_tools, policy, Action::ReplaceTools);
+            plan.set_field("domain:keywords", &s.keywords, &bundle.manifest.keywords, policy, Action::ReplaceKeywords);
+        },
+    }
+
+    for scen in &bundle.domain.scenarios {
+        let existing = current_scenarios.get(scen.id.as_str());
+        plan.item(
+            format!("scenario:{}", scen.id),
+            existing.is_some(),
+            existing.is_some_and(|e| !same(*e, scen)),
+            policy,
+            Action::InsertScenario(scen.clone()),
+            Action::UpdateScenario(scen.clone()),
+        );
+    }
+
+    let current_golden = if summary.is_some() {
+        store.load_golden_sets_by_domain(name).await?.golden_sets
+    } else {
+        Vec::new()
+    };
+    for entry in &bundle.golden_sets.golden_sets {
+        let existing = current_golden.iter().find(|g| g.scenario_id == entry.scenario_id);
+        plan.item(
+            format!("golden_set:{}", entry.scenario_id),
+            existing.is_some(),
+            existing.is_some_and(|e| !same(e, entry)),
+            policy,
+            Action::InsertGolden(entry.clone()),
+            Action::UpdateGolden(entry.clone()),
+        );
+    }
+
+    let current_tools = store.list_external_tools_by_domain(name).await?;
+    for tool in &bundle.external_tools {
+        let existing = current_tools
+            .iter()
+            .find(|t| t.name == tool.name)
+            .cloned()
+            .map(external_to_bundle)
+            .transpose()?;
+        let row = external_to_row(name, tool);
+        plan.item(
+            format!("external_tool:{}", tool.name),
+            existing.is_some(),
+            existing.is_some_and(|e| &e != tool),
+            policy,
+            Action::UpsertExternalTool(row.clone()),
+            Action::UpsertExternalTool(row),
+        );
+    }
+
+    let current_servers = store.list_mcp_servers_by_domain(name).await?;
+    for server in &bundle.mcp_servers {
+        let existing = current_servers.iter().find(|s| s.name == server.name).cloned().map(mcp_to_bundle).transpose()?;
+        let row = mcp_to_row(name, server);
+        plan.item(
+            format!("mcp_server:{}", server.name),
+            existing.is_some(),
+            existing.is_some_and(|e| &e != server),
+            policy,
+            Action::UpsertMcpServer(row.clone()),
+            Action::UpsertMcpServer(row),
+        );
+    }
+
+    // PromptSet 은 불변 버전이므로 충돌 대신 "같은 내용이 있으면 건너뛰고
+    // 없으면 새 버전으로 추가" 한다. 활성 버전은 새 도메인이거나 overwrite
+    // 일 때만 번들을 따른다.
+    let current_prompts: Vec<(i64, bool, BundlePromptSet)> = store
+        .list_prompt_sets(name)
+        .await?
+        .into_iter()
+        .map(|p| {
+            (
+                p.version,
+                p.is_active,
+                BundlePromptSet {
+                    version: p.version,
+                    perceive_system: p.perceive_system,
+                    perceive_user: p.perceive_user,
+                    policy_system: p.policy_system,
+                    policy_user: p.policy_user,
+                    notes: p.notes,
+                    is_active: p.is_active,
+                },
+            )
+        })
+        .collect();
+    let follow_active = summary.is_none() || policy == ImportPolicy::Overwrite;
+    for ps in &bundle.prompt_sets {
+        match current_prompts.iter().find(|(_, _, c)| c.same_templates(ps)) {
+            | Some((version, is_active, _)) => {
+                plan.report.unchanged += 1;
+                if ps.is_active && !is_active && follow_active {
+                    plan.report.updated.push(format!("prompt_set:v{version}:active"));
+                    plan.actions.push(Action::ActivatePromptSet(*version));
+                }
+            },
+            | None => {
+                plan.report.created.push(format!("prompt_set:v{}", ps.version));
+                let mut ps = ps.clone();
+                ps.is_active &= follow_active;
+                plan.actions.push(Action::InsertPromptSet(ps));
+            },
+        }
+    }
+    Ok(plan)
+}
+
+/// 계획된 작업을 한 트랜잭션으로 적용한다. 중간에 실패하면 앞선 작업도
+/// 모두 되돌린다.
+async fn apply(store: &SqliteStore, bundle: &DomainBundle, actions: Vec<Action>) -> Result<(), BundleError> {
+    let name = bundle.manifest.domain.as_str();
+    let version = bundle.golden_sets.version.as_str();
+    let mut tx = store.pool().begin().await.map_err(StoreError::from)?;
+    let mut position: i64 = sqlx::query_scalar("SELECT COALESCE(MAX(position), -1) + 1 FROM eval_scenarios WHERE domain = ?")
+        .bind(name)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(StoreError::from)?;
+    for action in actions {
+        match action {
+            | Action::CreateDomain => sqlite_store::insert_domain_on(&mut tx, name, &bundle.domain.description).await?,
+            | Action::UpdateDescription => sqlite_store::update_domain_on(&mut tx, name, &bundle.domain.description).await?,
+            | Action::ReplaceTools(tools) => sqlite_store::replace_domain_tools_on(&mut tx, name, &tools).await?,
+            | Action::ReplaceKeywords(keywords) => sqlite_store::replace_domain_keywords_on(&mut tx, name, &keywords).await?,
+            | Action::InsertScenario(s) => {
+                sqlite_store::insert_scenario_on(&mut tx, name, &s, position).await?;
+                position += 1;
+            },
+            | Action::UpdateScenario(s) => sqlite_store::update_scenario_on(&mut tx, name, &s.id, &s).await?,
+            | Action::InsertGolden(g) => sqlite_store::insert_golden_entry_on(&mut tx, name, version, &g).await?,
+            | Action::UpdateGolden(g) => sqlite_store::update_golden_entry_on(&mut tx, name, &g.scenario_id, &g).await?,
+            | Action::UpsertExternalTool(row) => sqlite_store::upsert_external_tool_on(&mut tx, &row).await?,
+            | Action::UpsertMcpServer(row) => sqlite_store::upsert_mcp_server_on(&mut tx, &row).await?,
+            | Action::InsertPromptSet(ps) => {
+                let inserted = sqlite_store::insert_prompt_set_on(
+                    &mut tx,
+                    &PromptSetInsert {
+                        domain_name: name,
+                        perceive_system: &ps.perceive_system,
+                        perceive_user: &ps.perceive_user,
+                        policy_system: &ps.policy_system,
+                        policy_user: &ps.policy_user,
+                        notes: ps.notes.as_deref(),
+                        is_bootstrap: false,
+                    },
+                )
+                .await?;
+                if ps.is_active {
+                    sqlite_store::activate_prompt_set_on(&mut tx, name, inserted).await?;
+                }
+            },
+            | Action::ActivatePromptSet(version) => sqlite_store::activate_prompt_set_on(&mut tx, name, version).await?,
+        }
+    }
+    tx.commit().await.map_err(StoreError::from)?;
+    Ok(())
+}
+
+/// 번들을 스토어로 가져온다. 충돌은 항상 보고되며, `policy` 에 따라
+/// 적용 여부가 정해진다. `dry_run` 이면 계획만 보고한다.
+///
+/// @trace SPEC: SPEC-043
+/// @trace FR: PRD-043/FR-3, PRD-043/FR-4
+pub async fn import_domain(store: &SqliteStore, bundle: &DomainBundle, policy: ImportPolicy, dry_run: bool) -> Result<ImportReport, BundleError> {
+    let Plan {
+        actions,
+        mut report,
+    } = plan_import(store, bundle, policy, dry_run).await?;
+    let blocked = policy == ImportPolicy::Fail && !report.conflicts.is_empty();
+    if dry_run || blocked {
+        return Ok(report);
+    }
+    apply(store, bundle, actions).await?;
+    report.applied = true;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-043
+    // @trace PRD: PRD-043
+    // @trace FR: PRD-043/FR-1, PRD-043/FR-2, PRD-043/FR-3, PRD-043/FR-4
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use crate::models::{GoldenSetExpectedOutput,
+                        GoldenSetInput};
+    use tempfile::tempdir;
+
+    fn scenario(id: &str, task: &str) -> ScenarioConfig {
+        ScenarioConfig {
+            id: id.into(),
+            name: id.into(),
+            description: String::new(),
+            task_description: task.into(),
+            initial_environment: HashMap::from([("amount".to_string(), Value::from(100))]),
+            expected_tools: vec!["healthcare__lookup".into()],
+            success_criteria: HashMap::new(),
+            difficulty: "easy".into(),
+            conversation: None,
+            approval: None,
+            workspace: None,
+        }
+    }
+
+    fn golden(id: &str) -> GoldenSetEntry {
+        GoldenSetEntry {
+            scenario_id: id.into(),
+            input: GoldenSetInput {
+                task: "조회".into(),
+                environment: HashMap::new(),
+            },
+            expected_output: GoldenSetExpectedOutput {
+                tool_sequence: vec!["healthcare__lookup".into()],
+                tool_results: HashMap::new(),
+                tolerance: 0.01,
+                expected_domain: Some("healthcare".into()),
+            },
+        }
+    }
+
+    /// 시나리오·골든셋·키워드·외부 도구·MCP·PromptSet 2버전을 가진 도메인.
+    async fn source_store() -> SqliteStore {
+        let store = SqliteStore::open_in_memory().await.unwrap();
+        store.insert_domain("healthcare", "의료 도메인").await.unwrap();
+        store.replace_domain_tools("healthcare", &["LookupTool".into()]).await.unwrap();
+        store.replace_domain_keywords("healthcare", &["진료".into(), "병원".into()]).await.unwrap();
+        store.insert_scenario("healthcare", &scenario("hc_001", "환자 조회"), 0).await.unwrap();
+        store.insert_golden_entry("healthcare", "1.0", &golden("hc_001")).await.unwrap();
+        store
+            .upsert_external_tool(&ExternalToolRow {
+                name: "lookup".into(),
+                domain: "healthcare".into(),
+                description: "환자 조회".into(),
+                method: "POST".into(),
+                url: "http://127.0.0.1:9000/lookup".into(),
+                headers_json: Some(r#"{"x-team":"eval"}"#.into()),
+                body_template: "{}".into(),
+                params_schema: r#"{"type":"object"}"#.into(),
+                timeout_ms: 5000,
+            })
+            .await
+            .unwrap();
+        store
+            .upsert_mcp_server(&McpServerRow {
+                name: "ehr".into(),
+                domain: "healthcare".into(),
+                transport: "stdio".into(),
+                command: Some("ehr-mcp".into()),
+                args_json: r#"["--demo"]"#.into(),
+                env_json: None,
+                url: None,
+                timeout_ms: 10000,
+            })
+            .await
+            .unwrap();
+        for (i, sys) in ["SYS v1", "SYS v2"].into_iter().enumerate() {
+            store
+                .insert_prompt_set(PromptSetInsert {
+                    domain_name: "healthcare",
+                    perceive_system: sys,
+                    perceive_user: "{task_description}",
+                    policy_system: "POL",
+                    policy_user: "{tools}",
+                    notes: None,
+                    is_bootstrap: i == 0,
+                })
+                .await
+                .unwrap();
+        }
+        store.activate_prompt_set("healthcare", 2).await.unwrap();
+        store
+    }
+
+    /// @trace TC: SPEC-043/TC-1
+    /// @trace FR: PRD-043/FR-1, PRD-043/FR-2, PRD-043/FR-3
+    #[tokio::test]
+    async fn spec043_tc_1_round_trip_through_files_into_empty_store() {
+        let source = source_store().await;
+        let bundle = export_domain(&source, "healthcare").await.unwrap();
+        let dir = tempdir().unwrap();
+        write_bundle_dir(&bundle, dir.path()).unwrap();
+        let manifest = std::fs::read_to_string(dir.path().join(MANIFEST_FILE)).unwrap();
+        assert!(manifest.contains("format_version: 1"));
+        let tools_json = std::fs::read_to_string(dir.path().join(EXTERNAL_TOOLS_FILE)).unwrap();
+        assert!(tools_json.contains("\"x-team\": \"eval\""), "headers 는 구조로 풀어 쓴다");
+
+        let read = read_bundle_dir(dir.path()).unwrap();
+        let target = SqliteStore::open_in_memory().await.unwrap();
+        let report = import_domain(&target, &read, ImportPolicy::Fail, false).await.unwrap();
+        assert!(report.applied);
+        assert!(report.conflicts.is_empty());
+
+        let again = export_domain(&target, "healthcare").await.unwrap();
+        assert!(same(&again.domain, &bundle.domain));
+        assert!(same(&again.golden_sets, &bundle.golden_sets));
+        assert_eq!(again.external_tools, bundle.external_tools);
+        assert_eq!(again.mcp_servers, bundle.mcp_servers);
+        let mut keywords = again.manifest.keywords.clone();
+        keywords.sort();
+        assert_eq!(keywords, vec!["병원".to_string(), "진료".to_string()]);
+        let active: Vec<&str> = again.prompt_sets.iter().filter(|p| p.is_active).map(|p| p.perceive_system.as_str()).collect();
+        assert_eq!(active, vec!["SYS v2"], "활성 PromptSet 도 옮겨진다");
+
+        // 같은 번들을 다시 가져오면 모두 unchanged.
+        let report = import_domain(&target, &read, ImportPolicy::Fail, false).await.unwrap();
+        assert!(report.created.is_empty() && report.updated.is_empty() && report.conflicts.is_empty());
+    }
+
+    /// @trace TC: SPEC-043/TC-2
+    /// @trace FR: PRD-043/FR-4
+    #[tokio::test]
+    async fn spec043_tc_2_conflicts_follow_policy() {
+        let store = source_store().await;
+        let mut bundle = export_domain(&store, "healthcare").await.unwrap();
+        bundle.domain.description = "의료 (수정)".into();
+        bundle.domain.scenarios[0].task_description = "환자 조회 후 예약".into();
+        bundle.domain.scenarios.push(scenario("hc_002", "처방 조회"));
+        bundle.manifest.keywords.push("처방".into());
+
+        // fail: 충돌 보고만, DB 는 그대로.
+        let report = import_domain(&store, &bundle, ImportPolicy::Fail, false).await.unwrap();
+        assert!(!report.applied);
+        let keys: Vec<&str> = report.conflicts.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, vec!["domain:description", "domain:keywords", "scenario:hc_001"]);
+        assert!(report.conflicts.iter().all(|c| c.resolution == "unresolved"));
+        assert_eq!(report.created, vec!["scenario:hc_002".to_string()]);
+        assert_eq!(store.get_domain_summary("healthcare").await.unwrap().unwrap().scenario_count, 1);
+
+        // dry-run overwrite: 계획만.
+        let report = import_domain(&store, &bundle, ImportPolicy::Overwrite, true).await.unwrap();
+        assert!(!report.applied);
+        assert_eq!(store.get_domain_summary("healthcare").await.unwrap().unwrap().scenario_count, 1);
+
+        // merge: 기존 값 유지, 새 시나리오 추가, 키워드 합집합.
+        let report = import_domain(&store, &bundle, ImportPolicy::Merge, false).await.unwrap();
+        assert!(report.applied);
+        let summary = store.get_domain_summary("healthcare").await.unwrap().unwrap();
+        assert_eq!(summary.description, "의료 도메인");
+        assert_eq!(summary.scenario_count, 2);
+        assert_eq!(summary.keywords.len(), 3);
+        let current = store.load_all_domains().await.unwrap();
+        assert_eq!(current[0].scenarios[0].task_description, "환자 조회");
+
+        // overwrite: 번들 내용으로 교체.
+        let report = import_domain(&store, &bundle, ImportPolicy::Overwrite, false).await.unwrap();
+        assert!(report.conflicts.iter().all(|c| c.resolution == "overwritten"));
+        assert_eq!(store.get_domain_summary("healthcare").await.unwrap().unwrap().description, "의료 (수정)");
+        let current = store.load_all_domains().await.unwrap();
+        assert_eq!(current[0].scenarios[0].task_description, "환자 조회 후 예약");
+    }
+
+    /// @trace TC: SPEC-043/TC-3
+    /// @trace FR: PRD-043/FR-2
+    #[tokio::test]
+    async fn spec043_tc_3_rejects_inconsistent_bundle() {
+        let store = source_store().await;
+        let mut bundle = export_domain(&store, "healthcare").await.unwrap();
+        bundle.manifest.format_version = BUNDLE_FORMAT_VERSION + 1;
+        let target = SqliteStore::open_in_memory().await.unwrap();
+        assert!(matches!(
+            import_domain(&target, &bundle, ImportPolicy::Fail, false).await,
+            Err(BundleError::Invalid(_))
+        ));
+
+        bundle.manifest.format_version = BUNDLE_FORMAT_VERSION;
+        bundle.golden_sets.golden_sets.push(golden("hc_999"));
+        let err = import_domain(&target, &bundle, ImportPolicy::Fail, false).await.unwrap_err();
+        assert!(err.to_string().contains("hc_999"));
+        assert!(target.get_domain_summary("healthcare").await.unwrap().is_none());
+
+        bundle.golden_sets.golden_sets.pop();
+        bundle.mcp_servers[0].timeout_ms = 0;
+        let err = import_domain(&target, &bundle, ImportPolicy::Fail, false).await.unwrap_err();
+        assert!(err.to_string().contains("timeout_ms"), "{err}");
+        assert!(target.get_domain_summary("healthcare").await.unwrap().is_none());
+    }
+
+    /// @trace TC: SPEC-043/TC-4
+    /// @trace FR: PRD-043/FR-3
+    #[tokio::test]
+    async fn spec043_tc_4_failed_import_rolls_back() {
+        let source = source_store().await;
+        let bundle = export_domain(&source, "healthcare").await.unwrap();
+        let target = SqliteStore::open_in_memory().await.unwrap();
+        // 도메인·시나리오·골든셋을 쓴 뒤 MCP 서버 단계에서 실패시킨다.
+        sqlx::query("CREATE TRIGGER fail_mcp BEFORE INSERT ON mcp_servers BEGIN SELECT RAISE(ABORT, 'boom'); END")
+            .execute(target.pool())
+            .await
+            .unwrap();
+        let err = import_domain(&target, &bundle, ImportPolicy::Fail, false).await.unwrap_err();
+        assert!(err.to_string().contains("boom"), "{err}");
+        assert!(target.get_domain_summary("healthcare").await.unwrap().is_none());
+        assert!(target.load_all_domains().await.unwrap().is_empty());
+
+        sqlx::query("DROP TRIGGER fail_mcp").execute(target.pool()).await.unwrap();
+        assert!(import_domain(&target, &bundle, ImportPolicy::Fail, false).await.unwrap().applied);
+        assert_eq!(target.get_domain_summary("healthcare").await.unwrap().unwrap().scenario_count, 1);
+    }
+}
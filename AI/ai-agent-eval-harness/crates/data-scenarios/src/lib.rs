@@ -1,3 +1,4 @@
+pub mod bundle;
 pub mod loader;
 pub mod models;
 pub mod seed_embedded;
@@ -1,3 +1,6 @@
+use agent_models::{approval::ApprovalPolicy,
+                   domain_config::{ConversationConfig,
+                                   WorkspaceConfig}};
 use eval_models::traits::{EvalContext,
                           GoldenSetContext};
 use serde::{Deserialize,
@@ -16,6 +19,15 @@ pub struct Scenario {
     pub difficulty: String,
     #[serde(default = "default_domain")]
     pub domain: String,
+    /// SPEC-032: 멀티턴 시나리오 정의
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conversation: Option<ConversationConfig>,
+    /// SPEC-044: 위험 도구 승인 정책
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approval: Option<ApprovalPolicy>,
+    /// SPEC-045: 작업 공간 fixture 와 기대 파일
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<WorkspaceConfig>,
 }
 
 fn default_domain() -> String { "general".into() }
@@ -68,6 +80,8 @@ impl GoldenSetFile {
 impl EvalContext for Scenario {
     fn expected_tools(&self) -> &[String] { &self.expected_tools }
     fn success_criteria(&self) -> &HashMap<String, serde_json::Value> { &self.success_criteria }
+
+    fn expected_domain(&self) -> Option<&str> { Some(self.domain.as_str()).filter(|d| !d.is_empty()) }
 }
 
 impl GoldenSetContext for GoldenSetEntry {
@@ -15,8 +15,10 @@ use crate::models::{GoldenSetEntry,
 use agent_models::domain_config::{DomainConfig,
                                   ScenarioConfig,
                                   ToolConfig};
+use serde::Serialize;
 use serde_json::Value;
 use sqlx::{Row,
+           SqliteConnection,
            SqlitePool,
            sqlite::{SqliteConnectOptions,
                     SqlitePoolOptions}};
@@ -25,7 +27,7 @@ use std::{collections::HashMap,
                  PathBuf}};
 use thiserror::Error;
 
-const SCHEMA_VERSION: i64 = 7;
+const SCHEMA_VERSION: i64 = 14;
 
 /// sqlx UNIQUE 제약 위반을 `StoreError::Conflict` 로 매핑.
 fn map_unique_violation(err: sqlx::Error, subject: String) -> StoreError {
@@ -277,6 +279,57 @@ impl SqliteStore {
             "CREATE UNIQUE INDEX IF NOT EXISTS idx_prompt_sets_active_per_domain
                 ON prompt_sets(domain_name) WHERE is_active = 1",
             "CREATE INDEX IF NOT EXISTS idx_prompt_sets_domain ON prompt_sets(domain_name)",
+            // SPEC-026 v8: LLM judge 루브릭 채점 결과. 궤적당 루브릭별 1행.
+            "CREATE TABLE IF NOT EXISTS judge_results (
+                task_id         TEXT NOT NULL,
+                rubric_name     TEXT NOT NULL,
+                weighted_score  REAL NOT NULL,
+                result_json     TEXT NOT NULL,
+                created_at      TEXT NOT NULL DEFAULT (datetime('now')),
+                PRIMARY KEY (task_id, rubric_name),
+                FOREIGN KEY (task_id) REFERENCES trajectories(task_id) ON DELETE CASCADE
+            )",
+            // SPEC-036 v10: 임베딩 라우터용 도메인 벡터. 모델별로 따로 보관하며
+            // vector 는 f32 배열 JSON.
+            "CREATE TABLE IF NOT EXISTS domain_embeddings (
+                domain      TEXT NOT NULL,
+                model       TEXT NOT NULL,
+                kind        TEXT NOT NULL,
+                text        TEXT NOT NULL,
+                vector_json TEXT NOT NULL,
+                created_at  TEXT NOT NULL DEFAULT (datetime('now')),
+                PRIMARY KEY (domain, model, kind, text),
+                FOREIGN KEY (domain) REFERENCES domains(name) ON DELETE CASCADE
+            )",
+            // SPEC-037 v11: MCP 서버. transport 가 'stdio' 면 command/args_json/
+            // env_json, 'http' 면 url 을 사용한다.
+            "CREATE TABLE IF NOT EXISTS mcp_servers (
+                name        TEXT NOT NULL,
+                domain      TEXT NOT NULL,
+                transport   TEXT NOT NULL DEFAULT 'stdio',
+                command     TEXT,
+                args_json   TEXT NOT NULL DEFAULT '[]',
+                env_json    TEXT,
+                url         TEXT,
+                timeout_ms  INTEGER NOT NULL DEFAULT 10000,
+                created_at  TEXT NOT NULL DEFAULT (datetime('now')),
+                PRIMARY KEY (domain, name),
+                FOREIGN KEY (domain) REFERENCES domains(name) ON DELETE CASCADE
+            )",
+            "CREATE INDEX IF NOT EXISTS idx_mcp_servers_domain ON mcp_servers(domain)",
+            // SPEC-042 v12: LLM 이 생성한 시나리오+골든셋 초안. status 는
+            // 'pending' → 'approved' | 'rejected'. 승인 시에만 eval_scenarios /
+            // golden_sets 에 INSERT 된다.
+            "CREATE TABLE IF NOT EXISTS scenario_drafts (
+                id            INTEGER PRIMARY KEY AUTOINCREMENT,
+                domain        TEXT NOT NULL,
+                scenario_json TEXT NOT NULL,
+                golden_json   TEXT NOT NULL,
+                status        TEXT NOT NULL DEFAULT 'pending',
+                created_at    TEXT NOT NULL DEFAULT (datetime('now')),
+                FOREIGN KEY (domain) REFERENCES domains(name) ON DELETE CASCADE
+            )",
+            "CREATE INDEX IF NOT EXISTS idx_scenario_drafts_domain ON scenario_drafts(domain, status)",
         ];
         for sql in stmts.iter() {
             sqlx::query(sql).execute(&self.pool).await?;
@@ -296,6 +349,12 @@ impl SqliteStore {
         // SPEC-025 v7: trajectories / evaluations 에 prompt_set_id 컬럼 추가.
         // ALTER TABLE ADD COLUMN 은 IF NOT EXISTS 미지원 → PRAGMA 사전 검사.
         self.migrate_v7_prompt_set_id().await?;
+        // SPEC-032 v9: eval_scenarios 에 멀티턴 정의 컬럼 추가.
+        self.migrate_v9_conversation().await?;
+        // SPEC-044 v13: eval_scenarios 에 도구 승인 정책 컬럼 추가.
+        self.migrate_v13_approval().await?;
+        // SPEC-045 v14: eval_scenarios 에 작업 공간 정의 컬럼 추가.
+        self.migrate_v14_workspace().await?;
 
         sqlx::query("INSERT OR IGNORE INTO schema_migrations (version, applied_at) VALUES (?, datetime('now'))")
             .bind(SCHEMA_VERSION)
@@ -385,6 +444,63 @@ impl SqliteStore {
         Ok(())
     }
 
+    /// SPEC-032 v9: `eval_scenarios` 에 `conversation_json TEXT NULL` 추가.
+    /// NULL 이면 단일 턴 시나리오.
+    ///
+    /// @trace SPEC: SPEC-032
+    /// @trace FR: PRD-032/FR-1
+    async fn migrate_v9_conversation(&self) -> Result<(), StoreError> {
+        let cols = sqlx::query("PRAGMA table_info('eval_scenarios')").fetch_all(&self.pool).await?;
+        let has = cols.iter().any(|r| {
+            let n: String = r.get("name");
+            n == "conversation_json"
+        });
+        if !has {
+            sqlx::query("ALTER TABLE eval_scenarios ADD COLUMN conversation_json TEXT")
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// SPEC-044 v13: `eval_scenarios` 에 `approval_json TEXT NULL` 추가.
+    /// NULL 이면 실행 옵션의 승인 정책을 따른다.
+    ///
+    /// @trace SPEC: SPEC-044
+    /// @trace FR: PRD-044/FR-1
+    async fn migrate_v13_approval(&self) -> Result<(), StoreError> {
+        let cols = sqlx::query("PRAGMA table_info('eval_scenarios')").fetch_all(&self.pool).await?;
+        let has = cols.iter().any(|r| {
+            let n: String = r.get("name");
+            n == "approval_json"
+        });
+        if !has {
+            sqlx::query("ALTER TABLE eval_scenarios ADD COLUMN approval_json TEXT")
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// SPEC-045 v14: `eval_scenarios` 에 `workspace_json TEXT NULL` 추가.
+    /// NULL 이면 빈 작업 공간에서 실행하고 파일 비교를 하지 않는다.
+    ///
+    /// @trace SPEC: SPEC-045
+    /// @trace FR: PRD-045/FR-1
+    async fn migrate_v14_workspace(&self) -> Result<(), StoreError> {
+        let cols = sqlx::query("PRAGMA table_info('eval_scenarios')").fetch_all(&self.pool).await?;
+        let has = cols.iter().any(|r| {
+            let n: String = r.get("name");
+            n == "workspace_json"
+        });
+        if !has {
+            sqlx::query("ALTER TABLE eval_scenarios ADD COLUMN workspace_json TEXT")
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
     /// eval_scenarios 테이블이 비어 있는지.
     pub async fn is_empty(&self) -> Result<bool, StoreError> {
         let row = sqlx::query("SELECT COUNT(*) AS cnt FROM eval_scenarios").fetch_one(&self.pool).await?;
@@ -509,12 +625,15 @@ impl SqliteStore {
                 let env_json = serde_json::to_string(&scen.initial_environment)?;
                 let tools_json = serde_json::to_string(&scen.expected_tools)?;
                 let crit_json = serde_json::to_string(&scen.success_criteria)?;
+                let conv_json = scen.conversation.as_ref().map(serde_json::to_string).transpose()?;
+                let approval_json = scen.approval.as_ref().map(serde_json::to_string).transpose()?;
+                let workspace_json = scen.workspace.as_ref().map(serde_json::to_string).transpose()?;
                 let r = sqlx::query(
                     "INSERT OR IGNORE INTO eval_scenarios
                      (domain, id, name, description, task_description,
                       initial_environment, expected_tools, success_criteria,
-                      difficulty, position)
-                     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                      difficulty, position, conversation_json, approval_json, workspace_json)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
                 )
                 .bind(&cfg.name)
                 .bind(&scen.id)
@@ -526,6 +645,9 @@ impl SqliteStore {
                 .bind(crit_json)
                 .bind(&scen.difficulty)
                 .bind(idx as i64)
+                .bind(conv_json)
+                .bind(approval_json)
+                .bind(workspace_json)
                 .execute(&mut *tx)
                 .await?;
                 if r.rows_affected() > 0 {
@@ -604,7 +726,8 @@ impl SqliteStore {
 
             let scen_rows = sqlx::query(
                 "SELECT id, name, description, task_description,
-                        initial_environment, expected_tools, success_criteria, difficulty
+                        initial_environment, expected_tools, success_criteria, difficulty,
+                        conversation_json, approval_json, workspace_json
                  FROM eval_scenarios
                  WHERE domain = ?
                  ORDER BY position",
@@ -621,6 +744,12 @@ impl SqliteStore {
                 let initial_environment: HashMap<String, Value> = serde_json::from_str(&env_json)?;
                 let expected_tools: Vec<String> = serde_json::from_str(&tools_json)?;
                 let success_criteria: HashMap<String, Value> = serde_json::from_str(&crit_json)?;
+                let conv_json: Option<String> = r.get("conversation_json");
+                let conversation = conv_json.as_deref().map(serde_json::from_str).transpose()?;
+                let approval_json: Option<String> = r.get("approval_json");
+                let approval = approval_json.as_deref().map(serde_json::from_str).transpose()?;
+                let workspace_json: Option<String> = r.get("workspace_json");
+                let workspace = workspace_json.as_deref().map(serde_json::from_str).transpose()?;
                 scenarios.push(ScenarioConfig {
                     id: r.get("id"),
                     name: r.get("name"),
@@ -630,6 +759,9 @@ impl SqliteStore {
                     expected_tools,
                     success_criteria,
                     difficulty: r.get("difficulty"),
+                    conversation,
+                    approval,
+                    workspace,
                 });
             }
 
@@ -699,39 +831,8 @@ impl SqliteStore {
     /// @trace SPEC: SPEC-019
     /// @trace FR: PRD-019/FR-1, PRD-019/FR-5
     pub async fn insert_scenario(&self, domain: &str, scenario: &ScenarioConfig, position: i64) -> Result<(), StoreError> {
-        // 상위 도메인이 없으면 생성 (seed 단계가 아닐 때 대비).
-        sqlx::query("INSERT OR IGNORE INTO domains (name, description) VALUES (?, '')")
-            .bind(domain)
-            .execute(&self.pool)
-            .await?;
-
-        let env_json = serde_json::to_string(&scenario.initial_environment)?;
-        let tools_json = serde_json::to_string(&scenario.expected_tools)?;
-        let crit_json = serde_json::to_string(&scenario.success_criteria)?;
-
-        let res = sqlx::query(
-            "INSERT INTO eval_scenarios
-             (domain, id, name, description, task_description,
-              initial_environment, expected_tools, success_criteria,
-              difficulty, position)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
-        )
-        .bind(domain)
-        .bind(&scenario.id)
-        .bind(&scenario.name)
-        .bind(&scenario.description)
-        .bind(&scenario.task_description)
-        .bind(env_json)
-        .bind(tools_json)
-        .bind(crit_json)
-        .bind(&scenario.difficulty)
-        .bind(position)
-        .execute(&self.pool)
-        .await;
-        match res {
-            | Ok(_) => Ok(()),
-            | Err(e) => Err(map_unique_violation(e, format!("scenario ({domain}, {})", scenario.id))),
-        }
+        let mut conn = self.pool.acquire().await?;
+        insert_scenario_on(&mut conn, domain, scenario, position).await
     }
 
     /// 기존 시나리오 UPDATE. 없으면 `StoreError::NotFound`.
@@ -739,31 +840,8 @@ impl SqliteStore {
     /// @trace SPEC: SPEC-019
     /// @trace FR: PRD-019/FR-1, PRD-019/FR-5
     pub async fn update_scenario(&self, domain: &str, id: &str, scenario: &ScenarioConfig) -> Result<(), StoreError> {
-        let env_json = serde_json::to_string(&scenario.initial_environment)?;
-        let tools_json = serde_json::to_string(&scenario.expected_tools)?;
-        let crit_json = serde_json::to_string(&scenario.success_criteria)?;
-        let res = sqlx::query(
-            "UPDATE eval_scenarios
-             SET name = ?, description = ?, task_description = ?,
-                 initial_environment = ?, expected_tools = ?, success_criteria = ?,
-                 difficulty = ?
-             WHERE domain = ? AND id = ?",
-        )
-        .bind(&scenario.name)
-        .bind(&scenario.description)
-        .bind(&scenario.task_description)
-        .bind(env_json)
-        .bind(tools_json)
-        .bind(crit_json)
-        .bind(&scenario.difficulty)
-        .bind(domain)
-        .bind(id)
-        .execute(&self.pool)
-        .await?;
-        if res.rows_affected() == 0 {
-            return Err(StoreError::NotFound(format!("scenario ({domain}, {id})")));
-        }
-        Ok(())
+        let mut conn = self.pool.acquire().await?;
+        update_scenario_on(&mut conn, domain, id, scenario).await
     }
 
     /// 시나리오 DELETE. 없으면 `StoreError::NotFound`. 연결된 golden_set 는
@@ -788,30 +866,8 @@ impl SqliteStore {
     /// @trace SPEC: SPEC-019
     /// @trace FR: PRD-019/FR-2
     pub async fn insert_golden_entry(&self, domain: &str, version: &str, entry: &GoldenSetEntry) -> Result<(), StoreError> {
-        let env_json = serde_json::to_string(&entry.input.environment)?;
-        let seq_json = serde_json::to_string(&entry.expected_output.tool_sequence)?;
-        let res_json = serde_json::to_string(&entry.expected_output.tool_results)?;
-        let res = sqlx::query(
-            "INSERT INTO golden_sets
-             (domain, scenario_id, version, task,
-              input_environment, tool_sequence, tool_results, tolerance, expected_domain)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
-        )
-        .bind(domain)
-        .bind(&entry.scenario_id)
-        .bind(version)
-        .bind(&entry.input.task)
-        .bind(env_json)
-        .bind(seq_json)
-        .bind(res_json)
-        .bind(entry.expected_output.tolerance)
-        .bind(&entry.expected_output.expected_domain)
-        .execute(&self.pool)
-        .await;
-        match res {
-            | Ok(_) => Ok(()),
-            | Err(e) => Err(map_unique_violation(e, format!("golden_entry ({domain}, {})", entry.scenario_id))),
-        }
+        let mut conn = self.pool.acquire().await?;
+        insert_golden_entry_on(&mut conn, domain, version, entry).await
     }
 
     /// 골든셋 엔트리 UPDATE.
@@ -819,29 +875,8 @@ impl SqliteStore {
     /// @trace SPEC: SPEC-019
     /// @trace FR: PRD-019/FR-2, PRD-019/FR-5
     pub async fn update_golden_entry(&self, domain: &str, scenario_id: &str, entry: &GoldenSetEntry) -> Result<(), StoreError> {
-        let env_json = serde_json::to_string(&entry.input.environment)?;
-        let seq_json = serde_json::to_string(&entry.expected_output.tool_sequence)?;
-        let res_json = serde_json::to_string(&entry.expected_output.tool_results)?;
-        let res = sqlx::query(
-            "UPDATE golden_sets
-             SET task = ?, input_environment = ?, tool_sequence = ?,
-                 tool_results = ?, tolerance = ?, expected_domain = ?
-             WHERE domain = ? AND scenario_id = ?",
-        )
-        .bind(&entry.input.task)
-        .bind(env_json)
-        .bind(seq_json)
-        .bind(res_json)
-        .bind(entry.expected_output.tolerance)
-        .bind(&entry.expected_output.expected_domain)
-        .bind(domain)
-        .bind(scenario_id)
-        .execute(&self.pool)
-        .await?;
-        if res.rows_affected() == 0 {
-            return Err(StoreError::NotFound(format!("golden_entry ({domain}, {scenario_id})")));
-        }
-        Ok(())
+        let mut conn = self.pool.acquire().await?;
+        update_golden_entry_on(&mut conn, domain, scenario_id, entry).await
     }
 
     /// 골든셋 엔트리 DELETE.
@@ -1090,6 +1125,12 @@ impl SqliteStore {
             .flatten()
             .and_then(|s| serde_json::from_str(&s).ok());
         let success: i64 = r.get("success");
+        let llm_judge: Vec<serde_json::Value> = self
+            .list_judge_results(task_id)
+            .await?
+            .into_iter()
+            .filter_map(|j| serde_json::from_str(&j.result_json).ok())
+            .collect();
         Ok(Some(serde_json::json!({
             "trajectory": {
                 "task_id": r.get::<String, _>("task_id"),
@@ -1110,6 +1151,7 @@ impl SqliteStore {
                 "domain_routing_score": r.try_get::<Option<f64>, _>("domain_routing_score").ok().flatten(),
                 "overall_score": r.try_get::<Option<f64>, _>("overall_score").ok().flatten(),
             },
+            "llm_judge": llm_judge,
         })))
     }
 
@@ -1153,15 +1195,8 @@ impl SqliteStore {
     /// @trace SPEC: SPEC-022
     /// @trace FR: PRD-022/FR-1
     pub async fn insert_domain(&self, name: &str, description: &str) -> Result<(), StoreError> {
-        let res = sqlx::query("INSERT INTO domains (name, description) VALUES (?, ?)")
-            .bind(name)
-            .bind(description)
-            .execute(&self.pool)
-            .await;
-        match res {
-            | Ok(_) => Ok(()),
-            | Err(e) => Err(map_unique_violation(e, format!("domain ({name})"))),
-        }
+        let mut conn = self.pool.acquire().await?;
+        insert_domain_on(&mut conn, name, description).await
     }
 
     /// 도메인 description 갱신. 미존재 시 `NotFound`.
@@ -1169,15 +1204,8 @@ impl SqliteStore {
     /// @trace SPEC: SPEC-022
     /// @trace FR: PRD-022/FR-1
     pub async fn update_domain(&self, name: &str, description: &str) -> Result<(), StoreError> {
-        let res = sqlx::query("UPDATE domains SET description = ? WHERE name = ?")
-            .bind(description)
-            .bind(name)
-            .execute(&self.pool)
-            .await?;
-        if res.rows_affected() == 0 {
-            return Err(StoreError::NotFound(format!("domain ({name})")));
-        }
-        Ok(())
+        let mut conn = self.pool.acquire().await?;
+        update_domain_on(&mut conn, name, description).await
     }
 
     /// 도메인 삭제. cascade 로 scenarios/goldens/tools/keywords 모두 함께 삭제.
@@ -1251,16 +1279,7 @@ impl SqliteStore {
     /// @trace FR: PRD-022/FR-1
     pub async fn replace_domain_tools(&self, domain: &str, tool_class_names: &[String]) -> Result<(), StoreError> {
         let mut tx = self.pool.begin().await?;
-        sqlx::query("DELETE FROM domain_tools WHERE domain = ?").bind(domain).execute(&mut *tx).await?;
-        for (idx, name) in tool_class_names.iter().enumerate() {
-            sqlx::query("INSERT INTO domain_tools (domain, class_name, module_path, position) VALUES (?, ?, ?, ?)")
-                .bind(domain)
-                .bind(name)
-                .bind("") // module_path: SPEC-022 이후 의미 없음. 후속 정리.
-                .bind(idx as i64)
-                .execute(&mut *tx)
-                .await?;
-        }
+        replace_domain_tools_on(&mut tx, domain, tool_class_names).await?;
         tx.commit().await?;
         Ok(())
     }
@@ -1271,21 +1290,7 @@ impl SqliteStore {
     /// @trace FR: PRD-022/FR-3
     pub async fn replace_domain_keywords(&self, domain: &str, keywords: &[String]) -> Result<(), StoreError> {
         let mut tx = self.pool.begin().await?;
-        sqlx::query("DELETE FROM domain_keywords WHERE domain = ?")
-            .bind(domain)
-            .execute(&mut *tx)
-            .await?;
-        for kw in keywords {
-            let trimmed = kw.trim();
-            if trimmed.is_empty() {
-                continue;
-            }
-            sqlx::query("INSERT OR IGNORE INTO domain_keywords (domain, keyword) VALUES (?, ?)")
-                .bind(domain)
-                .bind(trimmed)
-                .execute(&mut *tx)
-                .await?;
-        }
+        replace_domain_keywords_on(&mut tx, domain, keywords).await?;
         tx.commit().await?;
         Ok(())
     }
@@ -1330,6 +1335,60 @@ impl SqliteStore {
         Ok(inserted)
     }
 
+    // =========================================================================
+    // SPEC-036: domain_embeddings (임베딩 라우터)
+    // =========================================================================
+
+    /// `model` 로 만든 모든 도메인 벡터를 domain, kind, text 정렬로 반환.
+    ///
+    /// @trace SPEC: SPEC-036
+    /// @trace FR: PRD-036/FR-2
+    pub async fn list_domain_embeddings(&self, model: &str) -> Result<Vec<DomainEmbeddingRow>, StoreError> {
+        let rows = sqlx::query(
+            "SELECT domain, kind, text, vector_json FROM domain_embeddings
+             WHERE model = ? ORDER BY domain, kind, text",
+        )
+        .bind(model)
+        .fetch_all(&self.pool)
+        .await?;
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            let vector_json: String = r.get("vector_json");
+            out.push(DomainEmbeddingRow {
+                domain: r.get("domain"),
+                kind: r.get("kind"),
+                text: r.get("text"),
+                vector: serde_json::from_str(&vector_json)?,
+            });
+        }
+        Ok(out)
+    }
+
+    /// 도메인의 `model` 벡터를 통째로 교체. 다른 모델의 벡터는 유지한다.
+    ///
+    /// @trace SPEC: SPEC-036
+    /// @trace FR: PRD-036/FR-2
+    pub async fn replace_domain_embeddings(&self, domain: &str, model: &str, rows: &[DomainEmbeddingRow]) -> Result<(), StoreError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM domain_embeddings WHERE domain = ? AND model = ?")
+            .bind(domain)
+            .bind(model)
+            .execute(&mut *tx)
+            .await?;
+        for row in rows {
+            sqlx::query("INSERT OR REPLACE INTO domain_embeddings (domain, model, kind, text, vector_json) VALUES (?, ?, ?, ?, ?)")
+                .bind(domain)
+                .bind(model)
+                .bind(&row.kind)
+                .bind(&row.text)
+                .bind(serde_json::to_string(&row.vector)?)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
     // =========================================================================
     // SPEC-023: external_tools CRUD
     // =========================================================================
@@ -1365,25 +1424,8 @@ impl SqliteStore {
     /// @trace SPEC: SPEC-023
     /// @trace FR: PRD-023/FR-1
     pub async fn upsert_external_tool(&self, row: &ExternalToolRow) -> Result<(), StoreError> {
-        sqlx::query(
-            "INSERT OR REPLACE INTO external_tools
-             (name, domain, description, method, url, headers_json, body_template, params_schema, timeout_ms, created_at)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, COALESCE((SELECT created_at FROM external_tools WHERE domain = ? AND name = ?), datetime('now')))",
-        )
-        .bind(&row.name)
-        .bind(&row.domain)
-        .bind(&row.description)
-        .bind(&row.method)
-        .bind(&row.url)
-        .bind(&row.headers_json)
-        .bind(&row.body_template)
-        .bind(&row.params_schema)
-        .bind(row.timeout_ms)
-        .bind(&row.domain)
-        .bind(&row.name)
-        .execute(&self.pool)
-        .await?;
-        Ok(())
+        let mut conn = self.pool.acquire().await?;
+        upsert_external_tool_on(&mut conn, row).await
     }
 
     /// external tool 삭제. 미존재 시 NotFound.
@@ -1399,6 +1441,153 @@ impl SqliteStore {
         Ok(())
     }
 
+    // =========================================================================
+    // SPEC-037: mcp_servers CRUD
+    // =========================================================================
+
+    /// 모든 MCP 서버 행을 반환. domain, name 정렬.
+    ///
+    /// @trace SPEC: SPEC-037
+    /// @trace FR: PRD-037/FR-4
+    pub async fn list_mcp_servers(&self) -> Result<Vec<McpServerRow>, StoreError> {
+        let rows = sqlx::query(
+            "SELECT name, domain, transport, command, args_json, env_json, url, timeout_ms
+             FROM mcp_servers ORDER BY domain, name",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(row_to_mcp_server).collect())
+    }
+
+    /// 특정 도메인의 MCP 서버 행 반환.
+    pub async fn list_mcp_servers_by_domain(&self, domain: &str) -> Result<Vec<McpServerRow>, StoreError> {
+        let rows = sqlx::query(
+            "SELECT name, domain, transport, command, args_json, env_json, url, timeout_ms
+             FROM mcp_servers WHERE domain = ? ORDER BY name",
+        )
+        .bind(domain)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(row_to_mcp_server).collect())
+    }
+
+    /// MCP 서버 1행 INSERT OR REPLACE.
+    ///
+    /// @trace SPEC: SPEC-037
+    /// @trace FR: PRD-037/FR-4
+    pub async fn upsert_mcp_server(&self, row: &McpServerRow) -> Result<(), StoreError> {
+        let mut conn = self.pool.acquire().await?;
+        upsert_mcp_server_on(&mut conn, row).await
+    }
+
+    /// MCP 서버 삭제. 미존재 시 NotFound.
+    pub async fn delete_mcp_server(&self, domain: &str, name: &str) -> Result<(), StoreError> {
+        let res = sqlx::query("DELETE FROM mcp_servers WHERE domain = ? AND name = ?")
+            .bind(domain)
+            .bind(name)
+            .execute(&self.pool)
+            .await?;
+        if res.rows_affected() == 0 {
+            return Err(StoreError::NotFound(format!("mcp_server ({domain}, {name})")));
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // SPEC-042: scenario_drafts (생성 초안 → 승인/거절)
+    // =========================================================================
+
+    /// 초안 1건을 `pending` 으로 저장하고 id 를 돌려준다.
+    ///
+    /// @trace SPEC: SPEC-042
+    /// @trace FR: PRD-042/FR-4
+    pub async fn insert_scenario_draft(&self, domain: &str, scenario: &ScenarioConfig, golden: &GoldenSetEntry) -> Result<i64, StoreError> {
+        let res = sqlx::query("INSERT INTO scenario_drafts (domain, scenario_json, golden_json) VALUES (?, ?, ?)")
+            .bind(domain)
+            .bind(serde_json::to_string(scenario)?)
+            .bind(serde_json::to_string(golden)?)
+            .execute(&self.pool)
+            .await?;
+        Ok(res.last_insert_rowid())
+    }
+
+    /// 초안 목록. `domain`, `status` 로 거를 수 있으며 id 오름차순.
+    pub async fn list_scenario_drafts(&self, domain: Option<&str>, status: Option<&str>) -> Result<Vec<ScenarioDraftRow>, StoreError> {
+        let rows = sqlx::query(
+            "SELECT id, domain, scenario_json, golden_json, status, created_at FROM scenario_drafts
+             WHERE (?1 IS NULL OR domain = ?1) AND (?2 IS NULL OR status = ?2)
+             ORDER BY id",
+        )
+        .bind(domain)
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+        rows.into_iter().map(row_to_scenario_draft).collect()
+    }
+
+    pub async fn get_scenario_draft(&self, id: i64) -> Result<Option<ScenarioDraftRow>, StoreError> {
+        let row = sqlx::query("SELECT id, domain, scenario_json, golden_json, status, created_at FROM scenario_drafts WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        row.map(row_to_scenario_draft).transpose()
+    }
+
+    /// `pending` 초안을 승인한다. 한 트랜잭션 안에서 시나리오(도메인의 마지막
+    /// position 뒤)와 골든셋 엔트리(도메인의 기존 버전)를 INSERT 하고 상태를
+    /// `approved` 로 바꾼다. 이미 처리된 초안이거나 같은 id 의 시나리오가
+    /// 있으면 `Conflict`.
+    ///
+    /// @trace SPEC: SPEC-042
+    /// @trace FR: PRD-042/FR-5
+    pub async fn approve_scenario_draft(&self, id: i64) -> Result<ScenarioDraftRow, StoreError> {
+        let mut tx = self.pool.begin().await?;
+        let row = sqlx::query("SELECT id, domain, scenario_json, golden_json, status, created_at FROM scenario_drafts WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or_else(|| StoreError::NotFound(format!("scenario_draft {id}")))?;
+        let mut draft = row_to_scenario_draft(row)?;
+        if draft.status != "pending" {
+            return Err(StoreError::Conflict(format!("scenario_draft {id} is already {}", draft.status)));
+        }
+        let position: i64 = sqlx::query_scalar("SELECT COALESCE(MAX(position), -1) + 1 FROM eval_scenarios WHERE domain = ?")
+            .bind(&draft.domain)
+            .fetch_one(&mut *tx)
+            .await?;
+        let version: Option<String> = sqlx::query_scalar("SELECT version FROM golden_sets WHERE domain = ? LIMIT 1")
+            .bind(&draft.domain)
+            .fetch_optional(&mut *tx)
+            .await?;
+        insert_scenario_on(&mut tx, &draft.domain, &draft.scenario, position).await?;
+        insert_golden_entry_on(&mut tx, &draft.domain, version.as_deref().unwrap_or("1.0"), &draft.golden).await?;
+        sqlx::query("UPDATE scenario_drafts SET status = 'approved' WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        draft.status = "approved".into();
+        Ok(draft)
+    }
+
+    /// `pending` 초안을 거절한다. 행은 중복 검사 이력으로 남는다.
+    ///
+    /// @trace SPEC: SPEC-042
+    /// @trace FR: PRD-042/FR-5
+    pub async fn reject_scenario_draft(&self, id: i64) -> Result<(), StoreError> {
+        let res = sqlx::query("UPDATE scenario_drafts SET status = 'rejected' WHERE id = ? AND status = 'pending'")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        if res.rows_affected() == 0 {
+            return match self.get_scenario_draft(id).await? {
+                | Some(d) => Err(StoreError::Conflict(format!("scenario_draft {id} is already {}", d.status))),
+                | None => Err(StoreError::NotFound(format!("scenario_draft {id}"))),
+            };
+        }
+        Ok(())
+    }
+
     // =========================================================================
     // SPEC-025: prompt_sets CRUD (도메인별 프롬프트 번들 + 불변 버전관리)
     // =========================================================================
@@ -1449,33 +1638,7 @@ impl SqliteStore {
     /// @trace FR: PRD-025/FR-1, PRD-025/FR-6
     pub async fn insert_prompt_set(&self, row: PromptSetInsert<'_>) -> Result<PromptSetRow, StoreError> {
         let mut tx = self.pool.begin().await?;
-        let next_version: i64 = sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) + 1 FROM prompt_sets WHERE domain_name = ?")
-            .bind(row.domain_name)
-            .fetch_one(&mut *tx)
-            .await?;
-        let any_existing: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM prompt_sets WHERE domain_name = ?")
-            .bind(row.domain_name)
-            .fetch_one(&mut *tx)
-            .await?;
-        let is_active = if any_existing == 0 { 1_i64 } else { 0_i64 };
-        let is_bootstrap = if row.is_bootstrap { 1_i64 } else { 0_i64 };
-        sqlx::query(
-            "INSERT INTO prompt_sets
-             (domain_name, version, perceive_system, perceive_user, policy_system, policy_user,
-              notes, is_active, is_bootstrap, created_at)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, datetime('now'))",
-        )
-        .bind(row.domain_name)
-        .bind(next_version)
-        .bind(row.perceive_system)
-        .bind(row.perceive_user)
-        .bind(row.policy_system)
-        .bind(row.policy_user)
-        .bind(row.notes)
-        .bind(is_active)
-        .bind(is_bootstrap)
-        .execute(&mut *tx)
-        .await?;
+        let next_version = insert_prompt_set_on(&mut tx, &row).await?;
         tx.commit().await?;
         // 삽입된 행을 다시 읽어 반환 (id 포함)
         self.get_prompt_set(row.domain_name, next_version)
@@ -1490,24 +1653,7 @@ impl SqliteStore {
     /// @trace FR: PRD-025/FR-3, PRD-025/FR-7
     pub async fn activate_prompt_set(&self, domain: &str, version: i64) -> Result<(), StoreError> {
         let mut tx = self.pool.begin().await?;
-        // 존재 확인
-        let exists: Option<i64> = sqlx::query_scalar("SELECT id FROM prompt_sets WHERE domain_name = ? AND version = ?")
-            .bind(domain)
-            .bind(version)
-            .fetch_optional(&mut *tx)
-            .await?;
-        if exists.is_none() {
-            return Err(StoreError::NotFound(format!("prompt_set {domain}/v{version}")));
-        }
-        sqlx::query("UPDATE prompt_sets SET is_active = 0 WHERE domain_name = ? AND is_active = 1")
-            .bind(domain)
-            .execute(&mut *tx)
-            .await?;
-        sqlx::query("UPDATE prompt_sets SET is_active = 1 WHERE domain_name = ? AND version = ?")
-            .bind(domain)
-            .bind(version)
-            .execute(&mut *tx)
-            .await?;
+        activate_prompt_set_on(&mut tx, domain, version).await?;
         tx.commit().await?;
         Ok(())
     }
@@ -1566,6 +1712,53 @@ impl SqliteStore {
         }
         Ok(inserted)
     }
+
+    // =========================================================================
+    // SPEC-026: judge_results (LLM-as-judge 루브릭 채점)
+    // =========================================================================
+
+    /// judge 결과 1행 INSERT OR REPLACE. `(task_id, rubric_name)` 이 PK 이므로
+    /// 같은 루브릭으로 재채점하면 덮어쓴다. trajectory 가 먼저 존재해야 FK 충족.
+    ///
+    /// @trace SPEC: SPEC-026
+    /// @trace FR: PRD-026/FR-4
+    pub async fn upsert_judge_result(&self, task_id: &str, rubric_name: &str, weighted_score: f64, result_json: &str) -> Result<(), StoreError> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO judge_results (task_id, rubric_name, weighted_score, result_json, created_at)
+             VALUES (?, ?, ?, ?, datetime('now'))",
+        )
+        .bind(task_id)
+        .bind(rubric_name)
+        .bind(weighted_score)
+        .bind(result_json)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 특정 궤적의 judge 결과를 rubric_name 정렬로 반환.
+    ///
+    /// @trace SPEC: SPEC-026
+    /// @trace FR: PRD-026/FR-4
+    pub async fn list_judge_results(&self, task_id: &str) -> Result<Vec<JudgeResultRow>, StoreError> {
+        let rows = sqlx::query(
+            "SELECT task_id, rubric_name, weighted_score, result_json, created_at
+             FROM judge_results WHERE task_id = ? ORDER BY rubric_name",
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| JudgeResultRow {
+                task_id: r.get("task_id"),
+                rubric_name: r.get("rubric_name"),
+                weighted_score: r.get("weighted_score"),
+                result_json: r.get("result_json"),
+                created_at: r.get("created_at"),
+            })
+            .collect())
+    }
 }
 
 const PROMPT_SET_SELECT_COLS: &str = "SELECT id, domain_name, version, perceive_system, perceive_user, policy_system, policy_user,
@@ -1646,6 +1839,330 @@ fn row_to_external_tool(r: sqlx::sqlite::SqliteRow) -> ExternalToolRow {
     }
 }
 
+/// `insert_scenario` 본체. 승인 트랜잭션(SPEC-042)에서도 같은 SQL 을 쓰도록
+/// 커넥션을 인자로 받는다.
+pub(crate) async fn insert_scenario_on(conn: &mut SqliteConnection, domain: &str, scenario: &ScenarioConfig, position: i64) -> Result<(), StoreError> {
+    // 상위 도메인이 없으면 생성 (seed 단계가 아닐 때 대비).
+    sqlx::query("INSERT OR IGNORE INTO domains (name, description) VALUES (?, '')")
+        .bind(domain)
+        .execute(&mut *conn)
+        .await?;
+
+    let env_json = serde_json::to_string(&scenario.initial_environment)?;
+    let tools_json = serde_json::to_string(&scenario.expected_tools)?;
+    let crit_json = serde_json::to_string(&scenario.success_criteria)?;
+    let conv_json = scenario.conversation.as_ref().map(serde_json::to_string).transpose()?;
+    let approval_json = scenario.approval.as_ref().map(serde_json::to_string).transpose()?;
+    let workspace_json = scenario.workspace.as_ref().map(serde_json::to_string).transpose()?;
+
+    let res = sqlx::query(
+        "INSERT INTO eval_scenarios
+         (domain, id, name, description, task_description,
+          initial_environment, expected_tools, success_criteria,
+          difficulty, position, conversation_json, approval_json, workspace_json)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(domain)
+    .bind(&scenario.id)
+    .bind(&scenario.name)
+    .bind(&scenario.description)
+    .bind(&scenario.task_description)
+    .bind(env_json)
+    .bind(tools_json)
+    .bind(crit_json)
+    .bind(&scenario.difficulty)
+    .bind(position)
+    .bind(conv_json)
+    .bind(approval_json)
+    .bind(workspace_json)
+    .execute(&mut *conn)
+    .await;
+    match res {
+        | Ok(_) => Ok(()),
+        | Err(e) => Err(map_unique_violation(e, format!("scenario ({domain}, {})", scenario.id))),
+    }
+}
+
+/// `insert_golden_entry` 본체.
+pub(crate) async fn insert_golden_entry_on(conn: &mut SqliteConnection, domain: &str, version: &str, entry: &GoldenSetEntry) -> Result<(), StoreError> {
+    let env_json = serde_json::to_string(&entry.input.environment)?;
+    let seq_json = serde_json::to_string(&entry.expected_output.tool_sequence)?;
+    let res_json = serde_json::to_string(&entry.expected_output.tool_results)?;
+    let res = sqlx::query(
+        "INSERT INTO golden_sets
+         (domain, scenario_id, version, task,
+          input_environment, tool_sequence, tool_results, tolerance, expected_domain)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(domain)
+    .bind(&entry.scenario_id)
+    .bind(version)
+    .bind(&entry.input.task)
+    .bind(env_json)
+    .bind(seq_json)
+    .bind(res_json)
+    .bind(entry.expected_output.tolerance)
+    .bind(&entry.expected_output.expected_domain)
+    .execute(&mut *conn)
+    .await;
+    match res {
+        | Ok(_) => Ok(()),
+        | Err(e) => Err(map_unique_violation(e, format!("golden_entry ({domain}, {})", entry.scenario_id))),
+    }
+}
+
+/// `insert_domain` 본체.
+pub(crate) async fn insert_domain_on(conn: &mut SqliteConnection, name: &str, description: &str) -> Result<(), StoreError> {
+    let res = sqlx::query("INSERT INTO domains (name, description) VALUES (?, ?)")
+        .bind(name)
+        .bind(description)
+        .execute(&mut *conn)
+        .await;
+    match res {
+        | Ok(_) => Ok(()),
+        | Err(e) => Err(map_unique_violation(e, format!("domain ({name})"))),
+    }
+}
+
+/// `update_domain` 본체.
+pub(crate) async fn update_domain_on(conn: &mut SqliteConnection, name: &str, description: &str) -> Result<(), StoreError> {
+    let res = sqlx::query("UPDATE domains SET description = ? WHERE name = ?")
+        .bind(description)
+        .bind(name)
+        .execute(&mut *conn)
+        .await?;
+    if res.rows_affected() == 0 {
+        return Err(StoreError::NotFound(format!("domain ({name})")));
+    }
+    Ok(())
+}
+
+/// `replace_domain_tools` 본체.
+pub(crate) async fn replace_domain_tools_on(conn: &mut SqliteConnection, domain: &str, tool_class_names: &[String]) -> Result<(), StoreError> {
+    sqlx::query("DELETE FROM domain_tools WHERE domain = ?")
+        .bind(domain)
+        .execute(&mut *conn)
+        .await?;
+    for (idx, name) in tool_class_names.iter().enumerate() {
+        sqlx::query("INSERT INTO domain_tools (domain, class_name, module_path, position) VALUES (?, ?, ?, ?)")
+            .bind(domain)
+            .bind(name)
+            .bind("") // module_path: SPEC-022 이후 의미 없음. 후속 정리.
+            .bind(idx as i64)
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(())
+}
+
+/// `replace_domain_keywords` 본체.
+pub(crate) async fn replace_domain_keywords_on(conn: &mut SqliteConnection, domain: &str, keywords: &[String]) -> Result<(), StoreError> {
+    sqlx::query("DELETE FROM domain_keywords WHERE domain = ?")
+        .bind(domain)
+        .execute(&mut *conn)
+        .await?;
+    for kw in keywords {
+        let trimmed = kw.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        sqlx::query("INSERT OR IGNORE INTO domain_keywords (domain, keyword) VALUES (?, ?)")
+            .bind(domain)
+            .bind(trimmed)
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(())
+}
+
+/// `update_scenario` 본체.
+pub(crate) async fn update_scenario_on(conn: &mut SqliteConnection, domain: &str, id: &str, scenario: &ScenarioConfig) -> Result<(), StoreError> {
+    let env_json = serde_json::to_string(&scenario.initial_environment)?;
+    let tools_json = serde_json::to_string(&scenario.expected_tools)?;
+    let crit_json = serde_json::to_string(&scenario.success_criteria)?;
+    let conv_json = scenario.conversation.as_ref().map(serde_json::to_string).transpose()?;
+    let approval_json = scenario.approval.as_ref().map(serde_json::to_string).transpose()?;
+    let workspace_json = scenario.workspace.as_ref().map(serde_json::to_string).transpose()?;
+    let res = sqlx::query(
+        "UPDATE eval_scenarios
+         SET name = ?, description = ?, task_description = ?,
+             initial_environment = ?, expected_tools = ?, success_criteria = ?,
+             difficulty = ?, conversation_json = ?, approval_json = ?, workspace_json = ?
+         WHERE domain = ? AND id = ?",
+    )
+    .bind(&scenario.name)
+    .bind(&scenario.description)
+    .bind(&scenario.task_description)
+    .bind(env_json)
+    .bind(tools_json)
+    .bind(crit_json)
+    .bind(&scenario.difficulty)
+    .bind(conv_json)
+    .bind(approval_json)
+    .bind(workspace_json)
+    .bind(domain)
+    .bind(id)
+    .execute(&mut *conn)
+    .await?;
+    if res.rows_affected() == 0 {
+        return Err(StoreError::NotFound(format!("scenario ({domain}, {id})")));
+    }
+    Ok(())
+}
+
+/// `update_golden_entry` 본체.
+pub(crate) async fn update_golden_entry_on(conn: &mut SqliteConnection, domain: &str, scenario_id: &str, entry: &GoldenSetEntry) -> Result<(), StoreError> {
+    let env_json = serde_json::to_string(&entry.input.environment)?;
+    let seq_json = serde_json::to_string(&entry.expected_output.tool_sequence)?;
+    let res_json = serde_json::to_string(&entry.expected_output.tool_results)?;
+    let res = sqlx::query(
+        "UPDATE golden_sets
+         SET task = ?, input_environment = ?, tool_sequence = ?,
+             tool_results = ?, tolerance = ?, expected_domain = ?
+         WHERE domain = ? AND scenario_id = ?",
+    )
+    .bind(&entry.input.task)
+    .bind(env_json)
+    .bind(seq_json)
+    .bind(res_json)
+    .bind(entry.expected_output.tolerance)
+    .bind(&entry.expected_output.expected_domain)
+    .bind(domain)
+    .bind(scenario_id)
+    .execute(&mut *conn)
+    .await?;
+    if res.rows_affected() == 0 {
+        return Err(StoreError::NotFound(format!("golden_entry ({domain}, {scenario_id})")));
+    }
+    Ok(())
+}
+
+/// `upsert_external_tool` 본체.
+pub(crate) async fn upsert_external_tool_on(conn: &mut SqliteConnection, row: &ExternalToolRow) -> Result<(), StoreError> {
+    sqlx::query(
+        "INSERT OR REPLACE INTO external_tools
+         (name, domain, description, method, url, headers_json, body_template, params_schema, timeout_ms, created_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, COALESCE((SELECT created_at FROM external_tools WHERE domain = ? AND name = ?), datetime('now')))",
+    )
+    .bind(&row.name)
+    .bind(&row.domain)
+    .bind(&row.description)
+    .bind(&row.method)
+    .bind(&row.url)
+    .bind(&row.headers_json)
+    .bind(&row.body_template)
+    .bind(&row.params_schema)
+    .bind(row.timeout_ms)
+    .bind(&row.domain)
+    .bind(&row.name)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// `upsert_mcp_server` 본체.
+pub(crate) async fn upsert_mcp_server_on(conn: &mut SqliteConnection, row: &McpServerRow) -> Result<(), StoreError> {
+    sqlx::query(
+        "INSERT OR REPLACE INTO mcp_servers
+         (name, domain, transport, command, args_json, env_json, url, timeout_ms, created_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, COALESCE((SELECT created_at FROM mcp_servers WHERE domain = ? AND name = ?), datetime('now')))",
+    )
+    .bind(&row.name)
+    .bind(&row.domain)
+    .bind(&row.transport)
+    .bind(&row.command)
+    .bind(&row.args_json)
+    .bind(&row.env_json)
+    .bind(&row.url)
+    .bind(row.timeout_ms)
+    .bind(&row.domain)
+    .bind(&row.name)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// `activate_prompt_set` 본체.
+pub(crate) async fn activate_prompt_set_on(conn: &mut SqliteConnection, domain: &str, version: i64) -> Result<(), StoreError> {
+    // 존재 확인
+    let exists: Option<i64> = sqlx::query_scalar("SELECT id FROM prompt_sets WHERE domain_name = ? AND version = ?")
+        .bind(domain)
+        .bind(version)
+        .fetch_optional(&mut *conn)
+        .await?;
+    if exists.is_none() {
+        return Err(StoreError::NotFound(format!("prompt_set {domain}/v{version}")));
+    }
+    sqlx::query("UPDATE prompt_sets SET is_active = 0 WHERE domain_name = ? AND is_active = 1")
+        .bind(domain)
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query("UPDATE prompt_sets SET is_active = 1 WHERE domain_name = ? AND version = ?")
+        .bind(domain)
+        .bind(version)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+/// `insert_prompt_set` 본체. 삽입한 version 을 돌려준다.
+pub(crate) async fn insert_prompt_set_on(conn: &mut SqliteConnection, row: &PromptSetInsert<'_>) -> Result<i64, StoreError> {
+    let next_version: i64 = sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) + 1 FROM prompt_sets WHERE domain_name = ?")
+        .bind(row.domain_name)
+        .fetch_one(&mut *conn)
+        .await?;
+    let any_existing: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM prompt_sets WHERE domain_name = ?")
+        .bind(row.domain_name)
+        .fetch_one(&mut *conn)
+        .await?;
+    let is_active = if any_existing == 0 { 1_i64 } else { 0_i64 };
+    let is_bootstrap = if row.is_bootstrap { 1_i64 } else { 0_i64 };
+    sqlx::query(
+        "INSERT INTO prompt_sets
+         (domain_name, version, perceive_system, perceive_user, policy_system, policy_user,
+          notes, is_active, is_bootstrap, created_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, datetime('now'))",
+    )
+    .bind(row.domain_name)
+    .bind(next_version)
+    .bind(row.perceive_system)
+    .bind(row.perceive_user)
+    .bind(row.policy_system)
+    .bind(row.policy_user)
+    .bind(row.notes)
+    .bind(is_active)
+    .bind(is_bootstrap)
+    .execute(&mut *conn)
+    .await?;
+    Ok(next_version)
+}
+
+fn row_to_scenario_draft(r: sqlx::sqlite::SqliteRow) -> Result<ScenarioDraftRow, StoreError> {
+    let scenario_json: String = r.get("scenario_json");
+    let golden_json: String = r.get("golden_json");
+    Ok(ScenarioDraftRow {
+        id: r.get("id"),
+        domain: r.get("domain"),
+        scenario: serde_json::from_str(&scenario_json)?,
+        golden: serde_json::from_str(&golden_json)?,
+        status: r.get("status"),
+        created_at: r.get("created_at"),
+    })
+}
+
+fn row_to_mcp_server(r: sqlx::sqlite::SqliteRow) -> McpServerRow {
+    McpServerRow {
+        name: r.get("name"),
+        domain: r.get("domain"),
+        transport: r.get("transport"),
+        command: r.try_get("command").ok().flatten(),
+        args_json: r.get("args_json"),
+        env_json: r.try_get("env_json").ok().flatten(),
+        url: r.try_get("url").ok().flatten(),
+        timeout_ms: r.get("timeout_ms"),
+    }
+}
+
 /// SPEC-022: 도메인 단위 요약 정보. UI/REST 응답용.
 #[derive(Debug, Clone)]
 pub struct DomainSummary {
@@ -1656,6 +2173,16 @@ pub struct DomainSummary {
     pub scenario_count: i64,
 }
 
+/// SPEC-036: 도메인 임베딩 행. `kind` 는 `description`(도메인 설명) 또는
+/// `example`(시나리오 작업 설명).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DomainEmbeddingRow {
+    pub domain: String,
+    pub kind: String,
+    pub text: String,
+    pub vector: Vec<f32>,
+}
+
 /// SPEC-023: 외부 HTTP 도구 등록 행. `HttpCallTool::from_row` 가 이 값으로
 /// 인스턴스를 만든다.
 #[derive(Debug, Clone)]
@@ -1671,6 +2198,32 @@ pub struct ExternalToolRow {
     pub timeout_ms: i64,
 }
 
+/// SPEC-037: MCP 서버 등록 행. `transport` 는 `stdio`(command + args_json +
+/// env_json) 또는 `http`(url). 서버가 노출한 도구는 모두 `domain` 에 속한다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct McpServerRow {
+    pub name: String,
+    pub domain: String,
+    pub transport: String,
+    pub command: Option<String>,
+    pub args_json: String,
+    pub env_json: Option<String>,
+    pub url: Option<String>,
+    pub timeout_ms: i64,
+}
+
+/// SPEC-042: 승인 대기 중인 생성 시나리오 초안. `status` 는 `pending`,
+/// `approved`, `rejected` 중 하나.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScenarioDraftRow {
+    pub id: i64,
+    pub domain: String,
+    pub scenario: ScenarioConfig,
+    pub golden: GoldenSetEntry,
+    pub status: String,
+    pub created_at: String,
+}
+
 /// SPEC-021: trajectories 목록 조회 행.
 #[derive(Debug, Clone)]
 pub struct TrajectoryListRow {
@@ -1694,6 +2247,17 @@ pub struct EvaluationListRow {
     pub overall_score: Option<f64>,
 }
 
+/// SPEC-026: judge_results 조회 행. `result_json` 은 `RubricJudgeResult`
+/// 직렬화 텍스트.
+#[derive(Debug, Clone)]
+pub struct JudgeResultRow {
+    pub task_id: String,
+    pub rubric_name: String,
+    pub weighted_score: f64,
+    pub result_json: String,
+    pub created_at: String,
+}
+
 /// SPEC-021: 시간 범위 평균 결과(`compare --agent --since --until`).
 #[derive(Debug, Clone, Default)]
 pub struct EvaluationWindow {
@@ -1964,6 +2528,9 @@ scenarios:
             expected_tools: vec!["tool_a".to_string()],
             success_criteria: crit,
             difficulty: "easy".to_string(),
+            conversation: None,
+            approval: None,
+            workspace: None,
         }
     }
 
@@ -2485,6 +3052,88 @@ scenarios:
         assert!(matches!(err, StoreError::NotFound(_)));
     }
 
+    // -------- SPEC-037 --------
+
+    /// @trace TC: SPEC-037/TC-4
+    /// @trace FR: PRD-037/FR-4
+    #[tokio::test]
+    async fn spec037_tc_4_mcp_servers_upsert_delete_and_cascade() {
+        let store = SqliteStore::open_in_memory().await.unwrap();
+        store.insert_domain("financial", "").await.unwrap();
+        let mut row = McpServerRow {
+            name: "fx".into(),
+            domain: "financial".into(),
+            transport: "stdio".into(),
+            command: Some("npx".into()),
+            args_json: r#"["-y","fx-mcp"]"#.into(),
+            env_json: None,
+            url: None,
+            timeout_ms: 5000,
+        };
+        store.upsert_mcp_server(&row).await.unwrap();
+        row.transport = "http".into();
+        row.command = None;
+        row.url = Some("http://127.0.0.1:8931/mcp".into());
+        store.upsert_mcp_server(&row).await.unwrap();
+        assert_eq!(store.list_mcp_servers().await.unwrap(), vec![row.clone()]);
+        assert_eq!(store.list_mcp_servers_by_domain("financial").await.unwrap().len(), 1);
+
+        store.delete_mcp_server("financial", "fx").await.unwrap();
+        assert!(matches!(store.delete_mcp_server("financial", "fx").await.unwrap_err(), StoreError::NotFound(_)));
+
+        store.upsert_mcp_server(&row).await.unwrap();
+        store.delete_domain("financial").await.unwrap();
+        assert!(store.list_mcp_servers().await.unwrap().is_empty(), "도메인 삭제 시 mcp_servers cascade 삭제");
+    }
+
+    // -------- SPEC-042 --------
+
+    async fn has_scenario(store: &SqliteStore, domain: &str, id: &str) -> bool {
+        let domains = store.load_all_domains().await.unwrap();
+        domains.iter().any(|d| d.name == domain && d.scenarios.iter().any(|s| s.id == id))
+    }
+
+    /// @trace TC: SPEC-042/TC-1
+    /// @trace FR: PRD-042/FR-4, PRD-042/FR-5
+    #[tokio::test]
+    async fn spec042_tc_1_draft_approve_inserts_scenario_and_golden() {
+        let store = seeded_store().await;
+        let id = store
+            .insert_scenario_draft("financial", &sample_scenario("fin_gen", "생성된 시나리오"), &sample_entry("fin_gen"))
+            .await
+            .unwrap();
+        assert!(!has_scenario(&store, "financial", "fin_gen").await, "승인 전에는 시나리오에 없음");
+        assert_eq!(store.list_scenario_drafts(Some("financial"), Some("pending")).await.unwrap().len(), 1);
+
+        let approved = store.approve_scenario_draft(id).await.unwrap();
+        assert_eq!(approved.status, "approved");
+        assert!(has_scenario(&store, "financial", "fin_gen").await);
+        let golden = store.load_golden_sets_by_domain("financial").await.unwrap();
+        assert!(golden.golden_sets.iter().any(|e| e.scenario_id == "fin_gen"));
+
+        assert!(matches!(store.approve_scenario_draft(id).await.unwrap_err(), StoreError::Conflict(_)));
+        assert!(matches!(store.reject_scenario_draft(id).await.unwrap_err(), StoreError::Conflict(_)));
+        assert!(matches!(store.approve_scenario_draft(999).await.unwrap_err(), StoreError::NotFound(_)));
+    }
+
+    /// @trace TC: SPEC-042/TC-2
+    /// @trace FR: PRD-042/FR-5
+    #[tokio::test]
+    async fn spec042_tc_2_failed_approve_rolls_back() {
+        let store = seeded_store().await;
+        // 같은 id 의 시나리오가 이미 있으면 승인 전체가 롤백된다.
+        let id = store
+            .insert_scenario_draft("financial", &sample_scenario("fin_001", "중복"), &sample_entry("fin_001"))
+            .await
+            .unwrap();
+        assert!(matches!(store.approve_scenario_draft(id).await.unwrap_err(), StoreError::Conflict(_)));
+        assert_eq!(store.get_scenario_draft(id).await.unwrap().unwrap().status, "pending");
+
+        store.reject_scenario_draft(id).await.unwrap();
+        assert!(store.list_scenario_drafts(None, Some("pending")).await.unwrap().is_empty());
+        assert_eq!(store.list_scenario_drafts(Some("financial"), None).await.unwrap().len(), 1);
+    }
+
     // -------- SPEC-025 --------
 
     fn sample_bundle() -> BootstrapBundleRef<'static> {
@@ -2690,4 +3339,179 @@ scenarios:
         let err = store.activate_prompt_set("customer_service", 99).await.unwrap_err();
         assert!(matches!(err, StoreError::NotFound(_)));
     }
+    // -------- SPEC-026 --------
+
+    /// @trace TC: SPEC-026/TC-7
+    /// @trace FR: PRD-026/FR-4
+    #[tokio::test]
+    async fn spec026_tc_7_judge_result_upsert_and_evaluation_json() {
+        let store = SqliteStore::open_in_memory().await.unwrap();
+        let task_id = "550e8400-e29b-41d4-a716-446655440026";
+        store
+            .upsert_trajectory(task_id, "t", "ppa", None, None, true, 1, "2026-04-11T10:00:00Z", None, "[]", None, None)
+            .await
+            .unwrap();
+        store
+            .upsert_evaluation(task_id, "ppa", None, None, true, None, None, None, None, "{}", None)
+            .await
+            .unwrap();
+        store
+            .upsert_judge_result(task_id, "general", 0.5, r#"{"rubric_name":"general","weighted_score":0.5}"#)
+            .await
+            .unwrap();
+        // 같은 루브릭 재채점은 덮어쓴다
+        store
+            .upsert_judge_result(task_id, "general", 0.75, r#"{"rubric_name":"general","weighted_score":0.75}"#)
+            .await
+            .unwrap();
+
+        let rows = store.list_judge_results(task_id).await.unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].weighted_score, 0.75);
+
+        let json = store.get_evaluation_json(task_id).await.unwrap().unwrap();
+        assert_eq!(json["llm_judge"][0]["weighted_score"], 0.75);
+    }
+
+    /// @trace TC: SPEC-026/TC-8
+    /// @trace FR: PRD-026/FR-4
+    #[tokio::test]
+    async fn spec026_tc_8_judge_result_requires_trajectory() {
+        let store = SqliteStore::open_in_memory().await.unwrap();
+        let err = store.upsert_judge_result("missing", "general", 0.5, "{}").await.unwrap_err();
+        assert!(matches!(err, StoreError::Sqlx(_)));
+    }
+
+    /// @trace TC: SPEC-032/TC-5
+    /// @trace FR: PRD-032/FR-1
+    #[tokio::test]
+    async fn spec032_tc_5_conversation_json_roundtrip() {
+        use agent_models::domain_config::{ConversationConfig,
+                                          ConversationTurnConfig};
+
+        let store = seeded_store().await;
+        let mut scen = sample_scenario("fin_conv", "대화 시나리오");
+        scen.conversation = Some(ConversationConfig {
+            turns: vec![
+                ConversationTurnConfig {
+                    user_input: "잔액 알려줘".into(),
+                    ..Default::default()
+                },
+                ConversationTurnConfig {
+                    user_input: "이자도 계산해줘".into(),
+                    expected_tools: vec!["calculate_simple_interest".into()],
+                    expected_context_keys: vec!["balance".into()],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        store.insert_scenario("financial", &scen, 99).await.unwrap();
+
+        let domains = store.load_all_domains().await.unwrap();
+        let fin = domains.iter().find(|d| d.name == "financial").unwrap();
+        let loaded = fin.scenarios.iter().find(|s| s.id == "fin_conv").unwrap();
+        let conv = loaded.conversation.as_ref().expect("conversation should roundtrip");
+        assert_eq!(conv.turns.len(), 2);
+        assert_eq!(conv.turns[1].expected_tools, vec!["calculate_simple_interest".to_string()]);
+        assert_eq!(conv.turns[1].expected_context_keys, vec!["balance".to_string()]);
+
+        // 대화 정의가 없는 기존 시나리오는 None 으로 로드된다.
+        let plain = fin.scenarios.iter().find(|s| s.id == "fin_001").unwrap();
+        assert!(plain.conversation.is_none());
+    }
+
+    /// @trace TC: SPEC-044/TC-6
+    /// @trace FR: PRD-044/FR-1
+    #[tokio::test]
+    async fn spec044_tc_6_approval_policy_roundtrip() {
+        use agent_models::approval::{ApprovalDecision,
+                                     ApprovalPolicy};
+
+        let store = seeded_store().await;
+        let mut scen = sample_scenario("fin_gate", "승인 시나리오");
+        let policy = ApprovalPolicy::Scripted {
+            decisions: HashMap::from([("write_file".to_string(), vec![ApprovalDecision::Denied, ApprovalDecision::Approved])]),
+            default: ApprovalDecision::Denied,
+        };
+        scen.approval = Some(policy.clone());
+        store.insert_scenario("financial", &scen, 99).await.unwrap();
+
+        let load = |domains: Vec<DomainConfig>, id: &str| {
+            let fin = domains.into_iter().find(|d| d.name == "financial").unwrap();
+            fin.scenarios.into_iter().find(|s| s.id == id).unwrap()
+        };
+        assert_eq!(load(store.load_all_domains().await.unwrap(), "fin_gate").approval, Some(policy));
+
+        scen.approval = Some(ApprovalPolicy::AutoDeny);
+        store.update_scenario("financial", "fin_gate", &scen).await.unwrap();
+        assert_eq!(
+            load(store.load_all_domains().await.unwrap(), "fin_gate").approval,
+            Some(ApprovalPolicy::AutoDeny)
+        );
+        assert!(load(store.load_all_domains().await.unwrap(), "fin_001").approval.is_none());
+    }
+
+    /// @trace TC: SPEC-045/TC-4
+    /// @trace FR: PRD-045/FR-1
+    #[tokio::test]
+    async fn spec045_tc_4_workspace_config_roundtrip() {
+        use agent_models::domain_config::WorkspaceConfig;
+        use std::collections::BTreeMap;
+
+        let store = seeded_store().await;
+        let mut scen = sample_scenario("fin_files", "파일 시나리오");
+        let workspace = WorkspaceConfig {
+            fixtures: BTreeMap::from([("input/orders.csv".to_string(), "id,amount\n1,100\n".to_string())]),
+            expected_files: BTreeMap::from([("out/summary.txt".to_string(), Some("total: 100".to_string())), ("tmp.txt".to_string(), None)]),
+            max_file_bytes: Some(4096),
+            max_total_bytes: None,
+        };
+        scen.workspace = Some(workspace.clone());
+        store.insert_scenario("financial", &scen, 99).await.unwrap();
+
+        let load = |domains: Vec<DomainConfig>, id: &str| {
+            let fin = domains.into_iter().find(|d| d.name == "financial").unwrap();
+            fin.scenarios.into_iter().find(|s| s.id == id).unwrap()
+        };
+        assert_eq!(load(store.load_all_domains().await.unwrap(), "fin_files").workspace, Some(workspace));
+
+        scen.workspace = None;
+        store.update_scenario("financial", "fin_files", &scen).await.unwrap();
+        assert!(load(store.load_all_domains().await.unwrap(), "fin_files").workspace.is_none());
+    }
+
+    /// @trace TC: SPEC-036/TC-3
+    /// @trace FR: PRD-036/FR-2
+    #[tokio::test]
+    async fn spec036_tc_3_domain_embeddings_replace_per_model_and_cascade() {
+        let store = seeded_store().await;
+        let row = |kind: &str, text: &str, v: Vec<f32>| DomainEmbeddingRow {
+            domain: "financial".into(),
+            kind: kind.into(),
+            text: text.into(),
+            vector: v,
+        };
+        let m1_rows = [row("description", "금융", vec![1.0, 0.0]), row("example", "이자", vec![0.5, 0.5])];
+        store.replace_domain_embeddings("financial", "m1", &m1_rows).await.unwrap();
+        let m2_rows = [row("description", "금융", vec![0.0, 1.0])];
+        store.replace_domain_embeddings("financial", "m2", &m2_rows).await.unwrap();
+
+        let m1 = store.list_domain_embeddings("m1").await.unwrap();
+        assert_eq!(m1.len(), 2);
+        assert_eq!(m1[0].kind, "description");
+        assert_eq!(m1[1].vector, vec![0.5, 0.5]);
+
+        // 같은 모델 교체는 이전 행을 지우고, 다른 모델 행은 건드리지 않는다.
+        let m1_rows = [row("example", "대출", vec![0.1, 0.9])];
+        store.replace_domain_embeddings("financial", "m1", &m1_rows).await.unwrap();
+        let m1 = store.list_domain_embeddings("m1").await.unwrap();
+        assert_eq!(m1.len(), 1);
+        assert_eq!(m1[0].text, "대출");
+        assert_eq!(store.list_domain_embeddings("m2").await.unwrap().len(), 1);
+
+        store.delete_domain("financial").await.unwrap();
+        assert!(store.list_domain_embeddings("m1").await.unwrap().is_empty());
+        assert!(store.list_domain_embeddings("m2").await.unwrap().is_empty());
+    }
 }
@@ -113,6 +113,8 @@ fn task_json_to_report(task_id: &str, json: &serde_json::Value) -> EvaluationRep
         success_rate: if success { 1.0 } else { 0.0 },
         average_metrics,
         scenarios: Vec::new(),
+        trials: 1,
+        pass_at_k: Default::default(),
     }
 }
 
@@ -143,5 +145,7 @@ fn window_to_report(agent: &str, label: &str, w: &EvaluationWindow) -> Evaluatio
         success_rate: if total > 0 { success_count as f64 / total as f64 } else { 0.0 },
         average_metrics,
         scenarios: Vec::new(),
+        trials: 1,
+        pass_at_k: Default::default(),
     }
 }
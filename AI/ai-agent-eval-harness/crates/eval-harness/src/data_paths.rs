@@ -65,6 +65,8 @@ struct ConfigFile {
     data: ConfigData,
     #[serde(default)]
     evaluation: ConfigEvaluation,
+    /// SPEC-035: `[pricing.models."<model>"]`, `[pricing.default]`
+    pricing: Option<eval_models::pricing::PriceTable>,
 }
 
 #[derive(Debug, serde::Deserialize, Default)]
@@ -79,6 +81,10 @@ struct ConfigEvaluation {
     max_iterations: Option<u32>,
     early_stop_threshold: Option<u32>,
     domain_router_top_k: Option<usize>,
+    /// SPEC-031: `[evaluation.fault_injection]`
+    fault_injection: Option<execution_fault_injection::models::FaultInjectionConfig>,
+    /// SPEC-034: `prompt` | `native`
+    tool_calling_mode: Option<agent_core::config::ToolCallingMode>,
 }
 
 impl Default for DataPaths {
@@ -178,6 +184,7 @@ impl DataPaths {
 /// @trace SPEC: SPEC-016
 /// @trace TC: SPEC-016/TC-1, SPEC-016/TC-2, SPEC-016/TC-3, SPEC-016/TC-4
 /// @trace FR: PRD-016/FR-1, PRD-016/FR-2
+/// @trace SPEC: SPEC-031
 pub fn load_evaluation_config(base: &Path) -> Result<agent_core::config::EvaluationConfig, DataPathsError> {
     let mut cfg = agent_core::config::EvaluationConfig::default();
     let cfg_path = base.join(DEFAULT_CONFIG_FILENAME);
@@ -206,9 +213,54 @@ pub fn load_evaluation_config(base: &Path) -> Result<agent_core::config::Evaluat
     if let Some(v) = parsed.evaluation.domain_router_top_k {
         cfg.domain_router_top_k = v;
     }
+    if let Some(fi) = parsed.evaluation.fault_injection {
+        if !(0.0 ..= 1.0).contains(&fi.global_failure_rate) {
+            return Err(DataPathsError::Invalid {
+                path: cfg_path,
+                message: "evaluation.fault_injection.global_failure_rate must be within 0.0..=1.0".into(),
+            });
+        }
+        if let Err(message) = fi.validate_distribution() {
+            return Err(DataPathsError::Invalid {
+                path: cfg_path,
+                message: format!("evaluation.fault_injection.{message}"),
+            });
+        }
+        cfg.fault_injection = Some(fi);
+    }
+    if let Some(mode) = parsed.evaluation.tool_calling_mode {
+        cfg.tool_calling_mode = mode;
+    }
     Ok(cfg)
 }
 
+/// `base/eval-harness.toml` 의 `[pricing]` 섹션을 읽는다. 파일이나 섹션이
+/// 없으면 `None` (비용 미계산). 음수 단가는 검증 에러.
+///
+/// @trace SPEC: SPEC-035
+/// @trace FR: PRD-035/FR-2
+pub fn load_price_table(base: &Path) -> Result<Option<eval_models::pricing::PriceTable>, DataPathsError> {
+    let cfg_path = base.join(DEFAULT_CONFIG_FILENAME);
+    if !cfg_path.is_file() {
+        return Ok(None);
+    }
+    let Some(table) = read_config(&cfg_path)?.pricing else {
+        return Ok(None);
+    };
+    let negative = table
+        .models
+        .values()
+        .chain(table.default.iter())
+        .any(|p| p.prompt_per_million < 0.0 || p.completion_per_million < 0.0);
+    if negative {
+        return Err(DataPathsError::Invalid {
+            path: cfg_path,
+            message: "pricing.*.prompt_per_million / completion_per_million must be >= 0".into(),
+        });
+    }
+    Ok(Some(table))
+}
+
 fn read_config(path: &Path) -> Result<ConfigFile, DataPathsError> {
     let text = std::fs::read_to_string(path).map_err(|e| DataPathsError::Io {
         path: path.to_path_buf(),
@@ -530,4 +582,109 @@ mod tests {
             | other => panic!("expected Parse error, got: {other:?}"),
         }
     }
+
+    /// @trace TC: SPEC-031/TC-6
+    /// @trace FR: PRD-031/FR-1
+    /// @trace scenario: [evaluation.fault_injection] 은 필수 키만으로 로드
+    #[test]
+    fn test_spec031_tc_6_load_fault_injection_section() {
+        let base = tempdir().unwrap();
+        write_cfg(
+            base.path(),
+            r#"
+                [evaluation.fault_injection]
+                enabled             = true
+                global_failure_rate = 0.3
+                seed                = 11
+            "#,
+        );
+        let cfg = load_evaluation_config(base.path()).unwrap();
+        let fi = cfg.fault_injection.expect("fault_injection 섹션");
+        assert!(fi.enabled);
+        assert_eq!(fi.global_failure_rate, 0.3);
+        assert_eq!(fi.seed, Some(11));
+        assert!(fi.failure_mode_distribution.is_empty());
+
+        write_cfg(
+            base.path(),
+            r#"
+                [evaluation.fault_injection]
+                enabled             = true
+                global_failure_rate = 1.5
+            "#,
+        );
+        assert!(matches!(load_evaluation_config(base.path()), Err(DataPathsError::Invalid { .. })));
+
+        write_cfg(
+            base.path(),
+            r#"
+                [evaluation.fault_injection]
+                enabled             = true
+                global_failure_rate = 0.3
+                [evaluation.fault_injection.failure_mode_distribution]
+                timeout   = 0.0
+                exception = 0.0
+            "#,
+        );
+        assert!(matches!(load_evaluation_config(base.path()), Err(DataPathsError::Invalid { .. })));
+    }
+
+    /// @trace TC: SPEC-034/TC-4
+    /// @trace FR: PRD-034/FR-3
+    /// @trace scenario: tool_calling_mode 기본 prompt, 알 수 없는 값은 에러
+    #[test]
+    fn test_spec034_tc_4_load_tool_calling_mode() {
+        let base = tempdir().unwrap();
+        assert_eq!(
+            load_evaluation_config(base.path()).unwrap().tool_calling_mode,
+            agent_core::config::ToolCallingMode::Prompt
+        );
+
+        write_cfg(base.path(), "[evaluation]\ntool_calling_mode = \"native\"\n");
+        assert_eq!(
+            load_evaluation_config(base.path()).unwrap().tool_calling_mode,
+            agent_core::config::ToolCallingMode::Native
+        );
+
+        write_cfg(base.path(), "[evaluation]\ntool_calling_mode = \"json\"\n");
+        assert!(matches!(load_evaluation_config(base.path()), Err(DataPathsError::Parse { .. })));
+    }
+
+    /// @trace TC: SPEC-035/TC-5
+    /// @trace FR: PRD-035/FR-2
+    /// @trace scenario: [pricing] 없으면 None, 모델별/기본 단가, 음수 에러
+    #[test]
+    fn test_spec035_tc_5_load_price_table() {
+        let base = tempdir().unwrap();
+        assert!(load_price_table(base.path()).unwrap().is_none());
+
+        write_cfg(base.path(), "[evaluation]\nmax_iterations = 3\n");
+        assert!(load_price_table(base.path()).unwrap().is_none());
+
+        write_cfg(
+            base.path(),
+            r#"
+                [pricing.models."gpt-4o-mini"]
+                prompt_per_million     = 0.15
+                completion_per_million = 0.6
+
+                [pricing.default]
+                prompt_per_million     = 1.0
+                completion_per_million = 2.0
+            "#,
+        );
+        let table = load_price_table(base.path()).unwrap().expect("pricing 섹션");
+        assert_eq!(table.price_for(Some("gpt-4o-mini")).unwrap().completion_per_million, 0.6);
+        assert_eq!(table.price_for(Some("llama3")).unwrap().prompt_per_million, 1.0);
+
+        write_cfg(
+            base.path(),
+            r#"
+                [pricing.default]
+                prompt_per_million     = -1.0
+                completion_per_million = 2.0
+            "#,
+        );
+        assert!(matches!(load_price_table(base.path()), Err(DataPathsError::Invalid { .. })));
+    }
 }
@@ -74,6 +74,7 @@ mod tests {
             golden_sets_dir: std::path::PathBuf::from("."),
             trajectories_dir: std::path::PathBuf::from("."),
             store: None,
+            jobs: Default::default(),
         };
         let _router: axum::Router = crate::web::build_router(state);
     }
@@ -0,0 +1,41 @@
+// =============================================================================
+// @trace SPEC-043
+// @trace PRD: PRD-043
+// @trace FR: PRD-043/FR-1, PRD-043/FR-2, PRD-043/FR-3
+// @trace file-type: impl
+// =============================================================================
+//
+// `export-domain` / `import-domain` CLI 용 동기 래퍼. 번들 형식과 충돌 처리는
+// `data_scenarios::bundle` 에 있고, 여기서는 설치된 전역 스토어와 디렉토리를
+// 연결하고 가져오기 후 라우터 캐시를 비운다.
+
+use crate::web::db_query::block_on;
+use data_scenarios::bundle::{self,
+                             ImportPolicy,
+                             ImportReport};
+use std::path::Path;
+
+/// 도메인을 번들 디렉토리로 내보낸다.
+///
+/// @trace SPEC: SPEC-043
+/// @trace FR: PRD-043/FR-1, PRD-043/FR-2
+pub fn export_domain_to_dir(domain: &str, out: &Path) -> anyhow::Result<()> {
+    let store = data_scenarios::loader::try_installed_store().ok_or_else(|| anyhow::anyhow!("SqliteStore not installed"))?;
+    let bundle = block_on(bundle::export_domain(&store, domain))?;
+    bundle::write_bundle_dir(&bundle, out)?;
+    Ok(())
+}
+
+/// 번들 디렉토리를 가져온다. 적용됐으면 임베딩 라우터 캐시를 무효화한다.
+///
+/// @trace SPEC: SPEC-043
+/// @trace FR: PRD-043/FR-3, PRD-043/FR-4
+pub fn import_domain_from_dir(dir: &Path, policy: ImportPolicy, dry_run: bool) -> anyhow::Result<ImportReport> {
+    let store = data_scenarios::loader::try_installed_store().ok_or_else(|| anyhow::anyhow!("SqliteStore not installed"))?;
+    let bundle = bundle::read_bundle_dir(dir)?;
+    let report = block_on(bundle::import_domain(&store, &bundle, policy, dry_run))?;
+    if report.applied {
+        agent_core::domain_router::invalidate_cache();
+    }
+    Ok(report)
+}
@@ -0,0 +1,198 @@
+// =============================================================================
+// @trace SPEC-027
+// @trace PRD: PRD-027
+// @trace FR: PRD-027/FR-3, PRD-027/FR-4
+// @trace file-type: impl
+// =============================================================================
+//
+// `judge-pairwise` 명령과 `/api/judge/pairwise` 의 공용 구현. SqliteStore 에
+// 저장된 두 궤적을 불러와 같은 시나리오인지 확인한 뒤 `PairwiseJudge` 로
+// 비교한다. 두 궤적의 prompt_set_id 를 함께 보고해 PromptSet 간 head-to-head
+// 비교에 쓴다.
+
+use agent_models::models::Trajectory;
+use data_scenarios::sqlite_store::{SqliteStore,
+                                   StoreError};
+use scoring_llm_judge::{models::PairwiseComparison,
+                        pairwise::PairwiseJudge};
+use serde::Serialize;
+use std::{path::Path,
+          sync::Arc};
+
+#[derive(Debug, thiserror::Error)]
+pub enum PairwiseError {
+    #[error("trajectory not found: {0}")]
+    NotFound(String),
+    #[error("invalid pair: {0}")]
+    InvalidPair(String),
+    #[error("store error: {0}")]
+    Store(#[from] StoreError),
+    #[error("judge error: {0}")]
+    Judge(String),
+}
+
+/// 비교 결과 + 두 궤적의 시나리오/PromptSet 식별 정보.
+#[derive(Debug, Clone, Serialize)]
+pub struct PairwiseReport {
+    pub comparison: PairwiseComparison,
+    pub domain: Option<String>,
+    pub scenario_id: Option<String>,
+    pub prompt_set_a: Option<i64>,
+    pub prompt_set_b: Option<i64>,
+}
+
+struct StoredTrajectory {
+    trajectory: Trajectory,
+    domain: Option<String>,
+    scenario_id: Option<String>,
+}
+
+async fn load_stored(store: &SqliteStore, task_id: &str) -> Result<StoredTrajectory, PairwiseError> {
+    let json = store
+        .get_trajectory_json(task_id)
+        .await?
+        .ok_or_else(|| PairwiseError::NotFound(task_id.to_string()))?;
+    let domain = json.get("domain").and_then(|v| v.as_str()).map(String::from);
+    let scenario_id = json.get("scenario_id").and_then(|v| v.as_str()).map(String::from);
+    let trajectory: Trajectory = serde_json::from_value(json).map_err(|e| PairwiseError::Judge(format!("trajectory {task_id} 역직렬화 실패: {e}")))?;
+    Ok(StoredTrajectory {
+        trajectory,
+        domain,
+        scenario_id,
+    })
+}
+
+/// 두 궤적이 같은 시나리오인지 검사. scenario_id 가 기록돼 있으면 일치해야
+/// 하고, 없으면 task_description 으로 대조한다.
+fn check_same_scenario(a: &StoredTrajectory, b: &StoredTrajectory) -> Result<(), PairwiseError> {
+    if a.trajectory.task_id == b.trajectory.task_id {
+        return Err(PairwiseError::InvalidPair("같은 궤적끼리는 비교할 수 없습니다".into()));
+    }
+    match (&a.scenario_id, &b.scenario_id) {
+        | (Some(sa), Some(sb)) if sa != sb || a.domain != b.domain => Err(PairwiseError::InvalidPair(format!(
+            "서로 다른 시나리오입니다: {}/{} vs {}/{}",
+            a.domain.as_deref().unwrap_or("-"),
+            sa,
+            b.domain.as_deref().unwrap_or("-"),
+            sb
+        ))),
+        | (Some(_), Some(_)) => Ok(()),
+        | _ if a.trajectory.task_description == b.trajectory.task_description => Ok(()),
+        | _ => Err(PairwiseError::InvalidPair("scenario_id 가 없고 task_description 도 다릅니다".into())),
+    }
+}
+
+/// 저장된 두 궤적을 pairwise 비교.
+///
+/// @trace SPEC: SPEC-027
+/// @trace FR: PRD-027/FR-3, PRD-027/FR-4
+pub async fn judge_stored_pair(store: &SqliteStore, judge: &PairwiseJudge, task_a: &str, task_b: &str) -> Result<PairwiseReport, PairwiseError> {
+    let a = load_stored(store, task_a).await?;
+    let b = load_stored(store, task_b).await?;
+    check_same_scenario(&a, &b)?;
+    let comparison = judge
+        .compare(&a.trajectory, &b.trajectory)
+        .await
+        .map_err(|e| PairwiseError::Judge(e.to_string()))?;
+    Ok(PairwiseReport {
+        comparison,
+        domain: a.domain,
+        scenario_id: a.scenario_id,
+        prompt_set_a: a.trajectory.prompt_set_id,
+        prompt_set_b: b.trajectory.prompt_set_id,
+    })
+}
+
+/// 환경변수 LLM 설정 + 루브릭(생략 시 기본)으로 pairwise judge 구성.
+///
+/// @trace SPEC: SPEC-027
+/// @trace FR: PRD-027/FR-3
+pub fn pairwise_judge_from_env(rubric: Option<&Path>) -> anyhow::Result<PairwiseJudge> {
+    dotenvy::dotenv().ok();
+    let llm_config = agent_core::config::LlmConfig::from_env_default().map_err(|e| anyhow::anyhow!("LLM 설정 확인 필요: {e}"))?;
+    let rubric = match rubric {
+        | Some(p) => scoring_llm_judge::judge::load_rubric(p)?,
+        | None => scoring_llm_judge::judge::default_rubric(),
+    };
+    let llm = Arc::new(agent_core::llm_client::LlmClient::new(llm_config));
+    Ok(PairwiseJudge::new(llm, rubric))
+}
+
+/// CLI 용 동기 진입점. 설치된 전역 SqliteStore 를 사용한다.
+///
+/// @trace SPEC: SPEC-027
+/// @trace FR: PRD-027/FR-3
+pub fn judge_pairwise(task_a: &str, task_b: &str, judge: &PairwiseJudge) -> anyhow::Result<PairwiseReport> {
+    let store = data_scenarios::loader::try_installed_store().ok_or_else(|| anyhow::anyhow!("SqliteStore not installed"))?;
+    Ok(crate::web::db_query::block_on(judge_stored_pair(&store, judge, task_a, task_b))?)
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-027
+    // @trace PRD: PRD-027
+    // @trace FR: PRD-027/FR-3
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use agent_core::{config::AzureOpenAiConfig,
+                     llm_client::LlmClient};
+
+    /// LLM 호출 전 단계에서 실패해야 하므로 도달 불가능한 엔드포인트로 충분.
+    fn offline_judge() -> PairwiseJudge {
+        let llm = LlmClient::new(AzureOpenAiConfig {
+            azure_openai_endpoint: "http://127.0.0.1:1".into(),
+            azure_openai_api_key: "x".into(),
+            azure_openai_deployment: "x".into(),
+            azure_openai_api_version: "x".into(),
+            azure_openai_region: "x".into(),
+            temperature: 1.0,
+            max_tokens: None,
+        });
+        PairwiseJudge::new(Arc::new(llm), scoring_llm_judge::judge::default_rubric())
+    }
+
+    async fn insert(store: &SqliteStore, task_id: &str, scenario_id: Option<&str>) {
+        store
+            .upsert_trajectory(
+                task_id,
+                "task",
+                "ppa",
+                Some("financial"),
+                scenario_id,
+                true,
+                1,
+                "2026-04-11T10:00:00Z",
+                None,
+                "[]",
+                None,
+                Some(1),
+            )
+            .await
+            .unwrap();
+    }
+
+    /// @trace TC: SPEC-027/TC-5
+    #[tokio::test]
+    async fn spec027_tc_5_missing_trajectory_is_not_found() {
+        let store = SqliteStore::open_in_memory_for_loader().await.unwrap();
+        insert(&store, "a", Some("fin_001")).await;
+        let err = judge_stored_pair(&store, &offline_judge(), "a", "missing").await.unwrap_err();
+        assert!(matches!(err, PairwiseError::NotFound(id) if id == "missing"));
+    }
+
+    /// @trace TC: SPEC-027/TC-6
+    #[tokio::test]
+    async fn spec027_tc_6_different_scenarios_rejected() {
+        let store = SqliteStore::open_in_memory_for_loader().await.unwrap();
+        insert(&store, "a", Some("fin_001")).await;
+        insert(&store, "b", Some("fin_002")).await;
+        let err = judge_stored_pair(&store, &offline_judge(), "a", "b").await.unwrap_err();
+        assert!(matches!(err, PairwiseError::InvalidPair(_)));
+
+        let err = judge_stored_pair(&store, &offline_judge(), "a", "a").await.unwrap_err();
+        assert!(matches!(err, PairwiseError::InvalidPair(_)));
+    }
+}
@@ -14,6 +14,9 @@ pub mod build_release;
 pub mod compare_db;
 pub mod data_paths;
 pub mod desktop_helpers;
+pub mod domain_bundle;
+pub mod judge_pairwise;
+pub mod scenario_gen;
 pub mod tui;
 pub mod web;
 pub mod web_theme;
@@ -6,6 +6,11 @@
 // @trace file-type: impl
 // =============================================================================
 
+use agent_models::{approval::{ApprovalDecision,
+                              ApprovalPolicy,
+                              ApprovalRequest},
+                   progress::{ProgressEvent,
+                              ProgressSink}};
 use clap::{Parser,
            Subcommand};
 use eval_harness::{data_paths::DataPaths,
@@ -14,10 +19,16 @@ use eval_harness::{data_paths::DataPaths,
 use execution::{agent_registry::AgentRegistry,
                 base_agent::PassthroughAgent,
                 comparator::ReportComparator,
+                models::EvaluationReport,
+                report_export::{self,
+                                ExportFormat},
                 report_renderer::ReportRenderer,
                 runner::HarnessRunner};
-use std::{path::Path,
-          sync::Arc};
+use std::{io::{BufRead,
+               Write},
+          path::Path,
+          sync::{Arc,
+                 Mutex}};
 
 #[derive(Parser)]
 #[command(name = "eval-harness", about = "AI Agent 평가 하네스 - 통합 실행 및 비교 도구")]
@@ -40,6 +51,31 @@ enum Commands {
         scenarios_dir: Option<String>,
         #[arg(long, default_value = "reporting_logs")]
         output_dir: String,
+        /// SPEC-026: 골든셋 검증 시 LLM judge 루브릭 채점을 함께 수행
+        #[arg(long)]
+        llm_judge: bool,
+        /// SPEC-026: judge 루브릭 YAML 경로 (생략 시 기본 범용 루브릭)
+        #[arg(long)]
+        rubric: Option<String>,
+        /// SPEC-028: 도메인 안에서 동시에 실행할 시나리오 수
+        #[arg(long, default_value = "1")]
+        concurrency: usize,
+        /// SPEC-028: 시나리오별 제한 시간(초). 초과 시 실패로 기록
+        #[arg(long)]
+        scenario_timeout: Option<u64>,
+        /// SPEC-038: 시나리오당 반복 시행 수. 시행별 결과가 리포트에 남는다
+        #[arg(long, default_value = "1")]
+        trials: usize,
+        /// SPEC-039: JUnit XML 리포트 저장 경로 (CI 테스트 결과용)
+        #[arg(long)]
+        junit: Option<String>,
+        /// SPEC-039: 궤적·도구 호출 타임라인을 포함한 단일 HTML 리포트 경로
+        #[arg(long)]
+        html: Option<String>,
+        /// SPEC-044: 위험 도구 승인 정책 (auto-approve | auto-deny |
+        /// interactive). 시나리오의 `approval` 이 우선한다
+        #[arg(long, default_value = "auto-approve")]
+        approval: ApprovalPolicy,
     },
     /// 두 리포트 비교 및 회귀 감지. 파일 인자 또는 SPEC-021 의 DB 쿼리
     /// 모드(--baseline-task/--current-task, 또는 --agent/--since/--until)
@@ -53,6 +89,10 @@ enum Commands {
         current: String,
         #[arg(short, long, default_value = "5.0")]
         threshold: f64,
+        /// SPEC-038: 회귀 판정 유의수준. 실행별 표본이 있는 메트릭은
+        /// Mann-Whitney p-value 가 이보다 작아야 회귀로 본다
+        #[arg(long, default_value = "0.05")]
+        alpha: f64,
         #[arg(short, long)]
         output: Option<String>,
         /// SPEC-021: 베이스라인 task_id (DB 조회)
@@ -86,13 +126,79 @@ enum Commands {
         #[arg(long, default_value = "reporting_logs")]
         logs_dir: String,
     },
+    /// SPEC-027: 저장된 두 궤적(같은 시나리오)을 LLM judge 로 pairwise 비교.
+    /// A/B 순서를 바꿔 두 번 판정하며, 결론이 엇갈리면 tie 로 보고한다.
+    JudgePairwise {
+        /// 궤적 A 의 task_id
+        task_a: String,
+        /// 궤적 B 의 task_id
+        task_b: String,
+        /// judge 루브릭 YAML 경로 (생략 시 기본 범용 루브릭)
+        #[arg(long)]
+        rubric: Option<String>,
+        /// 결과 JSON 저장 경로
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// SPEC-042: 도메인에 등록된 도구로 시나리오+골든셋 초안을 LLM 으로
+    /// 생성한다. 중복을 제외한 초안은 승인 대기(pending)로 저장된다.
+    GenerateScenarios {
+        #[arg(long)]
+        domain: String,
+        #[arg(long, default_value = "5")]
+        count: usize,
+    },
+    /// SPEC-042: 시나리오 초안 목록 표시 및 승인/거절. 승인한 초안만
+    /// eval_scenarios / golden_sets 에 추가된다.
+    ScenarioDrafts {
+        #[arg(long)]
+        domain: Option<String>,
+        /// 승인할 초안 id (여러 번 지정 가능)
+        #[arg(long)]
+        approve: Vec<i64>,
+        /// 거절할 초안 id (여러 번 지정 가능)
+        #[arg(long)]
+        reject: Vec<i64>,
+    },
+    /// SPEC-043: 도메인 1개(시나리오·골든셋·키워드·외부 도구·MCP 서버·
+    /// PromptSet)를 버전이 붙은 YAML/JSON 번들 디렉토리로 내보낸다.
+    ExportDomain {
+        #[arg(long)]
+        domain: String,
+        /// 번들을 쓸 디렉토리 (없으면 생성)
+        #[arg(long)]
+        out: String,
+    },
+    /// SPEC-043: 번들 디렉토리를 가져온다. 기존 행과 다르면 충돌로 보고하고,
+    /// `--policy` 로 처리 방법을 고른다.
+    ImportDomain {
+        /// `export-domain` 이 만든 번들 디렉토리
+        dir: String,
+        /// fail(충돌 시 중단) | merge(기존 유지, 새 항목만 추가) | overwrite
+        #[arg(long, default_value = "fail")]
+        policy: data_scenarios::bundle::ImportPolicy,
+        /// 적용하지 않고 계획만 표시
+        #[arg(long)]
+        dry_run: bool,
+    },
     /// 사용 가능한 평가 시나리오 목록 표시
     List {
         #[arg(long)]
         scenarios_dir: Option<String>,
     },
     /// 저장된 리포트 표시
-    Report { filepath: String },
+    Report {
+        filepath: String,
+        /// SPEC-039: json | junit | html 로 내보내기 (생략 시 터미널 출력)
+        #[arg(long)]
+        format: Option<String>,
+        /// 내보내기 저장 경로 (생략 시 stdout)
+        #[arg(short, long)]
+        output: Option<String>,
+        /// 파일로 저장된 궤적을 찾을 디렉토리 (DB 에 없을 때)
+        #[arg(long, default_value = "reporting_trajectories")]
+        trajectories_dir: String,
+    },
     /// 대화형 TUI 모드 실행
     Tui {
         #[arg(long)]
@@ -115,6 +221,27 @@ enum Commands {
     },
 }
 
+/// SPEC-039: 리포트를 궤적과 함께 `format` 으로 렌더링해 `output` 에 저장
+/// (없으면 stdout).
+fn write_export(report: &EvaluationReport, format: ExportFormat, output: Option<&str>, trajectory_dirs: &[&Path]) -> std::io::Result<()> {
+    let trajectories = web::handlers::report_trajectories_impl(report, trajectory_dirs);
+    let body = report_export::export(report, format, &trajectories);
+    match output {
+        | Some(path) => {
+            if let Some(parent) = Path::new(path).parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, body)?;
+            println!("리포트 내보내기 ({}): {}", format.extension(), path);
+            Ok(())
+        },
+        | None => {
+            print!("{body}");
+            Ok(())
+        },
+    }
+}
+
 fn resolve_data_paths(scenarios: Option<&str>, golden_sets: Option<&str>) -> DataPaths {
     let base = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
     match DataPaths::load(&base) {
@@ -137,29 +264,89 @@ fn build_registry() -> AgentRegistry {
     registry.register("passthrough", Arc::new(PassthroughAgent));
 
     dotenvy::dotenv().ok();
-    if let Ok(llm_config) = agent_core::config::AzureOpenAiConfig::from_env() {
-        let base = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
-        let eval_config = match eval_harness::data_paths::load_evaluation_config(&base) {
-            | Ok(cfg) => cfg,
-            | Err(e) => {
-                eprintln!("[warn] eval-harness.toml [evaluation] 파싱 실패: {e} — 기본값 사용");
-                agent_core::config::EvaluationConfig::default()
-            },
-        };
+    let base = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
+    let eval_config = match eval_harness::data_paths::load_evaluation_config(&base) {
+        | Ok(cfg) => cfg,
+        | Err(e) => {
+            eprintln!("[warn] eval-harness.toml [evaluation] 파싱 실패: {e} — 기본값 사용");
+            agent_core::config::EvaluationConfig::default()
+        },
+    };
+    let names = web::api::register_ppa_agents(&mut registry, &eval_config);
+    if names.is_empty() {
+        eprintln!("PPA 에이전트 초기화 실패 (LLM 설정 확인 필요) - passthrough만 사용 가능");
+    } else {
         println!(
-            "[cfg] PPA 설정: max_iterations={}, early_stop_threshold={}",
-            eval_config.max_iterations, eval_config.early_stop_threshold
+            "[cfg] PPA 설정: max_iterations={}, early_stop_threshold={}, agents={}",
+            eval_config.max_iterations,
+            eval_config.early_stop_threshold,
+            names.join(",")
         );
-        let llm = agent_core::llm_client::LlmClient::new(llm_config);
-        let agent = agent_core::agent::PpaAgent::new(llm, eval_config);
-        registry.register("ppa", Arc::new(agent));
-    } else {
-        eprintln!("PPA 에이전트 초기화 실패 (LLM 설정 확인 필요) - passthrough만 사용 가능");
+    }
+    // SPEC-036: 임베딩 모델이 있으면 라우터가 임베딩 유사도를 쓴다.
+    match agent_core::domain_router::install_embedder_from_env() {
+        | Some(model) => println!("[cfg] 도메인 라우터: embedding (model={model})"),
+        | None => println!("[cfg] 도메인 라우터: keyword"),
     }
 
     registry
 }
 
+/// `--llm-judge` 용 judge 구성. LLM 설정이 없거나 루브릭이 잘못되면 종료.
+///
+/// @trace SPEC: SPEC-026
+/// @trace FR: PRD-026/FR-3
+fn build_judge(rubric: Option<&str>) -> Arc<scoring_llm_judge::judge::LlmJudge> {
+    dotenvy::dotenv().ok();
+    let llm_config = match agent_core::config::LlmConfig::from_env_default() {
+        | Ok(c) => c,
+        | Err(e) => {
+            eprintln!("LLM judge 초기화 실패 (LLM 설정 확인 필요): {e}");
+            std::process::exit(1);
+        },
+    };
+    let rubric = match rubric {
+        | Some(p) => match scoring_llm_judge::judge::load_rubric(Path::new(p)) {
+            | Ok(r) => r,
+            | Err(e) => {
+                eprintln!("루브릭 오류: {e:#}");
+                std::process::exit(1);
+            },
+        },
+        | None => scoring_llm_judge::judge::default_rubric(),
+    };
+    let llm = Arc::new(agent_core::llm_client::LlmClient::new(llm_config));
+    match scoring_llm_judge::judge::LlmJudge::new(llm, rubric) {
+        | Ok(j) => Arc::new(j),
+        | Err(e) => {
+            eprintln!("루브릭 오류: {e}");
+            std::process::exit(1);
+        },
+    }
+}
+
+/// SPEC-044: `run --approval interactive` 에서 터미널로 승인을 묻는 sink.
+/// 동시 실행 중에도 질문이 섞이지 않도록 한 번에 하나씩 묻는다.
+struct StdinApprover(Mutex<()>);
+
+impl ProgressSink for StdinApprover {
+    fn emit(&self, _event: ProgressEvent) {}
+
+    fn request_approval(&self, request: &ApprovalRequest) -> Option<ApprovalDecision> {
+        let _turn = self.0.lock().unwrap();
+        let params = serde_json::to_string(&request.parameters).unwrap_or_default();
+        print!("  승인 요청: {} {} — 실행할까요? [y/N] ", request.tool_name, params);
+        std::io::stdout().flush().ok();
+        let mut line = String::new();
+        std::io::stdin().lock().read_line(&mut line).ok()?;
+        Some(if line.trim().eq_ignore_ascii_case("y") {
+            ApprovalDecision::Approved
+        } else {
+            ApprovalDecision::Denied
+        })
+    }
+}
+
 fn main() {
     let cli = Cli::parse();
 
@@ -170,6 +357,14 @@ fn main() {
             output,
             scenarios_dir,
             output_dir,
+            llm_judge,
+            rubric,
+            concurrency,
+            scenario_timeout,
+            trials,
+            junit,
+            html,
+            approval,
         } => {
             let registry = build_registry();
             let agent_impl = match registry.get_agent(&agent) {
@@ -184,8 +379,20 @@ fn main() {
             let paths = resolve_data_paths(scenarios_dir.as_deref(), None);
             install_data_store(&paths);
             let scenarios_dir = paths.scenarios_dir.to_string_lossy().into_owned();
-            let mut runner = HarnessRunner::new(&output_dir);
-            let report = match runner.run_eval_scenario(&eval_scenario, agent_impl.as_ref(), &scenarios_dir) {
+            let mut runner = web::api::apply_pricing(HarnessRunner::new(&output_dir))
+                .with_concurrency(concurrency)
+                .with_trials(trials);
+            if approval == ApprovalPolicy::Interactive {
+                runner = runner.with_progress(Arc::new(StdinApprover(Mutex::new(()))));
+            }
+            runner = runner.with_approval(approval);
+            if let Some(secs) = scenario_timeout {
+                runner = runner.with_scenario_timeout(std::time::Duration::from_secs(secs));
+            }
+            if llm_judge || rubric.is_some() {
+                runner = runner.with_judge(build_judge(rubric.as_deref()));
+            }
+            let report = match runner.run_eval_scenario(&eval_scenario, agent_impl, &scenarios_dir) {
                 | Ok(r) => r,
                 | Err(e) => {
                     eprintln!("실행 오류: {}", e);
@@ -196,12 +403,23 @@ fn main() {
             runner.save_report(&report, output.as_deref()).ok();
             let renderer = ReportRenderer::new();
             renderer.render(&report);
+
+            for (format, path) in [(ExportFormat::Junit, junit), (ExportFormat::Html, html)] {
+                let Some(path) = path else {
+                    continue;
+                };
+                if let Err(e) = write_export(&report, format, Some(&path), &[Path::new(&output_dir)]) {
+                    eprintln!("{} 저장 실패: {}", format.extension(), e);
+                    std::process::exit(1);
+                }
+            }
         },
 
         | Commands::Compare {
             baseline,
             current,
             threshold,
+            alpha,
             output,
             baseline_task,
             current_task,
@@ -211,14 +429,12 @@ fn main() {
             current_since,
             current_until,
         } => {
-            let comparator = ReportComparator::new(threshold);
+            let comparator = ReportComparator::new(threshold).with_alpha(alpha);
             // SPEC-021: 입력 모드 결정. 우선순위 = 시간 범위 > task_id > 파일.
-            let result = if agent.is_some() && baseline_since.is_some() {
+            let result = if let (Some(agent), Some(bs)) = (agent, baseline_since) {
                 // 시간 범위 모드
                 let paths = resolve_data_paths(None, None);
                 install_data_store(&paths);
-                let agent = agent.unwrap();
-                let bs = baseline_since.unwrap();
                 let bu = baseline_until.expect("--baseline-until 필요");
                 let cs = current_since.expect("--current-since 필요");
                 let cu = current_until.expect("--current-until 필요");
@@ -278,6 +494,190 @@ fn main() {
             );
         },
 
+        | Commands::JudgePairwise {
+            task_a,
+            task_b,
+            rubric,
+            output,
+        } => {
+            let paths = resolve_data_paths(None, None);
+            install_data_store(&paths);
+            let judge = match eval_harness::judge_pairwise::pairwise_judge_from_env(rubric.as_deref().map(Path::new)) {
+                | Ok(j) => j,
+                | Err(e) => {
+                    eprintln!("pairwise judge 초기화 실패: {e:#}");
+                    std::process::exit(1);
+                },
+            };
+            let report = match eval_harness::judge_pairwise::judge_pairwise(&task_a, &task_b, &judge) {
+                | Ok(r) => r,
+                | Err(e) => {
+                    eprintln!("pairwise 비교 오류: {e}");
+                    std::process::exit(1);
+                },
+            };
+            let c = &report.comparison;
+            println!(
+                "[pairwise] A={} (prompt_set={:?}) vs B={} (prompt_set={:?})",
+                c.trajectory_a_id, report.prompt_set_a, c.trajectory_b_id, report.prompt_set_b
+            );
+            println!(
+                "[pairwise] winner={} confidence={:.2} position_consistent={}",
+                c.winner, c.confidence, c.position_consistent
+            );
+            println!("{}", c.reasoning);
+            if let Some(out) = output {
+                match serde_json::to_string_pretty(&report).map_err(anyhow::Error::from).and_then(|j| Ok(std::fs::write(&out, j)?)) {
+                    | Ok(()) => println!("결과 저장: {out}"),
+                    | Err(e) => eprintln!("결과 저장 실패: {e}"),
+                }
+            }
+        },
+
+        | Commands::GenerateScenarios {
+            domain,
+            count,
+        } => {
+            let paths = resolve_data_paths(None, None);
+            install_data_store(&paths);
+            let llm = match eval_harness::scenario_gen::generator_llm_from_env() {
+                | Ok(l) => l,
+                | Err(e) => {
+                    eprintln!("시나리오 생성기 초기화 실패: {e:#}");
+                    std::process::exit(1);
+                },
+            };
+            let outcome = match eval_harness::scenario_gen::generate_scenarios(&domain, count, &llm) {
+                | Ok(o) => o,
+                | Err(e) => {
+                    eprintln!("시나리오 생성 오류: {e:#}");
+                    std::process::exit(1);
+                },
+            };
+            for d in &outcome.drafts {
+                println!(
+                    "[draft #{}] {} ({}) tools={} — {}",
+                    d.id,
+                    d.scenario.id,
+                    d.scenario.difficulty,
+                    d.scenario.expected_tools.join(","),
+                    d.scenario.task_description
+                );
+            }
+            for s in &outcome.skipped {
+                println!("[skip] {}: {}", s.id, s.reason);
+            }
+            println!(
+                "초안 {}건 저장 ({}건 제외). 승인: eval-harness scenario-drafts --approve <id>",
+                outcome.drafts.len(),
+                outcome.skipped.len()
+            );
+        },
+
+        | Commands::ScenarioDrafts {
+            domain,
+            approve,
+            reject,
+        } => {
+            let paths = resolve_data_paths(None, None);
+            install_data_store(&paths);
+            let mut failed = false;
+            for id in approve {
+                match eval_harness::scenario_gen::approve_draft(id) {
+                    | Ok(d) => println!("✔ 초안 #{id} 승인: {}/{}", d.domain, d.scenario.id),
+                    | Err(e) => {
+                        eprintln!("✘ 초안 #{id} 승인 실패: {e:#}");
+                        failed = true;
+                    },
+                }
+            }
+            for id in reject {
+                match eval_harness::scenario_gen::reject_draft(id) {
+                    | Ok(()) => println!("✔ 초안 #{id} 거절"),
+                    | Err(e) => {
+                        eprintln!("✘ 초안 #{id} 거절 실패: {e:#}");
+                        failed = true;
+                    },
+                }
+            }
+            match eval_harness::scenario_gen::list_drafts(domain.as_deref(), Some("pending")) {
+                | Ok(rows) if rows.is_empty() => println!("승인 대기 중인 초안이 없습니다."),
+                | Ok(rows) =>
+                    for d in rows {
+                        println!(
+                            "[draft #{}] {}/{} ({}) — {}",
+                            d.id, d.domain, d.scenario.id, d.scenario.difficulty, d.scenario.task_description
+                        );
+                    },
+                | Err(e) => {
+                    eprintln!("초안 조회 오류: {e:#}");
+                    failed = true;
+                },
+            }
+            if failed {
+                std::process::exit(1);
+            }
+        },
+
+        | Commands::ExportDomain {
+            domain,
+            out,
+        } => {
+            let paths = resolve_data_paths(None, None);
+            install_data_store(&paths);
+            match eval_harness::domain_bundle::export_domain_to_dir(&domain, Path::new(&out)) {
+                | Ok(()) => println!("✔ 도메인 {domain} 번들 저장: {out}"),
+                | Err(e) => {
+                    eprintln!("도메인 내보내기 오류: {e:#}");
+                    std::process::exit(1);
+                },
+            }
+        },
+
+        | Commands::ImportDomain {
+            dir,
+            policy,
+            dry_run,
+        } => {
+            let paths = resolve_data_paths(None, None);
+            install_data_store(&paths);
+            let report = match eval_harness::domain_bundle::import_domain_from_dir(Path::new(&dir), policy, dry_run) {
+                | Ok(r) => r,
+                | Err(e) => {
+                    eprintln!("도메인 가져오기 오류: {e:#}");
+                    std::process::exit(1);
+                },
+            };
+            for key in &report.created {
+                println!("[create] {key}");
+            }
+            for key in &report.updated {
+                println!("[update] {key}");
+            }
+            for c in &report.conflicts {
+                println!("[conflict] {} ({})", c.key, c.resolution);
+            }
+            let state = if report.applied {
+                "적용됨"
+            } else if dry_run {
+                "dry-run"
+            } else {
+                "적용 안 됨"
+            };
+            println!(
+                "도메인 {}: 생성 {} · 수정 {} · 동일 {} · 충돌 {} — {state}",
+                report.domain,
+                report.created.len(),
+                report.updated.len(),
+                report.unchanged,
+                report.conflicts.len()
+            );
+            if !report.applied && !dry_run {
+                eprintln!("충돌이 있어 가져오지 않았습니다. --policy merge 또는 --policy overwrite 를 지정하세요.");
+                std::process::exit(1);
+            }
+        },
+
         | Commands::List {
             scenarios_dir,
         } => {
@@ -358,14 +758,32 @@ fn main() {
 
         | Commands::Report {
             filepath,
+            format,
+            output,
+            trajectories_dir,
         } => {
             let renderer = ReportRenderer::new();
-            match renderer.load_report(&filepath) {
-                | Ok(report) => renderer.render(&report),
+            let report = match renderer.load_report(&filepath) {
+                | Ok(report) => report,
                 | Err(e) => {
                     eprintln!("파일 오류: {}", e);
                     std::process::exit(1);
                 },
+            };
+            let Some(format) = format else {
+                renderer.render(&report);
+                return;
+            };
+            let Some(format) = ExportFormat::parse(&format) else {
+                eprintln!("알 수 없는 형식: {} (json|junit|html)", format);
+                std::process::exit(1);
+            };
+            install_data_store(&resolve_data_paths(None, None));
+            // DB 없이 실행해 리포트 옆에 저장된 궤적도 찾는다.
+            let report_dir = Path::new(&filepath).parent().unwrap_or(Path::new("."));
+            if let Err(e) = write_export(&report, format, output.as_deref(), &[Path::new(&trajectories_dir), report_dir]) {
+                eprintln!("저장 실패: {}", e);
+                std::process::exit(1);
             }
         },
     }
@@ -0,0 +1,544 @@
+// =============================================================================
+// @trace SPEC-042
+// @trace PRD: PRD-042
+// @trace FR: PRD-042/FR-1, PRD-042/FR-2, PRD-042/FR-3, PRD-042/FR-4
+// @trace file-type: impl
+// =============================================================================
+//
+// `generate-scenarios` 명령과 `/api/domains/:name/scenario-drafts/generate`
+// 의 공용 구현. 도메인에 등록된 도구와 파라미터 스키마를 LLM 에 보여 주고
+// 시나리오 + 골든셋 초안을 받아, 도구 이름 검증과 중복 검사를 거친 뒤
+// `scenario_drafts` 에 `pending` 으로 저장한다. 실제 eval_scenarios /
+// golden_sets INSERT 는 승인(`approve_scenario_draft`) 때 일어난다.
+
+use crate::web::db_query::block_on;
+use agent_core::llm_client::{LlmClient,
+                             Message};
+use agent_models::domain_config::ScenarioConfig;
+use data_scenarios::{models::{GoldenSetEntry,
+                              GoldenSetExpectedOutput,
+                              GoldenSetInput},
+                     sqlite_store::{ScenarioDraftRow,
+                                    SqliteStore,
+                                    StoreError}};
+use execution_tools::registry::ToolRegistry;
+use serde::{Deserialize,
+            Serialize};
+use serde_json::Value;
+use std::collections::{HashMap,
+                       HashSet};
+
+/// 한 번에 요청할 수 있는 최대 초안 수.
+pub const MAX_COUNT: usize = 20;
+
+/// 작업 설명 토큰 Jaccard 유사도가 이 값 이상이면 중복으로 본다.
+const DUPLICATE_SIMILARITY: f64 = 0.8;
+
+/// 같은 도구 순서·초기 환경이어도 작업 설명 유사도가 이 값 이상일 때만
+/// 중복으로 본다. 빈 환경의 단일 도구 시나리오는 흔해서 호출만으로는
+/// 구별되지 않는다.
+const SAME_CALL_SIMILARITY: f64 = 0.4;
+
+const DIFFICULTIES: [&str; 3] = ["easy", "medium", "hard"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum GenerateError {
+    #[error("domain not found: {0}")]
+    DomainNotFound(String),
+    #[error("invalid request: {0}")]
+    Invalid(String),
+    #[error("store error: {0}")]
+    Store(#[from] StoreError),
+    #[error("llm error: {0}")]
+    Llm(String),
+}
+
+/// 검증·중복 검사에서 걸러진 초안과 그 이유.
+#[derive(Debug, Clone, Serialize)]
+pub struct SkippedDraft {
+    pub id: String,
+    pub reason: String,
+}
+
+/// 생성 결과. `drafts` 는 저장된 `pending` 초안이다.
+#[derive(Debug, Clone, Serialize)]
+pub struct GenerateOutcome {
+    pub domain: String,
+    pub drafts: Vec<ScenarioDraftRow>,
+    pub skipped: Vec<SkippedDraft>,
+}
+
+/// LLM 이 돌려주는 초안 1건.
+#[derive(Debug, Deserialize)]
+struct GeneratedScenario {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    task_description: String,
+    #[serde(default)]
+    initial_environment: HashMap<String, Value>,
+    #[serde(default)]
+    expected_tools: Vec<String>,
+    #[serde(default)]
+    success_criteria: HashMap<String, Value>,
+    #[serde(default)]
+    difficulty: String,
+    #[serde(default)]
+    golden: GeneratedGolden,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GeneratedGolden {
+    #[serde(default)]
+    tool_sequence: Vec<String>,
+    #[serde(default)]
+    tool_results: HashMap<String, Value>,
+    #[serde(default)]
+    tolerance: Option<f64>,
+}
+
+/// 도메인에 등록된 도구 메타데이터 (내장 + 외부 HTTP + MCP). 이름은
+/// 레지스트리 키(`<domain>__<tool>`)이고 `parameters_schema` 를 포함한다.
+///
+/// @trace SPEC: SPEC-042
+/// @trace FR: PRD-042/FR-1
+pub fn domain_tool_catalog(domain: &str) -> Vec<Value> {
+    let mut registry = ToolRegistry::new();
+    domains::register_all(&mut registry);
+    agent_core::external_tools::register_external_tools_from_db(&mut registry);
+    agent_core::mcp_tools::register_mcp_tools_from_db(&mut registry);
+    let mut tools: Vec<Value> = registry
+        .get_tools_metadata()
+        .into_iter()
+        .filter(|t| t.get("domain").and_then(Value::as_str) == Some(domain))
+        .collect();
+    tools.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+    tools
+}
+
+fn build_messages(domain: &str, description: &str, tools: &[Value], existing: &[&ScenarioConfig], count: usize) -> Vec<Message> {
+    let system = "당신은 AI 에이전트 평가 시나리오 작성자입니다. 주어진 도구만 사용해 풀 수 있는 평가 \
+                  시나리오와 골든셋 기대 출력을 JSON 으로만 답하세요. 형식:\n\
+                  {\"scenarios\": [{\"id\": \"snake_case\", \"name\": \"...\", \"description\": \"...\", \
+                  \"task_description\": \"...\", \"initial_environment\": {...}, \"expected_tools\": [\"도구 이름\"], \
+                  \"success_criteria\": {...}, \"difficulty\": \"easy|medium|hard\", \
+                  \"golden\": {\"tool_sequence\": [\"도구 이름\"], \"tool_results\": {...}, \"tolerance\": 0.01}}]}\n\
+                  도구 이름은 목록의 name 을 그대로 쓰고, tool_results 는 initial_environment 로 계산되는 값이어야 \
+                  합니다.";
+    let tools_view: Vec<Value> = tools
+        .iter()
+        .map(|t| serde_json::json!({"name": t["name"], "description": t["description"], "parameters_schema": t["parameters_schema"]}))
+        .collect();
+    let existing_view: Vec<String> = existing.iter().map(|s| format!("- {}: {}", s.id, s.task_description)).collect();
+    let user = format!(
+        "도메인: {domain}\n설명: {description}\n\n도구:\n{}\n\n기존 시나리오 (중복 금지):\n{}\n\n서로 다른 난이도를 섞어 새 시나리오 {count}개를 \
+         작성하세요.",
+        serde_json::to_string_pretty(&tools_view).unwrap_or_default(),
+        if existing_view.is_empty() {
+            "(없음)".to_string()
+        } else {
+            existing_view.join("\n")
+        },
+    );
+    vec![Message::system(system), Message::user(user)]
+}
+
+/// 중복 비교용 토큰 집합. 영숫자·한글 외 문자는 구분자로 본다.
+fn tokens(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// `candidate` 와 같거나 거의 같은 작업을 가진 시나리오의 id.
+/// 작업 설명이 유사하거나, 같은 도구 순서를 같은 초기 환경으로 호출하면서
+/// 작업 설명도 어느 정도 겹치면 중복이다.
+///
+/// @trace SPEC: SPEC-042
+/// @trace FR: PRD-042/FR-3
+pub fn find_duplicate<'a>(candidate: &ScenarioConfig, known: impl IntoIterator<Item = &'a ScenarioConfig>) -> Option<String> {
+    let cand_tokens = tokens(&candidate.task_description);
+    known
+        .into_iter()
+        .find(|k| {
+            let sim = similarity(&cand_tokens, &tokens(&k.task_description));
+            let same_call = k.expected_tools == candidate.expected_tools && k.initial_environment == candidate.initial_environment;
+            sim >= DUPLICATE_SIMILARITY || (same_call && sim >= SAME_CALL_SIMILARITY)
+        })
+        .map(|k| k.id.clone())
+}
+
+/// 안전한 id 로 정리하고, 이미 쓰인 id 면 `<domain>_gen_<n>` 으로 바꾼다.
+fn assign_id(raw: &str, domain: &str, taken: &HashSet<String>) -> String {
+    let cleaned: String = raw.chars().filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-').take(64).collect();
+    if !cleaned.is_empty() && !taken.contains(&cleaned) {
+        return cleaned;
+    }
+    (1 ..)
+        .map(|n| format!("{domain}_gen_{n:03}"))
+        .find(|id| !taken.contains(id))
+        .expect("unbounded range")
+}
+
+/// 검증을 통과한 초안 후보. 골든셋의 `scenario_id` 는 id 확정 후 채운다.
+type Candidate = (ScenarioConfig, GoldenSetEntry);
+
+/// LLM 응답을 초안 후보로 바꾼다. 검증에 실패한 항목은 이유와 함께
+/// `skipped` 로 돌려준다.
+fn parse_candidates(text: &str, domain: &str, tool_names: &HashSet<&str>) -> Result<(Vec<Candidate>, Vec<SkippedDraft>), GenerateError> {
+    let parsed = LlmClient::parse_json_response(text);
+    let items = parsed
+        .get("scenarios")
+        .and_then(Value::as_array)
+        .ok_or_else(|| GenerateError::Llm("응답에 scenarios 배열이 없습니다".into()))?;
+
+    let mut out = Vec::new();
+    let mut skipped = Vec::new();
+    for item in items {
+        let label = item.get("id").and_then(Value::as_str).unwrap_or("-").to_string();
+        let g: GeneratedScenario = match serde_json::from_value(item.clone()) {
+            | Ok(g) => g,
+            | Err(e) => {
+                skipped.push(SkippedDraft {
+                    id: label,
+                    reason: format!("형식 오류: {e}"),
+                });
+                continue;
+            },
+        };
+        let sequence = if g.golden.tool_sequence.is_empty() {
+            g.expected_tools.clone()
+        } else {
+            g.golden.tool_sequence
+        };
+        let reason = if g.task_description.trim().is_empty() {
+            Some("task_description 이 비어 있습니다".to_string())
+        } else if g.expected_tools.is_empty() {
+            Some("expected_tools 가 비어 있습니다".to_string())
+        } else {
+            g.expected_tools
+                .iter()
+                .chain(&sequence)
+                .find(|t| !tool_names.contains(t.as_str()))
+                .map(|t| format!("도메인에 없는 도구: {t}"))
+        };
+        if let Some(reason) = reason {
+            skipped.push(SkippedDraft {
+                id: label,
+                reason,
+            });
+            continue;
+        }
+        let difficulty = g.difficulty.to_lowercase();
+        let scenario = ScenarioConfig {
+            id: g.id,
+            name: if g.name.trim().is_empty() { g.task_description.clone() } else { g.name },
+            description: g.description,
+            task_description: g.task_description.clone(),
+            initial_environment: g.initial_environment.clone(),
+            expected_tools: g.expected_tools,
+            success_criteria: g.success_criteria,
+            difficulty: if DIFFICULTIES.contains(&difficulty.as_str()) {
+                difficulty
+            } else {
+                "medium".into()
+            },
+            conversation: None,
+            approval: None,
+            workspace: None,
+        };
+        let golden = GoldenSetEntry {
+            scenario_id: String::new(),
+            input: GoldenSetInput {
+                task: g.task_description,
+                environment: g.initial_environment,
+            },
+            expected_output: GoldenSetExpectedOutput {
+                tool_sequence: sequence,
+                tool_results: g.golden.tool_results,
+                tolerance: g.golden.tolerance.unwrap_or(0.01),
+                expected_domain: Some(domain.to_string()),
+            },
+        };
+        out.push((scenario, golden));
+    }
+    Ok((out, skipped))
+}
+
+/// `domain` 의 도구로 시나리오 초안을 최대 `count` 개 생성해 저장한다.
+/// 기존 시나리오와 대기 중인 초안, 그리고 같은 응답 안의 앞선 초안과
+/// 중복되는 항목은 저장하지 않는다.
+///
+/// @trace SPEC: SPEC-042
+/// @trace FR: PRD-042/FR-1, PRD-042/FR-2, PRD-042/FR-3, PRD-042/FR-4
+pub async fn generate_drafts(store: &SqliteStore, llm: &LlmClient, domain: &str, count: usize, tools: &[Value]) -> Result<GenerateOutcome, GenerateError> {
+    if count == 0 || count > MAX_COUNT {
+        return Err(GenerateError::Invalid(format!("count must be 1..={MAX_COUNT}")));
+    }
+    let config = store
+        .load_all_domains()
+        .await?
+        .into_iter()
+        .find(|d| d.name == domain)
+        .ok_or_else(|| GenerateError::DomainNotFound(domain.to_string()))?;
+    if tools.is_empty() {
+        return Err(GenerateError::Invalid(format!("도메인 {domain} 에 등록된 도구가 없습니다")));
+    }
+    let pending: Vec<ScenarioConfig> = store
+        .list_scenario_drafts(Some(domain), Some("pending"))
+        .await?
+        .into_iter()
+        .map(|d| d.scenario)
+        .collect();
+    let mut known: Vec<ScenarioConfig> = config.scenarios.iter().cloned().chain(pending).collect();
+    let mut taken: HashSet<String> = known.iter().map(|s| s.id.clone()).collect();
+
+    let messages = build_messages(domain, &config.description, tools, &known.iter().collect::<Vec<_>>(), count);
+    let text = llm.invoke(messages).await.map_err(|e| GenerateError::Llm(e.to_string()))?;
+    let tool_names: HashSet<&str> = tools.iter().filter_map(|t| t["name"].as_str()).collect();
+    let (candidates, mut skipped) = parse_candidates(&text, domain, &tool_names)?;
+
+    let mut drafts = Vec::new();
+    for (mut scenario, mut golden) in candidates {
+        if drafts.len() == count {
+            break;
+        }
+        if let Some(dup) = find_duplicate(&scenario, &known) {
+            skipped.push(SkippedDraft {
+                id: scenario.id,
+                reason: format!("{dup} 와 중복"),
+            });
+            continue;
+        }
+        scenario.id = assign_id(&scenario.id, domain, &taken);
+        golden.scenario_id = scenario.id.clone();
+        let id = store.insert_scenario_draft(domain, &scenario, &golden).await?;
+        if let Some(row) = store.get_scenario_draft(id).await? {
+            drafts.push(row);
+        }
+        taken.insert(scenario.id.clone());
+        known.push(scenario);
+    }
+    Ok(GenerateOutcome {
+        domain: domain.to_string(),
+        drafts,
+        skipped,
+    })
+}
+
+/// 환경변수 LLM 설정으로 생성용 클라이언트 구성.
+///
+/// @trace SPEC: SPEC-042
+/// @trace FR: PRD-042/FR-2
+pub fn generator_llm_from_env() -> anyhow::Result<LlmClient> {
+    dotenvy::dotenv().ok();
+    let llm_config = agent_core::config::LlmConfig::from_env_default().map_err(|e| anyhow::anyhow!("LLM 설정 확인 필요: {e}"))?;
+    Ok(LlmClient::new(llm_config))
+}
+
+fn installed_store() -> anyhow::Result<std::sync::Arc<SqliteStore>> {
+    data_scenarios::loader::try_installed_store().ok_or_else(|| anyhow::anyhow!("SqliteStore not installed"))
+}
+
+/// CLI 용 동기 진입점. 설치된 전역 SqliteStore 를 사용한다.
+///
+/// @trace SPEC: SPEC-042
+/// @trace FR: PRD-042/FR-1
+pub fn generate_scenarios(domain: &str, count: usize, llm: &LlmClient) -> anyhow::Result<GenerateOutcome> {
+    let store = installed_store()?;
+    let tools = domain_tool_catalog(domain);
+    Ok(block_on(generate_drafts(&store, llm, domain, count, &tools))?)
+}
+
+/// CLI 용 초안 목록 조회.
+pub fn list_drafts(domain: Option<&str>, status: Option<&str>) -> anyhow::Result<Vec<ScenarioDraftRow>> {
+    let store = installed_store()?;
+    Ok(block_on(store.list_scenario_drafts(domain, status))?)
+}
+
+/// CLI 용 승인. 승인된 시나리오는 라우터 예시에도 반영된다.
+///
+/// @trace SPEC: SPEC-042
+/// @trace FR: PRD-042/FR-5
+pub fn approve_draft(id: i64) -> anyhow::Result<ScenarioDraftRow> {
+    let store = installed_store()?;
+    let row = block_on(store.approve_scenario_draft(id))?;
+    agent_core::domain_router::invalidate_cache();
+    Ok(row)
+}
+
+/// CLI 용 거절.
+pub fn reject_draft(id: i64) -> anyhow::Result<()> {
+    let store = installed_store()?;
+    Ok(block_on(store.reject_scenario_draft(id))?)
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-042
+    // @trace PRD: PRD-042
+    // @trace FR: PRD-042/FR-2, PRD-042/FR-3, PRD-042/FR-4
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use agent_core::config::AzureOpenAiConfig;
+    use test_support::spawn_mock_chat_server;
+
+    fn client_for(endpoint: String) -> LlmClient {
+        LlmClient::new(AzureOpenAiConfig {
+            azure_openai_endpoint: endpoint,
+            azure_openai_api_key: "test".into(),
+            azure_openai_deployment: "gen".into(),
+            azure_openai_api_version: "2024-12-01-preview".into(),
+            azure_openai_region: "local".into(),
+            temperature: 1.0,
+            max_tokens: None,
+        })
+    }
+
+    fn tools() -> Vec<Value> {
+        let mut registry = ToolRegistry::new();
+        domains::register_financial(&mut registry);
+        registry.get_tools_metadata()
+    }
+
+    async fn store_with_scenario() -> SqliteStore {
+        let store = SqliteStore::open_in_memory_for_loader().await.unwrap();
+        store.insert_domain("financial", "금융").await.unwrap();
+        let existing = ScenarioConfig {
+            id: "fin_001".into(),
+            name: "단리".into(),
+            description: String::new(),
+            task_description: "100만원을 연 5% 로 2년 예치했을 때 단리 이자를 계산".into(),
+            initial_environment: HashMap::new(),
+            expected_tools: vec!["financial__calculate_simple_interest".into()],
+            success_criteria: HashMap::new(),
+            difficulty: "easy".into(),
+            conversation: None,
+            approval: None,
+            workspace: None,
+        };
+        store.insert_scenario("financial", &existing, 0).await.unwrap();
+        store
+    }
+
+    fn item(id: &str, task: &str, tools: &[&str], principal: i64) -> Value {
+        serde_json::json!({
+            "id": id,
+            "name": id,
+            "task_description": task,
+            "initial_environment": {"principal": principal},
+            "expected_tools": tools,
+            "success_criteria": {"interest_calculated": true},
+            "difficulty": "Hard",
+            "golden": {"tool_results": {"interest": 1.0}}
+        })
+    }
+
+    /// @trace TC: SPEC-042/TC-3
+    /// @trace FR: PRD-042/FR-3
+    #[test]
+    fn spec042_tc_3_find_duplicate_by_task_or_tool_call() {
+        let base = ScenarioConfig {
+            id: "a".into(),
+            name: String::new(),
+            description: String::new(),
+            task_description: "복리 이자를 계산하고 단리와 비교".into(),
+            initial_environment: HashMap::from([("x".to_string(), Value::from(1))]),
+            expected_tools: vec!["t1".into()],
+            success_criteria: HashMap::new(),
+            difficulty: "easy".into(),
+            conversation: None,
+            approval: None,
+            workspace: None,
+        };
+        let mut same_task = base.clone();
+        same_task.id = "b".into();
+        same_task.task_description = "복리 이자를 계산하고, 단리와 비교".into();
+        same_task.expected_tools = vec!["t2".into()];
+        assert_eq!(find_duplicate(&same_task, [&base]), Some("a".into()));
+
+        let mut same_call = base.clone();
+        same_call.task_description = "복리 이자를 계산하고 단리와 비교해 보고".into();
+        assert_eq!(find_duplicate(&same_call, [&base]), Some("a".into()));
+
+        let mut different = same_call.clone();
+        different.initial_environment = HashMap::from([("x".to_string(), Value::from(2))]);
+        assert_eq!(find_duplicate(&different, [&base]), None);
+
+        // 빈 환경의 단일 도구 시나리오라도 작업이 다르면 둘 다 남긴다.
+        let mut lookup = base.clone();
+        lookup.initial_environment = HashMap::new();
+        lookup.task_description = "주문 번호 1234 의 배송 상태를 알려줘".into();
+        let mut refund = lookup.clone();
+        refund.id = "c".into();
+        refund.task_description = "환불 가능한 기간이 며칠인지 확인".into();
+        assert_eq!(find_duplicate(&refund, [&lookup]), None);
+    }
+
+    /// @trace TC: SPEC-042/TC-4
+    /// @trace FR: PRD-042/FR-1, PRD-042/FR-2, PRD-042/FR-3, PRD-042/FR-4
+    #[tokio::test]
+    async fn spec042_tc_4_generate_validates_dedupes_and_stages() {
+        let store = store_with_scenario().await;
+        let reply = serde_json::json!({"scenarios": [
+            item("fin_001", "대출 원금 500만원의 복리 이자 계산", &["financial__calculate_compound_interest"], 5_000_000),
+            item("dup", "100만원을 연 5% 로 2년 예치했을 때 단리 이자를 계산", &["financial__calculate_simple_interest"], 1),
+            item("bad_tool", "환율 조회", &["financial__fx_rate"], 1),
+            item("fin_tx", "300만원 출금 거래 검증", &["financial__validate_transaction"], 3_000_000),
+        ]});
+        let (endpoint, requests) = spawn_mock_chat_server(vec![format!("```json\n{reply}\n```")]);
+        let outcome = generate_drafts(&store, &client_for(endpoint), "financial", 5, &tools()).await.unwrap();
+
+        let prompt = requests.recv().unwrap().body;
+        assert!(prompt.contains("financial__calculate_compound_interest"), "도구 카탈로그가 프롬프트에 포함");
+        assert!(prompt.contains("fin_001"), "기존 시나리오가 프롬프트에 포함");
+
+        let ids: Vec<&str> = outcome.drafts.iter().map(|d| d.scenario.id.as_str()).collect();
+        assert_eq!(ids, vec!["financial_gen_001", "fin_tx"], "기존 id 와 겹치면 새 id 로 바뀐다");
+        let first = &outcome.drafts[0];
+        assert_eq!(first.status, "pending");
+        assert_eq!(first.scenario.difficulty, "hard");
+        assert_eq!(first.golden.scenario_id, "financial_gen_001");
+        assert_eq!(first.golden.expected_output.tool_sequence, vec!["financial__calculate_compound_interest"]);
+        assert_eq!(first.golden.expected_output.expected_domain.as_deref(), Some("financial"));
+
+        let reasons: Vec<(&str, &str)> = outcome.skipped.iter().map(|s| (s.id.as_str(), s.reason.as_str())).collect();
+        assert!(reasons.iter().any(|(id, r)| *id == "dup" && r.contains("fin_001")));
+        assert!(reasons.iter().any(|(id, r)| *id == "bad_tool" && r.contains("financial__fx_rate")));
+
+        // 승인 전에는 시나리오로 보이지 않는다.
+        let domains = store.load_all_domains().await.unwrap();
+        assert_eq!(domains[0].scenarios.len(), 1);
+        assert_eq!(store.list_scenario_drafts(Some("financial"), Some("pending")).await.unwrap().len(), 2);
+    }
+
+    /// @trace TC: SPEC-042/TC-5
+    /// @trace FR: PRD-042/FR-1
+    #[tokio::test]
+    async fn spec042_tc_5_rejects_unknown_domain_and_bad_count() {
+        let store = store_with_scenario().await;
+        let llm = client_for("http://127.0.0.1:1".into());
+        let err = generate_drafts(&store, &llm, "nope", 3, &tools()).await.unwrap_err();
+        assert!(matches!(err, GenerateError::DomainNotFound(_)));
+        let err = generate_drafts(&store, &llm, "financial", MAX_COUNT + 1, &tools()).await.unwrap_err();
+        assert!(matches!(err, GenerateError::Invalid(_)));
+        let err = generate_drafts(&store, &llm, "financial", 3, &[]).await.unwrap_err();
+        assert!(matches!(err, GenerateError::Invalid(_)));
+    }
+}
@@ -0,0 +1,56 @@
+// =============================================================================
+// @trace SPEC-041
+// @trace PRD: PRD-041
+// @trace FR: PRD-041/FR-1, PRD-041/FR-4
+// @trace file-type: impl
+// =============================================================================
+//
+// TUI 목록을 SqliteStore 에서 읽는다. 도메인/시나리오는 설치된 로더,
+// 지난 평가는 `evaluations` 테이블, 에이전트는 웹과 같은 레지스트리에서 온다.
+
+use super::state::{ReportEntry,
+                   Target};
+use crate::web::{api::{build_agent_registry,
+                       list_agents_impl,
+                       load_report_by_name},
+                 db_query};
+use data_scenarios::loader::ScenarioLoader;
+use execution::models::EvaluationReport;
+use std::path::Path;
+
+pub fn load_targets() -> Vec<Target> {
+    let domains = ScenarioLoader::new().load_all_domains("").unwrap_or_default();
+    Target::from_domains(&domains)
+}
+
+pub fn load_agents() -> Vec<String> { list_agents_impl(&build_agent_registry()) }
+
+/// DB 평가 목록 (최신순). 스토어가 없으면 빈 목록.
+pub fn load_stored_reports() -> Vec<ReportEntry> { db_query::list_evaluations().unwrap_or_default().into_iter().map(ReportEntry::Stored).collect() }
+
+/// 목록 항목의 리포트를 읽는다. DB 평가는 1-시나리오 리포트로 변환된다.
+///
+/// @trace SPEC: SPEC-041
+/// @trace FR: PRD-041/FR-4
+pub fn load_report(entry: &ReportEntry, reports_dir: &Path) -> Result<EvaluationReport, String> {
+    match entry {
+        | ReportEntry::Session {
+            report, ..
+        } => Ok(report.clone()),
+        | ReportEntry::Stored(row) => {
+            let mut report = load_report_by_name(reports_dir, &db_query::evaluation_row_to_filename(row))?;
+            // 궤적 JSON 에는 도메인/시나리오가 없으므로 목록 행의 값으로
+            // 채운다.
+            for s in &mut report.scenarios {
+                if let Some(d) = &row.domain {
+                    s.domain = d.clone();
+                }
+                if let Some(id) = &row.scenario_id {
+                    s.scenario_id = id.clone();
+                }
+            }
+            report.agent_name = row.agent_name.clone();
+            Ok(report)
+        },
+    }
+}
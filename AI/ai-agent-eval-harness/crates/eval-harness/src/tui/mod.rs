@@ -1,10 +1,13 @@
 // =============================================================================
 // @trace SPEC-001
-// @trace PRD: PRD-001
-// @trace FR: FR-1, FR-2, FR-3, FR-4
+// @trace SPEC-041
+// @trace PRD: PRD-001, PRD-041
+// @trace FR: FR-1, FR-2, FR-3, FR-4, PRD-041/FR-1, PRD-041/FR-2, PRD-041/FR-3
 // @trace file-type: impl
 // =============================================================================
 
+pub mod catalog;
+pub mod run;
 pub mod state;
 pub mod view;
 
@@ -17,42 +20,160 @@ use crossterm::{event::{self,
                            enable_raw_mode}};
 use ratatui::{Terminal,
               backend::CrosstermBackend};
-use state::TuiState;
+use run::{RunHandle,
+          RunMessage};
+use state::{TuiAction,
+            TuiState};
 use std::{io,
           path::Path,
           time::Duration};
 
 /// 터미널을 raw 모드로 전환하고 TUI 메인 루프를 실행한다.
 ///
-/// @trace SPEC: SPEC-001
+/// 실행 중 러너·에이전트의 콘솔 출력이 화면을 깨뜨리지 않도록, TUI 가 떠
+/// 있는 동안 stdout/stderr 는 `<reports_dir>/tui.log` 로 돌린다 (unix).
+///
+/// @trace SPEC: SPEC-001, SPEC-041
 /// @trace TC: TC-1
-/// @trace FR: PRD-001/FR-1
+/// @trace FR: PRD-001/FR-1, PRD-041/FR-1
 pub fn run_tui(scenarios_dir: &Path, reports_dir: &Path) -> io::Result<()> {
+    let mut state = TuiState::new(catalog::load_targets(), catalog::load_agents(), catalog::load_stored_reports());
+
+    let (capture, mut out) = console::capture(&reports_dir.join("tui.log"))?;
     enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout);
+    execute!(out, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(out);
     let mut terminal = Terminal::new(backend)?;
 
-    let result = event_loop(&mut terminal, scenarios_dir, reports_dir);
+    let result = event_loop(&mut terminal, &mut state, scenarios_dir, reports_dir);
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
+    drop(capture);
     result
 }
 
-fn event_loop<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, scenarios_dir: &Path, reports_dir: &Path) -> io::Result<()> {
-    let mut state = TuiState::new(scenarios_dir, reports_dir)?;
+fn event_loop<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, state: &mut TuiState, scenarios_dir: &Path, reports_dir: &Path) -> io::Result<()> {
+    let mut active: Option<RunHandle> = None;
     loop {
-        terminal.draw(|f| view::draw(f, &state))?;
+        if let Some(handle) = &active {
+            let mut finished = false;
+            for message in handle.drain() {
+                finished |= matches!(message, RunMessage::Finished(_));
+                state.apply(message);
+            }
+            if finished {
+                active = None;
+                state.replace_stored(catalog::load_stored_reports());
+            }
+        }
+        terminal.draw(|f| view::draw(f, state))?;
         if event::poll(Duration::from_millis(200))? {
             if let Event::Key(key) = event::read()? {
-                state.handle_key(key.code);
+                match state.handle_key(key.code) {
+                    | Some(TuiAction::StartRun {
+                        domain,
+                        scenario_id,
+                        agent,
+                    }) => active = Some(run::spawn_run(domain, scenario_id, agent, scenarios_dir, reports_dir)),
+                    | Some(TuiAction::Approve(decision)) =>
+                        if let Some(handle) = &active {
+                            handle.approve(decision);
+                        },
+                    | Some(TuiAction::CancelRun) =>
+                        if let Some(handle) = &active {
+                            handle.cancel();
+                            state.status = "취소 요청됨 — 진행 중인 단계가 끝나면 멈춥니다".into();
+                        },
+                    | Some(TuiAction::OpenReport(idx)) => match catalog::load_report(&state.reports[idx], reports_dir) {
+                        | Ok(report) => state.open_report(state.reports[idx].label(), report),
+                        | Err(e) => state.status = e,
+                    },
+                    | Some(TuiAction::SetBaseline(idx)) => match catalog::load_report(&state.reports[idx], reports_dir) {
+                        | Ok(report) => state.set_baseline(state.reports[idx].label(), report),
+                        | Err(e) => state.status = e,
+                    },
+                    | None => {},
+                }
             }
         }
         if state.should_quit {
+            if let Some(handle) = &active {
+                handle.cancel();
+            }
             return Ok(());
         }
     }
 }
+
+#[cfg(unix)]
+mod console {
+    use std::{fs::{File,
+                   OpenOptions},
+              io::{self,
+                   Write},
+              os::fd::{AsRawFd,
+                       FromRawFd,
+                       RawFd},
+              path::Path};
+
+    /// drop 시 원래 stdout/stderr 를 되돌린다.
+    pub struct Capture {
+        saved: [(RawFd, RawFd); 2],
+    }
+
+    /// fd 1, 2 를 `log` 로 돌리고, 원래 터미널을 가리키는 출력을 돌려준다.
+    pub fn capture(log: &Path) -> io::Result<(Capture, Box<dyn Write>)> {
+        if let Some(parent) = log.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(log)?;
+        io::stdout().flush()?;
+        // SAFETY: `dup` 이 방금 만든 fd 로 이 File 만 소유한다.
+        let out = unsafe { File::from_raw_fd(dup(1)?) };
+        // 도중에 실패해도 drop 이 이미 바꾼 fd 를 되돌린다.
+        let capture = Capture {
+            saved: [(1, dup(1)?), (2, dup(2)?)],
+        };
+        for (fd, _) in capture.saved {
+            // SAFETY: 둘 다 열린 fd 이고, dup2 는 대상 fd 를 원자적으로
+            // 교체한다.
+            if unsafe { libc::dup2(file.as_raw_fd(), fd) } < 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok((capture, Box::new(out)))
+    }
+
+    fn dup(fd: RawFd) -> io::Result<RawFd> {
+        // SAFETY: 표준 fd 복제. 실패는 음수로 보고된다.
+        let new = unsafe { libc::dup(fd) };
+        if new < 0 { Err(io::Error::last_os_error()) } else { Ok(new) }
+    }
+
+    impl Drop for Capture {
+        fn drop(&mut self) {
+            io::stdout().flush().ok();
+            for (fd, saved) in self.saved {
+                // SAFETY: `saved` 는 capture 에서 복제해 이 구조체만 소유한 fd.
+                unsafe {
+                    libc::dup2(saved, fd);
+                    libc::close(saved);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(unix))]
+mod console {
+    use std::{io::{self,
+                   Write},
+              path::Path};
+
+    pub struct Capture;
+
+    /// unix 외 플랫폼은 출력을 돌리지 않는다.
+    pub fn capture(_log: &Path) -> io::Result<(Capture, Box<dyn Write>)> { Ok((Capture, Box::new(io::stdout()))) }
+}
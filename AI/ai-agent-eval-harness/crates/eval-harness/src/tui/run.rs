@@ -0,0 +1,140 @@
+// =============================================================================
+// @trace SPEC-041
+// @trace PRD: PRD-041
+// @trace FR: PRD-041/FR-2, PRD-041/FR-3
+// @trace file-type: impl
+// =============================================================================
+//
+// TUI 안에서 평가를 백그라운드 스레드로 실행한다. 러너의 진행 이벤트는
+// 채널로 이벤트 루프에 전달되고, 취소 플래그는 `ProgressSink::is_cancelled`
+// 로 러너에 전달된다.
+//
+// SPEC-044: 실행은 `interactive` 승인 정책을 쓴다. 위험 도구 호출은
+// `RunMessage::Approval` 로 화면에 묻고, `RunHandle::approve` 로 받은 결정이
+// 올 때까지 (또는 취소될 때까지) 실행 스레드가 기다린다.
+
+use crate::web::api::{apply_pricing,
+                      build_agent_registry};
+use agent_models::{approval::{ApprovalDecision,
+                              ApprovalPolicy,
+                              ApprovalRequest},
+                   progress::{ProgressEvent,
+                              ProgressSink}};
+use execution::{models::EvaluationReport,
+                runner::HarnessRunner};
+use std::{path::Path,
+          sync::{Arc,
+                 Mutex,
+                 atomic::{AtomicBool,
+                          Ordering},
+                 mpsc::{Receiver,
+                        RecvTimeoutError,
+                        Sender,
+                        channel}},
+          time::Duration};
+
+/// 실행 스레드 → 이벤트 루프 메시지.
+#[derive(Debug)]
+pub enum RunMessage {
+    Progress(ProgressEvent),
+    /// SPEC-044: 위험 도구 승인 요청. `RunHandle::approve` 로 답한다.
+    Approval(ApprovalRequest),
+    /// 리포트와 저장 경로, 또는 오류 (취소 포함).
+    Finished(Result<(EvaluationReport, String), String>),
+}
+
+pub struct RunHandle {
+    rx: Receiver<RunMessage>,
+    cancel: Arc<AtomicBool>,
+    decisions: Sender<ApprovalDecision>,
+}
+
+impl RunHandle {
+    pub fn cancel(&self) { self.cancel.store(true, Ordering::SeqCst); }
+
+    /// 기다리는 승인 요청에 답한다.
+    pub fn approve(&self, decision: ApprovalDecision) { self.decisions.send(decision).ok(); }
+
+    /// 지금까지 도착한 메시지를 모두 꺼낸다.
+    pub fn drain(&self) -> Vec<RunMessage> { self.rx.try_iter().collect() }
+}
+
+struct ChannelSink {
+    tx: Sender<RunMessage>,
+    cancel: Arc<AtomicBool>,
+    decisions: Mutex<Receiver<ApprovalDecision>>,
+}
+
+impl ProgressSink for ChannelSink {
+    fn emit(&self, event: ProgressEvent) { self.tx.send(RunMessage::Progress(event)).ok(); }
+
+    fn is_cancelled(&self) -> bool { self.cancel.load(Ordering::SeqCst) }
+
+    /// 화면에 묻고 답을 기다린다. 취소되거나 TUI 가 닫히면 거부.
+    ///
+    /// @trace SPEC: SPEC-044
+    /// @trace FR: PRD-044/FR-2
+    fn request_approval(&self, request: &ApprovalRequest) -> Option<ApprovalDecision> {
+        let decisions = self.decisions.lock().unwrap();
+        // 이전 요청에 늦게 도착한 답은 버린다.
+        while decisions.try_recv().is_ok() {}
+        self.tx.send(RunMessage::Approval(request.clone())).ok()?;
+        loop {
+            match decisions.recv_timeout(Duration::from_millis(200)) {
+                | Ok(decision) => return Some(decision),
+                | Err(RecvTimeoutError::Timeout) if !self.is_cancelled() => continue,
+                | Err(_) => return Some(ApprovalDecision::Denied),
+            }
+        }
+    }
+}
+
+/// `domain` (또는 그 안의 `scenario_id` 하나) 를 `agent` 로 실행하는 스레드를
+/// 띄운다. 끝난 리포트는 `reports_dir` 에 저장된다.
+///
+/// @trace SPEC: SPEC-041
+/// @trace FR: PRD-041/FR-2
+pub fn spawn_run(domain: String, scenario_id: Option<String>, agent: String, scenarios_dir: &Path, reports_dir: &Path) -> RunHandle {
+    let (tx, rx) = channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let (decisions, decisions_rx) = channel();
+    let sink = Arc::new(ChannelSink {
+        tx: tx.clone(),
+        cancel: cancel.clone(),
+        decisions: Mutex::new(decisions_rx),
+    });
+    let (scenarios_dir, reports_dir) = (scenarios_dir.to_path_buf(), reports_dir.to_path_buf());
+    std::thread::spawn(move || {
+        let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            execute(&domain, scenario_id, &agent, &scenarios_dir, &reports_dir, sink)
+        }))
+        .unwrap_or_else(|_| Err("실행 중 패닉".to_string()));
+        tx.send(RunMessage::Finished(outcome)).ok();
+    });
+    RunHandle {
+        rx,
+        cancel,
+        decisions,
+    }
+}
+
+fn execute(
+    domain: &str,
+    scenario_id: Option<String>,
+    agent_name: &str,
+    scenarios_dir: &Path,
+    reports_dir: &Path,
+    sink: Arc<ChannelSink>,
+) -> Result<(EvaluationReport, String), String> {
+    let registry = build_agent_registry();
+    let agent = registry.get_agent(agent_name).ok_or_else(|| format!("unknown agent: {agent_name}"))?;
+    let reports_str = reports_dir.to_str().ok_or("invalid reports_dir")?;
+    let scenarios_str = scenarios_dir.to_str().ok_or("invalid scenarios_dir")?;
+    let mut runner = apply_pricing(HarnessRunner::new(reports_str))
+        .with_progress(sink)
+        .with_approval(ApprovalPolicy::Interactive)
+        .with_scenario_ids(scenario_id.into_iter().collect());
+    let report = runner.run_eval_scenario(domain, agent, scenarios_str).map_err(|e| e.to_string())?;
+    let saved_to = runner.save_report(&report, None).map_err(|e| e.to_string())?;
+    Ok((report, saved_to.display().to_string()))
+}
@@ -1,14 +1,29 @@
 // =============================================================================
 // @trace SPEC-001
-// @trace PRD: PRD-001
-// @trace FR: FR-2, FR-3, FR-4
+// @trace SPEC-041
+// @trace PRD: PRD-001, PRD-041
+// @trace FR: FR-2, FR-3, FR-4, PRD-041/FR-1, PRD-041/FR-3, PRD-041/FR-4
 // @trace file-type: impl
 // =============================================================================
+//
+// TUI 상태. 키 입력과 실행 메시지를 상태에 반영만 하고, 실행 시작이나 DB
+// 조회 같은 부수 효과는 `TuiAction` 으로 이벤트 루프에 넘긴다.
 
+use super::run::RunMessage;
+use agent_models::{approval::{ApprovalDecision,
+                              ApprovalRequest},
+                   domain_config::DomainConfig,
+                   progress::ProgressEvent};
 use crossterm::event::KeyCode;
-use std::{fs,
-          io,
-          path::Path};
+use data_scenarios::sqlite_store::EvaluationListRow;
+use execution::{comparator::ReportComparator,
+                models::{ComparisonResult,
+                         EvaluationReport}};
+
+/// 실행 로그는 최근 이 줄 수까지만 보관한다.
+const MAX_LOG_LINES: usize = 1000;
+/// 베이스라인 비교의 회귀 임계값(%). CLI `compare` 기본값과 같다.
+const DIFF_THRESHOLD_PERCENT: f64 = 5.0;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Focus {
@@ -16,35 +31,137 @@ pub enum Focus {
     Reports,
 }
 
+/// 실행 대상. `scenario_id` 가 None 이면 도메인 전체.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target {
+    pub domain: String,
+    pub scenario_id: Option<String>,
+    pub label: String,
+}
+
+impl Target {
+    /// 도메인마다 "도메인 전체" 항목 뒤에 그 시나리오들을 나열한다.
+    ///
+    /// @trace SPEC: SPEC-041
+    /// @trace FR: PRD-041/FR-1
+    pub fn from_domains(domains: &[DomainConfig]) -> Vec<Self> {
+        let mut out = Vec::new();
+        for d in domains {
+            out.push(Self {
+                domain: d.name.clone(),
+                scenario_id: None,
+                label: format!("{} (all {})", d.name, d.scenarios.len()),
+            });
+            for s in &d.scenarios {
+                out.push(Self {
+                    domain: d.name.clone(),
+                    scenario_id: Some(s.id.clone()),
+                    label: format!("  {} — {}", s.id, s.name),
+                });
+            }
+        }
+        out
+    }
+}
+
+/// 리포트 목록 항목.
+#[derive(Debug, Clone)]
+pub enum ReportEntry {
+    /// 이 TUI 세션에서 실행한 집계 리포트.
+    Session { label: String, report: EvaluationReport },
+    /// DB `evaluations` 테이블의 단일 평가.
+    Stored(EvaluationListRow),
+}
+
+impl ReportEntry {
+    pub fn label(&self) -> String {
+        match self {
+            | Self::Session {
+                label, ..
+            } => label.clone(),
+            | Self::Stored(row) => format!(
+                "{} {} {}/{} {}",
+                row.created_at,
+                row.agent_name,
+                row.domain.as_deref().unwrap_or("-"),
+                row.scenario_id.as_deref().unwrap_or("-"),
+                if row.success { "✔" } else { "✘" }
+            ),
+        }
+    }
+}
+
+/// 열어 본 리포트와, 베이스라인이 있으면 그에 대한 비교 결과.
+#[derive(Debug)]
+pub struct OpenReport {
+    pub label: String,
+    pub report: EvaluationReport,
+    pub diff: Option<ComparisonResult>,
+}
+
+/// 이벤트 루프가 수행할 부수 효과.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TuiAction {
+    StartRun {
+        domain: String,
+        scenario_id: Option<String>,
+        agent: String,
+    },
+    CancelRun,
+    /// SPEC-044: 기다리는 승인 요청에 대한 결정.
+    Approve(ApprovalDecision),
+    OpenReport(usize),
+    SetBaseline(usize),
+}
+
 #[derive(Debug)]
 pub struct TuiState {
-    pub scenarios: Vec<String>,
-    pub reports: Vec<String>,
-    pub scenario_idx: usize,
+    pub targets: Vec<Target>,
+    pub agents: Vec<String>,
+    pub reports: Vec<ReportEntry>,
+    pub target_idx: usize,
     pub report_idx: usize,
+    pub agent_idx: usize,
     pub focus: Focus,
+    /// 진행 중인 실행의 라벨.
+    pub running: Option<String>,
+    /// 실시간 궤적 패널 (PPA 단계·도구 호출).
+    pub log: Vec<String>,
+    /// SPEC-044: 답을 기다리는 위험 도구 승인 요청.
+    pub pending_approval: Option<ApprovalRequest>,
+    pub opened: Option<OpenReport>,
+    pub baseline: Option<(String, EvaluationReport)>,
+    pub status: String,
     pub should_quit: bool,
 }
 
 impl TuiState {
-    /// 시나리오/리포트 디렉토리를 스캔해 초기 상태를 만든다.
+    /// 스토어에서 읽은 목록으로 초기 상태를 만든다.
     ///
-    /// @trace SPEC: SPEC-001
+    /// @trace SPEC: SPEC-001, SPEC-041
     /// @trace TC: TC-2, TC-4
-    /// @trace FR: PRD-001/FR-2, PRD-001/FR-3
-    pub fn new(scenarios_dir: &Path, reports_dir: &Path) -> io::Result<Self> {
-        let scenarios = load_files(scenarios_dir, "yaml").unwrap_or_default();
-        let reports = load_files(reports_dir, "json").unwrap_or_default();
-        Ok(Self {
-            scenarios,
+    /// @trace FR: PRD-001/FR-2, PRD-001/FR-3, PRD-041/FR-1
+    pub fn new(targets: Vec<Target>, agents: Vec<String>, reports: Vec<ReportEntry>) -> Self {
+        Self {
+            targets,
+            agents,
             reports,
-            scenario_idx: 0,
+            target_idx: 0,
             report_idx: 0,
+            agent_idx: 0,
             focus: Focus::Scenarios,
+            running: None,
+            log: Vec::new(),
+            pending_approval: None,
+            opened: None,
+            baseline: None,
+            status: String::new(),
             should_quit: false,
-        })
+        }
     }
 
+    pub fn agent(&self) -> Option<&str> { self.agents.get(self.agent_idx).map(String::as_str) }
+
     /// 포커스된 리스트에서 다음 항목으로 이동.
     ///
     /// @trace SPEC: SPEC-001
@@ -73,12 +190,39 @@ impl TuiState {
         self.set_focused_idx(new_idx);
     }
 
-    /// 키 이벤트를 상태에 반영한다.
+    /// 키 이벤트를 상태에 반영하고, 필요한 부수 효과를 돌려준다.
     ///
-    /// @trace SPEC: SPEC-001
+    /// @trace SPEC: SPEC-001, SPEC-041
     /// @trace TC: TC-5, TC-6
-    /// @trace FR: PRD-001/FR-3, PRD-001/FR-4
-    pub fn handle_key(&mut self, key: KeyCode) {
+    /// @trace FR: PRD-001/FR-3, PRD-001/FR-4, PRD-041/FR-2, PRD-041/FR-4
+    pub fn handle_key(&mut self, key: KeyCode) -> Option<TuiAction> {
+        // SPEC-044: 승인 요청이 떠 있으면 y/n 이 어느 화면에서나 우선한다.
+        if let Some(request) = &self.pending_approval {
+            let decision = match key {
+                | KeyCode::Char('y') => Some(ApprovalDecision::Approved),
+                | KeyCode::Char('n') => Some(ApprovalDecision::Denied),
+                | _ => None,
+            };
+            if let Some(decision) = decision {
+                self.push_log(format!("      ? {} → {decision:?}", request.tool_name));
+                self.pending_approval = None;
+                self.status.clear();
+                return Some(TuiAction::Approve(decision));
+            }
+        }
+        if let Some(opened) = &self.opened {
+            match key {
+                | KeyCode::Char('q') => self.should_quit = true,
+                | KeyCode::Esc | KeyCode::Backspace => self.opened = None,
+                | KeyCode::Char('b') => {
+                    let (label, report) = (opened.label.clone(), opened.report.clone());
+                    self.set_baseline(label, report);
+                },
+                | KeyCode::Char('c') if self.running.is_some() => return Some(TuiAction::CancelRun),
+                | _ => {},
+            }
+            return None;
+        }
         match key {
             | KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
             | KeyCode::Tab => {
@@ -89,69 +233,257 @@ impl TuiState {
             },
             | KeyCode::Down | KeyCode::Char('j') => self.next(),
             | KeyCode::Up | KeyCode::Char('k') => self.prev(),
+            | KeyCode::Char('a') if !self.agents.is_empty() => self.agent_idx = (self.agent_idx + 1) % self.agents.len(),
+            | KeyCode::Char('c') if self.running.is_some() => return Some(TuiAction::CancelRun),
+            | KeyCode::Enter | KeyCode::Char('r') if self.focus == Focus::Scenarios => return self.start_run(),
+            | KeyCode::Enter if !self.reports.is_empty() => return Some(TuiAction::OpenReport(self.report_idx)),
+            | KeyCode::Char('b') if self.focus == Focus::Reports && !self.reports.is_empty() => return Some(TuiAction::SetBaseline(self.report_idx)),
             | _ => {},
         }
+        None
+    }
+
+    fn start_run(&mut self) -> Option<TuiAction> {
+        if let Some(label) = &self.running {
+            self.status = format!("이미 실행 중: {label}");
+            return None;
+        }
+        let target = self.targets.get(self.target_idx)?.clone();
+        let Some(agent) = self.agent().map(str::to_string) else {
+            self.status = "등록된 에이전트가 없습니다".into();
+            return None;
+        };
+        let label = format!("{} ({})", target.label.trim(), agent);
+        self.log.clear();
+        self.log.push(format!("▶ {label}"));
+        self.status = format!("실행 중: {label}");
+        self.running = Some(label);
+        Some(TuiAction::StartRun {
+            domain: target.domain,
+            scenario_id: target.scenario_id,
+            agent,
+        })
+    }
+
+    /// 실행 스레드의 메시지를 반영한다. 끝난 실행의 리포트는 목록 맨 앞에
+    /// 추가하고 바로 연다.
+    ///
+    /// @trace SPEC: SPEC-041
+    /// @trace FR: PRD-041/FR-3
+    pub fn apply(&mut self, message: RunMessage) {
+        match message {
+            | RunMessage::Progress(event) => self.push_log(progress_line(&event)),
+            | RunMessage::Approval(request) => {
+                self.status = format!("승인 요청: {} — y: 승인 / n: 거부", request.tool_name);
+                self.pending_approval = Some(request);
+            },
+            | RunMessage::Finished(Ok((report, saved_to))) => {
+                let label = format!(
+                    "[session] {} {} {} {}/{}",
+                    report.timestamp, report.agent_name, report.eval_scenario, report.success_count, report.total_scenarios
+                );
+                self.push_log(format!("■ 완료: {}/{} 성공", report.success_count, report.total_scenarios));
+                self.running = None;
+                self.pending_approval = None;
+                self.status = format!("리포트 저장: {saved_to}");
+                self.reports.insert(
+                    0,
+                    ReportEntry::Session {
+                        label: label.clone(),
+                        report: report.clone(),
+                    },
+                );
+                self.report_idx = 0;
+                self.open_report(label, report);
+            },
+            | RunMessage::Finished(Err(e)) => {
+                self.push_log(format!("✘ {e}"));
+                self.running = None;
+                self.pending_approval = None;
+                self.status = e;
+            },
+        }
+    }
+
+    /// 리포트를 연다. 다른 리포트가 베이스라인이면 비교 결과를 함께 만든다.
+    ///
+    /// @trace SPEC: SPEC-041
+    /// @trace FR: PRD-041/FR-4
+    pub fn open_report(&mut self, label: String, report: EvaluationReport) {
+        let diff = self
+            .baseline
+            .as_ref()
+            .filter(|(base_label, _)| *base_label != label)
+            .map(|(_, base)| ReportComparator::new(DIFF_THRESHOLD_PERCENT).compare(base, &report));
+        self.opened = Some(OpenReport {
+            label,
+            report,
+            diff,
+        });
+    }
+
+    /// @trace SPEC: SPEC-041
+    /// @trace FR: PRD-041/FR-4
+    pub fn set_baseline(&mut self, label: String, report: EvaluationReport) {
+        self.status = format!("베이스라인: {label}");
+        self.baseline = Some((label, report));
+        if let Some(opened) = self.opened.take() {
+            self.open_report(opened.label, opened.report);
+        }
+    }
+
+    /// DB 목록을 새로 읽은 것으로 바꾼다. 세션 리포트는 맨 앞에 유지한다.
+    pub fn replace_stored(&mut self, stored: Vec<ReportEntry>) {
+        self.reports.retain(|r| matches!(r, ReportEntry::Session { .. }));
+        self.reports.extend(stored);
+        self.report_idx = self.report_idx.min(self.reports.len().saturating_sub(1));
+    }
+
+    fn push_log(&mut self, line: String) {
+        self.log.push(line);
+        if self.log.len() > MAX_LOG_LINES {
+            self.log.drain(.. self.log.len() - MAX_LOG_LINES);
+        }
     }
 
     fn focused_list(&self) -> (usize, usize) {
         match self.focus {
-            | Focus::Scenarios => (self.scenario_idx, self.scenarios.len()),
+            | Focus::Scenarios => (self.target_idx, self.targets.len()),
             | Focus::Reports => (self.report_idx, self.reports.len()),
         }
     }
 
     fn set_focused_idx(&mut self, idx: usize) {
         match self.focus {
-            | Focus::Scenarios => self.scenario_idx = idx,
+            | Focus::Scenarios => self.target_idx = idx,
             | Focus::Reports => self.report_idx = idx,
         }
     }
 }
 
-fn load_files(dir: &Path, ext: &str) -> io::Result<Vec<String>> {
-    let mut out = Vec::new();
-    if !dir.exists() {
-        return Ok(out);
-    }
-    for entry in fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.extension().and_then(|e| e.to_str()) == Some(ext) {
-            if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                out.push(name.to_string());
-            }
-        }
+/// 진행 이벤트 한 건을 궤적 패널의 한 줄로.
+fn progress_line(event: &ProgressEvent) -> String {
+    match event {
+        | ProgressEvent::ScenarioStarted {
+            index,
+            total,
+            domain,
+            scenario_id,
+            name,
+        } => format!("[{}/{}] {}/{} — {}", index + 1, total, domain, scenario_id, name),
+        | ProgressEvent::ScenarioFinished {
+            index,
+            total,
+            scenario_id,
+            success,
+            duration_ms,
+            ..
+        } => format!(
+            "[{}/{}] {} {} ({:.1}s)",
+            index + 1,
+            total,
+            if *success { "✔" } else { "✘" },
+            scenario_id,
+            duration_ms / 1000.0
+        ),
+        | ProgressEvent::Step {
+            stage,
+            iteration,
+            duration_ms,
+            ..
+        } => match duration_ms {
+            | Some(ms) => format!("    #{iteration} {stage:?} {ms:.0}ms"),
+            | None => format!("    #{iteration} {stage:?}"),
+        },
+        | ProgressEvent::ToolCall {
+            call, ..
+        } => {
+            let outcome = match &call.approval {
+                | Some(record) if record.decision == ApprovalDecision::Denied => "denied",
+                | _ if call.success => "ok",
+                | _ => "fail",
+            };
+            format!("      → {} {}", call.tool_name, outcome)
+        },
     }
-    out.sort();
-    Ok(out)
 }
 
 #[cfg(test)]
 mod tests {
     // =============================================================================
     // @trace SPEC-001
-    // @trace PRD: PRD-001
-    // @trace FR: FR-2, FR-3, FR-4
+    // @trace SPEC-041
+    // @trace PRD: PRD-001, PRD-041
+    // @trace FR: FR-2, FR-3, FR-4, PRD-041/FR-1, PRD-041/FR-3, PRD-041/FR-4
     // @trace file-type: test
     // =============================================================================
 
     use super::*;
-    use std::{fs::File,
-              io::Write};
-    use tempfile::tempdir;
+    use agent_models::models::{PpaStage,
+                               ToolCall};
+    use std::collections::HashMap;
+
+    fn domain(name: &str, ids: &[&str]) -> DomainConfig {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "description": "",
+            "tools": [],
+            "scenarios": ids.iter().map(|id| serde_json::json!({
+                "id": id,
+                "name": format!("{id} name"),
+                "description": "",
+                "task_description": "t",
+                "initial_environment": {},
+                "expected_tools": [],
+                "success_criteria": {},
+                "difficulty": "easy",
+            })).collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    fn sample_state() -> TuiState {
+        TuiState::new(
+            Target::from_domains(&[domain("cs", &["cs_001", "cs_002"]), domain("fin", &["fin_001"])]),
+            vec!["passthrough".into(), "ppa".into()],
+            Vec::new(),
+        )
+    }
+
+    fn report(success_rate: f64) -> EvaluationReport {
+        EvaluationReport {
+            version: "1.0".into(),
+            timestamp: "20260101_000000".into(),
+            agent_name: "passthrough".into(),
+            eval_scenario: "cs".into(),
+            total_scenarios: 1,
+            success_count: 1,
+            success_rate,
+            average_metrics: HashMap::from([("overall_score".to_string(), success_rate)]),
+            scenarios: Vec::new(),
+            trials: 1,
+            pass_at_k: Default::default(),
+        }
+    }
 
     /// @trace TC: SPEC-001/TC-2
-    /// @trace FR: PRD-001/FR-2
-    /// @trace scenario: 시나리오 목록 로딩
+    /// @trace FR: PRD-001/FR-2, PRD-041/FR-1
+    /// @trace scenario: 도메인/시나리오 목록 구성
     #[test]
     fn test_tc_2_load_scenarios() {
-        let scen = tempdir().unwrap();
-        let reps = tempdir().unwrap();
-        File::create(scen.path().join("cs.yaml")).unwrap().write_all(b"x").unwrap();
-        File::create(scen.path().join("fin.yaml")).unwrap().write_all(b"x").unwrap();
-        let state = TuiState::new(scen.path(), reps.path()).unwrap();
-        assert_eq!(state.scenarios.len(), 2);
-        assert!(state.scenarios.contains(&"cs.yaml".to_string()));
+        let state = sample_state();
+        let labels: Vec<(&str, Option<&str>)> = state.targets.iter().map(|t| (t.domain.as_str(), t.scenario_id.as_deref())).collect();
+        assert_eq!(
+            labels,
+            vec![
+                ("cs", None),
+                ("cs", Some("cs_001")),
+                ("cs", Some("cs_002")),
+                ("fin", None),
+                ("fin", Some("fin_001"))
+            ]
+        );
+        assert_eq!(state.targets[0].label, "cs (all 2)");
     }
 
     /// @trace TC: SPEC-001/TC-3
@@ -159,34 +491,34 @@ mod tests {
     /// @trace scenario: 선택 인덱스 이동
     #[test]
     fn test_tc_3_next_prev_moves_index() {
-        let scen = tempdir().unwrap();
-        let reps = tempdir().unwrap();
-        for n in ["a.yaml", "b.yaml", "c.yaml"] {
-            File::create(scen.path().join(n)).unwrap().write_all(b"x").unwrap();
-        }
-        let mut state = TuiState::new(scen.path(), reps.path()).unwrap();
-        assert_eq!(state.scenario_idx, 0);
+        let mut state = TuiState::new(Target::from_domains(&[domain("cs", &["a", "b"])]), Vec::new(), Vec::new());
+        assert_eq!(state.target_idx, 0);
         state.next();
-        assert_eq!(state.scenario_idx, 1);
+        assert_eq!(state.target_idx, 1);
         state.next();
-        assert_eq!(state.scenario_idx, 2);
+        assert_eq!(state.target_idx, 2);
         state.next();
-        assert_eq!(state.scenario_idx, 0, "wraps around");
+        assert_eq!(state.target_idx, 0, "wraps around");
         state.prev();
-        assert_eq!(state.scenario_idx, 2);
+        assert_eq!(state.target_idx, 2);
     }
 
     /// @trace TC: SPEC-001/TC-4
     /// @trace FR: PRD-001/FR-3
-    /// @trace scenario: 리포트 파일 목록 로딩
+    /// @trace scenario: DB 평가 목록 표시
     #[test]
     fn test_tc_4_load_reports() {
-        let scen = tempdir().unwrap();
-        let reps = tempdir().unwrap();
-        File::create(reps.path().join("r1.json")).unwrap().write_all(b"{}").unwrap();
-        File::create(reps.path().join("ignore.txt")).unwrap().write_all(b"x").unwrap();
-        let state = TuiState::new(scen.path(), reps.path()).unwrap();
-        assert_eq!(state.reports, vec!["r1.json".to_string()]);
+        let row = EvaluationListRow {
+            task_id: "t1".into(),
+            created_at: "2026-01-01 00:00:00".into(),
+            agent_name: "ppa".into(),
+            domain: Some("cs".into()),
+            scenario_id: Some("cs_001".into()),
+            success: true,
+            overall_score: Some(0.9),
+        };
+        let state = TuiState::new(Vec::new(), Vec::new(), vec![ReportEntry::Stored(row)]);
+        assert_eq!(state.reports[0].label(), "2026-01-01 00:00:00 ppa cs/cs_001 ✔");
     }
 
     /// @trace TC: SPEC-001/TC-5
@@ -194,9 +526,7 @@ mod tests {
     /// @trace scenario: Tab으로 포커스 전환
     #[test]
     fn test_tc_5_tab_toggles_focus() {
-        let scen = tempdir().unwrap();
-        let reps = tempdir().unwrap();
-        let mut state = TuiState::new(scen.path(), reps.path()).unwrap();
+        let mut state = sample_state();
         assert_eq!(state.focus, Focus::Scenarios);
         state.handle_key(KeyCode::Tab);
         assert_eq!(state.focus, Focus::Reports);
@@ -209,15 +539,119 @@ mod tests {
     /// @trace scenario: q/Esc 입력 시 should_quit=true
     #[test]
     fn test_tc_6_quit_keys_set_should_quit() {
-        let scen = tempdir().unwrap();
-        let reps = tempdir().unwrap();
-        let mut state = TuiState::new(scen.path(), reps.path()).unwrap();
+        let mut state = sample_state();
         assert!(!state.should_quit);
         state.handle_key(KeyCode::Char('q'));
         assert!(state.should_quit);
 
-        let mut state2 = TuiState::new(scen.path(), reps.path()).unwrap();
+        let mut state2 = sample_state();
         state2.handle_key(KeyCode::Esc);
         assert!(state2.should_quit);
     }
+
+    /// @trace TC: SPEC-041/TC-2
+    /// @trace FR: PRD-041/FR-2
+    #[test]
+    fn spec041_tc_2_enter_starts_run_for_selected_target_and_agent() {
+        let mut state = sample_state();
+        state.next();
+        state.handle_key(KeyCode::Char('a'));
+        let action = state.handle_key(KeyCode::Enter);
+        assert_eq!(
+            action,
+            Some(TuiAction::StartRun {
+                domain: "cs".into(),
+                scenario_id: Some("cs_001".into()),
+                agent: "ppa".into(),
+            })
+        );
+        assert!(state.running.is_some());
+        assert_eq!(state.handle_key(KeyCode::Enter), None, "실행 중에는 새 실행을 시작하지 않는다");
+        assert_eq!(state.handle_key(KeyCode::Char('c')), Some(TuiAction::CancelRun));
+    }
+
+    /// @trace TC: SPEC-041/TC-3
+    /// @trace FR: PRD-041/FR-3
+    #[test]
+    fn spec041_tc_3_progress_messages_fill_log_and_finished_run_opens_report() {
+        let mut state = sample_state();
+        state.handle_key(KeyCode::Enter);
+        state.apply(RunMessage::Progress(ProgressEvent::ScenarioStarted {
+            index: 0,
+            total: 2,
+            domain: "cs".into(),
+            scenario_id: "cs_001".into(),
+            name: "n".into(),
+        }));
+        state.apply(RunMessage::Progress(ProgressEvent::Step {
+            task_id: "t".into(),
+            stage: PpaStage::Policy,
+            iteration: 1,
+            duration_ms: Some(12.0),
+        }));
+        state.apply(RunMessage::Progress(ProgressEvent::ToolCall {
+            task_id: "t".into(),
+            iteration: 1,
+            call: ToolCall::new("lookup".into(), HashMap::new()),
+        }));
+        assert_eq!(state.log[1 ..], ["[1/2] cs/cs_001 — n", "    #1 Policy 12ms", "      → lookup ok"]);
+
+        state.apply(RunMessage::Finished(Ok((report(1.0), "out.json".into()))));
+        assert!(state.running.is_none());
+        assert!(matches!(state.reports[0], ReportEntry::Session { .. }));
+        assert_eq!(state.opened.as_ref().unwrap().report.success_rate, 1.0);
+        assert!(state.opened.as_ref().unwrap().diff.is_none(), "베이스라인 없음");
+    }
+
+    /// @trace TC: SPEC-041/TC-4
+    /// @trace FR: PRD-041/FR-4
+    #[test]
+    fn spec041_tc_4_opened_report_is_diffed_against_baseline() {
+        let mut state = sample_state();
+        state.set_baseline("base".into(), report(1.0));
+        state.open_report("cur".into(), report(0.5));
+        let diff = state.opened.as_ref().unwrap().diff.as_ref().unwrap();
+        let overall = diff.metric_deltas.iter().find(|d| d.metric_name == "overall_score").unwrap();
+        assert_eq!((overall.baseline_value, overall.current_value), (Some(1.0), Some(0.5)));
+        assert!(overall.is_regression);
+
+        // 열린 리포트를 베이스라인으로 삼으면 자기 자신과는 비교하지 않는다.
+        state.handle_key(KeyCode::Char('b'));
+        assert!(state.opened.as_ref().unwrap().diff.is_none());
+        state.handle_key(KeyCode::Esc);
+        assert!(state.opened.is_none() && !state.should_quit);
+    }
+
+    /// @trace TC: SPEC-044/TC-5
+    /// @trace FR: PRD-044/FR-2
+    #[test]
+    fn spec044_tc_5_approval_prompt_answers_with_y_or_n() {
+        let mut state = sample_state();
+        state.handle_key(KeyCode::Enter);
+        state.apply(RunMessage::Approval(ApprovalRequest {
+            task_id: "t".into(),
+            iteration: 1,
+            tool_name: "write_file".into(),
+            parameters: HashMap::new(),
+        }));
+        assert!(state.status.contains("write_file"));
+        // 다른 키는 승인 요청을 닫지 않는다.
+        assert_eq!(state.handle_key(KeyCode::Down), None);
+        assert!(state.pending_approval.is_some());
+        assert_eq!(state.handle_key(KeyCode::Char('n')), Some(TuiAction::Approve(ApprovalDecision::Denied)));
+        assert!(state.pending_approval.is_none());
+        assert_eq!(state.handle_key(KeyCode::Char('n')), None, "요청이 없으면 n 은 무시");
+
+        let mut denied = ToolCall::new("write_file".into(), HashMap::new());
+        denied.approval = Some(agent_models::approval::ApprovalRecord {
+            decision: ApprovalDecision::Denied,
+            policy: "interactive".into(),
+        });
+        state.apply(RunMessage::Progress(ProgressEvent::ToolCall {
+            task_id: "t".into(),
+            iteration: 1,
+            call: denied,
+        }));
+        assert_eq!(state.log.last().map(String::as_str), Some("      → write_file denied"));
+    }
 }
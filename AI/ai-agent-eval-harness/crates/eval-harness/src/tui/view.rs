@@ -1,16 +1,19 @@
 // =============================================================================
 // @trace SPEC-001
-// @trace PRD: PRD-001
-// @trace FR: FR-2, FR-3
+// @trace SPEC-041
+// @trace PRD: PRD-001, PRD-041
+// @trace FR: FR-2, FR-3, PRD-041/FR-3, PRD-041/FR-4
 // @trace file-type: impl
 // =============================================================================
 
 use super::state::{Focus,
+                   OpenReport,
                    TuiState};
 use ratatui::{Frame,
               layout::{Constraint,
                        Direction,
-                       Layout},
+                       Layout,
+                       Rect},
               style::{Color,
                       Modifier,
                       Style},
@@ -20,31 +23,174 @@ use ratatui::{Frame,
                         List,
                         ListItem,
                         ListState,
-                        Paragraph}};
+                        Paragraph,
+                        Row,
+                        Table}};
 
-/// TUI 화면을 렌더링한다.
+/// TUI 화면을 렌더링한다. 리포트가 열려 있으면 리포트 화면을 그린다.
 ///
-/// @trace SPEC: SPEC-001
+/// @trace SPEC: SPEC-001, SPEC-041
 /// @trace TC: -
-/// @trace FR: PRD-001/FR-2, PRD-001/FR-3
+/// @trace FR: PRD-001/FR-2, PRD-001/FR-3, PRD-041/FR-3
 pub fn draw(f: &mut Frame, state: &TuiState) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(5), Constraint::Length(3)])
+        .constraints([Constraint::Length(3), Constraint::Min(5), Constraint::Length(3)])
         .split(f.area());
 
+    let run = state.running.as_deref().map(|l| format!("running: {l}")).unwrap_or_else(|| "idle".into());
+    let baseline = state.baseline.as_ref().map(|(l, _)| l.as_str()).unwrap_or("-");
+    let header = format!("Agent: {}  |  {}  |  Baseline: {}", state.agent().unwrap_or("-"), run, baseline);
+    f.render_widget(
+        Paragraph::new(Line::from(header)).block(Block::default().borders(Borders::ALL).title("eval-harness")),
+        chunks[0],
+    );
+
+    let mut help = match &state.opened {
+        | Some(opened) => {
+            draw_report(f, chunks[1], opened);
+            "b: set as baseline  |  c: cancel run  |  Esc: back  |  q: quit"
+        },
+        | None => {
+            draw_browse(f, chunks[1], state);
+            "↑/↓ or j/k: move  |  Tab: switch panel  |  Enter: run / open  |  a: agent  |  b: baseline  |  c: cancel  |  q/Esc: quit"
+        },
+    };
+    // SPEC-044: 승인 요청이 떠 있는 동안은 y/n 안내가 우선한다.
+    if state.pending_approval.is_some() {
+        help = "y: approve tool call  |  n: deny  |  c: cancel run";
+    }
+    let footer = if state.status.is_empty() {
+        help.to_string()
+    } else {
+        format!("{}  —  {}", state.status, help)
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(footer)).block(Block::default().borders(Borders::ALL).title("Help")),
+        chunks[2],
+    );
+}
+
+fn draw_browse(f: &mut Frame, area: Rect, state: &TuiState) {
     let body = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(chunks[0]);
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(30), Constraint::Percentage(40)])
+        .split(area);
 
-    render_list(f, body[0], "Scenarios", &state.scenarios, state.scenario_idx, state.focus == Focus::Scenarios);
-    render_list(f, body[1], "Reports", &state.reports, state.report_idx, state.focus == Focus::Reports);
+    let targets: Vec<String> = state.targets.iter().map(|t| t.label.clone()).collect();
+    let reports: Vec<String> = state.reports.iter().map(|r| r.label()).collect();
+    render_list(f, body[0], "Scenarios", &targets, state.target_idx, state.focus == Focus::Scenarios);
+    render_list(f, body[1], "Reports", &reports, state.report_idx, state.focus == Focus::Reports);
 
-    let help = Paragraph::new(Line::from("↑/↓ or j/k: move  |  Tab: switch panel  |  q/Esc: quit")).block(Block::default().borders(Borders::ALL).title("Help"));
-    f.render_widget(help, chunks[1]);
+    // 최근 줄이 보이도록 패널 높이만큼 끝에서 자른다.
+    let height = body[2].height.saturating_sub(2) as usize;
+    let lines: Vec<Line> = state.log[state.log.len().saturating_sub(height) ..]
+        .iter()
+        .map(|l| Line::from(l.as_str()))
+        .collect();
+    f.render_widget(Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("Trajectory")), body[2]);
 }
 
+/// 요약, 메트릭 표(베이스라인이 있으면 비교 열 포함), 시나리오 표.
+///
+/// @trace SPEC: SPEC-041
+/// @trace FR: PRD-041/FR-4
+fn draw_report(f: &mut Frame, area: Rect, opened: &OpenReport) {
+    let report = &opened.report;
+    let scenario_rows = report.scenarios.len().min(10) as u16 + 3;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(5), Constraint::Length(scenario_rows)])
+        .split(area);
+
+    let mut summary = format!(
+        "{} · {} · success {}/{} ({:.1}%)",
+        report.agent_name,
+        report.eval_scenario,
+        report.success_count,
+        report.total_scenarios,
+        report.success_rate * 100.0
+    );
+    if report.trials > 1 {
+        for (k, v) in &report.pass_at_k {
+            summary.push_str(&format!(" · pass@{k} {:.1}%", v * 100.0));
+        }
+    }
+    if let Some(diff) = &opened.diff {
+        summary.push_str(&format!(" · vs baseline: {} ({} regressions)", diff.verdict, diff.regression_count));
+    }
+    f.render_widget(
+        Paragraph::new(Line::from(summary)).block(Block::default().borders(Borders::ALL).title(opened.label.as_str())),
+        chunks[0],
+    );
+
+    let header_style = Style::default().add_modifier(Modifier::BOLD);
+    let metrics = match &opened.diff {
+        | Some(diff) => {
+            let rows = diff.metric_deltas.iter().map(|d| {
+                let style = if d.is_regression {
+                    Style::default().fg(Color::Red)
+                } else if d.direction == "improved" {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default()
+                };
+                Row::new(vec![
+                    d.metric_name.clone(),
+                    fmt_value(d.current_value),
+                    fmt_value(d.baseline_value),
+                    d.delta_percent.map(|p| format!("{p:+.1}%")).unwrap_or_else(|| "-".into()),
+                    if d.is_regression { "REGRESSION".into() } else { d.direction.clone() },
+                ])
+                .style(style)
+            });
+            Table::new(
+                rows,
+                [
+                    Constraint::Percentage(34),
+                    Constraint::Percentage(16),
+                    Constraint::Percentage(16),
+                    Constraint::Percentage(14),
+                    Constraint::Percentage(20),
+                ],
+            )
+            .header(Row::new(vec!["Metric", "Current", "Baseline", "Δ%", "Verdict"]).style(header_style))
+        },
+        | None => {
+            let mut names: Vec<&String> = report.average_metrics.keys().collect();
+            names.sort();
+            let rows = names
+                .into_iter()
+                .map(|k| Row::new(vec![k.clone(), fmt_value(report.average_metrics.get(k).copied())]));
+            Table::new(rows, [Constraint::Percentage(60), Constraint::Percentage(40)]).header(Row::new(vec!["Metric", "Value"]).style(header_style))
+        },
+    };
+    f.render_widget(metrics.block(Block::default().borders(Borders::ALL).title("Metrics")), chunks[1]);
+
+    let rows = report.scenarios.iter().map(|s| {
+        Row::new(vec![
+            format!("{}/{}", s.domain, s.scenario_id),
+            s.trial.to_string(),
+            if s.success { "✔".into() } else { "✘".into() },
+            s.total_iterations.to_string(),
+        ])
+    });
+    let scenarios = Table::new(
+        rows,
+        [
+            Constraint::Percentage(55),
+            Constraint::Percentage(15),
+            Constraint::Percentage(15),
+            Constraint::Percentage(15),
+        ],
+    )
+    .header(Row::new(vec!["Scenario", "Trial", "Success", "Iterations"]).style(header_style))
+    .block(Block::default().borders(Borders::ALL).title("Scenarios"));
+    f.render_widget(scenarios, chunks[2]);
+}
+
+fn fmt_value(v: Option<f64>) -> String { v.map(|x| format!("{x:.4}")).unwrap_or_else(|| "-".into()) }
+
 fn render_list(f: &mut Frame, area: ratatui::layout::Rect, title: &str, items: &[String], selected: usize, focused: bool) {
     let list_items: Vec<ListItem> = items.iter().map(|s| ListItem::new(s.as_str())).collect();
     let border_style = if focused { Style::default().fg(Color::Yellow) } else { Style::default() };
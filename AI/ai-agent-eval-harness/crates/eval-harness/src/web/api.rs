@@ -8,7 +8,9 @@
 use super::{AppState,
             handlers::{DomainSummary,
                        is_safe_name}};
-use agent_models::domain_config::ScenarioConfig;
+use agent_models::{approval::ApprovalPolicy,
+                   domain_config::ScenarioConfig,
+                   progress::ProgressSink};
 use axum::{extract::{Path as AxPath,
                      State},
            http::StatusCode,
@@ -42,20 +44,60 @@ pub fn build_agent_registry() -> AgentRegistry {
     let mut registry = AgentRegistry::new();
     registry.register("passthrough", Arc::new(PassthroughAgent));
     dotenvy::dotenv().ok();
-    if let Ok(llm_config) = agent_core::config::AzureOpenAiConfig::from_env() {
-        let base = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
-        let eval_config = match crate::data_paths::load_evaluation_config(&base) {
-            | Ok(cfg) => cfg,
-            | Err(e) => {
-                eprintln!("[warn] eval-harness.toml [evaluation] 파싱 실패: {e} — 기본값 사용");
-                agent_core::config::EvaluationConfig::default()
-            },
+    let base = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
+    let eval_config = match crate::data_paths::load_evaluation_config(&base) {
+        | Ok(cfg) => cfg,
+        | Err(e) => {
+            eprintln!("[warn] eval-harness.toml [evaluation] 파싱 실패: {e} — 기본값 사용");
+            agent_core::config::EvaluationConfig::default()
+        },
+    };
+    register_ppa_agents(&mut registry, &eval_config);
+    agent_core::domain_router::install_embedder_from_env();
+    registry
+}
+
+/// SPEC-035: CWD 의 `eval-harness.toml` `[pricing]` 단가표를 러너에 붙인다.
+/// 섹션이 없거나 잘못되었으면 비용 없이 그대로 반환한다.
+///
+/// @trace SPEC: SPEC-035
+/// @trace FR: PRD-035/FR-2
+pub fn apply_pricing(runner: HarnessRunner) -> HarnessRunner {
+    let base = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
+    match crate::data_paths::load_price_table(&base) {
+        | Ok(Some(table)) => runner.with_pricing(table),
+        | Ok(None) => runner,
+        | Err(e) => {
+            eprintln!("[warn] eval-harness.toml [pricing] 파싱 실패: {e} — 비용 미계산");
+            runner
+        },
+    }
+}
+
+/// 환경변수 설정이 있는 LLM provider 마다 `ppa-<provider>` 에이전트를,
+/// `LLM_PROVIDER` 로 지정된 기본 provider 로 `ppa` 에이전트를 등록한다.
+/// 등록된 이름 목록을 반환한다.
+///
+/// @trace SPEC: SPEC-033
+/// @trace FR: PRD-033/FR-2
+pub fn register_ppa_agents(registry: &mut AgentRegistry, eval_config: &agent_core::config::EvaluationConfig) -> Vec<String> {
+    let default_provider = agent_core::config::LlmProvider::from_env();
+    let mut names = Vec::new();
+    for provider in agent_core::config::LlmProvider::ALL {
+        let Ok(llm_config) = agent_core::config::LlmConfig::from_env(provider) else {
+            continue;
         };
+        if provider == default_provider {
+            let llm = agent_core::llm_client::LlmClient::new(llm_config.clone());
+            registry.register("ppa", Arc::new(agent_core::agent::PpaAgent::new(llm, eval_config.clone())));
+            names.push("ppa".to_string());
+        }
+        let name = format!("ppa-{}", provider.as_str());
         let llm = agent_core::llm_client::LlmClient::new(llm_config);
-        let agent = agent_core::agent::PpaAgent::new(llm, eval_config);
-        registry.register("ppa", Arc::new(agent));
+        registry.register(&name, Arc::new(agent_core::agent::PpaAgent::new(llm, eval_config.clone())));
+        names.push(name);
     }
-    registry
+    names
 }
 
 // --------------------------------------------------------------------------
@@ -110,7 +152,43 @@ pub fn scenario_detail_impl(dir: &Path, domain: &str, id: &str) -> Option<Scenar
 /// @trace FR: PRD-003/FR-5
 #[cfg(test)]
 pub fn run_eval_scenario_impl(eval_scenario: &str, agent_name: &str, scenarios_dir: &Path, reports_dir: &Path) -> Result<EvaluationReport, String> {
-    run_eval_scenario_with_save_impl(eval_scenario, agent_name, scenarios_dir, reports_dir, None).map(|(r, _)| r)
+    run_eval_scenario_with_save_impl(eval_scenario, agent_name, scenarios_dir, reports_dir, None, &RunOptions::default()).map(|(r, _)| r)
+}
+
+/// `/api/run` 실행 옵션. 생략 시 순차 실행, 타임아웃 없음.
+///
+/// @trace SPEC: SPEC-028
+/// @trace FR: PRD-028/FR-4
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RunOptions {
+    #[serde(default)]
+    pub concurrency: Option<usize>,
+    #[serde(default)]
+    pub scenario_timeout_secs: Option<u64>,
+    /// SPEC-038: 시나리오당 반복 시행 수
+    #[serde(default)]
+    pub trials: Option<usize>,
+    /// SPEC-044: 위험 도구 기본 승인 정책 (시나리오 정책이 우선)
+    #[serde(default)]
+    pub approval: Option<ApprovalPolicy>,
+}
+
+impl RunOptions {
+    fn apply(&self, mut runner: HarnessRunner) -> HarnessRunner {
+        if let Some(n) = self.concurrency {
+            runner = runner.with_concurrency(n);
+        }
+        if let Some(secs) = self.scenario_timeout_secs {
+            runner = runner.with_scenario_timeout(std::time::Duration::from_secs(secs));
+        }
+        if let Some(n) = self.trials {
+            runner = runner.with_trials(n);
+        }
+        if let Some(policy) = &self.approval {
+            runner = runner.with_approval(policy.clone());
+        }
+        runner
+    }
 }
 
 /// SPEC-005: 실행 후 aggregate report를 디스크에 저장한다.
@@ -125,6 +203,24 @@ pub fn run_eval_scenario_with_save_impl(
     scenarios_dir: &Path,
     reports_dir: &Path,
     output: Option<&str>,
+    options: &RunOptions,
+) -> Result<(EvaluationReport, String), String> {
+    run_eval_scenario_with_progress_impl(eval_scenario, agent_name, scenarios_dir, reports_dir, output, options, None)
+}
+
+/// SPEC-040: `progress` 가 있으면 러너의 진행 이벤트를 보내고 취소 요청을
+/// 따른다. 취소되면 리포트를 저장하지 않고 Err.
+///
+/// @trace SPEC: SPEC-040
+/// @trace FR: PRD-040/FR-1
+pub fn run_eval_scenario_with_progress_impl(
+    eval_scenario: &str,
+    agent_name: &str,
+    scenarios_dir: &Path,
+    reports_dir: &Path,
+    output: Option<&str>,
+    options: &RunOptions,
+    progress: Option<Arc<dyn ProgressSink>>,
 ) -> Result<(EvaluationReport, String), String> {
     if !is_safe_name(eval_scenario) || !is_safe_name(agent_name) {
         return Err("invalid eval_scenario/agent name".into());
@@ -138,10 +234,11 @@ pub fn run_eval_scenario_with_save_impl(
     let agent = registry.get_agent(agent_name).ok_or_else(|| format!("unknown agent: {}", agent_name))?;
     let reports_str = reports_dir.to_str().ok_or_else(|| "invalid reports_dir".to_string())?;
     let scenarios_str = scenarios_dir.to_str().ok_or_else(|| "invalid scenarios_dir".to_string())?;
-    let mut runner = HarnessRunner::new(reports_str);
-    let report = runner
-        .run_eval_scenario(eval_scenario, agent.as_ref(), scenarios_str)
-        .map_err(|e| e.to_string())?;
+    let mut runner = options.apply(apply_pricing(HarnessRunner::new(reports_str)));
+    if let Some(sink) = progress {
+        runner = runner.with_progress(sink);
+    }
+    let report = runner.run_eval_scenario(eval_scenario, agent, scenarios_str).map_err(|e| e.to_string())?;
     let save_path: PathBuf = match output {
         | Some(n) => reports_dir.join(n),
         | None => reports_dir.join(format!("evaluation_report_{}.json", report.timestamp)),
@@ -207,7 +304,7 @@ pub fn compare_with_save_impl(
 ///
 /// @trace SPEC: SPEC-021
 /// @trace FR: PRD-021/FR-4
-fn load_report_by_name(reports_dir: &Path, name: &str) -> Result<EvaluationReport, String> {
+pub fn load_report_by_name(reports_dir: &Path, name: &str) -> Result<EvaluationReport, String> {
     let path = reports_dir.join(name);
     if let Ok(s) = std::fs::read_to_string(&path) {
         // 1) 집계 보고서(`evaluation_report_*`) 포맷
@@ -283,7 +380,10 @@ fn db_row_to_report(name: &str, v: &serde_json::Value) -> Result<EvaluationRepor
             metrics: scen_metrics,
             domain,
             scenario_id,
+            trial: 0,
         }],
+        trials: 1,
+        pass_at_k: Default::default(),
     })
 }
 
@@ -375,6 +475,8 @@ pub struct RunRequest {
     pub agent: String,
     #[serde(default)]
     pub output: Option<String>,
+    #[serde(flatten)]
+    pub options: RunOptions,
 }
 
 pub async fn run_eval_scenario(State(st): State<AppState>, Json(req): Json<RunRequest>) -> Result<Json<RunResponse>, (StatusCode, String)> {
@@ -383,9 +485,11 @@ pub async fn run_eval_scenario(State(st): State<AppState>, Json(req): Json<RunRe
     let eval_scenario_label = req.eval_scenario.clone();
     let agent_label = req.agent.clone();
     println!("▶ [web] POST /api/run eval_scenario={} agent={}", eval_scenario_label, agent_label);
-    let res = tokio::task::spawn_blocking(move || run_eval_scenario_with_save_impl(&req.eval_scenario, &req.agent, &scen, &reps, req.output.as_deref()))
-        .await
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let res = tokio::task::spawn_blocking(move || {
+        run_eval_scenario_with_save_impl(&req.eval_scenario, &req.agent, &scen, &reps, req.output.as_deref(), &req.options)
+    })
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
     match &res {
         | Ok((_, saved_to)) => println!(
             "✔ [web] 평가 시나리오 완료: eval_scenario={} agent={} → {}",
@@ -596,7 +700,7 @@ mod tests {
             return;
         }
         let reps = tempdir().unwrap();
-        let (_report, path) = run_eval_scenario_with_save_impl("customer_service", "passthrough", &scen, reps.path(), None).unwrap();
+        let (_report, path) = run_eval_scenario_with_save_impl("customer_service", "passthrough", &scen, reps.path(), None, &RunOptions::default()).unwrap();
         assert!(std::path::Path::new(&path).exists());
         assert!(path.contains("evaluation_report_"));
     }
@@ -611,7 +715,15 @@ mod tests {
             return;
         }
         let reps = tempdir().unwrap();
-        let (_r, path) = run_eval_scenario_with_save_impl("customer_service", "passthrough", &scen, reps.path(), Some("custom_name.json")).unwrap();
+        let (_r, path) = run_eval_scenario_with_save_impl(
+            "customer_service",
+            "passthrough",
+            &scen,
+            reps.path(),
+            Some("custom_name.json"),
+            &RunOptions::default(),
+        )
+        .unwrap();
         assert!(path.ends_with("custom_name.json"));
         assert!(reps.path().join("custom_name.json").exists());
     }
@@ -622,7 +734,14 @@ mod tests {
     #[test]
     fn test_spec005_tc_3_run_rejects_traversal_output() {
         let reps = tempdir().unwrap();
-        let r = run_eval_scenario_with_save_impl("customer_service", "passthrough", Path::new("/nonexistent"), reps.path(), Some("../evil.json"));
+        let r = run_eval_scenario_with_save_impl(
+            "customer_service",
+            "passthrough",
+            Path::new("/nonexistent"),
+            reps.path(),
+            Some("../evil.json"),
+            &RunOptions::default(),
+        );
         assert!(r.is_err());
     }
 
@@ -686,4 +805,23 @@ mod tests {
         assert!(!out.domains.is_empty());
         assert!(out.agents.contains(&"passthrough".to_string()));
     }
+
+    // --- SPEC-028 tests --------------------------------------------------
+
+    /// @trace TC: SPEC-028/TC-4
+    /// @trace FR: PRD-028/FR-4
+    /// @trace scenario: /api/run 동시 실행 옵션
+    #[test]
+    fn test_spec028_tc_4_run_request_options() {
+        let req: RunRequest = serde_json::from_str(r#"{"eval_scenario":"all","agent":"passthrough","concurrency":4,"scenario_timeout_secs":30}"#).unwrap();
+        assert_eq!(req.options.concurrency, Some(4));
+        assert_eq!(req.options.scenario_timeout_secs, Some(30));
+
+        let scen = workspace_scenarios();
+        let reps = tempdir().unwrap();
+        let sequential = run_eval_scenario_impl("all", "passthrough", &scen, reps.path()).unwrap();
+        let (parallel, _) = run_eval_scenario_with_save_impl("all", "passthrough", &scen, reps.path(), Some("parallel.json"), &req.options).unwrap();
+        let ids = |r: &EvaluationReport| r.scenarios.iter().map(|s| s.task_description.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&parallel), ids(&sequential));
+    }
 }
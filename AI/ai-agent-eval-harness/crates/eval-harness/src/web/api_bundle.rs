@@ -0,0 +1,75 @@
+// =============================================================================
+// @trace SPEC-043
+// @trace PRD: PRD-043
+// @trace FR: PRD-043/FR-1, PRD-043/FR-3, PRD-043/FR-4
+// @trace file-type: impl
+// =============================================================================
+//
+// 도메인 번들 내보내기/가져오기 라우트. 번들은 `DomainBundle` JSON 하나로
+// 주고받는다. fail 정책에서 충돌이 있으면 보고서와 함께 409 를 돌려준다.
+
+use super::{AppState,
+            api_crud::CrudFailure};
+use axum::{extract::{Path as AxPath,
+                     Query,
+                     State},
+           http::StatusCode,
+           response::{IntoResponse,
+                      Json,
+                      Response}};
+use data_scenarios::{bundle::{self,
+                              BundleError,
+                              DomainBundle,
+                              ImportPolicy},
+                     sqlite_store::StoreError};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct ImportQuery {
+    #[serde(default)]
+    pub policy: ImportPolicy,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+impl From<BundleError> for CrudFailure {
+    fn from(e: BundleError) -> Self {
+        match e {
+            | BundleError::Store(StoreError::NotFound(m)) => CrudFailure::NotFound(m),
+            | BundleError::Invalid(m) => CrudFailure::BadRequest(m),
+            | other => CrudFailure::Internal(other.to_string()),
+        }
+    }
+}
+
+/// GET /api/domains/:name/bundle — 도메인 번들 JSON.
+///
+/// @trace SPEC: SPEC-043
+/// @trace FR: PRD-043/FR-1
+pub async fn export_bundle(State(st): State<AppState>, AxPath(name): AxPath<String>) -> Result<Json<DomainBundle>, CrudFailure> {
+    let store = st.store.clone().ok_or_else(|| CrudFailure::Internal("store not configured".into()))?;
+    Ok(Json(bundle::export_domain(&store, &name).await?))
+}
+
+/// POST /api/domains/import?policy=fail|merge|overwrite&dry_run= — 번들을
+/// 가져와 `ImportReport` 를 돌려준다.
+///
+/// @trace SPEC: SPEC-043
+/// @trace FR: PRD-043/FR-3, PRD-043/FR-4
+pub async fn import_bundle(State(st): State<AppState>, Query(q): Query<ImportQuery>, Json(body): Json<DomainBundle>) -> Result<Response, CrudFailure> {
+    let store = st.store.clone().ok_or_else(|| CrudFailure::Internal("store not configured".into()))?;
+    println!(
+        "▶ [web] POST /api/domains/import domain={} policy={:?} dry_run={}",
+        body.manifest.domain, q.policy, q.dry_run
+    );
+    let report = bundle::import_domain(&store, &body, q.policy, q.dry_run).await?;
+    if report.applied {
+        agent_core::domain_router::invalidate_cache();
+    }
+    let status = if !report.applied && !q.dry_run {
+        StatusCode::CONFLICT
+    } else {
+        StatusCode::OK
+    };
+    Ok((status, Json(report)).into_response())
+}
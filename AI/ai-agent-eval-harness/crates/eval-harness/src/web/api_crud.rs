@@ -7,7 +7,10 @@
 // =============================================================================
 
 use super::AppState;
-use agent_models::domain_config::ScenarioConfig;
+use agent_models::{approval::ApprovalPolicy,
+                   domain_config::{ConversationConfig,
+                                   ScenarioConfig,
+                                   WorkspaceConfig}};
 use axum::{extract::{Json as JsonExt,
                      Path as AxPath,
                      State},
@@ -46,6 +49,15 @@ pub struct EvalScenarioUpsert {
     pub difficulty: String,
     #[serde(default)]
     pub position: Option<i64>,
+    /// SPEC-032: 멀티턴 시나리오 정의
+    #[serde(default)]
+    pub conversation: Option<ConversationConfig>,
+    /// SPEC-044: 위험 도구 승인 정책
+    #[serde(default)]
+    pub approval: Option<ApprovalPolicy>,
+    /// SPEC-045: 작업 공간 fixture 와 기대 파일
+    #[serde(default)]
+    pub workspace: Option<WorkspaceConfig>,
 }
 
 fn default_difficulty() -> String { "medium".into() }
@@ -167,9 +179,14 @@ pub async fn create_scenario_impl(store: &SqliteStore, domain: &str, body: EvalS
         expected_tools: body.expected_tools,
         success_criteria: body.success_criteria,
         difficulty: body.difficulty,
+        conversation: body.conversation,
+        approval: body.approval,
+        workspace: body.workspace,
     };
     let position = body.position.unwrap_or(9999);
     store.insert_scenario(domain, &scen, position).await?;
+    // SPEC-036: 시나리오 작업 설명은 임베딩 라우터의 도메인 예시다.
+    agent_core::domain_router::invalidate_cache();
     Ok(scen)
 }
 
@@ -192,8 +209,12 @@ pub async fn update_scenario_impl(store: &SqliteStore, domain: &str, id: &str, b
         expected_tools: body.expected_tools,
         success_criteria: body.success_criteria,
         difficulty: body.difficulty,
+        conversation: body.conversation,
+        approval: body.approval,
+        workspace: body.workspace,
     };
     store.update_scenario(domain, id, &scen).await?;
+    agent_core::domain_router::invalidate_cache();
     Ok(scen)
 }
 
@@ -205,6 +226,7 @@ pub async fn delete_scenario_impl(store: &SqliteStore, domain: &str, id: &str) -
     validate_id("domain", domain)?;
     validate_id("id", id)?;
     store.delete_scenario(domain, id).await?;
+    agent_core::domain_router::invalidate_cache();
     Ok(())
 }
 
@@ -455,7 +477,7 @@ pub async fn update_domain_impl(store: &SqliteStore, name: &str, body: DomainUps
 /// @trace FR: PRD-022/FR-1, PRD-022/FR-5
 pub async fn delete_domain_impl(store: &SqliteStore, name: &str) -> Result<(), CrudFailure> {
     validate_id("domain", name)?;
-    if domains::known_domains().iter().any(|k| *k == name) {
+    if domains::known_domains().contains(&name) {
         return Err(CrudFailure::Conflict(format!("bootstrap domain '{name}' cannot be deleted")));
     }
     store.delete_domain(name).await?;
@@ -655,6 +677,194 @@ pub async fn delete_external_tool_handler(State(st): State<AppState>, AxPath((do
     Ok(StatusCode::NO_CONTENT)
 }
 
+// =============================================================================
+// SPEC-037: mcp_servers CRUD + 도구 목록 조회
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct McpServerUpsert {
+    pub name: String,
+    #[serde(default = "default_transport")]
+    pub transport: String,
+    #[serde(default)]
+    pub command: Option<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default = "default_timeout")]
+    pub timeout_ms: i64,
+}
+
+fn default_transport() -> String { "stdio".into() }
+
+#[derive(Debug, Serialize)]
+pub struct McpServerDto {
+    pub name: String,
+    pub domain: String,
+    pub transport: String,
+    pub command: Option<String>,
+    pub args: Vec<String>,
+    pub env: HashMap<String, String>,
+    pub url: Option<String>,
+    pub timeout_ms: i64,
+}
+
+impl McpServerDto {
+    fn from_row(r: data_scenarios::sqlite_store::McpServerRow) -> Self {
+        Self {
+            args: serde_json::from_str(&r.args_json).unwrap_or_default(),
+            env: r.env_json.as_deref().and_then(|s| serde_json::from_str(s).ok()).unwrap_or_default(),
+            name: r.name,
+            domain: r.domain,
+            transport: r.transport,
+            command: r.command,
+            url: r.url,
+            timeout_ms: r.timeout_ms,
+        }
+    }
+}
+
+/// MCP 서버가 노출한 도구 1건. 레지스트리 키는 `<domain>__<name>`.
+#[derive(Debug, Serialize)]
+pub struct McpToolDto {
+    pub name: String,
+    pub description: String,
+    pub input_schema: serde_json::Value,
+}
+
+/// @trace SPEC: SPEC-037
+/// @trace FR: PRD-037/FR-4
+pub async fn list_mcp_servers_impl(store: &SqliteStore) -> Result<Vec<McpServerDto>, CrudFailure> {
+    let rows = store.list_mcp_servers().await?;
+    Ok(rows.into_iter().map(McpServerDto::from_row).collect())
+}
+
+pub async fn list_mcp_servers_by_domain_impl(store: &SqliteStore, domain: &str) -> Result<Vec<McpServerDto>, CrudFailure> {
+    validate_id("domain", domain)?;
+    let rows = store.list_mcp_servers_by_domain(domain).await?;
+    Ok(rows.into_iter().map(McpServerDto::from_row).collect())
+}
+
+/// http transport 의 url 은 external tool 과 같은 allowlist 로 검사한다.
+///
+/// @trace SPEC: SPEC-037
+/// @trace FR: PRD-037/FR-4
+pub async fn upsert_mcp_server_impl(store: &SqliteStore, domain: &str, body: McpServerUpsert) -> Result<McpServerDto, CrudFailure> {
+    validate_id("domain", domain)?;
+    validate_id("name", &body.name)?;
+    if let Some(url) = body.url.as_deref().filter(|_| body.transport == "http") {
+        if !url_allowed(url) {
+            return Err(CrudFailure::BadRequest(format!("url '{url}' not in EVAL_HARNESS_HTTP_TOOL_ALLOWLIST")));
+        }
+    }
+    let row = data_scenarios::sqlite_store::McpServerRow {
+        name: body.name,
+        domain: domain.to_string(),
+        transport: body.transport,
+        command: body.command,
+        args_json: serde_json::to_string(&body.args).map_err(|e| CrudFailure::Internal(e.to_string()))?,
+        env_json: if body.env.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(&body.env).map_err(|e| CrudFailure::Internal(e.to_string()))?)
+        },
+        url: body.url,
+        timeout_ms: body.timeout_ms,
+    };
+    agent_core::mcp_tools::transport_from_row(&row).map_err(CrudFailure::BadRequest)?;
+    store.upsert_mcp_server(&row).await?;
+    Ok(McpServerDto::from_row(row))
+}
+
+pub async fn delete_mcp_server_impl(store: &SqliteStore, domain: &str, name: &str) -> Result<(), CrudFailure> {
+    validate_id("domain", domain)?;
+    validate_id("name", name)?;
+    store.delete_mcp_server(domain, name).await?;
+    Ok(())
+}
+
+/// 등록된 서버에 접속해 `tools/list` 결과를 돌려준다. 등록 직후 연결 확인용.
+///
+/// @trace SPEC: SPEC-037
+/// @trace FR: PRD-037/FR-2
+pub async fn list_mcp_server_tools_impl(store: &SqliteStore, domain: &str, name: &str) -> Result<Vec<McpToolDto>, CrudFailure> {
+    validate_id("domain", domain)?;
+    validate_id("name", name)?;
+    let row = store
+        .list_mcp_servers_by_domain(domain)
+        .await?
+        .into_iter()
+        .find(|r| r.name == name)
+        .ok_or_else(|| CrudFailure::NotFound(format!("mcp_server ({domain}, {name})")))?;
+    let transport = agent_core::mcp_tools::transport_from_row(&row).map_err(CrudFailure::BadRequest)?;
+    let tools =
+        tokio::task::spawn_blocking(move || execution_tools::mcp_tool::McpClient::connect(&transport, row.timeout_ms as u64).and_then(|c| c.list_tools()))
+            .await
+            .map_err(|e| CrudFailure::Internal(e.to_string()))?
+            .map_err(|e| CrudFailure::BadRequest(format!("MCP 서버 연결 실패: {e}")))?;
+    Ok(tools
+        .into_iter()
+        .map(|t| McpToolDto {
+            name: t.name,
+            description: t.description,
+            input_schema: t.input_schema,
+        })
+        .collect())
+}
+
+// ----- axum handlers -----
+
+pub async fn list_mcp_servers(State(st): State<AppState>) -> Result<JsonOut<Vec<McpServerDto>>, CrudFailure> {
+    let store = store_from(&st)?;
+    let dtos = list_mcp_servers_impl(store.as_ref()).await?;
+    Ok(JsonOut(dtos))
+}
+
+pub async fn list_mcp_servers_by_domain(State(st): State<AppState>, AxPath(domain): AxPath<String>) -> Result<JsonOut<Vec<McpServerDto>>, CrudFailure> {
+    let store = store_from(&st)?;
+    let dtos = list_mcp_servers_by_domain_impl(store.as_ref(), &domain).await?;
+    Ok(JsonOut(dtos))
+}
+
+pub async fn create_mcp_server(
+    State(st): State<AppState>,
+    AxPath(domain): AxPath<String>,
+    JsonExt(body): JsonExt<McpServerUpsert>,
+) -> Result<(StatusCode, JsonOut<McpServerDto>), CrudFailure> {
+    let store = store_from(&st)?;
+    let dto = upsert_mcp_server_impl(store.as_ref(), &domain, body).await?;
+    Ok((StatusCode::CREATED, JsonOut(dto)))
+}
+
+pub async fn update_mcp_server_handler(
+    State(st): State<AppState>,
+    AxPath((domain, name)): AxPath<(String, String)>,
+    JsonExt(mut body): JsonExt<McpServerUpsert>,
+) -> Result<JsonOut<McpServerDto>, CrudFailure> {
+    body.name = name; // path 우선
+    let store = store_from(&st)?;
+    let dto = upsert_mcp_server_impl(store.as_ref(), &domain, body).await?;
+    Ok(JsonOut(dto))
+}
+
+pub async fn delete_mcp_server_handler(State(st): State<AppState>, AxPath((domain, name)): AxPath<(String, String)>) -> Result<StatusCode, CrudFailure> {
+    let store = store_from(&st)?;
+    delete_mcp_server_impl(store.as_ref(), &domain, &name).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn list_mcp_server_tools(
+    State(st): State<AppState>,
+    AxPath((domain, name)): AxPath<(String, String)>,
+) -> Result<JsonOut<Vec<McpToolDto>>, CrudFailure> {
+    let store = store_from(&st)?;
+    let dtos = list_mcp_server_tools_impl(store.as_ref(), &domain, &name).await?;
+    Ok(JsonOut(dtos))
+}
+
 // =============================================================================
 // SPEC-025: PromptSet REST CRUD
 //
@@ -899,6 +1109,9 @@ scenarios:
             success_criteria: HashMap::new(),
             difficulty: "easy".into(),
             position: Some(1),
+            conversation: None,
+            approval: None,
+            workspace: None,
         }
     }
 
@@ -1074,6 +1287,45 @@ scenarios:
         assert!(url_allowed("https://anything"));
     }
 
+    // -------- SPEC-037 --------
+
+    /// @trace TC: SPEC-037/TC-6
+    /// @trace FR: PRD-037/FR-4
+    #[tokio::test]
+    async fn spec037_tc_6_mcp_server_upsert_validates_transport() {
+        let store = SqliteStore::open_in_memory_for_loader().await.unwrap();
+        store.insert_domain("financial", "").await.unwrap();
+        let body = |transport: &str, command: Option<&str>| McpServerUpsert {
+            name: "fx".into(),
+            transport: transport.into(),
+            command: command.map(str::to_string),
+            args: vec!["-y".into(), "fx-mcp".into()],
+            env: HashMap::new(),
+            url: None,
+            timeout_ms: 5000,
+        };
+
+        let err = upsert_mcp_server_impl(&store, "financial", body("stdio", None)).await.unwrap_err();
+        assert!(matches!(err, CrudFailure::BadRequest(_)), "command 누락");
+        let err = upsert_mcp_server_impl(&store, "financial", body("sse", Some("npx"))).await.unwrap_err();
+        assert!(matches!(err, CrudFailure::BadRequest(_)), "알 수 없는 transport");
+        let zero_timeout = McpServerUpsert {
+            timeout_ms: 0,
+            ..body("stdio", Some("npx"))
+        };
+        let err = upsert_mcp_server_impl(&store, "financial", zero_timeout).await.unwrap_err();
+        assert!(matches!(err, CrudFailure::BadRequest(_)), "timeout_ms 0");
+
+        let dto = upsert_mcp_server_impl(&store, "financial", body("stdio", Some("npx"))).await.unwrap();
+        assert_eq!(dto.args, vec!["-y", "fx-mcp"]);
+        let listed = list_mcp_servers_by_domain_impl(&store, "financial").await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].command.as_deref(), Some("npx"));
+
+        delete_mcp_server_impl(&store, "financial", "fx").await.unwrap();
+        assert!(list_mcp_servers_impl(&store).await.unwrap().is_empty());
+    }
+
     // -------- SPEC-025 --------
 
     fn valid_payload(label: &str) -> PromptSetCreatePayload {
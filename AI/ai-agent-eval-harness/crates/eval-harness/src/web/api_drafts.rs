@@ -0,0 +1,113 @@
+// =============================================================================
+// @trace SPEC-042
+// @trace PRD: PRD-042
+// @trace FR: PRD-042/FR-1, PRD-042/FR-5
+// @trace file-type: impl
+// =============================================================================
+//
+// 시나리오 초안 생성·검토 라우트. 생성은 LLM 을 호출하므로 환경변수 LLM
+// 설정이 없으면 503, LLM 오류는 502 로 돌려준다.
+
+use super::{AppState,
+            api_crud::CrudFailure};
+use crate::scenario_gen::{GenerateError,
+                          GenerateOutcome,
+                          domain_tool_catalog,
+                          generate_drafts,
+                          generator_llm_from_env};
+use axum::{extract::{Path as AxPath,
+                     Query,
+                     State},
+           http::StatusCode,
+           response::Json};
+use data_scenarios::sqlite_store::{ScenarioDraftRow,
+                                   SqliteStore};
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+pub struct GenerateRequest {
+    #[serde(default = "default_count")]
+    pub count: usize,
+}
+
+fn default_count() -> usize { 5 }
+
+#[derive(Deserialize)]
+pub struct DraftQuery {
+    pub domain: Option<String>,
+    pub status: Option<String>,
+}
+
+fn status_of(err: &GenerateError) -> StatusCode {
+    match err {
+        | GenerateError::DomainNotFound(_) => StatusCode::NOT_FOUND,
+        | GenerateError::Invalid(_) => StatusCode::BAD_REQUEST,
+        | GenerateError::Store(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        | GenerateError::Llm(_) => StatusCode::BAD_GATEWAY,
+    }
+}
+
+fn require_store(st: &AppState) -> Result<Arc<SqliteStore>, CrudFailure> {
+    st.store.clone().ok_or_else(|| CrudFailure::Internal("store not configured".into()))
+}
+
+/// POST /api/domains/:name/scenario-drafts/generate — 도메인 도구로 초안을
+/// 생성해 `pending` 으로 저장.
+///
+/// @trace SPEC: SPEC-042
+/// @trace FR: PRD-042/FR-1, PRD-042/FR-4
+pub async fn generate(
+    State(st): State<AppState>,
+    AxPath(domain): AxPath<String>,
+    Json(req): Json<GenerateRequest>,
+) -> Result<Json<GenerateOutcome>, (StatusCode, String)> {
+    let Some(store) = st.store.clone() else {
+        return Err((StatusCode::INTERNAL_SERVER_ERROR, "store not configured".into()));
+    };
+    let llm = generator_llm_from_env().map_err(|e| (StatusCode::SERVICE_UNAVAILABLE, e.to_string()))?;
+    println!("▶ [web] POST /api/domains/{domain}/scenario-drafts/generate count={}", req.count);
+    // MCP 서버 접속이 블로킹이므로 카탈로그는 블로킹 스레드에서 만든다.
+    let catalog_domain = domain.clone();
+    let tools = tokio::task::spawn_blocking(move || domain_tool_catalog(&catalog_domain))
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    match generate_drafts(&store, &llm, &domain, req.count, &tools).await {
+        | Ok(outcome) => {
+            println!("✔ [web] 초안 {}건 저장, {}건 제외", outcome.drafts.len(), outcome.skipped.len());
+            Ok(Json(outcome))
+        },
+        | Err(e) => {
+            println!("✘ [web] 초안 생성 실패: {e}");
+            Err((status_of(&e), e.to_string()))
+        },
+    }
+}
+
+/// GET /api/scenario-drafts?domain=&status= — 초안 목록.
+pub async fn list_drafts(State(st): State<AppState>, Query(q): Query<DraftQuery>) -> Result<Json<Vec<ScenarioDraftRow>>, CrudFailure> {
+    let store = require_store(&st)?;
+    Ok(Json(store.list_scenario_drafts(q.domain.as_deref(), q.status.as_deref()).await?))
+}
+
+/// POST /api/scenario-drafts/:id/approve — 초안을 시나리오·골든셋으로 INSERT.
+///
+/// @trace SPEC: SPEC-042
+/// @trace FR: PRD-042/FR-5
+pub async fn approve_draft(State(st): State<AppState>, AxPath(id): AxPath<i64>) -> Result<Json<ScenarioDraftRow>, CrudFailure> {
+    let store = require_store(&st)?;
+    let row = store.approve_scenario_draft(id).await?;
+    // SPEC-036: 시나리오 작업 설명은 임베딩 라우터의 도메인 예시다.
+    agent_core::domain_router::invalidate_cache();
+    Ok(Json(row))
+}
+
+/// POST /api/scenario-drafts/:id/reject — 초안 거절.
+///
+/// @trace SPEC: SPEC-042
+/// @trace FR: PRD-042/FR-5
+pub async fn reject_draft(State(st): State<AppState>, AxPath(id): AxPath<i64>) -> Result<StatusCode, CrudFailure> {
+    let store = require_store(&st)?;
+    store.reject_scenario_draft(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
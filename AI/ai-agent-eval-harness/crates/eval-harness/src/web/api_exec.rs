@@ -6,9 +6,12 @@
 // =============================================================================
 
 use super::{AppState,
-            api::build_agent_registry,
+            api::{apply_pricing,
+                  build_agent_registry},
             handlers::is_safe_name};
-use agent_models::models::Trajectory;
+use agent_models::{approval::ApprovalPolicy,
+                   models::Trajectory,
+                   progress::ProgressSink};
 use eval_models::models::EvaluationResult;
 use axum::{extract::{Path as AxPath,
                      State},
@@ -27,7 +30,8 @@ use serde_json::Value;
 use std::{collections::HashMap,
           fs,
           path::{Path,
-                 PathBuf}};
+                 PathBuf},
+          sync::Arc};
 
 // --------------------------------------------------------------------------
 // 공유 유틸
@@ -52,6 +56,24 @@ pub fn build_full_tool_registry() -> ToolRegistry {
 /// @trace TC: SPEC-004/TC-1, SPEC-004/TC-2
 /// @trace FR: PRD-004/FR-1
 pub fn run_scenario_impl(scen_dir: &Path, reps_dir: &Path, domain: &str, id: &str, agent_name: &str) -> Result<EvaluationResult, String> {
+    run_scenario_with_progress_impl(scen_dir, reps_dir, domain, id, agent_name, None, None)
+}
+
+/// SPEC-040: `progress` 로 PPA 단계·도구 호출을 보고하며 단일 시나리오 실행.
+/// 실행 중 취소가 요청되면 중간 결과를 버리고 Err.
+/// SPEC-044: `approval` 은 시나리오에 정책이 없을 때의 승인 정책.
+///
+/// @trace SPEC: SPEC-040, SPEC-044
+/// @trace FR: PRD-040/FR-1, PRD-044/FR-1
+pub fn run_scenario_with_progress_impl(
+    scen_dir: &Path,
+    reps_dir: &Path,
+    domain: &str,
+    id: &str,
+    agent_name: &str,
+    progress: Option<Arc<dyn ProgressSink>>,
+    approval: Option<ApprovalPolicy>,
+) -> Result<EvaluationResult, String> {
     if !is_safe_name(id) || !is_safe_name(agent_name) {
         return Err("invalid identifier".into());
     }
@@ -82,6 +104,9 @@ pub fn run_scenario_impl(scen_dir: &Path, reps_dir: &Path, domain: &str, id: &st
         success_criteria: scenario_cfg.success_criteria,
         difficulty: scenario_cfg.difficulty,
         domain: domain.to_string(),
+        conversation: scenario_cfg.conversation,
+        approval: scenario_cfg.approval,
+        workspace: scenario_cfg.workspace,
     };
 
     let registry = build_agent_registry();
@@ -89,8 +114,18 @@ pub fn run_scenario_impl(scen_dir: &Path, reps_dir: &Path, domain: &str, id: &st
     agent.load_domain_tools(&domain_cfg);
 
     let reps_str = reps_dir.to_str().ok_or("invalid reports_dir")?;
-    let runner = HarnessRunner::new(reps_str);
-    Ok(runner.run_scenario(&scenario, agent.as_ref()))
+    let mut runner = apply_pricing(HarnessRunner::new(reps_str));
+    if let Some(sink) = progress.clone() {
+        runner = runner.with_progress(sink);
+    }
+    if let Some(policy) = approval {
+        runner = runner.with_approval(policy);
+    }
+    let evaluation = runner.run_scenario(&scenario, agent.as_ref());
+    if progress.is_some_and(|p| p.is_cancelled()) {
+        return Err("실행이 취소되었습니다".into());
+    }
+    Ok(evaluation)
 }
 
 // --------------------------------------------------------------------------
@@ -114,7 +149,7 @@ pub fn agent_execute_impl(
         if !is_safe_name(d) {
             return Err("invalid domain name".into());
         }
-        if !domains::known_domains().iter().any(|k| *k == d) {
+        if !domains::known_domains().contains(&d) {
             return Err(format!("domain not found: {}", d));
         }
     }
@@ -193,6 +228,7 @@ pub fn fault_sim_impl(name: &str, params: &HashMap<String, Value>, config: Fault
     if !is_safe_name(name) {
         return Err("invalid tool name".into());
     }
+    config.validate_distribution()?;
     let base = build_full_tool_registry();
     // FaultInjector::wrap_registry는 enabled=false일 때 빈 레지스트리를 반환하므로
     // 폴백으로 원본 레지스트리를 사용한다.
@@ -260,6 +296,10 @@ pub fn get_trajectory_impl(dir: &Path, name: &str) -> Option<Value> {
 #[derive(Deserialize)]
 pub struct RunScenarioReq {
     pub agent: String,
+    /// SPEC-044: 위험 도구 기본 승인 정책. `interactive` 는 작업 실행에서만
+    /// 물을 곳이 있고, 동기 실행에서는 거부로 처리된다.
+    #[serde(default)]
+    pub approval: Option<ApprovalPolicy>,
 }
 
 pub async fn run_scenario(
@@ -272,7 +312,7 @@ pub async fn run_scenario(
     println!("▶ [web] POST /api/scenarios/{}/{}/run agent={}", domain, id, req.agent);
     let label = format!("{}/{}", domain, id);
     let agent_label = req.agent.clone();
-    let res = tokio::task::spawn_blocking(move || run_scenario_impl(&scen, &reps, &domain, &id, &req.agent))
+    let res = tokio::task::spawn_blocking(move || run_scenario_with_progress_impl(&scen, &reps, &domain, &id, &req.agent, None, req.approval))
         .await
         .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
     match &res {
@@ -412,6 +452,33 @@ mod tests {
         assert!(res.is_ok(), "{:?}", res.err());
     }
 
+    /// 항상 취소된 상태를 알리는 sink.
+    struct CancelledSink;
+
+    impl ProgressSink for CancelledSink {
+        fn emit(&self, _event: agent_models::progress::ProgressEvent) {}
+
+        fn is_cancelled(&self) -> bool { true }
+    }
+
+    /// @trace TC: SPEC-040/TC-7
+    /// @trace FR: PRD-040/FR-4
+    /// @trace scenario: 취소된 단일 시나리오 실행은 Err
+    #[test]
+    fn spec040_tc_7_cancelled_single_scenario_run_is_err() {
+        let reps = tempdir().unwrap();
+        let res = run_scenario_with_progress_impl(
+            &ws_scenarios(),
+            reps.path(),
+            "customer_service",
+            "cs_001",
+            "passthrough",
+            Some(Arc::new(CancelledSink)),
+            None,
+        );
+        assert!(res.unwrap_err().contains("취소"));
+    }
+
     /// @trace TC: SPEC-004/TC-2
     /// @trace FR: PRD-004/FR-1
     /// @trace scenario: 없는 시나리오
@@ -526,6 +593,11 @@ mod tests {
             success: false,
             total_iterations: 0,
             prompt_set_id: None,
+            injected_faults: Vec::new(),
+            token_usage: None,
+            tool_calling_mode: None,
+            llm_model: None,
+            domain_routing: None,
         };
         let res = score_impl(traj);
         assert_eq!(res.trajectory.task_id, "t1");
@@ -539,8 +611,11 @@ mod tests {
         let mut params = HashMap::new();
         params.insert("inquiry_text".into(), serde_json::json!("환불"));
         params.insert("customer_id".into(), serde_json::json!("C1"));
-        let mut config = FaultInjectionConfig::default();
-        config.enabled = false; // 정상 경로 유지
+        // 정상 경로 유지
+        let config = FaultInjectionConfig {
+            enabled: false,
+            ..Default::default()
+        };
         let res = fault_sim_impl("customer_service__classify_inquiry", &params, config);
         assert!(res.is_ok(), "{:?}", res.err());
     }
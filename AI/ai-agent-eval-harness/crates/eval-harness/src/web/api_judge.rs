@@ -0,0 +1,56 @@
+// =============================================================================
+// @trace SPEC-027
+// @trace PRD: PRD-027
+// @trace FR: PRD-027/FR-4
+// @trace file-type: impl
+// =============================================================================
+
+use super::AppState;
+use crate::judge_pairwise::{PairwiseError,
+                            PairwiseReport,
+                            judge_stored_pair,
+                            pairwise_judge_from_env};
+use axum::{extract::State,
+           http::StatusCode,
+           response::Json};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct PairwiseRequest {
+    pub task_a: String,
+    pub task_b: String,
+}
+
+fn status_of(err: &PairwiseError) -> StatusCode {
+    match err {
+        | PairwiseError::NotFound(_) => StatusCode::NOT_FOUND,
+        | PairwiseError::InvalidPair(_) => StatusCode::BAD_REQUEST,
+        | PairwiseError::Store(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        | PairwiseError::Judge(_) => StatusCode::BAD_GATEWAY,
+    }
+}
+
+/// POST /api/judge/pairwise — 저장된 두 궤적을 A/B 교체 2회 판정으로 비교.
+///
+/// @trace SPEC: SPEC-027
+/// @trace FR: PRD-027/FR-4
+pub async fn judge_pairwise(State(st): State<AppState>, Json(req): Json<PairwiseRequest>) -> Result<Json<PairwiseReport>, (StatusCode, String)> {
+    let Some(store) = st.store.clone() else {
+        return Err((StatusCode::INTERNAL_SERVER_ERROR, "store not configured".into()));
+    };
+    let judge = pairwise_judge_from_env(None).map_err(|e| (StatusCode::SERVICE_UNAVAILABLE, e.to_string()))?;
+    println!("▶ [web] POST /api/judge/pairwise task_a={} task_b={}", req.task_a, req.task_b);
+    match judge_stored_pair(&store, &judge, &req.task_a, &req.task_b).await {
+        | Ok(report) => {
+            println!(
+                "✔ [web] pairwise 완료: winner={} confidence={:.2}",
+                report.comparison.winner, report.comparison.confidence
+            );
+            Ok(Json(report))
+        },
+        | Err(e) => {
+            println!("✘ [web] pairwise 실패: {e}");
+            Err((status_of(&e), e.to_string()))
+        },
+    }
+}
@@ -68,6 +68,21 @@
         "extools.deleted": "삭제됨: ",
         "extools.loadError": "로드 오류: ",
         "extools.error": "오류: ",
+        "mcp.title": "MCP 서버",
+        "mcp.hint": "선택된 도메인에 MCP 서버를 등록합니다. stdio 는 command/args 로 서버를 띄우고, http 는 이미 떠 있는 서버의 URL 로 접속합니다. 서버의 모든 도구가 이 도메인 도구로 노출됩니다.",
+        "mcp.name": "이름",
+        "mcp.transport": "전송 방식",
+        "mcp.command": "command (stdio)",
+        "mcp.args": "args (JSON 배열, stdio)",
+        "mcp.env": "env (JSON 객체, stdio, 선택)",
+        "mcp.url": "URL (http)",
+        "mcp.timeout": "타임아웃 (ms)",
+        "mcp.save": "저장",
+        "mcp.delete": "삭제",
+        "mcp.clear": "초기화",
+        "mcp.probe": "도구 조회",
+        "mcp.selectServerFirst": "먼저 기존 서버를 선택하세요",
+        "mcp.badJson": "args/env JSON 파싱 오류: ",
         "manage.title": "시나리오/골든셋 관리 (CRUD)",
         "manage.domain": "도메인",
         "manage.refresh": "새로고침",
@@ -85,16 +100,33 @@
         "run.agent": "에이전트",
         "run.output": "출력",
         "run.outputPh": "(선택) my_report.json",
-        "run.button": "실행 → POST /api/run",
-        "run.hint": "POST /api/run 을 호출합니다. reports_dir 에 항상 저장됩니다 (output 생략 시 기본 파일명 사용).",
+        "run.concurrency": "동시 실행",
+        "run.timeout": "시나리오 제한(초)",
+        "run.trials": "반복 시행",
+        "run.button": "실행 → POST /api/jobs/run",
+        "run.hint": "백그라운드 작업으로 실행하고 진행 상황을 실시간으로 표시합니다. reports_dir 에 항상 저장됩니다 (output 생략 시 기본 파일명 사용).",
+        "jobs.cancel": "취소",
+        "jobs.started": "작업 시작",
+        "jobs.cancelRequested": "취소 요청됨",
+        "jobs.finished": "작업 종료",
+        "jobs.disconnected": "진행 스트림 연결이 끊어졌습니다",
+        "approval.label": "도구 승인",
+        "approval.autoApprove": "자동 승인",
+        "approval.autoDeny": "자동 거부",
+        "approval.interactive": "직접 결정",
+        "approval.prompt": "승인이 필요한 도구 호출입니다. 실행할까요?",
+        "approval.requested": "승인 요청",
         "common.idle": "준비됨",
         "common.running": "실행 중...",
+        "usage.tokens": "평균 토큰",
+        "usage.cost": "평균 비용",
+        "usage.costPerSuccess": "성공당 비용",
         "common.requesting": "요청 중…",
         "common.optional": "(선택)",
         "scenarios.title": "시나리오 (탐색 + 단일 실행)",
         "scenarios.empty": "시나리오를 선택하세요",
         "scenarios.formTitle": "이 시나리오 실행",
-        "scenarios.runBtn": "실행 → POST /api/scenarios/:d/:id/run",
+        "scenarios.runBtn": "실행 → POST /api/jobs/scenarios/:d/:id/run",
         "tools.title": "도구 (호출 + 폴트 주입)",
         "tools.empty": "도구를 선택하세요",
         "tools.params": "매개변수 (JSON)",
@@ -112,6 +144,7 @@
         "reports.current": "현재",
         "reports.threshold": "임계값",
         "reports.compareBtn": "비교 → POST /api/compare",
+        "reports.export": "내보내기",
         "traj.title": "궤적 (조회 + 채점)",
         "traj.refresh": "새로고침 → GET /api/trajectories",
         "traj.score": "이 궤적 채점 → POST /api/score",
@@ -185,21 +218,53 @@
         "extools.deleted": "deleted: ",
         "extools.loadError": "load error: ",
         "extools.error": "error: ",
+        "mcp.title": "MCP Servers",
+        "mcp.hint": "Register MCP servers for the selected domain. stdio launches the server from command/args, http connects to a running server URL. Every tool the server lists is exposed as a tool of this domain.",
+        "mcp.name": "name",
+        "mcp.transport": "transport",
+        "mcp.command": "command (stdio)",
+        "mcp.args": "args (JSON array, stdio)",
+        "mcp.env": "env (JSON object, stdio, optional)",
+        "mcp.url": "url (http)",
+        "mcp.timeout": "timeout_ms",
+        "mcp.save": "Save",
+        "mcp.delete": "Delete",
+        "mcp.clear": "Clear",
+        "mcp.probe": "List tools",
+        "mcp.selectServerFirst": "select an existing server first",
+        "mcp.badJson": "args/env JSON parse error: ",
         "run.title": "Run eval scenario",
         "run.evalScenario": "Eval Scenario",
         "run.agent": "Agent",
         "run.output": "Output",
         "run.outputPh": "(optional) my_report.json",
-        "run.button": "Run → POST /api/run",
-        "run.hint": "Calls POST /api/run. Always saves to reports_dir (default timestamp name if output omitted).",
+        "run.concurrency": "Concurrency",
+        "run.timeout": "Scenario timeout (s)",
+        "run.trials": "Trials",
+        "run.button": "Run → POST /api/jobs/run",
+        "run.hint": "Runs as a background job and streams progress live. Always saves to reports_dir (default timestamp name if output omitted).",
+        "jobs.cancel": "Cancel",
+        "jobs.started": "job started",
+        "jobs.cancelRequested": "cancel requested",
+        "jobs.finished": "job finished",
+        "jobs.disconnected": "progress stream disconnected",
+        "approval.label": "Tool approval",
+        "approval.autoApprove": "auto-approve",
+        "approval.autoDeny": "auto-deny",
+        "approval.interactive": "ask me",
+        "approval.prompt": "This tool call requires approval. Run it?",
+        "approval.requested": "approval requested",
         "common.idle": "Ready",
         "common.running": "running...",
+        "usage.tokens": "avg tokens",
+        "usage.cost": "avg cost",
+        "usage.costPerSuccess": "cost / success",
         "common.requesting": "Requesting…",
         "common.optional": "(optional)",
         "scenarios.title": "Scenarios (list + single run)",
         "scenarios.empty": "select a scenario",
         "scenarios.formTitle": "Run this scenario",
-        "scenarios.runBtn": "Run → POST /api/scenarios/:d/:id/run",
+        "scenarios.runBtn": "Run → POST /api/jobs/scenarios/:d/:id/run",
         "tools.title": "Tools (invoke + fault injection)",
         "tools.empty": "select a tool",
         "tools.params": "Parameters (JSON)",
@@ -217,6 +282,7 @@
         "reports.current": "Current",
         "reports.threshold": "Threshold",
         "reports.compareBtn": "Compare → POST /api/compare",
+        "reports.export": "Export",
         "traj.title": "Trajectories (view + score)",
         "traj.refresh": "Refresh → GET /api/trajectories",
         "traj.score": "Score this trajectory → POST /api/score",
@@ -269,6 +335,24 @@
     const pretty = (o) => JSON.stringify(o, null, 2);
     const showOk  = (el, data) => { el.className = 'ok'; el.textContent = pretty(data); };
     const showErr = (el, err)  => { el.className = 'error'; el.textContent = 'ERROR: ' + (err.message || err); };
+    // SPEC-035: 리포트의 토큰/비용 지표를 JSON 위에 한 줄로 요약한다.
+    const usageSummary = (report) => {
+      const m = (report && report.average_metrics) || {};
+      const parts = [];
+      if (m.total_tokens != null) parts.push(`${t('usage.tokens')} ${Math.round(m.total_tokens).toLocaleString()}`);
+      if (m.cost_usd != null) parts.push(`${t('usage.cost')} $${m.cost_usd.toFixed(4)}`);
+      if (m.cost_per_success_usd != null) parts.push(`${t('usage.costPerSuccess')} $${m.cost_per_success_usd.toFixed(4)}`);
+      // SPEC-038: 반복 시행 리포트는 pass@k 도 함께 표시.
+      if (report && report.trials > 1) {
+        Object.entries(report.pass_at_k || {}).forEach(([k, v]) => parts.push(`pass@${k} ${(v * 100).toFixed(1)}%`));
+      }
+      return parts.join(' · ');
+    };
+    const showReport = (el, data, report) => {
+      const summary = usageSummary(report);
+      el.className = 'ok';
+      el.textContent = (summary ? summary + '\n\n' : '') + pretty(data);
+    };
     const showPending = (el, label) => { el.className = ''; el.textContent = (label || t('common.running') || 'running...'); };
 
     // ---------- tabs ----------
@@ -294,14 +378,68 @@
       });
     }
 
+    // ---------- JOBS (SPEC-040) ----------
+    // 작업을 시작하고 SSE 진행 이벤트를 로그로 쌓다가, job_finished 에서 결과를 렌더링한다.
+    const jobLogLine = (ev) => {
+      const d = ev.data;
+      switch (ev.kind) {
+        case 'job_started': return `▶ ${t('jobs.started')}: ${d.label}`;
+        case 'scenario_started': return `[${d.index + 1}/${d.total}] ${d.domain}/${d.scenario_id} — ${d.name}`;
+        case 'scenario_finished': return `[${d.index + 1}/${d.total}] ${d.success ? '✔' : '✘'} ${d.scenario_id} (${(d.duration_ms / 1000).toFixed(1)}s)`;
+        case 'step': return `    #${d.iteration} ${d.stage}${d.duration_ms != null ? ` ${Math.round(d.duration_ms)}ms` : ''}`;
+        case 'tool_call': return `      → ${d.call.tool_name} ${d.call.approval?.decision === 'denied' ? 'denied' : d.call.success ? 'ok' : 'fail'}`;
+        case 'approval_requested': return `      ? ${t('approval.requested')}: ${d.tool_name}`;
+        case 'approval_resolved': return `      ? ${d.decision}`;
+        case 'cancel_requested': return `■ ${t('jobs.cancelRequested')}`;
+        case 'job_finished': return `■ ${t('jobs.finished')}: ${d.status}`;
+      }
+    };
+    const JOB_EVENT_KINDS = ['job_started', 'scenario_started', 'scenario_finished', 'step', 'tool_call', 'approval_requested', 'approval_resolved', 'cancel_requested', 'job_finished'];
+    // SPEC-044: interactive 승인 정책이면 작업이 결정을 기다린다. 재생된 지난
+    // 요청은 approval_resolved 가 뒤따르므로 아직 열린 것만 묻는다.
+    const askApproval = (jobId, d) => {
+      const decision = confirm(`${t('approval.prompt')}\n\n${d.tool_name}\n${JSON.stringify(d.parameters, null, 2)}`) ? 'approved' : 'denied';
+      API.post(`/api/jobs/${jobId}/approvals/${d.approval_id}`, { decision }).catch(() => {});
+    };
+    const approvalBody = (id) => { const v = $(id).value; return v === 'auto_approve' ? undefined : { mode: v }; };
+    async function runJob(path, body, outEl, cancelBtn, render) {
+      showPending(outEl, t('common.requesting'));
+      let job;
+      try { job = await API.post(path, body); } catch (err) { return showErr(outEl, err); }
+      const log = [];
+      const resolved = new Set();
+      cancelBtn.hidden = false;
+      cancelBtn.onclick = () => API.post(`/api/jobs/${job.job_id}/cancel`, {}).catch(() => {});
+      const es = new EventSource(`/api/jobs/${job.job_id}/events`);
+      const done = () => { es.close(); cancelBtn.hidden = true; };
+      const onEvent = (msg) => {
+        const ev = { kind: msg.type, data: JSON.parse(msg.data) };
+        log.push(jobLogLine(ev));
+        if (ev.kind === 'approval_resolved') resolved.add(ev.data.approval_id);
+        if (ev.kind === 'approval_requested') setTimeout(() => resolved.has(ev.data.approval_id) || askApproval(job.job_id, ev.data), 0);
+        if (ev.kind !== 'job_finished') { outEl.className = ''; outEl.textContent = log.join('\n'); outEl.scrollTop = outEl.scrollHeight; return; }
+        done();
+        if (ev.data.status === 'succeeded') render(ev.data.result, log);
+        else { outEl.className = 'error'; outEl.textContent = log.join('\n') + '\n\nERROR: ' + (ev.data.error || ev.data.status); }
+      };
+      JOB_EVENT_KINDS.forEach(k => es.addEventListener(k, onEvent));
+      es.onerror = () => { if (es.readyState === EventSource.CLOSED) return; done(); log.push(`✘ ${t('jobs.disconnected')}`); outEl.textContent = log.join('\n'); };
+    }
+
     // ---------- RUN ----------
     async function runEvalScenario(e) {
       e.preventDefault();
       const body = { eval_scenario: $('run-eval-scenario').value, agent: $('run-agent').value };
       const o = $('run-output').value.trim();
       if (o) body.output = o;
-      showPending($('run-out'));
-      try { showOk($('run-out'), await API.post('/api/run', body)); } catch (err) { showErr($('run-out'), err); }
+      const c = parseInt($('run-concurrency').value, 10);
+      if (c > 1) body.concurrency = c;
+      const t = parseInt($('run-timeout').value, 10);
+      if (t > 0) body.scenario_timeout_secs = t;
+      const n = parseInt($('run-trials').value, 10);
+      if (n > 1) body.trials = n;
+      body.approval = approvalBody('run-approval');
+      await runJob('/api/jobs/run', body, $('run-out'), $('run-cancel'), (res) => showReport($('run-out'), res, res.report));
     }
 
     // ---------- SCENARIOS ----------
@@ -333,11 +471,8 @@
     }
     async function runScenario() {
       if (!SELECTED_SCEN) return showErr($('scen-out'), 'select a scenario first');
-      const body = { agent: $('scen-agent').value };
-      showPending($('scen-out'));
-      try {
-        showOk($('scen-out'), await API.post(`/api/scenarios/${SELECTED_SCEN.domain}/${SELECTED_SCEN.id}/run`, body));
-      } catch (err) { showErr($('scen-out'), err); }
+      const body = { agent: $('scen-agent').value, approval: approvalBody('scen-approval') };
+      await runJob(`/api/jobs/scenarios/${SELECTED_SCEN.domain}/${SELECTED_SCEN.id}/run`, body, $('scen-out'), $('scen-cancel'), (res) => showOk($('scen-out'), res));
     }
 
     // ---------- TOOLS ----------
@@ -377,6 +512,9 @@
         failure_mode_distribution: { timeout:0.2, partial_result:0.25, incorrect_result:0.2, exception:0.2, network_error:0.1, permission_denied:0.05 },
         seed: seedStr === '' ? null : parseInt(seedStr)
       };
+      // SPEC-030: 특정 모드만 강제 (latency / rate_limited / malformed_result / stale_data 포함)
+      const mode = $('tool-fault-mode').value;
+      if (mode) config.failure_mode_distribution = { [mode]: 1.0 };
       try { showOk($('tool-out'), await API.post(`/api/tools/${SELECTED_TOOL}/simulate-fault`, { params, config })); } catch (err) { showErr($('tool-out'), err); }
     }
 
@@ -469,7 +607,15 @@
           document.querySelectorAll('#reports-list .item').forEach(x=>x.classList.remove('active'));
           it.classList.add('active');
           showPending($('report-out'));
-          try { showOk($('report-out'), await API.get(`/api/reports/${encodeURIComponent(name)}`)); } catch (err) { showErr($('report-out'), err); }
+          try {
+            const report = await API.get(`/api/reports/${encodeURIComponent(name)}`);
+            showReport($('report-out'), report, report);
+            // SPEC-039: 집계 리포트만 JUnit/HTML 로 내보낼 수 있다.
+            const base = `/api/reports/${encodeURIComponent(name)}?format=`;
+            $('report-export-junit').href = base + 'junit';
+            $('report-export-html').href = base + 'html';
+            $('report-export').style.display = Array.isArray(report.scenarios) ? '' : 'none';
+          } catch (err) { showErr($('report-out'), err); }
         };
         el.appendChild(it);
       });
@@ -616,7 +762,10 @@
         initial_environment: scen.initial_environment || {},
         expected_tools: scen.expected_tools || [],
         success_criteria: scen.success_criteria || {},
-        difficulty: scen.difficulty || 'medium'
+        difficulty: scen.difficulty || 'medium',
+        ...(scen.conversation ? {conversation: scen.conversation} : {}),
+        ...(scen.approval ? {approval: scen.approval} : {}),
+        ...(scen.workspace ? {workspace: scen.workspace} : {})
       }, null, 2);
     }
 
@@ -834,6 +983,7 @@
       Array.from(sel.options).forEach(o => o.selected = d.tool_class_names.includes(o.value));
       $('domain-keywords-input').value = (d.keywords || []).join(', ');
       refreshExternalTools(d.name);
+      refreshMcpServers(d.name);
       refreshPromptSets(d.name);
     }
 
@@ -928,6 +1078,107 @@
       } catch (e) { $('extool-out').textContent = t('extools.error') + e; }
     }
 
+    // ---------- SPEC-037 MCP servers ----------
+    let MCP_EDITOR = {mode: 'new', domain: null, name: null};
+    async function refreshMcpServers(domain) {
+      MCP_EDITOR.domain = domain;
+      const list = $('mcp-list');
+      if (!list) return;
+      list.innerHTML = '';
+      try {
+        const rows = await API.get('/api/mcp-servers/' + domain);
+        rows.forEach(r => {
+          const it = document.createElement('div');
+          it.className = 'item';
+          const name = document.createElement('span');
+          name.className = 'name';
+          name.textContent = r.name;
+          const meta = document.createElement('span');
+          meta.className = 'meta';
+          meta.textContent = r.transport === 'http' ? `http ${r.url || ''}` : `stdio ${r.command || ''} ${(r.args || []).join(' ')}`;
+          it.appendChild(name);
+          it.appendChild(meta);
+          it.onclick = () => {
+            document.querySelectorAll('#mcp-list .item').forEach(x => x.classList.remove('active'));
+            it.classList.add('active');
+            loadMcpServer(r);
+          };
+          list.appendChild(it);
+        });
+      } catch (e) { list.textContent = t('extools.loadError') + e; }
+    }
+    function loadMcpServer(r) {
+      MCP_EDITOR = {mode: 'edit', domain: r.domain, name: r.name};
+      $('mcp-name').value = r.name;
+      $('mcp-name').disabled = true;
+      $('mcp-transport').value = r.transport || 'stdio';
+      $('mcp-command').value = r.command || '';
+      $('mcp-args').value = JSON.stringify(r.args || []);
+      $('mcp-env').value = r.env && Object.keys(r.env).length ? JSON.stringify(r.env) : '';
+      $('mcp-url').value = r.url || '';
+      $('mcp-timeout').value = r.timeout_ms || 10000;
+    }
+    function clearMcpServerForm() {
+      MCP_EDITOR = {mode: 'new', domain: MCP_EDITOR.domain, name: null};
+      $('mcp-name').value = '';
+      $('mcp-name').disabled = false;
+      $('mcp-transport').value = 'stdio';
+      $('mcp-command').value = '';
+      $('mcp-args').value = '';
+      $('mcp-env').value = '';
+      $('mcp-url').value = '';
+      $('mcp-timeout').value = '10000';
+      $('mcp-out').textContent = t('common.idle');
+    }
+    async function saveMcpServer() {
+      const out = $('mcp-out');
+      if (!MCP_EDITOR.domain) { out.textContent = t('extools.selectDomainFirst'); return; }
+      let args, env;
+      try {
+        args = JSON.parse($('mcp-args').value.trim() || '[]');
+        env = JSON.parse($('mcp-env').value.trim() || '{}');
+      } catch (e) { out.textContent = t('mcp.badJson') + e; return; }
+      const body = {
+        name: $('mcp-name').value.trim(),
+        transport: $('mcp-transport').value,
+        command: $('mcp-command').value.trim() || null,
+        args,
+        env,
+        url: $('mcp-url').value.trim() || null,
+        timeout_ms: parseInt($('mcp-timeout').value || '10000', 10),
+      };
+      showPending(out);
+      try {
+        let res;
+        if (MCP_EDITOR.mode === 'new') {
+          res = await API.post('/api/mcp-servers/' + MCP_EDITOR.domain, body);
+        } else {
+          res = await API.put('/api/mcp-servers/' + MCP_EDITOR.domain + '/' + MCP_EDITOR.name, body);
+        }
+        out.textContent = JSON.stringify(res, null, 2);
+        await refreshMcpServers(MCP_EDITOR.domain);
+      } catch (e) { out.textContent = t('extools.error') + e; }
+    }
+    async function deleteMcpServer() {
+      if (MCP_EDITOR.mode !== 'edit') { $('mcp-out').textContent = t('mcp.selectServerFirst'); return; }
+      if (!confirm(t('extools.confirmDelete') + ' ' + MCP_EDITOR.name)) return;
+      showPending($('mcp-out'));
+      try {
+        await API.del('/api/mcp-servers/' + MCP_EDITOR.domain + '/' + MCP_EDITOR.name);
+        $('mcp-out').textContent = t('extools.deleted') + MCP_EDITOR.name;
+        clearMcpServerForm();
+        await refreshMcpServers(MCP_EDITOR.domain);
+      } catch (e) { $('mcp-out').textContent = t('extools.error') + e; }
+    }
+    async function probeMcpServer() {
+      if (MCP_EDITOR.mode !== 'edit') { $('mcp-out').textContent = t('mcp.selectServerFirst'); return; }
+      showPending($('mcp-out'));
+      try {
+        const tools = await API.get('/api/mcp-servers/' + MCP_EDITOR.domain + '/' + MCP_EDITOR.name + '/tools');
+        $('mcp-out').textContent = JSON.stringify(tools, null, 2);
+      } catch (e) { $('mcp-out').textContent = t('extools.error') + e; }
+    }
+
     function clearDomainEditor() { newDomain(); }
 
     async function saveDomain() {
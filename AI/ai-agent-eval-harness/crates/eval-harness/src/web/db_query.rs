@@ -12,7 +12,9 @@
 use data_scenarios::sqlite_store::{EvaluationListRow,
                                    TrajectoryListRow};
 
-fn block_on<F: std::future::Future>(fut: F) -> F::Output {
+/// 동기 컨텍스트에서 future 를 실행한다. 크레이트 안의 다른 동기 어댑터
+/// (번들 가져오기, 시나리오 생성 CLI 등)도 이 헬퍼를 쓴다.
+pub(crate) fn block_on<F: std::future::Future>(fut: F) -> F::Output {
     match tokio::runtime::Handle::try_current() {
         | Ok(handle) => tokio::task::block_in_place(|| handle.block_on(fut)),
         | Err(_) => tokio::runtime::Builder::new_current_thread()
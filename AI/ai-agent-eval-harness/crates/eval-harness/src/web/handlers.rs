@@ -6,17 +6,25 @@
 // =============================================================================
 
 use super::AppState;
-use agent_models::domain_config::DomainConfig;
+use agent_models::{domain_config::DomainConfig,
+                   models::Trajectory};
 use axum::{extract::{Path as AxPath,
+                     Query,
                      State},
            http::{StatusCode,
                   header},
            response::{Html,
                       IntoResponse,
-                      Json}};
+                      Json,
+                      Response}};
 use data_scenarios::loader::ScenarioLoader;
-use serde::Serialize;
-use std::{fs,
+use execution::{models::EvaluationReport,
+                report_export::{self,
+                                ExportFormat}};
+use serde::{Deserialize,
+            Serialize};
+use std::{collections::HashMap,
+          fs,
           path::{Path,
                  PathBuf}};
 
@@ -134,6 +142,61 @@ pub fn get_report_impl(dir: &Path, name: &str) -> Option<serde_json::Value> {
     serde_json::from_str(&text).ok()
 }
 
+/// 리포트 시나리오들의 궤적을 task_id 로 모은다. DB 우선, 없으면 `dirs`
+/// 의 `trajectory_<task_id>_*.json` 파일 (같은 task_id 가 여럿이면 최신).
+///
+/// @trace SPEC: SPEC-039
+/// @trace FR: PRD-039/FR-2
+pub fn report_trajectories_impl(report: &EvaluationReport, dirs: &[&Path]) -> HashMap<String, Trajectory> {
+    let mut files: Vec<PathBuf> = dirs
+        .iter()
+        .filter_map(|d| fs::read_dir(d).ok())
+        .flat_map(|entries| entries.flatten().map(|e| e.path()))
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with("trajectory_") && n.ends_with(".json"))
+        })
+        .collect();
+    files.sort();
+
+    let mut out = HashMap::new();
+    for s in &report.scenarios {
+        if s.task_id.is_empty() || out.contains_key(&s.task_id) {
+            continue;
+        }
+        let from_db = super::db_query::get_trajectory(&s.task_id).and_then(|v| serde_json::from_value::<Trajectory>(v).ok());
+        let trajectory = from_db.or_else(|| {
+            let prefix = format!("trajectory_{}_", s.task_id);
+            files
+                .iter()
+                .rev()
+                .filter(|p| p.file_name().and_then(|n| n.to_str()).is_some_and(|n| n.starts_with(&prefix)))
+                .find_map(|p| serde_json::from_str::<Trajectory>(&fs::read_to_string(p).ok()?).ok())
+        });
+        if let Some(t) = trajectory {
+            out.insert(s.task_id.clone(), t);
+        }
+    }
+    out
+}
+
+/// 집계 리포트를 `format` 으로 렌더링. 리포트가 없으면 404, 형식을 모르거나
+/// 집계 리포트가 아닌 JSON(개별 평가 로그 등)을 JUnit/HTML 로 요청하면 400.
+///
+/// @trace SPEC: SPEC-039
+/// @trace FR: PRD-039/FR-3
+pub fn export_report_impl(reports_dir: &Path, trajectories_dir: &Path, name: &str, format: &str) -> Result<(ExportFormat, String), (StatusCode, String)> {
+    let format = ExportFormat::parse(format).ok_or((StatusCode::BAD_REQUEST, format!("unknown format '{format}' (json|junit|html)")))?;
+    let value = get_report_impl(reports_dir, name).ok_or((StatusCode::NOT_FOUND, format!("report '{name}' not found")))?;
+    if format == ExportFormat::Json {
+        return Ok((format, serde_json::to_string_pretty(&value).unwrap_or_default()));
+    }
+    let report: EvaluationReport = serde_json::from_value(value).map_err(|e| (StatusCode::BAD_REQUEST, format!("not an evaluation report: {e}")))?;
+    let trajectories = report_trajectories_impl(&report, &[trajectories_dir, reports_dir]);
+    Ok((format, report_export::export(&report, format, &trajectories)))
+}
+
 // -------- axum handler wrappers --------
 
 pub async fn index() -> Html<&'static str> { Html(INDEX_HTML) }
@@ -162,11 +225,25 @@ pub async fn list_scenarios(State(st): State<AppState>) -> Json<Vec<DomainSummar
 
 pub async fn list_reports(State(st): State<AppState>) -> Json<Vec<String>> { Json(list_reports_impl(&st.reports_dir)) }
 
-pub async fn get_report(State(st): State<AppState>, AxPath(name): AxPath<String>) -> Result<Json<serde_json::Value>, StatusCode> {
-    match get_report_impl(&st.reports_dir, &name) {
-        | Some(v) => Ok(Json(v)),
-        | None => Err(StatusCode::NOT_FOUND),
-    }
+#[derive(Deserialize)]
+pub struct ReportQuery {
+    pub format: Option<String>,
+}
+
+/// SPEC-039: `?format=junit|html` 이면 내보내기 본문을, 없으면 기존처럼
+/// JSON 을 돌려준다.
+pub async fn get_report(State(st): State<AppState>, AxPath(name): AxPath<String>, Query(q): Query<ReportQuery>) -> Result<Response, (StatusCode, String)> {
+    let Some(format) = q.format else {
+        return match get_report_impl(&st.reports_dir, &name) {
+            | Some(v) => Ok(Json(v).into_response()),
+            | None => Err((StatusCode::NOT_FOUND, format!("report '{name}' not found"))),
+        };
+    };
+    let (reports, trajectories) = (st.reports_dir.clone(), st.trajectories_dir.clone());
+    let (format, body) = tokio::task::spawn_blocking(move || export_report_impl(&reports, &trajectories, &name, &format))
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))??;
+    Ok(([(header::CONTENT_TYPE, format.content_type())], body).into_response())
 }
 
 /// 테스트용 헬퍼 — index.html 본문 + 분리된 CSS/JS 를 합쳐
@@ -232,6 +309,48 @@ mod tests {
         assert!(get_report_impl(dir.path(), "missing.json").is_none());
     }
 
+    /// @trace TC: SPEC-039/TC-3
+    /// @trace FR: PRD-039/FR-3
+    /// @trace scenario: 저장된 리포트를 궤적과 함께 JUnit/HTML 로 내보내기
+    #[test]
+    fn spec039_tc_3_export_report_with_trajectories() {
+        let reports = tempdir().unwrap();
+        let trajectories = tempdir().unwrap();
+        let report = serde_json::json!({
+            "timestamp": "20260101_000000",
+            "total_scenarios": 1,
+            "success_count": 0,
+            "success_rate": 0.0,
+            "scenarios": [{
+                "task_id": "t1", "task_description": "환율 조회", "success": false,
+                "total_iterations": 1, "metrics": {}, "domain": "financial", "scenario_id": "fin_001"
+            }]
+        });
+        fs::write(reports.path().join("evaluation_report_1.json"), report.to_string()).unwrap();
+        fs::write(reports.path().join("r1.json"), br#"{"foo":42}"#).unwrap();
+        fs::write(
+            trajectories.path().join("trajectory_t1_20260101_000000.json"),
+            br#"{"task_id":"t1","task_description":"x","start_time":"2026-01-01T00:00:00Z","end_time":"2026-01-01T00:00:02Z",
+                "steps":[],"final_state":null,"success":false,"total_iterations":1}"#,
+        )
+        .unwrap();
+
+        let (format, xml) = export_report_impl(reports.path(), trajectories.path(), "evaluation_report_1.json", "junit").unwrap();
+        assert_eq!(format.content_type(), "application/xml; charset=utf-8");
+        assert!(xml.contains("name=\"fin_001\" time=\"2.000\""), "궤적 파일에서 소요 시간: {xml}");
+        assert!(xml.contains("<failure "));
+
+        let (_, html) = export_report_impl(reports.path(), trajectories.path(), "evaluation_report_1.json", "HTML").unwrap();
+        assert!(html.contains("<b>fin_001</b>") && !html.contains("궤적 없음"));
+
+        let (_, json) = export_report_impl(reports.path(), trajectories.path(), "r1.json", "json").unwrap();
+        assert!(json.contains("\"foo\": 42"));
+        let status = |name: &str, format: &str| export_report_impl(reports.path(), trajectories.path(), name, format).unwrap_err().0;
+        assert_eq!(status("r1.json", "html"), StatusCode::BAD_REQUEST, "집계 리포트가 아님");
+        assert_eq!(status("missing.json", "html"), StatusCode::NOT_FOUND);
+        assert_eq!(status("r1.json", "pdf"), StatusCode::BAD_REQUEST);
+    }
+
     /// @trace TC: SPEC-002/TC-5
     /// @trace FR: PRD-002/FR-4
     /// @trace scenario: 경로 순회 거부
@@ -285,7 +404,7 @@ mod tests {
     fn test_spec006_tc_2_run_form() {
         let html = index_html_body();
         assert!(html.contains("id=\"run-form\""));
-        assert!(html.contains("'/api/run'"));
+        assert!(html.contains("'/api/jobs/run'"));
     }
 
     /// @trace TC: SPEC-006/TC-3
@@ -0,0 +1,563 @@
+// =============================================================================
+// @trace SPEC-040
+// @trace PRD: PRD-040
+// @trace FR: PRD-040/FR-1, PRD-040/FR-3, PRD-040/FR-4
+// @trace file-type: impl
+// =============================================================================
+//
+// 백그라운드 실행 작업. `POST /api/jobs/...` 는 작업을 spawn_blocking 으로
+// 띄우고 id 만 즉시 돌려준다. 작업은 러너의 ProgressSink 로서 진행 이벤트를
+// 기록하고, `GET /api/jobs/:id/events` (SSE) 구독자에게 지난 이벤트를 재생한
+// 뒤 새 이벤트를 실시간으로 보낸다. 작업이 끝나면 `job_finished` 이벤트를
+// 마지막으로 스트림을 닫는다.
+//
+// SPEC-044: `interactive` 승인 정책이면 작업은 `approval_requested` 이벤트를
+// 보내고, `POST /api/jobs/:id/approvals/:approval_id` 로 결정이 올 때까지
+// (또는 취소·시간 초과까지) 해당 시나리오를 멈춘다.
+
+use super::{AppState,
+            api::{RunRequest,
+                  RunResponse,
+                  run_eval_scenario_with_progress_impl},
+            api_exec::{RunScenarioReq,
+                       run_scenario_with_progress_impl}};
+use agent_models::{approval::{ApprovalDecision,
+                              ApprovalRequest},
+                   progress::{ProgressEvent,
+                              ProgressSink}};
+use axum::{extract::{Path as AxPath,
+                     State},
+           http::StatusCode,
+           response::{Json,
+                      sse::{Event,
+                            KeepAlive,
+                            Sse}}};
+use chrono::{DateTime,
+             Utc};
+use serde::{Deserialize,
+            Serialize};
+use serde_json::Value;
+use std::{collections::HashMap,
+          convert::Infallible,
+          sync::{Arc,
+                 Mutex,
+                 atomic::{AtomicBool,
+                          Ordering},
+                 mpsc},
+          time::{Duration,
+                 Instant}};
+use tokio::sync::mpsc::{UnboundedSender,
+                        unbounded_channel};
+use tokio_stream::{Stream,
+                   StreamExt,
+                   wrappers::UnboundedReceiverStream};
+
+/// 끝난 작업을 이 개수까지만 보관한다 (오래된 것부터 제거).
+const MAX_FINISHED_JOBS: usize = 50;
+
+/// 승인 요청에 답이 없으면 이 시간 뒤 거부한다.
+const APPROVAL_TIMEOUT: Duration = Duration::from_secs(600);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+/// SSE 로 보내는 이벤트 한 건. `seq` 는 작업 안에서 0 부터 증가한다.
+#[derive(Debug, Clone)]
+pub struct JobEvent {
+    pub seq: usize,
+    pub kind: String,
+    pub data: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobSummary {
+    pub id: String,
+    pub kind: String,
+    pub label: String,
+    pub status: JobStatus,
+    pub created_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub event_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobDetail {
+    #[serde(flatten)]
+    pub summary: JobSummary,
+    pub result: Option<Value>,
+    pub error: Option<String>,
+}
+
+struct JobState {
+    status: JobStatus,
+    finished_at: Option<DateTime<Utc>>,
+    events: Vec<JobEvent>,
+    subscribers: Vec<UnboundedSender<JobEvent>>,
+    result: Option<Value>,
+    error: Option<String>,
+    /// SPEC-044: 답을 기다리는 승인 요청 (approval_id → 결정 채널)
+    approvals: HashMap<String, mpsc::Sender<ApprovalDecision>>,
+}
+
+pub struct Job {
+    pub id: String,
+    pub kind: String,
+    pub label: String,
+    pub created_at: DateTime<Utc>,
+    cancel_requested: AtomicBool,
+    state: Mutex<JobState>,
+}
+
+impl Job {
+    fn new(kind: &str, label: &str) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            kind: kind.to_string(),
+            label: label.to_string(),
+            created_at: Utc::now(),
+            cancel_requested: AtomicBool::new(false),
+            state: Mutex::new(JobState {
+                status: JobStatus::Running,
+                finished_at: None,
+                events: Vec::new(),
+                subscribers: Vec::new(),
+                result: None,
+                error: None,
+                approvals: HashMap::new(),
+            }),
+        }
+    }
+
+    /// 이벤트를 기록하고 구독자에게 보낸다. 끊긴 구독자는 정리한다.
+    fn push(&self, kind: &str, data: Value) {
+        let mut st = self.state.lock().unwrap();
+        let event = JobEvent {
+            seq: st.events.len(),
+            kind: kind.to_string(),
+            data,
+        };
+        st.subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+        st.events.push(event);
+    }
+
+    /// 결과를 기록하고 `job_finished` 이벤트를 보낸 뒤 구독 채널을 닫는다.
+    /// 취소 요청 뒤 실패로 끝난 작업은 `cancelled` 로 기록한다.
+    fn finish(&self, outcome: Result<Value, String>) {
+        let status = match &outcome {
+            | Ok(_) => JobStatus::Succeeded,
+            | Err(_) if self.cancel_requested.load(Ordering::SeqCst) => JobStatus::Cancelled,
+            | Err(_) => JobStatus::Failed,
+        };
+        let (result, error) = match outcome {
+            | Ok(v) => (Some(v), None),
+            | Err(e) => (None, Some(e)),
+        };
+        self.push("job_finished", serde_json::json!({ "status": status, "result": result, "error": error }));
+        let mut st = self.state.lock().unwrap();
+        st.status = status;
+        st.finished_at = Some(Utc::now());
+        st.result = result;
+        st.error = error;
+        st.subscribers.clear();
+    }
+
+    /// 실행 중이면 취소를 요청하고 true. 이미 끝났으면 false.
+    pub fn cancel(&self) -> bool {
+        if self.status() != JobStatus::Running {
+            return false;
+        }
+        if !self.cancel_requested.swap(true, Ordering::SeqCst) {
+            self.push("cancel_requested", serde_json::json!({}));
+        }
+        true
+    }
+
+    pub fn status(&self) -> JobStatus { self.state.lock().unwrap().status }
+
+    /// 기다리는 승인 요청에 결정을 전달한다. 그런 요청이 없으면 false.
+    ///
+    /// @trace SPEC: SPEC-044
+    /// @trace FR: PRD-044/FR-2
+    pub fn resolve_approval(&self, approval_id: &str, decision: ApprovalDecision) -> bool {
+        let tx = self.state.lock().unwrap().approvals.remove(approval_id);
+        tx.is_some_and(|tx| tx.send(decision).is_ok())
+    }
+
+    /// 지금까지의 이벤트와 이후 이벤트를 받을 스트림을 원자적으로 얻는다.
+    /// 이미 끝난 작업이면 스트림은 바로 닫혀 있다.
+    fn subscribe(&self) -> (Vec<JobEvent>, UnboundedReceiverStream<JobEvent>) {
+        let mut st = self.state.lock().unwrap();
+        let (tx, rx) = unbounded_channel();
+        if st.status == JobStatus::Running {
+            st.subscribers.push(tx);
+        }
+        (st.events.clone(), UnboundedReceiverStream::new(rx))
+    }
+
+    pub fn summary(&self) -> JobSummary {
+        let st = self.state.lock().unwrap();
+        JobSummary {
+            id: self.id.clone(),
+            kind: self.kind.clone(),
+            label: self.label.clone(),
+            status: st.status,
+            created_at: self.created_at,
+            finished_at: st.finished_at,
+            event_count: st.events.len(),
+        }
+    }
+
+    pub fn detail(&self) -> JobDetail {
+        let summary = self.summary();
+        let st = self.state.lock().unwrap();
+        JobDetail {
+            summary,
+            result: st.result.clone(),
+            error: st.error.clone(),
+        }
+    }
+}
+
+impl ProgressSink for Job {
+    fn emit(&self, event: ProgressEvent) {
+        let data = serde_json::to_value(&event).unwrap_or(Value::Null);
+        let kind = data.get("type").and_then(|t| t.as_str()).unwrap_or("progress").to_string();
+        self.push(&kind, data);
+    }
+
+    fn is_cancelled(&self) -> bool { self.cancel_requested.load(Ordering::SeqCst) }
+
+    /// `approval_requested` 를 보내고 결정을 기다린다. 취소되거나
+    /// `APPROVAL_TIMEOUT` 이 지나면 거부한다.
+    ///
+    /// @trace SPEC: SPEC-044
+    /// @trace FR: PRD-044/FR-2
+    fn request_approval(&self, request: &ApprovalRequest) -> Option<ApprovalDecision> {
+        let approval_id = uuid::Uuid::new_v4().to_string();
+        let (tx, rx) = mpsc::channel();
+        self.state.lock().unwrap().approvals.insert(approval_id.clone(), tx);
+        let mut data = serde_json::to_value(request).unwrap_or(Value::Null);
+        data["approval_id"] = Value::from(approval_id.as_str());
+        self.push("approval_requested", data);
+
+        let deadline = Instant::now() + APPROVAL_TIMEOUT;
+        let decision = loop {
+            match rx.recv_timeout(Duration::from_millis(200)) {
+                | Ok(decision) => break decision,
+                | Err(mpsc::RecvTimeoutError::Timeout) if !self.is_cancelled() && Instant::now() < deadline => continue,
+                | Err(_) => break ApprovalDecision::Denied,
+            }
+        };
+        self.state.lock().unwrap().approvals.remove(&approval_id);
+        self.push("approval_resolved", serde_json::json!({ "approval_id": approval_id, "decision": decision }));
+        Some(decision)
+    }
+}
+
+/// 서버 수명 동안의 작업 목록.
+#[derive(Default)]
+pub struct JobRegistry {
+    jobs: Mutex<HashMap<String, Arc<Job>>>,
+}
+
+impl JobRegistry {
+    pub fn get(&self, id: &str) -> Option<Arc<Job>> { self.jobs.lock().unwrap().get(id).cloned() }
+
+    /// 생성 시각 내림차순.
+    pub fn list(&self) -> Vec<JobSummary> {
+        let mut out: Vec<JobSummary> = self.jobs.lock().unwrap().values().map(|j| j.summary()).collect();
+        out.sort_by_key(|j| std::cmp::Reverse(j.created_at));
+        out
+    }
+
+    /// `work` 를 blocking 스레드에서 실행하는 작업을 등록하고 곧바로 반환한다.
+    /// `work` 는 작업 자신을 ProgressSink 로 받는다.
+    ///
+    /// @trace SPEC: SPEC-040
+    /// @trace FR: PRD-040/FR-1
+    pub fn spawn<F>(&self, kind: &str, label: &str, work: F) -> Arc<Job>
+    where
+        F: FnOnce(Arc<Job>) -> Result<Value, String> + Send + 'static,
+    {
+        let job = Arc::new(Job::new(kind, label));
+        job.push("job_started", serde_json::json!({ "id": job.id, "kind": kind, "label": label }));
+        {
+            let mut jobs = self.jobs.lock().unwrap();
+            prune_finished(&mut jobs);
+            jobs.insert(job.id.clone(), job.clone());
+        }
+        println!("▶ [web] 작업 시작: {} {} (job={})", kind, label, job.id);
+        let worker = job.clone();
+        tokio::task::spawn_blocking(move || {
+            let outcome =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| work(worker.clone()))).unwrap_or_else(|_| Err("작업 실행 중 패닉".to_string()));
+            worker.finish(outcome);
+            println!("✔ [web] 작업 종료: {:?} (job={})", worker.status(), worker.id);
+        });
+        job
+    }
+}
+
+fn prune_finished(jobs: &mut HashMap<String, Arc<Job>>) {
+    let mut finished: Vec<(DateTime<Utc>, String)> = jobs
+        .values()
+        .filter(|j| j.status() != JobStatus::Running)
+        .map(|j| (j.created_at, j.id.clone()))
+        .collect();
+    if finished.len() < MAX_FINISHED_JOBS {
+        return;
+    }
+    finished.sort();
+    for (_, id) in finished.iter().take(finished.len() + 1 - MAX_FINISHED_JOBS) {
+        jobs.remove(id);
+    }
+}
+
+/// 작업 이벤트 스트림. 지난 이벤트를 재생한 뒤 실시간 이벤트를 이어 붙인다.
+///
+/// @trace SPEC: SPEC-040
+/// @trace FR: PRD-040/FR-3
+pub fn job_event_stream(job: &Job) -> impl Stream<Item = JobEvent> + Send + 'static {
+    let (history, live) = job.subscribe();
+    tokio_stream::iter(history).chain(live)
+}
+
+// -------- axum handler wrappers --------
+
+#[derive(Serialize)]
+pub struct JobCreated {
+    pub job_id: String,
+    pub status: JobStatus,
+}
+
+fn created(job: &Job) -> (StatusCode, Json<JobCreated>) {
+    (
+        StatusCode::ACCEPTED,
+        Json(JobCreated {
+            job_id: job.id.clone(),
+            status: job.status(),
+        }),
+    )
+}
+
+/// `POST /api/jobs/run` — `/api/run` 과 같은 요청을 백그라운드 작업으로 실행.
+pub async fn start_run_job(State(st): State<AppState>, Json(req): Json<RunRequest>) -> (StatusCode, Json<JobCreated>) {
+    let (scen, reps) = (st.scenarios_dir.clone(), st.reports_dir.clone());
+    let label = format!("{} ({})", req.eval_scenario, req.agent);
+    let job = st.jobs.spawn("run", &label, move |job| {
+        let (report, saved_to) =
+            run_eval_scenario_with_progress_impl(&req.eval_scenario, &req.agent, &scen, &reps, req.output.as_deref(), &req.options, Some(job))?;
+        serde_json::to_value(RunResponse {
+            report,
+            saved_to,
+        })
+        .map_err(|e| e.to_string())
+    });
+    created(&job)
+}
+
+/// `POST /api/jobs/scenarios/:domain/:id/run` — 단일 시나리오 실행 작업.
+pub async fn start_scenario_job(
+    State(st): State<AppState>,
+    AxPath((domain, id)): AxPath<(String, String)>,
+    Json(req): Json<RunScenarioReq>,
+) -> (StatusCode, Json<JobCreated>) {
+    let (scen, reps) = (st.scenarios_dir.clone(), st.reports_dir.clone());
+    let label = format!("{}/{} ({})", domain, id, req.agent);
+    let job = st.jobs.spawn("scenario", &label, move |job| {
+        let evaluation = run_scenario_with_progress_impl(&scen, &reps, &domain, &id, &req.agent, Some(job), req.approval)?;
+        serde_json::to_value(evaluation).map_err(|e| e.to_string())
+    });
+    created(&job)
+}
+
+pub async fn list_jobs(State(st): State<AppState>) -> Json<Vec<JobSummary>> { Json(st.jobs.list()) }
+
+pub async fn get_job(State(st): State<AppState>, AxPath(id): AxPath<String>) -> Result<Json<JobDetail>, StatusCode> {
+    st.jobs.get(&id).map(|j| Json(j.detail())).ok_or(StatusCode::NOT_FOUND)
+}
+
+/// `POST /api/jobs/:id/cancel` — 실행 중이면 202, 이미 끝났으면 409.
+/// 취소는 진행 중인 시나리오의 다음 PPA 반복 경계에서 적용된다.
+pub async fn cancel_job(State(st): State<AppState>, AxPath(id): AxPath<String>) -> Result<StatusCode, (StatusCode, String)> {
+    let job = st.jobs.get(&id).ok_or((StatusCode::NOT_FOUND, format!("job '{id}' not found")))?;
+    if job.cancel() {
+        Ok(StatusCode::ACCEPTED)
+    } else {
+        Err((StatusCode::CONFLICT, format!("job '{id}' already {:?}", job.status()).to_lowercase()))
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ApprovalBody {
+    pub decision: ApprovalDecision,
+}
+
+/// `POST /api/jobs/:id/approvals/:approval_id` — `{"decision": "approved" |
+/// "denied"}`. 기다리는 요청이 없으면 404.
+///
+/// @trace SPEC: SPEC-044
+/// @trace FR: PRD-044/FR-2
+pub async fn resolve_approval(
+    State(st): State<AppState>,
+    AxPath((id, approval_id)): AxPath<(String, String)>,
+    Json(body): Json<ApprovalBody>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let job = st.jobs.get(&id).ok_or((StatusCode::NOT_FOUND, format!("job '{id}' not found")))?;
+    if job.resolve_approval(&approval_id, body.decision) {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err((StatusCode::NOT_FOUND, format!("approval '{approval_id}' is not pending")))
+    }
+}
+
+/// `GET /api/jobs/:id/events` — SSE. 이벤트 이름은 `type` (`scenario_started`,
+/// `step`, `tool_call`, `job_finished` 등), `id` 는 seq, 데이터는 JSON.
+pub async fn job_events(State(st): State<AppState>, AxPath(id): AxPath<String>) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, StatusCode> {
+    let job = st.jobs.get(&id).ok_or(StatusCode::NOT_FOUND)?;
+    let stream = job_event_stream(&job).map(|e| Ok(Event::default().event(e.kind).id(e.seq.to_string()).data(e.data.to_string())));
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-040
+    // @trace PRD: PRD-040
+    // @trace FR: PRD-040/FR-1, PRD-040/FR-3, PRD-040/FR-4
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use std::time::Duration;
+
+    async fn wait_finished(job: &Job) {
+        for _ in 0 .. 200 {
+            if job.status() != JobStatus::Running {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("job did not finish");
+    }
+
+    /// @trace TC: SPEC-040/TC-5
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn spec040_tc_5_job_streams_history_then_live_events() {
+        let registry = JobRegistry::default();
+        let (go_tx, go_rx) = std::sync::mpsc::channel::<()>();
+        let job = registry.spawn("test", "demo", move |job| {
+            job.emit(ProgressEvent::Step {
+                task_id: "t".into(),
+                stage: agent_models::models::PpaStage::Perceive,
+                iteration: 1,
+                duration_ms: None,
+            });
+            go_rx.recv().unwrap();
+            Ok(serde_json::json!({"answer": 42}))
+        });
+
+        // 늦게 붙은 구독자도 job_started 부터 받는다.
+        let mut stream = Box::pin(job_event_stream(&job));
+        assert_eq!(stream.next().await.unwrap().kind, "job_started");
+        assert_eq!(stream.next().await.unwrap().kind, "step");
+        go_tx.send(()).unwrap();
+        let last = stream.next().await.unwrap();
+        assert_eq!((last.seq, last.kind.as_str()), (2, "job_finished"));
+        assert_eq!(last.data["status"], "succeeded");
+        assert_eq!(last.data["result"]["answer"], 42);
+        assert!(stream.next().await.is_none(), "작업이 끝나면 스트림 종료");
+
+        wait_finished(&job).await;
+        let detail = registry.get(&job.id).unwrap().detail();
+        assert_eq!(detail.summary.status, JobStatus::Succeeded);
+        assert_eq!(detail.summary.event_count, 3);
+        assert_eq!(registry.list().len(), 1);
+
+        // 끝난 작업의 스트림은 기록만 재생하고 닫힌다.
+        let replay: Vec<JobEvent> = job_event_stream(&job).collect().await;
+        assert_eq!(replay.len(), 3);
+        assert!(!job.cancel(), "끝난 작업은 취소 불가");
+    }
+
+    /// @trace TC: SPEC-040/TC-6
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn spec040_tc_6_cancel_marks_job_cancelled() {
+        let registry = JobRegistry::default();
+        let job = registry.spawn("test", "loop", |job| {
+            while !job.is_cancelled() {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            Err("실행이 취소되었습니다".into())
+        });
+        assert!(job.cancel());
+        wait_finished(&job).await;
+        let detail = job.detail();
+        assert_eq!(detail.summary.status, JobStatus::Cancelled);
+        assert_eq!(detail.error.as_deref(), Some("실행이 취소되었습니다"));
+        let kinds: Vec<String> = job_event_stream(&job).map(|e| e.kind).collect().await;
+        assert_eq!(kinds, vec!["job_started", "cancel_requested", "job_finished"]);
+
+        let failing = registry.spawn("test", "panic", |_| panic!("boom"));
+        wait_finished(&failing).await;
+        assert_eq!(failing.detail().summary.status, JobStatus::Failed);
+    }
+
+    /// @trace TC: SPEC-044/TC-4
+    /// @trace FR: PRD-044/FR-2
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn spec044_tc_4_job_waits_for_approval_decision() {
+        let registry = JobRegistry::default();
+        let job = registry.spawn("test", "approval", |job| {
+            let decision = job.request_approval(&ApprovalRequest {
+                task_id: "t".into(),
+                iteration: 2,
+                tool_name: "write_file".into(),
+                parameters: HashMap::from([("path".to_string(), serde_json::json!("out.txt"))]),
+            });
+            Ok(serde_json::to_value(decision).unwrap())
+        });
+
+        let mut stream = Box::pin(job_event_stream(&job));
+        assert_eq!(stream.next().await.unwrap().kind, "job_started");
+        let requested = stream.next().await.unwrap();
+        assert_eq!(requested.kind, "approval_requested");
+        assert_eq!(requested.data["tool_name"], "write_file");
+        assert_eq!(requested.data["parameters"]["path"], "out.txt");
+        let approval_id = requested.data["approval_id"].as_str().unwrap().to_string();
+
+        assert!(!job.resolve_approval("nope", ApprovalDecision::Approved));
+        assert!(job.resolve_approval(&approval_id, ApprovalDecision::Approved));
+        let resolved = stream.next().await.unwrap();
+        assert_eq!(
+            (resolved.kind.as_str(), resolved.data["decision"].as_str()),
+            ("approval_resolved", Some("approved"))
+        );
+        let last = stream.next().await.unwrap();
+        assert_eq!(last.data["result"], "approved");
+        assert!(!job.resolve_approval(&approval_id, ApprovalDecision::Denied), "이미 결정된 요청");
+
+        // 취소되면 기다리던 요청은 거부된다.
+        let waiting = registry.spawn("test", "cancelled", |job| {
+            Ok(serde_json::to_value(job.request_approval(&ApprovalRequest {
+                task_id: "t".into(),
+                iteration: 1,
+                tool_name: "write_file".into(),
+                parameters: HashMap::new(),
+            }))
+            .unwrap())
+        });
+        let mut stream = Box::pin(job_event_stream(&waiting));
+        stream.next().await.unwrap();
+        assert_eq!(stream.next().await.unwrap().kind, "approval_requested");
+        assert!(waiting.cancel());
+        wait_finished(&waiting).await;
+        assert_eq!(waiting.detail().result, Some(serde_json::json!("denied")));
+    }
+}
@@ -6,10 +6,14 @@
 // =============================================================================
 
 pub mod api;
+pub mod api_bundle;
 pub mod api_crud;
+pub mod api_drafts;
 pub mod api_exec;
+pub mod api_judge;
 pub mod db_query;
 pub mod handlers;
+pub mod jobs;
 
 use axum::{Router,
            routing::{get,
@@ -34,6 +38,8 @@ pub struct AppState {
     /// @trace SPEC: SPEC-019
     /// @trace FR: PRD-019/FR-7
     pub store: Option<Arc<SqliteStore>>,
+    /// SPEC-040: 백그라운드 실행 작업 목록.
+    pub jobs: Arc<jobs::JobRegistry>,
 }
 
 /// axum 라우터 빌드. 테스트에서도 재사용 가능.
@@ -80,6 +86,11 @@ pub fn build_router(state: AppState) -> Router {
         .route("/api/external-tools", get(api_crud::list_external_tools))
         .route("/api/external-tools/:domain", get(api_crud::list_external_tools_by_domain).post(api_crud::create_external_tool))
         .route("/api/external-tools/:domain/:name", put(api_crud::update_external_tool_handler).delete(api_crud::delete_external_tool_handler))
+        // -------- SPEC-037: MCP servers --------
+        .route("/api/mcp-servers", get(api_crud::list_mcp_servers))
+        .route("/api/mcp-servers/:domain", get(api_crud::list_mcp_servers_by_domain).post(api_crud::create_mcp_server))
+        .route("/api/mcp-servers/:domain/:name", put(api_crud::update_mcp_server_handler).delete(api_crud::delete_mcp_server_handler))
+        .route("/api/mcp-servers/:domain/:name/tools", get(api_crud::list_mcp_server_tools))
         // -------- SPEC-025: prompt_sets CRUD --------
         .route(
             "/api/domains/:name/prompts",
@@ -90,6 +101,25 @@ pub fn build_router(state: AppState) -> Router {
             get(api_crud::get_prompt_set_handler).delete(api_crud::delete_prompt_set_handler),
         )
         .route("/api/domains/:name/prompts/:version/activate", put(api_crud::activate_prompt_set_handler))
+        // -------- SPEC-042: LLM 생성 시나리오 초안 --------
+        .route("/api/domains/:name/scenario-drafts/generate", post(api_drafts::generate))
+        .route("/api/scenario-drafts", get(api_drafts::list_drafts))
+        .route("/api/scenario-drafts/:id/approve", post(api_drafts::approve_draft))
+        .route("/api/scenario-drafts/:id/reject", post(api_drafts::reject_draft))
+        // -------- SPEC-043: 도메인 번들 내보내기/가져오기 --------
+        .route("/api/domains/:name/bundle", get(api_bundle::export_bundle))
+        .route("/api/domains/import", post(api_bundle::import_bundle))
+        // -------- SPEC-040: background run jobs --------
+        .route("/api/jobs", get(jobs::list_jobs))
+        .route("/api/jobs/run", post(jobs::start_run_job))
+        .route("/api/jobs/scenarios/:domain/:id/run", post(jobs::start_scenario_job))
+        .route("/api/jobs/:id", get(jobs::get_job))
+        .route("/api/jobs/:id/events", get(jobs::job_events))
+        .route("/api/jobs/:id/cancel", post(jobs::cancel_job))
+        // -------- SPEC-044: tool approval --------
+        .route("/api/jobs/:id/approvals/:approval_id", post(jobs::resolve_approval))
+        // -------- SPEC-027: pairwise judge --------
+        .route("/api/judge/pairwise", post(api_judge::judge_pairwise))
         .with_state(state)
 }
 
@@ -128,6 +158,7 @@ pub fn run_server(
             golden_sets_dir,
             trajectories_dir,
             store,
+            jobs: Arc::default(),
         };
         let app = build_router(state);
         let listener = tokio::net::TcpListener::bind(addr).await?;
@@ -33,6 +33,20 @@ pub struct GoldenSetResult {
     /// 실제로 처음 호출된 도구의 도메인(디버깅용). None 이면 tool call 없음.
     #[serde(default)]
     pub actual_first_domain: Option<String>,
+    /// LLM judge 의 차원별 채점 원본(`RubricJudgeResult` 직렬화). judge 미사용
+    /// 시 None.
+    #[serde(default)]
+    pub llm_judge_detail: Option<serde_json::Value>,
+}
+
+/// LLM judge 1회 채점 결과. `score` 는 루브릭 가중 합(0.0-1.0),
+/// `detail` 은 judge 구현체가 남기는 차원별 원본 JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JudgeVerdict {
+    pub rubric_name: String,
+    pub score: f64,
+    pub reasoning: String,
+    pub detail: serde_json::Value,
 }
 
 /// 평가 메트릭
@@ -57,6 +71,55 @@ pub struct EvaluationMetrics {
     pub adaptability: Option<f64>,
     // 골든셋 검증
     pub golden_set_score: Option<f64>,
+    // LLM-as-judge 루브릭 가중 점수 (0.0-1.0)
+    #[serde(default)]
+    pub llm_judge_score: Option<f64>,
+    // 폴트 주입 복원력 (SPEC-031). 해당 종류의 주입이 없으면 None.
+    /// 실패로 드러난 주입 후 같은 도구 재호출 성공 또는 작업 성공 비율
+    #[serde(default)]
+    pub fault_recovery_rate: Option<f64>,
+    /// 실패로 드러난 주입 한 건당 같은 도구 재시도 횟수 평균
+    #[serde(default)]
+    pub fault_retries_after_failure: Option<f64>,
+    /// 오염된 성공 결과를 검증 없이 그대로 받아들인 비율 (낮을수록 좋음)
+    #[serde(default)]
+    pub fault_silent_acceptance_rate: Option<f64>,
+    /// 권한 거부 후 사람 승인으로 에스컬레이션한 비율
+    #[serde(default)]
+    pub fault_permission_escalation_rate: Option<f64>,
+    // 멀티턴 대화 (SPEC-032). 단일 턴 시나리오는 None.
+    /// 성공한 턴 비율
+    #[serde(default)]
+    pub conversation_turn_success_rate: Option<f64>,
+    /// 턴별 기대 도구/성공 조건 점수(골든셋 점수)의 평균
+    #[serde(default)]
+    pub conversation_turn_score: Option<f64>,
+    /// 턴별 기대 컨텍스트 키 유지율의 평균
+    #[serde(default)]
+    pub conversation_context_retention: Option<f64>,
+    // 토큰/비용 (SPEC-035). 에이전트가 사용량을 보고하지 않으면 None.
+    #[serde(default)]
+    pub prompt_tokens: Option<f64>,
+    #[serde(default)]
+    pub completion_tokens: Option<f64>,
+    #[serde(default)]
+    pub total_tokens: Option<f64>,
+    /// 단가표(`[pricing]`)로 환산한 실행 비용 (USD). 모델 단가가 없으면 None.
+    #[serde(default)]
+    pub cost_usd: Option<f64>,
+    /// 골든셋/시나리오의 기대 도메인과 라우터 결정의 일치 여부 (SPEC-036).
+    /// 시나리오 평균이 곧 라우팅 정확도다.
+    #[serde(default)]
+    pub domain_routing_score: Option<f64>,
+    /// 승인 게이트를 거친 위험 도구 호출 중, 같은 반복의 Policy 단계가
+    /// `requires_human_approval` 로 승인을 먼저 요청한 비율 (SPEC-044).
+    /// 게이트를 거친 호출이 없으면 None.
+    #[serde(default)]
+    pub approval_request_rate: Option<f64>,
+    /// 작업 공간 최종 상태가 시나리오의 기대 파일과 일치한 비율 (SPEC-045).
+    /// 기대 파일이 없으면 None.
+    #[serde(default)]
+    pub workspace_match_score: Option<f64>,
 }
 
 impl EvaluationMetrics {
@@ -76,6 +139,21 @@ impl EvaluationMetrics {
         m.insert("safety_score".into(), self.safety_score);
         m.insert("adaptability".into(), self.adaptability);
         m.insert("golden_set_score".into(), self.golden_set_score);
+        m.insert("llm_judge_score".into(), self.llm_judge_score);
+        m.insert("fault_recovery_rate".into(), self.fault_recovery_rate);
+        m.insert("fault_retries_after_failure".into(), self.fault_retries_after_failure);
+        m.insert("fault_silent_acceptance_rate".into(), self.fault_silent_acceptance_rate);
+        m.insert("fault_permission_escalation_rate".into(), self.fault_permission_escalation_rate);
+        m.insert("conversation_turn_success_rate".into(), self.conversation_turn_success_rate);
+        m.insert("conversation_turn_score".into(), self.conversation_turn_score);
+        m.insert("conversation_context_retention".into(), self.conversation_context_retention);
+        m.insert("prompt_tokens".into(), self.prompt_tokens);
+        m.insert("completion_tokens".into(), self.completion_tokens);
+        m.insert("total_tokens".into(), self.total_tokens);
+        m.insert("cost_usd".into(), self.cost_usd);
+        m.insert("domain_routing_score".into(), self.domain_routing_score);
+        m.insert("approval_request_rate".into(), self.approval_request_rate);
+        m.insert("workspace_match_score".into(), self.workspace_match_score);
         m
     }
 }
@@ -0,0 +1,107 @@
+// =============================================================================
+// @trace SPEC-035
+// @trace PRD: PRD-035
+// @trace FR: PRD-035/FR-2
+// @trace file-type: impl
+// =============================================================================
+//
+// 모델별 토큰 단가표. `eval-harness.toml` 의 `[pricing]` 섹션에서 읽어
+// 궤적의 토큰 사용량을 비용(USD)으로 환산한다.
+
+use agent_models::models::TokenUsage;
+use serde::{Deserialize,
+            Serialize};
+use std::collections::HashMap;
+
+/// 100만 토큰당 단가 (USD).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModelPrice {
+    pub prompt_per_million: f64,
+    pub completion_per_million: f64,
+}
+
+impl ModelPrice {
+    pub fn cost(&self, usage: &TokenUsage) -> f64 {
+        (usage.prompt_tokens as f64 * self.prompt_per_million + usage.completion_tokens as f64 * self.completion_per_million) / 1_000_000.0
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PriceTable {
+    /// 모델(배포) 이름 → 단가.
+    #[serde(default)]
+    pub models: HashMap<String, ModelPrice>,
+    /// 표에 없는 모델에 적용할 단가. 없으면 비용을 계산하지 않는다.
+    #[serde(default)]
+    pub default: Option<ModelPrice>,
+}
+
+impl PriceTable {
+    /// 정확히 일치하는 이름, 모델 이름의 접두사 중 가장 긴 키
+    /// (`gpt-4o-mini` → `gpt-4o-mini-2024-07-18`), `default` 순으로 찾는다.
+    ///
+    /// @trace SPEC: SPEC-035
+    /// @trace FR: PRD-035/FR-2
+    pub fn price_for(&self, model: Option<&str>) -> Option<&ModelPrice> {
+        let by_name = model.and_then(|m| {
+            self.models.get(m).or_else(|| {
+                self.models
+                    .iter()
+                    .filter(|(k, _)| m.starts_with(k.as_str()))
+                    .max_by_key(|(k, _)| k.len())
+                    .map(|(_, p)| p)
+            })
+        });
+        by_name.or(self.default.as_ref())
+    }
+
+    pub fn cost(&self, model: Option<&str>, usage: &TokenUsage) -> Option<f64> { self.price_for(model).map(|p| p.cost(usage)) }
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-035
+    // @trace PRD: PRD-035
+    // @trace FR: PRD-035/FR-2
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+
+    fn usage(prompt: u64, completion: u64) -> TokenUsage {
+        TokenUsage {
+            prompt_tokens: prompt,
+            completion_tokens: completion,
+            total_tokens: prompt + completion,
+        }
+    }
+
+    /// @trace TC: SPEC-035/TC-1
+    /// @trace FR: PRD-035/FR-2
+    #[test]
+    fn spec035_tc_1_price_lookup_exact_prefix_default() {
+        let mut table = PriceTable::default();
+        table.models.insert("gpt-4o".into(), ModelPrice {
+            prompt_per_million: 2.5,
+            completion_per_million: 10.0,
+        });
+        table.models.insert("gpt-4o-mini".into(), ModelPrice {
+            prompt_per_million: 0.15,
+            completion_per_million: 0.6,
+        });
+
+        let u = usage(1_000_000, 500_000);
+        assert_eq!(table.cost(Some("gpt-4o"), &u), Some(7.5));
+        // 더 긴 접두사 키가 우선
+        assert!((table.cost(Some("gpt-4o-mini-2024-07-18"), &u).unwrap() - 0.45).abs() < 1e-9);
+        assert_eq!(table.cost(Some("llama3.1"), &u), None);
+        assert_eq!(table.cost(None, &u), None);
+
+        table.default = Some(ModelPrice {
+            prompt_per_million: 0.0,
+            completion_per_million: 0.0,
+        });
+        assert_eq!(table.cost(Some("llama3.1"), &u), Some(0.0));
+    }
+}
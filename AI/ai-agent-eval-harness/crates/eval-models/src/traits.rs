@@ -1,3 +1,5 @@
+use crate::models::JudgeVerdict;
+use agent_models::models::Trajectory;
 use std::collections::HashMap;
 
 /// 평가기가 시나리오로부터 필요로 하는 최소 계약.
@@ -6,6 +8,8 @@ use std::collections::HashMap;
 pub trait EvalContext {
     fn expected_tools(&self) -> &[String];
     fn success_criteria(&self) -> &HashMap<String, serde_json::Value>;
+    /// SPEC-036: 라우터가 골라야 할 도메인. 모르면 None (라우팅 미채점).
+    fn expected_domain(&self) -> Option<&str> { None }
 }
 
 /// 골든셋 엔트리로부터 평가기가 필요로 하는 최소 계약.
@@ -15,3 +19,9 @@ pub trait GoldenSetContext {
     fn tolerance(&self) -> f64;
     fn expected_domain(&self) -> Option<&str>;
 }
+
+/// 궤적 전체를 정성 채점하는 심사기 계약(LLM-as-judge 등).
+/// `scoring` 이 LLM 클라이언트에 직접 의존하지 않도록 트레이트로 분리한다.
+pub trait TrajectoryJudge: Send + Sync {
+    fn judge(&self, trajectory: &Trajectory) -> Result<JudgeVerdict, String>;
+}
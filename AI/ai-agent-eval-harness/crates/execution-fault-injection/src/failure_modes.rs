@@ -1,18 +1,43 @@
 #![allow(dead_code)]
-use crate::models::FailureMode;
-use execution_tools::base::ToolMetadata;
+use crate::models::{FailureMode,
+                    FaultInjectionConfig,
+                    LatencyDistribution};
+use execution_tools::base::{BaseTool,
+                            ToolMetadata};
 use rand::Rng;
 use std::collections::HashMap;
 
+/// SPEC-030 모드의 생성 파라미터. `FaultInjectionConfig` 에서 가져온다.
+#[derive(Debug, Clone)]
+pub struct ModeSettings {
+    pub latency: LatencyDistribution,
+    pub retry_after_secs: u64,
+    pub stale_age_secs: u64,
+}
+
+impl ModeSettings {
+    pub fn from_config(config: &FaultInjectionConfig) -> Self {
+        Self {
+            latency: config.latency.clone(),
+            retry_after_secs: config.retry_after_secs,
+            stale_age_secs: config.stale_age_secs,
+        }
+    }
+}
+
 pub struct FailureModeGenerator;
 
 impl FailureModeGenerator {
+    /// 실패 결과를 만든다. SPEC-030 의 latency / malformed / stale 모드는
+    /// 원본 도구를 실제로 실행한 결과를 가공하므로 `original` 이 필요하다.
     pub fn generate(
         failure_mode: &FailureMode,
-        tool_metadata: &ToolMetadata,
+        settings: &ModeSettings,
+        original: &dyn BaseTool,
         parameters: &HashMap<String, serde_json::Value>,
         rng: &mut impl Rng,
     ) -> HashMap<String, serde_json::Value> {
+        let tool_metadata = original.metadata();
         match failure_mode {
             | FailureMode::Timeout => Self::timeout(tool_metadata),
             | FailureMode::PartialResult => Self::partial_result(tool_metadata),
@@ -20,6 +45,36 @@ impl FailureModeGenerator {
             | FailureMode::Exception => Self::exception(tool_metadata, rng),
             | FailureMode::NetworkError => Self::network_error(tool_metadata),
             | FailureMode::PermissionDenied => Self::permission_denied(tool_metadata),
+            | FailureMode::Latency => Self::latency(&settings.latency, original, parameters, rng),
+            | FailureMode::RateLimited => Self::rate_limited(tool_metadata, settings.retry_after_secs),
+            | FailureMode::MalformedResult => Self::malformed_result(original, parameters, rng),
+            | FailureMode::StaleData => Self::stale_data(settings.stale_age_secs, original, parameters, rng),
+        }
+    }
+
+    /// 분포에서 지연(ms) 하나를 뽑는다.
+    pub fn sample_latency_ms(dist: &LatencyDistribution, rng: &mut impl Rng) -> u64 {
+        match dist {
+            | LatencyDistribution::Fixed {
+                ms,
+            } => *ms,
+            | LatencyDistribution::Uniform {
+                min_ms,
+                max_ms,
+            } =>
+                if max_ms <= min_ms {
+                    *min_ms
+                } else {
+                    rng.gen_range(*min_ms ..= *max_ms)
+                },
+            | LatencyDistribution::Exponential {
+                mean_ms,
+                max_ms,
+            } => {
+                let u: f64 = rng.gen();
+                let sample = -(*mean_ms as f64) * (1.0 - u).ln();
+                (sample.round() as u64).min(*max_ms)
+            },
         }
     }
 
@@ -97,4 +152,259 @@ impl FailureModeGenerator {
         m.insert("error_type".into(), serde_json::json!("permission_denied"));
         m
     }
+
+    fn latency(
+        dist: &LatencyDistribution,
+        original: &dyn BaseTool,
+        params: &HashMap<String, serde_json::Value>,
+        rng: &mut impl Rng,
+    ) -> HashMap<String, serde_json::Value> {
+        Self::delayed(Self::sample_latency_ms(dist, rng), original, params)
+    }
+
+    /// `ms` 만큼 기다린 뒤 원본 결과를 그대로 돌려준다. 재현 시에는 기록된
+    /// 지연으로 호출한다.
+    pub fn delayed(ms: u64, original: &dyn BaseTool, params: &HashMap<String, serde_json::Value>) -> HashMap<String, serde_json::Value> {
+        std::thread::sleep(std::time::Duration::from_millis(ms));
+        let mut m = original.execute(params);
+        m.insert("injected_latency_ms".into(), serde_json::json!(ms));
+        m.insert("error_type".into(), serde_json::json!("latency"));
+        m
+    }
+
+    fn rate_limited(meta: &ToolMetadata, retry_after_secs: u64) -> HashMap<String, serde_json::Value> {
+        let mut m = HashMap::new();
+        m.insert("success".into(), serde_json::Value::Bool(false));
+        m.insert(
+            "error".into(),
+            serde_json::json!(format!(
+                "HTTP 429 Too Many Requests: {} 호출 한도 초과 ({}초 후 재시도)",
+                meta.name, retry_after_secs
+            )),
+        );
+        m.insert("status_code".into(), serde_json::json!(429));
+        m.insert("retry_after_secs".into(), serde_json::json!(retry_after_secs));
+        m.insert("error_type".into(), serde_json::json!("rate_limited"));
+        m
+    }
+
+    /// 원본 결과를 JSON 중간에서 자르거나(truncated), 값의 타입을 바꿔
+    /// 스키마를 위반한다(schema_violation). 둘 다 `success: true` 로
+    /// 반환되어 에이전트가 스스로 이상을 알아채야 한다.
+    fn malformed_result(original: &dyn BaseTool, params: &HashMap<String, serde_json::Value>, rng: &mut impl Rng) -> HashMap<String, serde_json::Value> {
+        let result = original.execute(params);
+        let mut m = HashMap::new();
+        if rng.gen_bool(0.5) {
+            let text = serde_json::to_string(&result).unwrap_or_default();
+            let mut cut = if text.len() > 2 { rng.gen_range(1 .. text.len() - 1) } else { 0 };
+            while !text.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            m.insert("raw_response".into(), serde_json::json!(&text[.. cut]));
+            m.insert("malformed_kind".into(), serde_json::json!("truncated"));
+        } else {
+            for (key, value) in result {
+                if key == "success" {
+                    continue;
+                }
+                m.insert(key, Self::violate_type(value));
+            }
+            m.insert("malformed_kind".into(), serde_json::json!("schema_violation"));
+        }
+        m.insert("success".into(), serde_json::Value::Bool(true));
+        m.insert("error_type".into(), serde_json::json!("malformed_result"));
+        m
+    }
+
+    fn violate_type(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            | serde_json::Value::Number(n) => serde_json::json!(n.to_string()),
+            | serde_json::Value::String(s) => serde_json::json!(s.chars().count()),
+            | serde_json::Value::Bool(b) => serde_json::json!(if b { "yes" } else { "no" }),
+            | serde_json::Value::Null => serde_json::json!({}),
+            | serde_json::Value::Array(_) | serde_json::Value::Object(_) => serde_json::Value::Null,
+        }
+    }
+
+    /// 원본 결과의 실수 값을 과거 값처럼 흔들고(정수 id/카운트는 유지),
+    /// 기준 시점을 과거로 표기한다.
+    fn stale_data(
+        stale_age_secs: u64,
+        original: &dyn BaseTool,
+        params: &HashMap<String, serde_json::Value>,
+        rng: &mut impl Rng,
+    ) -> HashMap<String, serde_json::Value> {
+        let mut m = original.execute(params);
+        for value in m.values_mut() {
+            if let Some(n) = value.as_f64() {
+                if n != 0.0 && !value.is_u64() {
+                    let factor = rng.gen_range(0.8 .. 0.95);
+                    *value = serde_json::json!((n * factor * 100.0).round() / 100.0);
+                }
+            }
+        }
+        let as_of = chrono::Utc::now() - chrono::Duration::seconds(stale_age_secs as i64);
+        m.insert("data_as_of".into(), serde_json::json!(as_of.to_rfc3339()));
+        m.insert("cache_hit".into(), serde_json::Value::Bool(true));
+        m.insert("error_type".into(), serde_json::json!("stale_data"));
+        m
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-030
+    // @trace PRD: PRD-030
+    // @trace FR: PRD-030/FR-1, PRD-030/FR-2, PRD-030/FR-3, PRD-030/FR-4
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use rand::{SeedableRng,
+               rngs::StdRng};
+
+    struct InterestTool {
+        meta: ToolMetadata,
+    }
+
+    impl InterestTool {
+        fn new() -> Self {
+            Self {
+                meta: ToolMetadata {
+                    name: "interest".into(),
+                    description: "interest".into(),
+                    parameters_schema: serde_json::json!({}),
+                    safety_level: "safe".into(),
+                    requires_approval: false,
+                },
+            }
+        }
+    }
+
+    impl BaseTool for InterestTool {
+        fn metadata(&self) -> &ToolMetadata { &self.meta }
+
+        fn execute(&self, _params: &HashMap<String, serde_json::Value>) -> HashMap<String, serde_json::Value> {
+            HashMap::from([
+                ("success".to_string(), serde_json::json!(true)),
+                ("interest".to_string(), serde_json::json!(123.45)),
+                ("currency".to_string(), serde_json::json!("KRW")),
+                ("count".to_string(), serde_json::json!(3)),
+            ])
+        }
+    }
+
+    fn settings() -> ModeSettings { ModeSettings::from_config(&FaultInjectionConfig::default()) }
+
+    fn generate(mode: FailureMode, settings: &ModeSettings, seed: u64) -> HashMap<String, serde_json::Value> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        FailureModeGenerator::generate(&mode, settings, &InterestTool::new(), &HashMap::new(), &mut rng)
+    }
+
+    /// @trace TC: SPEC-030/TC-1
+    #[test]
+    fn spec030_tc_1_latency_distributions_stay_in_bounds() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let fixed = LatencyDistribution::Fixed {
+            ms: 40,
+        };
+        let uniform = LatencyDistribution::Uniform {
+            min_ms: 10,
+            max_ms: 20,
+        };
+        let exp = LatencyDistribution::Exponential {
+            mean_ms: 500,
+            max_ms: 800,
+        };
+        for _ in 0 .. 200 {
+            assert_eq!(FailureModeGenerator::sample_latency_ms(&fixed, &mut rng), 40);
+            assert!((10 ..= 20).contains(&FailureModeGenerator::sample_latency_ms(&uniform, &mut rng)));
+            assert!(FailureModeGenerator::sample_latency_ms(&exp, &mut rng) <= 800);
+        }
+    }
+
+    /// @trace TC: SPEC-030/TC-2
+    #[test]
+    fn spec030_tc_2_latency_mode_delays_then_returns_original() {
+        let mut s = settings();
+        s.latency = LatencyDistribution::Fixed {
+            ms: 30,
+        };
+        let started = std::time::Instant::now();
+        let m = generate(FailureMode::Latency, &s, 1);
+        assert!(started.elapsed() >= std::time::Duration::from_millis(30));
+        assert_eq!(m["interest"], serde_json::json!(123.45));
+        assert_eq!(m["injected_latency_ms"], serde_json::json!(30));
+        assert_eq!(m["error_type"], "latency");
+    }
+
+    /// @trace TC: SPEC-030/TC-3
+    #[test]
+    fn spec030_tc_3_rate_limited_carries_retry_after() {
+        let mut s = settings();
+        s.retry_after_secs = 7;
+        let m = generate(FailureMode::RateLimited, &s, 1);
+        assert_eq!(m["success"], false);
+        assert_eq!(m["status_code"], 429);
+        assert_eq!(m["retry_after_secs"], 7);
+        assert!(m["error"].as_str().unwrap().contains("429"));
+    }
+
+    /// @trace TC: SPEC-030/TC-4
+    #[test]
+    fn spec030_tc_4_malformed_result_truncates_or_violates_schema() {
+        let mut kinds = std::collections::HashSet::new();
+        for seed in 0 .. 20 {
+            let m = generate(FailureMode::MalformedResult, &settings(), seed);
+            assert_eq!(m["error_type"], "malformed_result");
+            match m["malformed_kind"].as_str().unwrap() {
+                | "truncated" => {
+                    let raw = m["raw_response"].as_str().unwrap();
+                    assert!(serde_json::from_str::<serde_json::Value>(raw).is_err(), "잘린 JSON 은 파싱되면 안 된다: {raw}");
+                },
+                | "schema_violation" => {
+                    assert!(m["interest"].is_string());
+                    assert!(m["currency"].is_number());
+                },
+                | other => panic!("unexpected kind {other}"),
+            }
+            kinds.insert(m["malformed_kind"].as_str().unwrap().to_string());
+        }
+        assert_eq!(kinds.len(), 2);
+    }
+
+    /// @trace TC: SPEC-030/TC-5
+    #[test]
+    fn spec030_tc_5_stale_data_is_backdated() {
+        let m = generate(FailureMode::StaleData, &settings(), 3);
+        let as_of = chrono::DateTime::parse_from_rfc3339(m["data_as_of"].as_str().unwrap()).unwrap();
+        assert!(chrono::Utc::now() - as_of.with_timezone(&chrono::Utc) >= chrono::Duration::seconds(86_000));
+        assert_ne!(m["interest"], serde_json::json!(123.45));
+        assert_eq!(m["count"], 3);
+        assert_eq!(m["success"], true);
+    }
+
+    /// @trace TC: SPEC-030/TC-6
+    #[test]
+    fn spec030_tc_6_config_without_new_fields_uses_defaults() {
+        let cfg: FaultInjectionConfig = serde_json::from_str(
+            r#"{"enabled":true,"global_failure_rate":0.5,"tool_specific_rates":{},"failure_mode_distribution":{"rate_limited":1.0},"seed":1}"#,
+        )
+        .unwrap();
+        assert_eq!(cfg.retry_after_secs, 2);
+        assert_eq!(cfg.latency, LatencyDistribution::default());
+        let cfg: FaultInjectionConfig = serde_json::from_value(serde_json::json!({
+            "enabled": true, "global_failure_rate": 0.5, "tool_specific_rates": {}, "failure_mode_distribution": {}, "seed": null,
+            "latency": {"kind": "exponential", "mean_ms": 100, "max_ms": 1000}
+        }))
+        .unwrap();
+        assert!(matches!(
+            cfg.latency,
+            LatencyDistribution::Exponential {
+                mean_ms: 100,
+                ..
+            }
+        ));
+    }
 }
@@ -1,27 +1,66 @@
 #![allow(dead_code)]
-use crate::{models::FaultInjectionConfig,
+use crate::{models::{FaultInjectionConfig,
+                     FaultReplay,
+                     InjectedFailure},
+            session::FaultSession,
             tool_wrapper::FaultInjectedTool};
 use execution_tools::registry::ToolRegistry;
-use std::sync::Arc;
+use std::{path::Path,
+          sync::Arc};
 
 pub struct FaultInjector {
     config: FaultInjectionConfig,
+    session: Arc<FaultSession>,
 }
 
 impl FaultInjector {
+    /// 주입기 하나가 실행 하나에 대응한다. 시드 RNG 스트림은 이 주입기가
+    /// 래핑한 모든 도구가 호출 순서대로 공유한다.
     pub fn new(config: FaultInjectionConfig) -> Self {
+        let session = Arc::new(FaultSession::new(config.seed));
         Self {
             config,
+            session,
         }
     }
 
+    /// 재현 파일의 호출 순번대로만 실패를 주입하는 주입기. `enabled` 와
+    /// 실패율 설정은 무시된다.
+    ///
+    /// @trace SPEC: SPEC-029
+    /// @trace FR: PRD-029/FR-3
+    pub fn from_replay(config: FaultInjectionConfig, replay: FaultReplay) -> Self {
+        Self {
+            config,
+            session: Arc::new(FaultSession::from_replay(replay)),
+        }
+    }
+
+    /// 지금까지 주입된 실패 목록 (호출 순번 순).
+    pub fn injected_failures(&self) -> Vec<InjectedFailure> { self.session.injected_failures() }
+
+    /// 궤적에 남길 주입 기록 (SPEC-031).
+    pub fn injected_faults(&self) -> Vec<agent_models::models::InjectedFault> {
+        self.session.injected_failures().iter().map(Into::into).collect()
+    }
+
+    /// 현재 세션을 재현 파일로 저장한다.
+    ///
+    /// @trace SPEC: SPEC-029
+    /// @trace FR: PRD-029/FR-2
+    pub fn save_replay(&self, path: &Path) -> std::io::Result<()> { self.session.replay().save(path) }
+
     pub fn wrap_registry(&self, registry: &ToolRegistry) -> ToolRegistry {
-        if !self.config.enabled {
+        if !self.config.enabled && !self.session.is_replay() {
             return ToolRegistry::new();
         }
 
+        // 원본 레지스트리 키(도메인 네임스페이스)를 그대로 유지한다.
         let mut wrapped = ToolRegistry::new();
-        for tool in registry.get_all_tools() {
+        for key in registry.get_tool_names() {
+            let Some(tool) = registry.get_tool(&key) else {
+                continue;
+            };
             let tool_name = tool.metadata().name.clone();
             let failure_rate = self
                 .config
@@ -30,14 +69,230 @@ impl FaultInjector {
                 .copied()
                 .unwrap_or(self.config.global_failure_rate);
 
-            let wrapped_tool = Arc::new(FaultInjectedTool::new(
-                tool,
-                failure_rate,
-                &self.config.failure_mode_distribution,
-                self.config.seed,
-            ));
-            wrapped.register(wrapped_tool);
+            let wrapped_tool = Arc::new(FaultInjectedTool::new(tool, failure_rate, &self.config, Arc::clone(&self.session)).with_key(&key));
+            match registry.get_tool_domain(&key) {
+                | Some(domain) if domain != "general" => wrapped.register_with_domain(wrapped_tool, domain),
+                | _ => wrapped.register(wrapped_tool),
+            }
         }
         wrapped
     }
 }
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-029
+    // @trace PRD: PRD-029
+    // @trace FR: PRD-029/FR-1, PRD-029/FR-2, PRD-029/FR-3
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use crate::models::{FailureMode,
+                        LatencyDistribution};
+    use execution_tools::base::{BaseTool,
+                                ToolMetadata};
+    use std::{collections::HashMap,
+              sync::atomic::{AtomicUsize,
+                             Ordering}};
+
+    struct EchoTool {
+        meta: ToolMetadata,
+    }
+
+    impl EchoTool {
+        fn new() -> Self {
+            Self {
+                meta: ToolMetadata {
+                    name: "echo".into(),
+                    description: "echo".into(),
+                    parameters_schema: serde_json::json!({}),
+                    safety_level: "safe".into(),
+                    requires_approval: false,
+                },
+            }
+        }
+    }
+
+    impl BaseTool for EchoTool {
+        fn metadata(&self) -> &ToolMetadata { &self.meta }
+
+        fn execute(&self, _params: &HashMap<String, serde_json::Value>) -> HashMap<String, serde_json::Value> {
+            HashMap::from([("success".to_string(), serde_json::json!(true))])
+        }
+    }
+
+    fn config(seed: Option<u64>) -> FaultInjectionConfig {
+        FaultInjectionConfig {
+            global_failure_rate: 0.5,
+            seed,
+            ..Default::default()
+        }
+    }
+
+    fn base_registry() -> ToolRegistry {
+        let mut reg = ToolRegistry::new();
+        reg.register_with_domain(Arc::new(EchoTool::new()), "test");
+        reg
+    }
+
+    /// 같은 도구를 `n` 번 호출하고 호출별 결과를 돌려준다.
+    fn call_n(injector: &FaultInjector, n: usize) -> Vec<HashMap<String, serde_json::Value>> {
+        let wrapped = injector.wrap_registry(&base_registry());
+        let tool = wrapped.get_tool("test__echo").expect("namespaced key must survive wrapping");
+        let params = HashMap::from([("x".to_string(), serde_json::json!(1.5))]);
+        (0 .. n).map(|_| tool.execute(&params)).collect()
+    }
+
+    fn modes(failures: &[InjectedFailure]) -> Vec<(u64, FailureMode)> { failures.iter().map(|f| (f.call_index, f.failure_mode.clone())).collect() }
+
+    /// @trace TC: SPEC-029/TC-1
+    #[test]
+    fn spec029_tc_1_seeded_stream_varies_per_call() {
+        let injector = FaultInjector::new(config(Some(42)));
+        call_n(&injector, 40);
+        let failures = injector.injected_failures();
+        assert!(
+            !failures.is_empty() && failures.len() < 40,
+            "시드가 있어도 호출마다 결과가 달라야 한다: {}",
+            failures.len()
+        );
+        assert!(failures.windows(2).all(|w| w[0].call_index < w[1].call_index));
+    }
+
+    /// @trace TC: SPEC-029/TC-2
+    #[test]
+    fn spec029_tc_2_same_seed_same_call_sequence() {
+        let a = FaultInjector::new(config(Some(7)));
+        let b = FaultInjector::new(config(Some(7)));
+        call_n(&a, 30);
+        call_n(&b, 30);
+        assert_eq!(modes(&a.injected_failures()), modes(&b.injected_failures()));
+    }
+
+    /// @trace TC: SPEC-029/TC-3
+    #[test]
+    fn spec029_tc_3_replay_file_reproduces_unseeded_run() {
+        let original = FaultInjector::new(config(None));
+        let first = call_n(&original, 30);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("replay.json");
+        original.save_replay(&path).unwrap();
+
+        let replay = FaultReplay::load(&path).unwrap();
+        let replayed = FaultInjector::from_replay(FaultInjectionConfig::default(), replay);
+        let second = call_n(&replayed, 30);
+
+        assert_eq!(first, second);
+        assert_eq!(modes(&original.injected_failures()), modes(&replayed.injected_failures()));
+    }
+
+    /// 재현 시에도 원본을 실행했던 호출(주입 없음, 지연, 가공 결과)은 원본을
+    /// 다시 실행한다. 도구의 부수효과가 기록된 실행과 같아야 한다.
+    ///
+    /// @trace TC: SPEC-029/TC-4
+    #[test]
+    fn spec029_tc_4_replay_runs_original_where_recording_did() {
+        struct CountingTool {
+            meta: ToolMetadata,
+            calls: AtomicUsize,
+        }
+        impl BaseTool for CountingTool {
+            fn metadata(&self) -> &ToolMetadata { &self.meta }
+
+            fn execute(&self, _params: &HashMap<String, serde_json::Value>) -> HashMap<String, serde_json::Value> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                HashMap::from([("success".to_string(), serde_json::json!(true))])
+            }
+        }
+        let cfg = FaultInjectionConfig {
+            global_failure_rate: 0.6,
+            failure_mode_distribution: HashMap::from([
+                ("latency".to_string(), 1.0),
+                ("malformed_result".to_string(), 1.0),
+                ("timeout".to_string(), 1.0),
+            ]),
+            latency: LatencyDistribution::Fixed {
+                ms: 0,
+            },
+            seed: Some(9),
+            ..Default::default()
+        };
+        let run = |injector: &FaultInjector| {
+            let tool = Arc::new(CountingTool {
+                meta: EchoTool::new().meta,
+                calls: AtomicUsize::new(0),
+            });
+            let mut reg = ToolRegistry::new();
+            reg.register_with_domain(tool.clone(), "test");
+            let wrapped = injector.wrap_registry(&reg);
+            let results: Vec<_> = (0 .. 30).map(|_| wrapped.get_tool("test__echo").unwrap().execute(&HashMap::new())).collect();
+            (tool.calls.load(Ordering::SeqCst), results)
+        };
+
+        let original = FaultInjector::new(cfg.clone());
+        let (original_calls, first) = run(&original);
+        let failures = original.injected_failures();
+        assert!(failures.iter().any(|f| f.failure_mode == FailureMode::Timeout), "{:?}", modes(&failures));
+        assert!(failures.iter().any(|f| f.failure_mode.runs_original()), "{:?}", modes(&failures));
+        assert!(original_calls > 0 && original_calls < 30, "timeout 은 원본을 실행하지 않는다: {original_calls}");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("replay.json");
+        original.save_replay(&path).unwrap();
+        let replayed = FaultInjector::from_replay(cfg, FaultReplay::load(&path).unwrap());
+        let (replayed_calls, second) = run(&replayed);
+        assert_eq!(replayed_calls, original_calls);
+        assert_eq!(first, second);
+    }
+
+    /// @trace TC: SPEC-031/TC-8
+    #[test]
+    fn spec031_tc_8_faults_recorded_under_registry_key() {
+        let injector = FaultInjector::new(config(Some(3)));
+        call_n(&injector, 20);
+        let faults = injector.injected_faults();
+        assert!(!faults.is_empty());
+        for (fault, failure) in faults.iter().zip(injector.injected_failures()) {
+            assert_eq!(fault.tool_name, "test__echo");
+            assert_eq!(fault.call_index, failure.call_index);
+            assert_eq!(fault.failure_mode, failure.failure_mode.as_str());
+            assert_eq!(fault.parameters["x"], serde_json::json!(1.5));
+        }
+    }
+
+    /// 분포에 한 모드만 있으면 그 모드만 주입된다 (없는 키는 0.0).
+    ///
+    /// @trace TC: SPEC-030/TC-7
+    #[test]
+    fn spec030_tc_7_single_mode_distribution_is_exhaustive() {
+        let injector = FaultInjector::new(FaultInjectionConfig {
+            global_failure_rate: 1.0,
+            failure_mode_distribution: HashMap::from([("rate_limited".to_string(), 1.0)]),
+            seed: Some(5),
+            ..Default::default()
+        });
+        call_n(&injector, 30);
+        let failures = injector.injected_failures();
+        assert_eq!(failures.len(), 30);
+        assert!(failures.iter().all(|f| f.failure_mode == FailureMode::RateLimited), "{:?}", modes(&failures));
+    }
+
+    /// 모두 0 이거나 음수인 분포는 거부된다. 비어 있는 분포는 기본 가중치.
+    ///
+    /// @trace TC: SPEC-030/TC-8
+    #[test]
+    fn spec030_tc_8_unusable_distribution_is_rejected() {
+        let with = |dist: &[(&str, f64)]| FaultInjectionConfig {
+            failure_mode_distribution: dist.iter().map(|(k, w)| (k.to_string(), *w)).collect(),
+            ..Default::default()
+        };
+        assert!(FaultInjectionConfig::default().validate_distribution().is_ok());
+        assert!(with(&[]).validate_distribution().is_ok());
+        assert!(with(&[("timeout", 0.0), ("latency", 1.0)]).validate_distribution().is_ok());
+        assert!(with(&[("timeout", 0.0), ("exception", 0.0)]).validate_distribution().is_err());
+        assert!(with(&[("timeout", -1.0), ("exception", 2.0)]).validate_distribution().is_err());
+        assert!(with(&[("timeout", f64::NAN)]).validate_distribution().is_err());
+    }
+}
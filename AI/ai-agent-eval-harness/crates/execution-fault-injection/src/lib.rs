@@ -1,4 +1,5 @@
 pub mod failure_modes;
 pub mod fault_injector;
 pub mod models;
+pub mod session;
 pub mod tool_wrapper;
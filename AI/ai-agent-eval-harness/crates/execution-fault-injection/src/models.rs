@@ -14,15 +14,88 @@ pub enum FailureMode {
     Exception,
     NetworkError,
     PermissionDenied,
+    /// SPEC-030: 지연 후 원본 결과를 그대로 반환
+    Latency,
+    /// SPEC-030: HTTP 429 + retry-after 힌트
+    RateLimited,
+    /// SPEC-030: 잘린 JSON 또는 스키마 위반 결과
+    MalformedResult,
+    /// SPEC-030: 오래된 시점의 (캐시된) 결과
+    StaleData,
 }
 
+impl FailureMode {
+    /// 원본 도구를 실제로 실행한 결과를 가공하는 모드 (SPEC-030).
+    pub fn runs_original(&self) -> bool { matches!(self, Self::Latency | Self::MalformedResult | Self::StaleData) }
+
+    /// serde 표기와 같은 snake_case 이름.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            | Self::Timeout => "timeout",
+            | Self::PartialResult => "partial_result",
+            | Self::IncorrectResult => "incorrect_result",
+            | Self::Exception => "exception",
+            | Self::NetworkError => "network_error",
+            | Self::PermissionDenied => "permission_denied",
+            | Self::Latency => "latency",
+            | Self::RateLimited => "rate_limited",
+            | Self::MalformedResult => "malformed_result",
+            | Self::StaleData => "stale_data",
+        }
+    }
+}
+
+/// SPEC-030: `Latency` 모드의 지연 분포 (밀리초).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LatencyDistribution {
+    Fixed {
+        ms: u64,
+    },
+    Uniform {
+        min_ms: u64,
+        max_ms: u64,
+    },
+    /// 평균 `mean_ms` 지수 분포. `max_ms` 로 상한을 둔다.
+    Exponential {
+        mean_ms: u64,
+        max_ms: u64,
+    },
+}
+
+impl Default for LatencyDistribution {
+    fn default() -> Self {
+        Self::Uniform {
+            min_ms: 200,
+            max_ms: 2000,
+        }
+    }
+}
+
+fn default_retry_after_secs() -> u64 { 2 }
+
+fn default_stale_age_secs() -> u64 { 86_400 }
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FaultInjectionConfig {
     pub enabled: bool,
     pub global_failure_rate: f64,
+    #[serde(default)]
     pub tool_specific_rates: HashMap<String, f64>,
+    /// 비어 있으면 도구 래퍼의 기본 가중치를 쓴다.
+    #[serde(default)]
     pub failure_mode_distribution: HashMap<String, f64>,
     pub seed: Option<u64>,
+    /// SPEC-030: `latency` 모드의 지연 분포
+    #[serde(default)]
+    pub latency: LatencyDistribution,
+    /// SPEC-030: `rate_limited` 모드가 알려줄 재시도 대기 시간(초)
+    #[serde(default = "default_retry_after_secs")]
+    pub retry_after_secs: u64,
+    /// SPEC-030: `stale_data` 모드 결과의 기준 시점이 현재보다 얼마나
+    /// 과거인지(초)
+    #[serde(default = "default_stale_age_secs")]
+    pub stale_age_secs: u64,
 }
 
 impl Default for FaultInjectionConfig {
@@ -34,6 +107,11 @@ impl Default for FaultInjectionConfig {
         dist.insert("exception".into(), 0.2);
         dist.insert("network_error".into(), 0.1);
         dist.insert("permission_denied".into(), 0.05);
+        // SPEC-030 모드는 기본 비활성. 분포에 가중치를 주면 켜진다.
+        dist.insert("latency".into(), 0.0);
+        dist.insert("rate_limited".into(), 0.0);
+        dist.insert("malformed_result".into(), 0.0);
+        dist.insert("stale_data".into(), 0.0);
 
         Self {
             enabled: true,
@@ -41,15 +119,57 @@ impl Default for FaultInjectionConfig {
             tool_specific_rates: HashMap::new(),
             failure_mode_distribution: dist,
             seed: None,
+            latency: LatencyDistribution::default(),
+            retry_after_secs: default_retry_after_secs(),
+            stale_age_secs: default_stale_age_secs(),
         }
     }
 }
 
+impl FaultInjectionConfig {
+    /// `failure_mode_distribution` 검증. 가중치는 0 이상의 유한한 값이어야
+    /// 하고, 분포가 주어졌다면 적어도 한 모드는 양수여야 한다. 모두 0 이면
+    /// 뽑을 모드가 없다.
+    pub fn validate_distribution(&self) -> Result<(), String> {
+        if let Some((mode, weight)) = self.failure_mode_distribution.iter().find(|(_, w)| !w.is_finite() || **w < 0.0) {
+            return Err(format!("failure_mode_distribution.{mode} must be a non-negative number, got {weight}"));
+        }
+        if !self.failure_mode_distribution.is_empty() && self.failure_mode_distribution.values().all(|w| *w == 0.0) {
+            return Err("failure_mode_distribution must give at least one mode a positive weight".into());
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InjectedFailure {
+    /// 주입기 세션 안에서의 전역 호출 순번(0 부터). 주입되지 않은 호출도
+    /// 순번을 소비하므로 재현 시 같은 호출을 가리킨다.
+    #[serde(default)]
+    pub call_index: u64,
     pub tool_name: String,
     pub failure_mode: FailureMode,
     pub original_parameters: HashMap<String, serde_json::Value>,
     pub injected_result: HashMap<String, serde_json::Value>,
     pub timestamp: DateTime<Utc>,
 }
+
+/// 궤적에 남길 요약 기록으로 변환 (SPEC-031).
+impl From<&InjectedFailure> for agent_models::models::InjectedFault {
+    fn from(f: &InjectedFailure) -> Self {
+        Self {
+            call_index: f.call_index,
+            tool_name: f.tool_name.clone(),
+            failure_mode: f.failure_mode.as_str().to_string(),
+            parameters: f.original_parameters.clone(),
+        }
+    }
+}
+
+/// 재현 파일 포맷. 한 세션에서 주입된 실패를 호출 순번과 함께 기록한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaultReplay {
+    pub version: u32,
+    pub seed: Option<u64>,
+    pub failures: Vec<InjectedFailure>,
+}
@@ -0,0 +1,162 @@
+// =============================================================================
+// @trace SPEC-029
+// @trace PRD: PRD-029
+// @trace FR: PRD-029/FR-1, PRD-029/FR-2, PRD-029/FR-3
+// @trace file-type: impl
+// =============================================================================
+//
+// 한 실행(run) 동안 래핑된 모든 도구가 공유하는 폴트 주입 상태. 시드 RNG
+// 스트림 하나를 호출 순서대로 소비하므로, 같은 시드 + 같은 호출 순서면 같은
+// 호출에서 같은 실패가 재현된다. 재현 파일이 주어지면 RNG 대신 기록된
+// 호출 순번 → 주입 결과를 그대로 돌려준다.
+
+use crate::models::{FailureMode,
+                    FaultReplay,
+                    InjectedFailure};
+use rand::{Rng,
+           SeedableRng,
+           rngs::StdRng};
+use std::{collections::HashMap,
+          path::Path,
+          sync::Mutex};
+
+pub const REPLAY_VERSION: u32 = 1;
+
+/// `FaultSession::plan_call` 의 결정.
+pub enum CallPlan {
+    /// 원본 도구를 그대로 실행.
+    Pass,
+    /// `mode` 로 실패를 생성해 주입. `rng` 는 이 호출 전용.
+    Inject { call_index: u64, mode: FailureMode, rng: StdRng },
+    /// 재현 파일에 기록된 주입 결과를 그대로 돌려준다.
+    Replay(InjectedFailure),
+}
+
+struct SessionState {
+    rng: StdRng,
+    next_call: u64,
+    failures: Vec<InjectedFailure>,
+}
+
+pub struct FaultSession {
+    seed: Option<u64>,
+    state: Mutex<SessionState>,
+    replay: Option<HashMap<u64, InjectedFailure>>,
+}
+
+impl FaultSession {
+    /// 시드가 없으면 엔트로피로 초기화한다. 이 경우에도 호출 순번은 기록되어
+    /// 재현 파일로 다시 돌릴 수 있다.
+    ///
+    /// @trace SPEC: SPEC-029
+    /// @trace FR: PRD-029/FR-1
+    pub fn new(seed: Option<u64>) -> Self {
+        let rng = match seed {
+            | Some(s) => StdRng::seed_from_u64(s),
+            | None => StdRng::from_entropy(),
+        };
+        Self {
+            seed,
+            state: Mutex::new(SessionState {
+                rng,
+                next_call: 0,
+                failures: Vec::new(),
+            }),
+            replay: None,
+        }
+    }
+
+    /// 재현 모드 세션. 기록된 호출 순번에서만 실패를 주입한다.
+    ///
+    /// @trace SPEC: SPEC-029
+    /// @trace FR: PRD-029/FR-3
+    pub fn from_replay(replay: FaultReplay) -> Self {
+        let mut session = Self::new(replay.seed);
+        session.replay = Some(replay.failures.into_iter().map(|f| (f.call_index, f)).collect());
+        session
+    }
+
+    pub fn is_replay(&self) -> bool { self.replay.is_some() }
+
+    /// 호출 하나에 순번을 매기고 주입 여부를 결정한다. `draw` 는 세션 락을
+    /// 잡은 채 공유 RNG 로 실패 모드를 뽑는다(주입 안 하면 None). 결과 생성은
+    /// 락 밖에서 하도록, 주입 시 스트림에서 뽑은 시드로 호출 전용 RNG 를
+    /// 만들어 넘긴다.
+    ///
+    /// @trace SPEC: SPEC-029
+    /// @trace FR: PRD-029/FR-1, PRD-029/FR-3
+    pub fn plan_call<F>(&self, tool_name: &str, draw: F) -> CallPlan
+    where
+        F: FnOnce(&mut StdRng) -> Option<FailureMode>,
+    {
+        let mut state = self.state.lock().unwrap();
+        let call_index = state.next_call;
+        state.next_call += 1;
+
+        if let Some(replay) = &self.replay {
+            return match replay.get(&call_index) {
+                | Some(f) if f.tool_name == tool_name => CallPlan::Replay(f.clone()),
+                | Some(f) => {
+                    eprintln!(
+                        "[warn] fault replay 불일치: 호출 #{call_index} 은 {} 였으나 {tool_name} 이(가) 호출됨 — 주입 생략",
+                        f.tool_name
+                    );
+                    CallPlan::Pass
+                },
+                | None => CallPlan::Pass,
+            };
+        }
+        match draw(&mut state.rng) {
+            | Some(mode) => {
+                let call_seed = state.rng.gen::<u64>();
+                CallPlan::Inject {
+                    call_index,
+                    mode,
+                    rng: StdRng::seed_from_u64(call_seed),
+                }
+            },
+            | None => CallPlan::Pass,
+        }
+    }
+
+    /// 주입 결과를 기록한다. 동시 호출이 있어도 호출 순번 순서를 유지한다.
+    pub fn record(&self, failure: InjectedFailure) {
+        let mut state = self.state.lock().unwrap();
+        let pos = state.failures.partition_point(|f| f.call_index < failure.call_index);
+        state.failures.insert(pos, failure);
+    }
+
+    /// 지금까지 주입된 실패 (호출 순번 순).
+    pub fn injected_failures(&self) -> Vec<InjectedFailure> { self.state.lock().unwrap().failures.clone() }
+
+    pub fn replay(&self) -> FaultReplay {
+        FaultReplay {
+            version: REPLAY_VERSION,
+            seed: self.seed,
+            failures: self.injected_failures(),
+        }
+    }
+}
+
+impl FaultReplay {
+    /// @trace SPEC: SPEC-029
+    /// @trace FR: PRD-029/FR-2
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        let replay: Self = serde_json::from_str(&text).map_err(std::io::Error::other)?;
+        if replay.version != REPLAY_VERSION {
+            return Err(std::io::Error::other(format!("지원하지 않는 replay version: {}", replay.version)));
+        }
+        Ok(replay)
+    }
+
+    /// @trace SPEC: SPEC-029
+    /// @trace FR: PRD-029/FR-2
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+}
@@ -1,7 +1,11 @@
 #![allow(dead_code)]
-use crate::{failure_modes::FailureModeGenerator,
+use crate::{failure_modes::{FailureModeGenerator,
+                            ModeSettings},
             models::{FailureMode,
-                     InjectedFailure}};
+                     FaultInjectionConfig,
+                     InjectedFailure},
+            session::{CallPlan,
+                      FaultSession}};
 use execution_tools::base::{BaseTool,
                             ToolMetadata};
 use rand::Rng;
@@ -10,38 +14,57 @@ use std::{collections::HashMap,
 
 pub struct FaultInjectedTool {
     original: Arc<dyn BaseTool>,
+    /// 세션 기록/재현 대조에 쓰는 이름. 기본은 메타데이터 이름이고,
+    /// 레지스트리 래핑 시에는 레지스트리 키(`domain__name`)가 된다.
+    key: String,
     failure_rate: f64,
     failure_mode_dist: Vec<(FailureMode, f64)>,
-    pub injected_failures: std::sync::Mutex<Vec<InjectedFailure>>,
-    seed: Option<u64>,
+    settings: ModeSettings,
+    session: Arc<FaultSession>,
 }
 
 impl FaultInjectedTool {
-    pub fn new(original: Arc<dyn BaseTool>, failure_rate: f64, failure_mode_distribution: &HashMap<String, f64>, seed: Option<u64>) -> Self {
+    /// `session` 은 같은 실행의 다른 래핑 도구와 공유한다 (SPEC-029).
+    pub fn new(original: Arc<dyn BaseTool>, failure_rate: f64, config: &FaultInjectionConfig, session: Arc<FaultSession>) -> Self {
+        // 분포가 주어지면 그것이 전부다 (없는 모드는 0.0). 비어 있을 때만
+        // 기본 가중치를 쓴다. SPEC-030 모드는 기본값에서도 비활성.
+        let given = &config.failure_mode_distribution;
+        let weight = |key: &str, default: f64| {
+            if given.is_empty() { default } else { given.get(key).copied().unwrap_or(0.0) }
+        };
         let dist = vec![
-            (FailureMode::Timeout, *failure_mode_distribution.get("timeout").unwrap_or(&0.2)),
-            (FailureMode::PartialResult, *failure_mode_distribution.get("partial_result").unwrap_or(&0.25)),
-            (FailureMode::IncorrectResult, *failure_mode_distribution.get("incorrect_result").unwrap_or(&0.2)),
-            (FailureMode::Exception, *failure_mode_distribution.get("exception").unwrap_or(&0.2)),
-            (FailureMode::NetworkError, *failure_mode_distribution.get("network_error").unwrap_or(&0.1)),
-            (
-                FailureMode::PermissionDenied,
-                *failure_mode_distribution.get("permission_denied").unwrap_or(&0.05),
-            ),
+            (FailureMode::Timeout, weight("timeout", 0.2)),
+            (FailureMode::PartialResult, weight("partial_result", 0.25)),
+            (FailureMode::IncorrectResult, weight("incorrect_result", 0.2)),
+            (FailureMode::Exception, weight("exception", 0.2)),
+            (FailureMode::NetworkError, weight("network_error", 0.1)),
+            (FailureMode::PermissionDenied, weight("permission_denied", 0.05)),
+            (FailureMode::Latency, weight("latency", 0.0)),
+            (FailureMode::RateLimited, weight("rate_limited", 0.0)),
+            (FailureMode::MalformedResult, weight("malformed_result", 0.0)),
+            (FailureMode::StaleData, weight("stale_data", 0.0)),
         ];
         Self {
+            key: original.metadata().name.clone(),
             original,
             failure_rate,
             failure_mode_dist: dist,
-            injected_failures: std::sync::Mutex::new(Vec::new()),
-            seed,
+            settings: ModeSettings::from_config(config),
+            session,
         }
     }
 
+    /// 기록용 이름을 레지스트리 키로 바꾼다. 평가기가 `ToolCall` 과 대조할
+    /// 수 있도록 (SPEC-031).
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+
     fn sample_failure_mode(&self, rng: &mut impl Rng) -> FailureMode {
         let total: f64 = self.failure_mode_dist.iter().map(|(_, w)| w).sum();
         let mut r = rng.gen::<f64>() * total;
-        for (mode, weight) in &self.failure_mode_dist {
+        for (mode, weight) in self.failure_mode_dist.iter().filter(|(_, w)| *w > 0.0) {
             r -= weight;
             if r <= 0.0 {
                 return mode.clone();
@@ -55,28 +78,52 @@ impl BaseTool for FaultInjectedTool {
     fn metadata(&self) -> &ToolMetadata { self.original.metadata() }
 
     fn execute(&self, params: &HashMap<String, serde_json::Value>) -> HashMap<String, serde_json::Value> {
-        let mut rng = if let Some(seed) = self.seed {
-            rand::rngs::StdRng::seed_from_u64(seed)
-        } else {
-            rand::rngs::StdRng::from_entropy()
+        let plan = self.session.plan_call(&self.key, |rng| {
+            if rng.gen::<f64>() >= self.failure_rate {
+                return None;
+            }
+            Some(self.sample_failure_mode(rng))
+        });
+        // 결과 생성(지연, 원본 실행 포함)은 세션 락 밖에서 한다.
+        let (call_index, failure_mode, result) = match plan {
+            | CallPlan::Pass => return self.original.execute(params),
+            | CallPlan::Inject {
+                call_index,
+                mode,
+                mut rng,
+            } => {
+                let result = FailureModeGenerator::generate(&mode, &self.settings, self.original.as_ref(), params, &mut rng);
+                (call_index, mode, result)
+            },
+            | CallPlan::Replay(recorded) => {
+                // 원본을 실행했던 호출은 재현 시에도 실행해 부수효과를
+                // 되살린다. 지연은 결과를 바꾸지 않으므로 기록된 지연만
+                // 재연하고, 가공된 결과만 기록으로 대체한다.
+                let result = match &recorded.failure_mode {
+                    | FailureMode::Latency => {
+                        let ms = recorded.injected_result.get("injected_latency_ms").and_then(|v| v.as_u64()).unwrap_or(0);
+                        FailureModeGenerator::delayed(ms, self.original.as_ref(), params)
+                    },
+                    | mode => {
+                        if mode.runs_original() {
+                            self.original.execute(params);
+                        }
+                        recorded.injected_result
+                    },
+                };
+                (recorded.call_index, recorded.failure_mode, result)
+            },
         };
-
-        if rng.gen::<f64>() < self.failure_rate {
-            let mode = self.sample_failure_mode(&mut rng);
-            let result = FailureModeGenerator::generate(&mode, self.original.metadata(), params, &mut rng);
-            self.injected_failures.lock().unwrap().push(InjectedFailure {
-                tool_name: self.original.metadata().name.clone(),
-                failure_mode: mode,
-                original_parameters: params.clone(),
-                injected_result: result.clone(),
-                timestamp: chrono::Utc::now(),
-            });
-            return result;
-        }
-        self.original.execute(params)
+        self.session.record(InjectedFailure {
+            call_index,
+            tool_name: self.key.clone(),
+            failure_mode,
+            original_parameters: params.clone(),
+            injected_result: result.clone(),
+            timestamp: chrono::Utc::now(),
+        });
+        result
     }
 
     fn validate_parameters(&self, params: &HashMap<String, serde_json::Value>) -> bool { self.original.validate_parameters(params) }
 }
-
-use rand::SeedableRng;
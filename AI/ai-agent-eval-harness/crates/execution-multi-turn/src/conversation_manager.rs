@@ -2,7 +2,8 @@
 use crate::{context_tracker::ContextTracker,
             models::{Conversation,
                      ConversationScenario,
-                     ConversationTurn}};
+                     ConversationTurn},
+            simulated_user::SimulatedUser};
 use agent_models::base_agent::BaseAgent;
 use std::collections::HashMap;
 
@@ -30,10 +31,32 @@ impl<'a> ConversationManager<'a> {
             context_retention_scores: Vec::new(),
         };
 
-        let mut conversation_context: HashMap<String, serde_json::Value> = HashMap::new();
+        // SPEC-032: 시나리오 initial_environment 가 첫 턴 컨텍스트가 된다.
+        let mut conversation_context: HashMap<String, serde_json::Value> = scenario.initial_context.clone();
+        let simulated_user = scenario.simulated_user.as_ref().map(SimulatedUser::new);
 
-        for (idx, turn_config) in scenario.turns.iter().enumerate() {
-            let turn_id = idx as u32 + 1;
+        let mut idx = 0usize;
+        loop {
+            // 스크립트 턴을 모두 보낸 뒤에는 가상 사용자가 직전 턴 궤적을 보고
+            // 다음 발화를 정한다.
+            let turn_config = match scenario.turns.get(idx) {
+                | Some(t) => t.clone(),
+                | None => {
+                    let last = conversation.turns.last().and_then(|t| t.trajectory.as_ref());
+                    match (&simulated_user, last) {
+                        | (Some(user), Some(last)) => match user.next_turn(idx, last) {
+                            | Some(mut t) => {
+                                t.scenario_id = Some(scenario.conversation_id.clone());
+                                t
+                            },
+                            | None => break,
+                        },
+                        | _ => break,
+                    }
+                },
+            };
+            idx += 1;
+            let turn_id = idx as u32;
             let mut env = turn_config.initial_environment.clone().unwrap_or_default();
             env.extend(conversation_context.clone());
 
@@ -53,6 +76,8 @@ impl<'a> ConversationManager<'a> {
                 context_from_previous: conversation_context.clone(),
                 trajectory: Some(trajectory),
                 evaluation_result: None,
+                expected_tools: turn_config.expected_tools,
+                success_criteria: turn_config.success_criteria,
             });
         }
 
@@ -90,3 +115,124 @@ impl<'a> ConversationManager<'a> {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-032
+    // @trace PRD: PRD-032
+    // @trace FR: PRD-032/FR-1, PRD-032/FR-2, PRD-032/FR-3
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use crate::models::TurnConfig;
+    use agent_models::{base_agent::AgentMetadata,
+                       domain_config::{ConversationConfig,
+                                       ConversationTurnConfig,
+                                       DomainConfig,
+                                       SimulatedUserConfig,
+                                       SimulatedUserRule,
+                                       UserRuleCondition},
+                       models::{AgentState,
+                                Trajectory}};
+    use std::sync::Mutex;
+
+    /// 받은 발화와 환경을 기록하고, 발화에 "실패" 가 있으면 실패 궤적을
+    /// 돌려주는 에이전트.
+    #[derive(Default)]
+    struct RecordingAgent {
+        calls: Mutex<Vec<(String, HashMap<String, serde_json::Value>)>>,
+    }
+
+    impl BaseAgent for RecordingAgent {
+        fn metadata(&self) -> AgentMetadata {
+            AgentMetadata {
+                name: "recording".into(),
+                description: "테스트용".into(),
+                version: "0.1.0".into(),
+            }
+        }
+
+        fn execute_task(&self, task_description: &str, initial_environment: Option<HashMap<String, serde_json::Value>>) -> Trajectory {
+            let env = initial_environment.unwrap_or_default();
+            self.calls.lock().unwrap().push((task_description.to_string(), env.clone()));
+            let mut state = AgentState::new(task_description.to_string()).with_environment(env);
+            state.perceived_info.insert("order_id".into(), serde_json::json!("O-7"));
+            Trajectory {
+                task_id: uuid::Uuid::new_v4().to_string(),
+                task_description: task_description.to_string(),
+                start_time: chrono::Utc::now(),
+                end_time: Some(chrono::Utc::now()),
+                steps: Vec::new(),
+                final_state: Some(state),
+                success: !task_description.contains("실패"),
+                total_iterations: 1,
+                prompt_set_id: None,
+                injected_faults: Vec::new(),
+                token_usage: None,
+                tool_calling_mode: None,
+                llm_model: None,
+                domain_routing: None,
+            }
+        }
+
+        fn load_domain_tools(&self, _domain_config: &DomainConfig) {}
+    }
+
+    fn turn(input: &str) -> ConversationTurnConfig {
+        ConversationTurnConfig {
+            user_input: input.into(),
+            ..Default::default()
+        }
+    }
+
+    /// @trace TC: SPEC-032/TC-3
+    #[test]
+    fn spec032_tc_3_scripted_then_simulated_turns_carry_context() {
+        let config = ConversationConfig {
+            turns: vec![turn("환불해 주세요 (실패)")],
+            simulated_user: Some(SimulatedUserConfig {
+                max_turns: 3,
+                rules: vec![
+                    SimulatedUserRule {
+                        when: UserRuleCondition::LastTurnFailed,
+                        reply: ConversationTurnConfig {
+                            expected_context_keys: vec!["order_id".into(), "customer_id".into()],
+                            ..turn("주문번호 O-7 로 다시 시도해 주세요")
+                        },
+                    },
+                    SimulatedUserRule {
+                        when: UserRuleCondition::Always,
+                        reply: turn("처리 결과를 요약해 주세요"),
+                    },
+                ],
+            }),
+            success_criteria: HashMap::from([("min_success_rate".to_string(), serde_json::json!(0.6))]),
+        };
+        let opening = TurnConfig::from(&turn("사용되지 않음"));
+        let initial = HashMap::from([("customer_id".to_string(), serde_json::json!("C1"))]);
+        let scenario = ConversationScenario::from_config("cs_conv", "customer_service", "멀티턴", &config, opening, initial);
+
+        let agent = RecordingAgent::default();
+        let conversation = ConversationManager::new(&agent).run_conversation(&scenario);
+
+        let inputs: Vec<&str> = conversation.turns.iter().map(|t| t.user_input.as_str()).collect();
+        assert_eq!(
+            inputs,
+            vec!["환불해 주세요 (실패)", "주문번호 O-7 로 다시 시도해 주세요", "처리 결과를 요약해 주세요"]
+        );
+        // 시나리오 초기 환경과 이전 턴 인지 정보가 다음 턴 환경으로 전달된다.
+        let calls = agent.calls.lock().unwrap();
+        assert_eq!(calls[0].1["customer_id"], serde_json::json!("C1"));
+        assert_eq!(calls[1].1["order_id"], serde_json::json!("O-7"));
+        assert_eq!(conversation.context_retention_scores[1], 1.0);
+        // 3 턴 중 2 턴 성공 ≥ 0.6
+        assert!(conversation.overall_success);
+        assert!(conversation.turns.iter().all(|t| t.scenario_id.as_deref() == Some("cs_conv")));
+
+        let combined = conversation.combined_trajectory("멀티턴");
+        assert!(combined.success);
+        assert_eq!(combined.total_iterations, 3);
+    }
+}
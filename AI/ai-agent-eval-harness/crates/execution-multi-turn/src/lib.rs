@@ -1,3 +1,4 @@
 pub mod context_tracker;
 pub mod conversation_manager;
 pub mod models;
+pub mod simulated_user;
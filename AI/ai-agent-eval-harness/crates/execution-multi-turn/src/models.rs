@@ -1,8 +1,12 @@
 #![allow(dead_code)]
-use agent_models::models::Trajectory;
-use eval_models::models::EvaluationResult;
+use agent_models::{domain_config::{ConversationConfig,
+                                   ConversationTurnConfig,
+                                   SimulatedUserConfig},
+                   models::Trajectory};
 use chrono::{DateTime,
              Utc};
+use eval_models::{models::EvaluationResult,
+                  traits::EvalContext};
 use serde::{Deserialize,
             Serialize};
 use std::collections::HashMap;
@@ -15,6 +19,11 @@ pub struct ConversationTurn {
     pub context_from_previous: HashMap<String, serde_json::Value>,
     pub trajectory: Option<Trajectory>,
     pub evaluation_result: Option<EvaluationResult>,
+    /// SPEC-032: 이 턴의 기대 도구/성공 조건 (턴 단위 채점용)
+    #[serde(default)]
+    pub expected_tools: Vec<String>,
+    #[serde(default)]
+    pub success_criteria: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +32,23 @@ pub struct TurnConfig {
     pub initial_environment: Option<HashMap<String, serde_json::Value>>,
     pub expected_context_keys: Vec<String>,
     pub scenario_id: Option<String>,
+    #[serde(default)]
+    pub expected_tools: Vec<String>,
+    #[serde(default)]
+    pub success_criteria: HashMap<String, serde_json::Value>,
+}
+
+impl From<&ConversationTurnConfig> for TurnConfig {
+    fn from(c: &ConversationTurnConfig) -> Self {
+        Self {
+            user_input: c.user_input.clone(),
+            initial_environment: (!c.initial_environment.is_empty()).then(|| c.initial_environment.clone()),
+            expected_context_keys: c.expected_context_keys.clone(),
+            scenario_id: None,
+            expected_tools: c.expected_tools.clone(),
+            success_criteria: c.success_criteria.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,6 +58,45 @@ pub struct ConversationScenario {
     pub description: String,
     pub turns: Vec<TurnConfig>,
     pub overall_success_criteria: HashMap<String, serde_json::Value>,
+    /// SPEC-032: 스크립트 턴 이후 대화를 이어가는 가상 사용자
+    #[serde(default)]
+    pub simulated_user: Option<SimulatedUserConfig>,
+    /// SPEC-032: 첫 턴 전 대화 컨텍스트 (시나리오 initial_environment)
+    #[serde(default)]
+    pub initial_context: HashMap<String, serde_json::Value>,
+}
+
+impl ConversationScenario {
+    /// 시나리오 정의의 `conversation` 으로 대화 시나리오를 만든다. 스크립트
+    /// 턴이 비어 있으면 `opening` 이 첫 턴이 된다.
+    ///
+    /// @trace SPEC: SPEC-032
+    /// @trace FR: PRD-032/FR-1
+    pub fn from_config(
+        conversation_id: &str,
+        domain: &str,
+        description: &str,
+        config: &ConversationConfig,
+        opening: TurnConfig,
+        initial_context: HashMap<String, serde_json::Value>,
+    ) -> Self {
+        let mut turns: Vec<TurnConfig> = config.turns.iter().map(TurnConfig::from).collect();
+        if turns.is_empty() {
+            turns.push(opening);
+        }
+        for t in &mut turns {
+            t.scenario_id = Some(conversation_id.to_string());
+        }
+        Self {
+            conversation_id: conversation_id.to_string(),
+            domain: domain.to_string(),
+            description: description.to_string(),
+            turns,
+            overall_success_criteria: config.success_criteria.clone(),
+            simulated_user: config.simulated_user.clone(),
+            initial_context,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,6 +110,54 @@ pub struct Conversation {
     pub context_retention_scores: Vec<f64>,
 }
 
+impl Conversation {
+    /// 모든 턴의 스텝을 이어 붙인 대화 전체 궤적. 전체 채점과 저장에 쓴다.
+    ///
+    /// @trace SPEC: SPEC-032
+    /// @trace FR: PRD-032/FR-4
+    pub fn combined_trajectory(&self, task_description: &str) -> Trajectory {
+        let trajectories: Vec<&Trajectory> = self.turns.iter().filter_map(|t| t.trajectory.as_ref()).collect();
+        // 주입 순번은 턴(실행)마다 0 부터 다시 세므로 앞 턴 뒤로 밀어 순서를
+        // 유지한다.
+        let mut injected_faults = Vec::new();
+        let mut offset = 0u64;
+        for t in &trajectories {
+            let next_offset = t.injected_faults.iter().map(|f| offset + f.call_index + 1).max().unwrap_or(offset);
+            injected_faults.extend(t.injected_faults.iter().cloned().map(|mut f| {
+                f.call_index += offset;
+                f
+            }));
+            offset = next_offset;
+        }
+        let token_usage = trajectories.iter().filter_map(|t| t.token_usage).reduce(|mut acc, u| {
+            acc.add(&u);
+            acc
+        });
+        Trajectory {
+            task_id: uuid::Uuid::new_v4().to_string(),
+            task_description: task_description.to_string(),
+            start_time: self.start_time,
+            end_time: self.end_time,
+            steps: trajectories.iter().flat_map(|t| t.steps.iter().cloned()).collect(),
+            final_state: trajectories.last().and_then(|t| t.final_state.clone()),
+            success: self.overall_success,
+            total_iterations: trajectories.iter().map(|t| t.total_iterations).sum(),
+            prompt_set_id: trajectories.iter().find_map(|t| t.prompt_set_id),
+            injected_faults,
+            token_usage,
+            tool_calling_mode: trajectories.iter().find_map(|t| t.tool_calling_mode.clone()),
+            llm_model: trajectories.iter().find_map(|t| t.llm_model.clone()),
+            domain_routing: trajectories.iter().find_map(|t| t.domain_routing.clone()),
+        }
+    }
+}
+
+impl EvalContext for ConversationTurn {
+    fn expected_tools(&self) -> &[String] { &self.expected_tools }
+
+    fn success_criteria(&self) -> &HashMap<String, serde_json::Value> { &self.success_criteria }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultiTurnMetrics {
     pub total_turns: usize,
@@ -0,0 +1,172 @@
+// =============================================================================
+// @trace SPEC-032
+// @trace PRD: PRD-032
+// @trace FR: PRD-032/FR-2
+// @trace file-type: impl
+// =============================================================================
+//
+// 규칙 기반 가상 사용자. 직전 턴의 궤적을 보고 위에서부터 처음 맞는 규칙의
+// 응답을 다음 사용자 턴으로 돌려준다. LLM 을 쓰지 않으므로 같은 궤적이면
+// 항상 같은 대화가 이어진다.
+
+use crate::models::TurnConfig;
+use agent_models::{domain_config::{SimulatedUserConfig,
+                                   UserRuleCondition},
+                   models::Trajectory};
+
+pub struct SimulatedUser<'a> {
+    config: &'a SimulatedUserConfig,
+}
+
+impl<'a> SimulatedUser<'a> {
+    pub fn new(config: &'a SimulatedUserConfig) -> Self {
+        Self {
+            config,
+        }
+    }
+
+    /// 지금까지 `turns_so_far` 턴을 진행했고 마지막 턴 궤적이 `last` 일 때
+    /// 보낼 다음 턴. None 이면 대화 종료.
+    ///
+    /// @trace SPEC: SPEC-032
+    /// @trace FR: PRD-032/FR-2
+    pub fn next_turn(&self, turns_so_far: usize, last: &Trajectory) -> Option<TurnConfig> {
+        if turns_so_far >= self.config.max_turns as usize {
+            return None;
+        }
+        self.config
+            .rules
+            .iter()
+            .find(|rule| condition_matches(&rule.when, last))
+            .map(|rule| TurnConfig::from(&rule.reply))
+    }
+}
+
+/// @trace SPEC: SPEC-032
+/// @trace FR: PRD-032/FR-2
+pub fn condition_matches(condition: &UserRuleCondition, last: &Trajectory) -> bool {
+    let successful_calls = || last.steps.iter().flat_map(|s| s.tool_calls.iter()).filter(|tc| tc.success);
+    match condition {
+        | UserRuleCondition::Always => true,
+        | UserRuleCondition::LastTurnSucceeded => last.success,
+        | UserRuleCondition::LastTurnFailed => !last.success,
+        | UserRuleCondition::ToolCalled {
+            tool,
+        } => successful_calls().any(|tc| &tc.tool_name == tool || tc.tool_name.rsplit("__").next() == Some(tool.as_str())),
+        | UserRuleCondition::OutputContains {
+            text,
+        } => {
+            let in_results = successful_calls()
+                .filter_map(|tc| tc.result.as_ref())
+                .any(|r| r.to_string().contains(text.as_str()));
+            let in_state = last
+                .final_state
+                .as_ref()
+                .and_then(|s| serde_json::to_string(&s.perceived_info).ok())
+                .is_some_and(|s| s.contains(text.as_str()));
+            in_results || in_state
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-032
+    // @trace PRD: PRD-032
+    // @trace FR: PRD-032/FR-2
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use agent_models::{domain_config::{ConversationTurnConfig,
+                                       SimulatedUserRule},
+                       models::{PpaStage,
+                                PpaStep,
+                                ToolCall}};
+    use std::collections::HashMap;
+
+    fn trajectory(success: bool, tool: &str, result: serde_json::Value) -> Trajectory {
+        let mut tc = ToolCall::new(tool.into(), HashMap::new());
+        tc.result = Some(result);
+        Trajectory {
+            task_id: "t".into(),
+            task_description: "t".into(),
+            start_time: chrono::Utc::now(),
+            end_time: None,
+            steps: vec![PpaStep {
+                stage: PpaStage::Action,
+                iteration: 1,
+                timestamp: chrono::Utc::now(),
+                input_data: HashMap::new(),
+                output_data: HashMap::new(),
+                tool_calls: vec![tc],
+                duration_ms: None,
+                token_usage: None,
+            }],
+            final_state: None,
+            success,
+            total_iterations: 1,
+            prompt_set_id: None,
+            injected_faults: Vec::new(),
+            token_usage: None,
+            tool_calling_mode: None,
+            llm_model: None,
+            domain_routing: None,
+        }
+    }
+
+    fn rule(when: UserRuleCondition, reply: &str) -> SimulatedUserRule {
+        SimulatedUserRule {
+            when,
+            reply: ConversationTurnConfig {
+                user_input: reply.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// @trace TC: SPEC-032/TC-1
+    #[test]
+    fn spec032_tc_1_conditions_match_last_turn() {
+        let t = trajectory(true, "customer_service__process_refund", serde_json::json!({"refund_id": "R-1"}));
+        assert!(condition_matches(&UserRuleCondition::LastTurnSucceeded, &t));
+        assert!(!condition_matches(&UserRuleCondition::LastTurnFailed, &t));
+        assert!(condition_matches(
+            &UserRuleCondition::ToolCalled {
+                tool: "process_refund".into()
+            },
+            &t
+        ));
+        assert!(condition_matches(
+            &UserRuleCondition::OutputContains {
+                text: "R-1".into()
+            },
+            &t
+        ));
+        assert!(!condition_matches(
+            &UserRuleCondition::ToolCalled {
+                tool: "escalate_issue".into()
+            },
+            &t
+        ));
+    }
+
+    /// @trace TC: SPEC-032/TC-2
+    #[test]
+    fn spec032_tc_2_first_matching_rule_wins_until_max_turns() {
+        let config = SimulatedUserConfig {
+            max_turns: 3,
+            rules: vec![
+                rule(UserRuleCondition::LastTurnFailed, "다시 해 주세요"),
+                rule(UserRuleCondition::Always, "고맙습니다. 처리 번호를 알려주세요"),
+            ],
+        };
+        let user = SimulatedUser::new(&config);
+        let failed = trajectory(false, "x", serde_json::json!({}));
+        let ok = trajectory(true, "x", serde_json::json!({}));
+        assert_eq!(user.next_turn(1, &failed).unwrap().user_input, "다시 해 주세요");
+        assert_eq!(user.next_turn(2, &ok).unwrap().user_input, "고맙습니다. 처리 번호를 알려주세요");
+        assert!(user.next_turn(3, &ok).is_none());
+    }
+}
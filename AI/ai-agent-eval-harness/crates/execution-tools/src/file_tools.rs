@@ -1,6 +1,8 @@
 #![allow(clippy::new_without_default)]
-use crate::base::{BaseTool,
-                  ToolMetadata};
+use crate::{base::{BaseTool,
+                   ToolMetadata},
+            workspace::{self,
+                        Workspace}};
 use std::{collections::HashMap,
           path::Path};
 
@@ -37,6 +39,12 @@ impl BaseTool for ReadFileTool {
             | Some(p) => p,
             | None => return err_map("file_path 파라미터가 필요합니다"),
         };
+        if let Some(ws) = workspace::current() {
+            return match ws.read(file_path) {
+                | Ok((path, content)) => read_result(ws.relative(&path), content),
+                | Err(e) => err_map(&e),
+            };
+        }
 
         let path = Path::new(file_path);
         if !path.exists() {
@@ -44,15 +52,7 @@ impl BaseTool for ReadFileTool {
         }
 
         match std::fs::read_to_string(path) {
-            | Ok(content) => {
-                let size = content.len();
-                let mut m = HashMap::new();
-                m.insert("success".into(), serde_json::Value::Bool(true));
-                m.insert("content".into(), serde_json::Value::String(content));
-                m.insert("file_path".into(), serde_json::Value::String(path.to_string_lossy().into()));
-                m.insert("size_bytes".into(), serde_json::json!(size));
-                m
-            },
+            | Ok(content) => read_result(path.to_string_lossy().into(), content),
             | Err(e) => err_map(&format!("파일 읽기 실패: {}", e)),
         }
     }
@@ -96,6 +96,12 @@ impl BaseTool for WriteFileTool {
             | Some(c) => c,
             | None => return err_map("content 파라미터가 필요합니다"),
         };
+        if let Some(ws) = workspace::current() {
+            return match ws.write(file_path, content) {
+                | Ok(path) => write_result(ws.relative(&path), content),
+                | Err(e) => err_map(&e),
+            };
+        }
 
         let path = Path::new(file_path);
         if let Some(parent) = path.parent() {
@@ -103,13 +109,7 @@ impl BaseTool for WriteFileTool {
         }
 
         match std::fs::write(path, content) {
-            | Ok(_) => {
-                let mut m = HashMap::new();
-                m.insert("success".into(), serde_json::Value::Bool(true));
-                m.insert("file_path".into(), serde_json::Value::String(path.to_string_lossy().into()));
-                m.insert("size_bytes".into(), serde_json::json!(content.len()));
-                m
-            },
+            | Ok(_) => write_result(path.to_string_lossy().into(), content),
             | Err(e) => err_map(&format!("파일 쓰기 실패: {}", e)),
         }
     }
@@ -150,7 +150,15 @@ impl BaseTool for ListDirectoryTool {
         };
         let recursive = params.get("recursive").and_then(|v| v.as_bool()).unwrap_or(false);
 
-        let path = Path::new(dir_path);
+        let ws = workspace::current();
+        let resolved = match &ws {
+            | Some(ws) => match ws.resolve(dir_path) {
+                | Ok(p) => p,
+                | Err(e) => return err_map(&e),
+            },
+            | None => dir_path.into(),
+        };
+        let path = resolved.as_path();
         if !path.exists() {
             return err_map(&format!("디렉토리가 존재하지 않습니다: {}", dir_path));
         }
@@ -166,9 +174,9 @@ impl BaseTool for ListDirectoryTool {
                 for entry in entries.flatten() {
                     let ep = entry.path();
                     let name = ep.file_name().unwrap_or_default().to_string_lossy().into_owned();
-                    if ep.is_file() {
+                    if is_listed(ws.as_deref(), &entry, |t| t.is_file()) {
                         files.push(name);
-                    } else if ep.is_dir() {
+                    } else if is_listed(ws.as_deref(), &entry, |t| t.is_dir()) {
                         directories.push(name);
                     }
                 }
@@ -176,21 +184,21 @@ impl BaseTool for ListDirectoryTool {
         };
 
         if recursive {
-            fn walk(p: &Path, files: &mut Vec<String>, dirs: &mut Vec<String>, base: &Path) {
+            fn walk(ws: Option<&Workspace>, p: &Path, files: &mut Vec<String>, dirs: &mut Vec<String>, base: &Path) {
                 if let Ok(entries) = std::fs::read_dir(p) {
                     for entry in entries.flatten() {
                         let ep = entry.path();
                         let rel = ep.strip_prefix(base).unwrap_or(&ep).to_string_lossy().into_owned();
-                        if ep.is_file() {
+                        if is_listed(ws, &entry, |t| t.is_file()) {
                             files.push(rel);
-                        } else if ep.is_dir() {
+                        } else if is_listed(ws, &entry, |t| t.is_dir()) {
                             dirs.push(rel.clone());
-                            walk(&ep, files, dirs, base);
+                            walk(ws, &ep, files, dirs, base);
                         }
                     }
                 }
             }
-            walk(path, &mut files, &mut directories, path);
+            walk(ws.as_deref(), path, &mut files, &mut directories, path);
         } else {
             collect(path);
         }
@@ -201,7 +209,8 @@ impl BaseTool for ListDirectoryTool {
 
         let mut m = HashMap::new();
         m.insert("success".into(), serde_json::Value::Bool(true));
-        m.insert("directory_path".into(), serde_json::Value::String(path.to_string_lossy().into()));
+        let shown = ws.as_ref().map_or_else(|| path.to_string_lossy().into(), |ws| ws.relative(path));
+        m.insert("directory_path".into(), serde_json::Value::String(shown));
         m.insert("files".into(), serde_json::json!(files));
         m.insert("directories".into(), serde_json::json!(directories));
         m.insert("total_items".into(), serde_json::json!(total));
@@ -209,6 +218,33 @@ impl BaseTool for ListDirectoryTool {
     }
 }
 
+/// 작업 공간 안에서는 링크를 따라가지 않는다(SPEC-045). 링크는 목록에서
+/// 빠지고 하위 탐색도 하지 않는다.
+fn is_listed(ws: Option<&Workspace>, entry: &std::fs::DirEntry, kind: impl Fn(&std::fs::FileType) -> bool) -> bool {
+    match ws {
+        | Some(_) => entry.file_type().is_ok_and(|t| kind(&t)),
+        | None => std::fs::metadata(entry.path()).is_ok_and(|m| kind(&m.file_type())),
+    }
+}
+
+fn read_result(file_path: String, content: String) -> HashMap<String, serde_json::Value> {
+    let size = content.len();
+    let mut m = HashMap::new();
+    m.insert("success".into(), serde_json::Value::Bool(true));
+    m.insert("content".into(), serde_json::Value::String(content));
+    m.insert("file_path".into(), serde_json::Value::String(file_path));
+    m.insert("size_bytes".into(), serde_json::json!(size));
+    m
+}
+
+fn write_result(file_path: String, content: &str) -> HashMap<String, serde_json::Value> {
+    let mut m = HashMap::new();
+    m.insert("success".into(), serde_json::Value::Bool(true));
+    m.insert("file_path".into(), serde_json::Value::String(file_path));
+    m.insert("size_bytes".into(), serde_json::json!(content.len()));
+    m
+}
+
 fn err_map(msg: &str) -> HashMap<String, serde_json::Value> {
     let mut m = HashMap::new();
     m.insert("success".into(), serde_json::Value::Bool(false));
@@ -280,4 +316,43 @@ mod tests {
         let with_param = make_params(&[("file_path", serde_json::json!("x"))]);
         assert!(tool.validate_parameters(&with_param));
     }
+
+    /// @trace TC: SPEC-045/TC-3
+    /// @trace FR: PRD-045/FR-1, PRD-045/FR-2
+    #[test]
+    fn spec045_tc_3_file_tools_stay_inside_workspace() {
+        let ws = std::sync::Arc::new(Workspace::new(workspace::WorkspaceLimits::default()).unwrap());
+        ws.seed(&[("input/data.txt".to_string(), "seed".to_string())].into()).unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        let outside_file = outside.path().join("leak.txt");
+
+        let results = workspace::scoped(Some(ws.clone()), || {
+            let read = ReadFileTool::new().execute(&make_params(&[("file_path", serde_json::json!("input/data.txt"))]));
+            let write = WriteFileTool::new().execute(&make_params(&[
+                ("file_path", serde_json::json!("out/result.txt")),
+                ("content", serde_json::json!("done")),
+            ]));
+            let escape = WriteFileTool::new().execute(&make_params(&[
+                ("file_path", serde_json::json!(outside_file.to_str().unwrap())),
+                ("content", serde_json::json!("x")),
+            ]));
+            let traversal = ReadFileTool::new().execute(&make_params(&[("file_path", serde_json::json!("../../etc/passwd"))]));
+            let list = ListDirectoryTool::new().execute(&make_params(&[
+                ("directory_path", serde_json::json!(".")),
+                ("recursive", serde_json::json!(true)),
+            ]));
+            (read, write, escape, traversal, list)
+        });
+        let (read, write, escape, traversal, list) = results;
+
+        assert_eq!(read["content"], serde_json::json!("seed"));
+        assert_eq!(read["file_path"], serde_json::json!("input/data.txt"), "작업 공간 기준 상대 경로로 보고");
+        assert_eq!(write["success"], serde_json::Value::Bool(true));
+        assert_eq!(std::fs::read_to_string(ws.root().join("out/result.txt")).unwrap(), "done");
+        assert_eq!(escape["success"], serde_json::Value::Bool(false));
+        assert!(!outside_file.exists());
+        assert_eq!(traversal["success"], serde_json::Value::Bool(false));
+        assert_eq!(list["directory_path"], serde_json::json!("."));
+        assert_eq!(list["files"], serde_json::json!(["input/data.txt", "out/result.txt"]));
+    }
 }
@@ -28,6 +28,7 @@ pub struct HttpCallTool {
 impl HttpCallTool {
     /// 새 인스턴스. `params_schema_json` 은 LLM 에게 그대로 노출될 JSON Schema
     /// 문자열이며, 파싱 실패 시 빈 객체로 폴백.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: impl Into<String>,
         description: impl Into<String>,
@@ -1,4 +1,6 @@
 pub mod base;
 pub mod file_tools;
 pub mod http_tool;
+pub mod mcp_tool;
 pub mod registry;
+pub mod workspace;
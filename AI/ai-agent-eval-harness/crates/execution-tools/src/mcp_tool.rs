@@ -0,0 +1,526 @@
+// =============================================================================
+// @trace SPEC-037
+// @trace PRD: PRD-037
+// @trace FR: PRD-037/FR-1, PRD-037/FR-2, PRD-037/FR-3
+// @trace file-type: impl
+// =============================================================================
+//
+// MCP(Model Context Protocol) 서버의 도구를 BaseTool 로 노출한다. SPEC-037 에서
+// 도입. `McpClient` 는 stdio 서버를 자식 프로세스로 띄우거나(줄 단위 JSON-RPC)
+// 이미 떠 있는 로컬 서버에 Streamable HTTP 로 접속해 `initialize` →
+// `tools/list` 를 수행하고, 각 도구는 `McpTool` 로 감싸 `tools/call` 을
+// 위임한다. 운영에서 쓰는 도구 서버를 그대로 평가에 붙이기 위한 경로다.
+
+use crate::base::{BaseTool,
+                  ToolMetadata};
+use serde_json::{Map,
+                 Value,
+                 json};
+use std::{collections::HashMap,
+          io::{BufRead,
+               BufReader,
+               Write},
+          process::{Child,
+                    ChildStdin,
+                    Command,
+                    Stdio},
+          sync::{Arc,
+                 Mutex,
+                 atomic::{AtomicU64,
+                          Ordering},
+                 mpsc::{Receiver,
+                        RecvTimeoutError,
+                        channel}},
+          time::{Duration,
+                 Instant}};
+
+/// 클라이언트가 `initialize` 에서 제시하는 프로토콜 버전.
+pub const MCP_PROTOCOL_VERSION: &str = "2025-03-26";
+
+/// MCP 서버 접속 방식.
+#[derive(Debug, Clone, PartialEq)]
+pub enum McpTransport {
+    /// `command args..` 를 자식 프로세스로 띄우고 stdin/stdout 으로 통신.
+    Stdio {
+        command: String,
+        args: Vec<String>,
+        env: HashMap<String, String>,
+    },
+    /// 이미 떠 있는 서버의 Streamable HTTP endpoint (예: `http://127.0.0.1:8931/mcp`).
+    Http { url: String },
+}
+
+/// `tools/list` 가 돌려준 도구 1건.
+#[derive(Debug, Clone, PartialEq)]
+pub struct McpToolInfo {
+    pub name: String,
+    pub description: String,
+    pub input_schema: Value,
+}
+
+enum Connection {
+    Stdio {
+        child: Child,
+        stdin: ChildStdin,
+        lines: Receiver<String>,
+    },
+    Http {
+        url: String,
+        http: reqwest::Client,
+        session_id: Option<String>,
+    },
+}
+
+/// MCP JSON-RPC 클라이언트. 요청은 직렬화되며 모든 호출은 동기다
+/// (BaseTool::execute 가 동기이므로 HttpCallTool 과 같은 방식).
+pub struct McpClient {
+    conn: Mutex<Connection>,
+    next_id: AtomicU64,
+    timeout: Duration,
+    server_name: String,
+}
+
+impl McpClient {
+    /// 서버에 접속하고 `initialize` 핸드셰이크까지 마친 클라이언트를 반환.
+    ///
+    /// @trace SPEC: SPEC-037
+    /// @trace FR: PRD-037/FR-1
+    pub fn connect(transport: &McpTransport, timeout_ms: u64) -> Result<Self, String> {
+        let conn = match transport {
+            | McpTransport::Stdio {
+                command,
+                args,
+                env,
+            } => {
+                let mut child = Command::new(command)
+                    .args(args)
+                    .envs(env)
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::null())
+                    .spawn()
+                    .map_err(|e| format!("spawn '{command}': {e}"))?;
+                let stdin = child.stdin.take().ok_or("stdin 없음")?;
+                let stdout = child.stdout.take().ok_or("stdout 없음")?;
+                // stdout 은 별도 스레드가 줄 단위로 읽어 채널로 넘긴다. 그래야
+                // 응답 대기에 timeout 을 걸 수 있다.
+                let (tx, rx) = channel();
+                std::thread::spawn(move || {
+                    for line in BufReader::new(stdout).lines() {
+                        let Ok(line) = line else {
+                            break;
+                        };
+                        if tx.send(line).is_err() {
+                            break;
+                        }
+                    }
+                });
+                Connection::Stdio {
+                    child,
+                    stdin,
+                    lines: rx,
+                }
+            },
+            | McpTransport::Http {
+                url,
+            } => Connection::Http {
+                url: url.clone(),
+                http: reqwest::Client::builder()
+                    .timeout(Duration::from_millis(timeout_ms))
+                    .build()
+                    .map_err(|e| e.to_string())?,
+                session_id: None,
+            },
+        };
+        let mut client = Self {
+            conn: Mutex::new(conn),
+            next_id: AtomicU64::new(1),
+            timeout: Duration::from_millis(timeout_ms),
+            server_name: String::new(),
+        };
+        let init = client.request(
+            "initialize",
+            json!({
+                "protocolVersion": MCP_PROTOCOL_VERSION,
+                "capabilities": {},
+                "clientInfo": {"name": "ai-agent-eval-harness", "version": env!("CARGO_PKG_VERSION")},
+            }),
+        )?;
+        client.server_name = init["serverInfo"]["name"].as_str().unwrap_or_default().to_string();
+        client.notify("notifications/initialized")?;
+        Ok(client)
+    }
+
+    /// `initialize` 응답의 `serverInfo.name`. 없으면 빈 문자열.
+    pub fn server_name(&self) -> &str { &self.server_name }
+
+    /// `tools/list` 를 `nextCursor` 가 없어질 때까지 반복 호출.
+    ///
+    /// @trace SPEC: SPEC-037
+    /// @trace FR: PRD-037/FR-2
+    pub fn list_tools(&self) -> Result<Vec<McpToolInfo>, String> {
+        let mut out = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let params = match &cursor {
+                | Some(c) => json!({ "cursor": c }),
+                | None => json!({}),
+            };
+            let result = self.request("tools/list", params)?;
+            for t in result["tools"].as_array().into_iter().flatten() {
+                let Some(name) = t["name"].as_str() else {
+                    continue;
+                };
+                out.push(McpToolInfo {
+                    name: name.to_string(),
+                    description: t["description"].as_str().unwrap_or_default().to_string(),
+                    input_schema: match &t["inputSchema"] {
+                        | Value::Object(_) => t["inputSchema"].clone(),
+                        | _ => json!({"type": "object"}),
+                    },
+                });
+            }
+            match result["nextCursor"].as_str() {
+                | Some(next) if !next.is_empty() => cursor = Some(next.to_string()),
+                | _ => return Ok(out),
+            }
+        }
+    }
+
+    /// `tools/call` 의 `result` 객체를 그대로 반환한다.
+    ///
+    /// @trace SPEC: SPEC-037
+    /// @trace FR: PRD-037/FR-3
+    pub fn call_tool(&self, name: &str, arguments: Map<String, Value>) -> Result<Value, String> {
+        self.request("tools/call", json!({ "name": name, "arguments": arguments }))
+    }
+
+    fn request(&self, method: &str, params: Value) -> Result<Value, String> {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let msg = json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params});
+        let mut conn = self.conn.lock().map_err(|_| "mcp connection poisoned".to_string())?;
+        let response = match &mut *conn {
+            | Connection::Stdio {
+                stdin,
+                lines,
+                ..
+            } => {
+                writeln!(stdin, "{msg}").and_then(|_| stdin.flush()).map_err(|e| format!("write: {e}"))?;
+                let deadline = Instant::now() + self.timeout;
+                loop {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    let line = match lines.recv_timeout(remaining) {
+                        | Ok(line) => line,
+                        | Err(RecvTimeoutError::Timeout) => return Err(format!("{method}: {}ms 내 응답 없음", self.timeout.as_millis())),
+                        | Err(RecvTimeoutError::Disconnected) => return Err(format!("{method}: 서버 프로세스가 종료됨")),
+                    };
+                    // 로그 줄이나 서버발 알림/요청은 건너뛴다.
+                    if let Ok(v) = serde_json::from_str::<Value>(&line) {
+                        if v["id"].as_u64() == Some(id) && v.get("method").is_none() {
+                            break v;
+                        }
+                    }
+                }
+            },
+            | Connection::Http {
+                url,
+                http,
+                session_id,
+            } => {
+                let (headers, body) = Self::run(Self::post(http, url, session_id.as_deref(), &msg))?;
+                if let Some(sid) = headers.get("mcp-session-id").and_then(|v| v.to_str().ok()) {
+                    *session_id = Some(sid.to_string());
+                }
+                Self::find_response(&body, id).ok_or_else(|| format!("{method}: id {id} 응답 없음"))?
+            },
+        };
+        if let Some(err) = response.get("error") {
+            return Err(format!(
+                "{method}: {} ({})",
+                err["message"].as_str().unwrap_or("unknown error"),
+                err["code"].as_i64().unwrap_or_default()
+            ));
+        }
+        Ok(response.get("result").cloned().unwrap_or(Value::Null))
+    }
+
+    fn notify(&self, method: &str) -> Result<(), String> {
+        let msg = json!({"jsonrpc": "2.0", "method": method});
+        let mut conn = self.conn.lock().map_err(|_| "mcp connection poisoned".to_string())?;
+        match &mut *conn {
+            | Connection::Stdio {
+                stdin, ..
+            } => writeln!(stdin, "{msg}").and_then(|_| stdin.flush()).map_err(|e| format!("write: {e}")),
+            | Connection::Http {
+                url,
+                http,
+                session_id,
+            } => Self::run(Self::post(http, url, session_id.as_deref(), &msg)).map(|_| ()),
+        }
+    }
+
+    async fn post(http: &reqwest::Client, url: &str, session_id: Option<&str>, msg: &Value) -> Result<(reqwest::header::HeaderMap, String), String> {
+        let mut builder = http.post(url).header("Accept", "application/json, text/event-stream").json(msg);
+        if let Some(sid) = session_id {
+            builder = builder.header("Mcp-Session-Id", sid);
+        }
+        let resp = builder.send().await.map_err(|e| format!("request failed: {e}"))?;
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let text = resp.text().await.map_err(|e| e.to_string())?;
+        if !status.is_success() {
+            return Err(format!("HTTP {}: {}", status, text.chars().take(500).collect::<String>()));
+        }
+        Ok((headers, text))
+    }
+
+    /// HTTP 응답 본문에서 `id` 에 해당하는 JSON-RPC 응답을 찾는다. 본문은
+    /// 단일 JSON, 배치 배열, 또는 SSE(`data:` 줄) 중 하나다.
+    fn find_response(body: &str, id: u64) -> Option<Value> {
+        let matches = |v: &Value| v["id"].as_u64() == Some(id) && v.get("method").is_none();
+        let candidates: Vec<Value> = match serde_json::from_str::<Value>(body) {
+            | Ok(Value::Array(items)) => items,
+            | Ok(v) => vec![v],
+            | Err(_) => body
+                .lines()
+                .filter_map(|l| l.strip_prefix("data:"))
+                .filter_map(|d| serde_json::from_str::<Value>(d.trim()).ok())
+                .collect(),
+        };
+        candidates.into_iter().find(matches)
+    }
+
+    fn run<F: std::future::Future>(fut: F) -> F::Output {
+        match tokio::runtime::Handle::try_current() {
+            | Ok(handle) => tokio::task::block_in_place(|| handle.block_on(fut)),
+            | Err(_) => tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to build tokio runtime")
+                .block_on(fut),
+        }
+    }
+}
+
+impl Drop for McpClient {
+    fn drop(&mut self) {
+        if let Ok(Connection::Stdio {
+            child, ..
+        }) = self.conn.get_mut()
+        {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// MCP 서버 도구 1건을 감싼 BaseTool. 같은 서버의 도구들은 클라이언트를
+/// 공유한다.
+pub struct McpTool {
+    metadata: ToolMetadata,
+    client: Arc<McpClient>,
+}
+
+impl McpTool {
+    pub fn new(client: Arc<McpClient>, info: McpToolInfo) -> Self {
+        Self {
+            metadata: ToolMetadata {
+                name: info.name,
+                description: info.description,
+                parameters_schema: info.input_schema,
+                safety_level: "external".into(),
+                requires_approval: false,
+            },
+            client,
+        }
+    }
+
+    /// 서버의 모든 도구를 `McpTool` 로 만든다.
+    ///
+    /// @trace SPEC: SPEC-037
+    /// @trace FR: PRD-037/FR-2
+    pub fn discover(client: Arc<McpClient>) -> Result<Vec<Self>, String> {
+        Ok(client.list_tools()?.into_iter().map(|info| Self::new(client.clone(), info)).collect())
+    }
+}
+
+/// `tools/call` 결과를 다른 도구와 같은 `success` 중심 맵으로 변환.
+/// `structuredContent` 객체나 JSON 객체인 텍스트는 키를 펼치고, 그 외
+/// 텍스트는 `response_text` 로 넣는다. `isError` 면 텍스트가 `error` 가 된다.
+fn result_to_output(result: &Value) -> HashMap<String, Value> {
+    let text = result["content"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|c| c["text"].as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut out = HashMap::new();
+    if result["isError"].as_bool().unwrap_or(false) {
+        out.insert("success".into(), Value::Bool(false));
+        out.insert("error".into(), Value::String(text));
+        return out;
+    }
+    match (&result["structuredContent"], &serde_json::from_str::<Value>(&text)) {
+        | (Value::Object(obj), _) | (_, Ok(Value::Object(obj))) => out.extend(obj.clone()),
+        | _ => {
+            out.insert("response_text".into(), Value::String(text));
+        },
+    }
+    out.insert("success".into(), Value::Bool(true));
+    out
+}
+
+impl BaseTool for McpTool {
+    fn metadata(&self) -> &ToolMetadata { &self.metadata }
+
+    fn execute(&self, params: &HashMap<String, Value>) -> HashMap<String, Value> {
+        let arguments: Map<String, Value> = params.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        match self.client.call_tool(&self.metadata.name, arguments) {
+            | Ok(result) => result_to_output(&result),
+            | Err(e) => {
+                let mut out = HashMap::new();
+                out.insert("success".into(), Value::Bool(false));
+                out.insert("error".into(), Value::String(format!("mcp call failed: {e}")));
+                out
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-037
+    // @trace PRD: PRD-037
+    // @trace FR: PRD-037/FR-1, PRD-037/FR-2, PRD-037/FR-3
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use test_support::{MockResponse,
+                       spawn_mock_server};
+
+    fn tools_list_result() -> Value {
+        json!({"tools": [{
+            "name": "lookup_rate",
+            "description": "환율 조회",
+            "inputSchema": {"type": "object", "properties": {"currency": {"type": "string"}}, "required": ["currency"]}
+        }]})
+    }
+
+    /// 요청 id 가 1(initialize), 2(tools/list), 3(tools/call) 순서라는 점을
+    /// 이용해 정해진 응답만 출력하는 stdio 서버.
+    #[cfg(unix)]
+    fn stdio_script() -> McpTransport {
+        let init = json!({"jsonrpc": "2.0", "id": 1, "result": {"protocolVersion": MCP_PROTOCOL_VERSION, "serverInfo": {"name": "fx"}}});
+        let list = json!({"jsonrpc": "2.0", "id": 2, "result": tools_list_result()});
+        let call = json!({"jsonrpc": "2.0", "id": 3, "result": {"content": [{"type": "text", "text": "{\"rate\": 1350.5}"}]}});
+        let script = format!("read l; echo 'server log'; echo '{init}'; read l; read l; echo '{list}'; read l; echo '{call}'; read l");
+        McpTransport::Stdio {
+            command: "sh".into(),
+            args: vec!["-c".into(), script],
+            env: HashMap::new(),
+        }
+    }
+
+    /// @trace TC: SPEC-037/TC-1
+    #[cfg(unix)]
+    #[test]
+    fn spec037_tc_1_stdio_server_tools_are_base_tools() {
+        let client = Arc::new(McpClient::connect(&stdio_script(), 5000).unwrap());
+        assert_eq!(client.server_name(), "fx");
+
+        let tools = McpTool::discover(client).unwrap();
+        assert_eq!(tools.len(), 1);
+        let m = tools[0].metadata();
+        assert_eq!(m.name, "lookup_rate");
+        assert_eq!(m.safety_level, "external");
+        assert_eq!(m.parameters_schema["required"], json!(["currency"]));
+
+        let mut params = HashMap::new();
+        params.insert("currency".to_string(), json!("USD"));
+        let out = tools[0].execute(&params);
+        assert_eq!(out["success"], json!(true));
+        assert_eq!(out["rate"], json!(1350.5));
+    }
+
+    /// @trace TC: SPEC-037/TC-2
+    #[test]
+    fn spec037_tc_2_http_transport_with_session_and_sse() {
+        let (url, requests) = spawn_mock_server(4, |req| {
+            let msg: Value = serde_json::from_str(&req.body).unwrap();
+            let id = msg["id"].clone();
+            let response = match msg["method"].as_str().unwrap() {
+                | "initialize" => MockResponse::json(&json!({"jsonrpc": "2.0", "id": id, "result": {"serverInfo": {"name": "fx-http"}}})),
+                | "notifications/initialized" => MockResponse::new("application/json", "").with_status("202 Accepted"),
+                | "tools/list" => {
+                    let resp = json!({"jsonrpc": "2.0", "id": id, "result": tools_list_result()});
+                    MockResponse::new("text/event-stream", format!("event: message\ndata: {resp}\n\n"))
+                },
+                | _ => MockResponse::json(
+                    &json!({"jsonrpc": "2.0", "id": id, "result": {"isError": true, "content": [{"type": "text", "text": "unknown currency"}]}}),
+                ),
+            };
+            response.with_header("Mcp-Session-Id", "s-1")
+        });
+        let url = format!("{url}/mcp");
+
+        let client = Arc::new(
+            McpClient::connect(
+                &McpTransport::Http {
+                    url,
+                },
+                5000,
+            )
+            .unwrap(),
+        );
+        assert_eq!(client.server_name(), "fx-http");
+        let tools = McpTool::discover(client).unwrap();
+        assert_eq!(tools[0].metadata().name, "lookup_rate");
+
+        let out = tools[0].execute(&HashMap::new());
+        assert_eq!(out["success"], json!(false));
+        assert_eq!(out["error"], json!("unknown currency"));
+
+        let seen: Vec<String> = requests.iter().map(|r| r.raw).collect();
+        assert!(!seen[0].to_ascii_lowercase().contains("mcp-session-id"));
+        assert!(seen[1 ..].iter().all(|r| r.to_ascii_lowercase().contains("mcp-session-id: s-1")), "{seen:?}");
+    }
+
+    /// @trace TC: SPEC-037/TC-3
+    #[cfg(unix)]
+    #[test]
+    fn spec037_tc_3_stdio_timeout_and_exit_are_errors() {
+        let silent = McpTransport::Stdio {
+            command: "sh".into(),
+            args: vec!["-c".into(), "sleep 5".into()],
+            env: HashMap::new(),
+        };
+        let err = McpClient::connect(&silent, 200).err().unwrap();
+        assert!(err.contains("initialize"), "{err}");
+
+        let exits = McpTransport::Stdio {
+            command: "sh".into(),
+            args: vec!["-c".into(), "exit 0".into()],
+            env: HashMap::new(),
+        };
+        assert!(McpClient::connect(&exits, 5000).is_err());
+        let missing = McpTransport::Stdio {
+            command: "definitely-not-an-mcp-server".into(),
+            args: vec![],
+            env: HashMap::new(),
+        };
+        assert!(McpClient::connect(&missing, 5000).err().unwrap().starts_with("spawn"));
+    }
+
+    #[test]
+    fn result_to_output_text_and_structured() {
+        let plain = result_to_output(&json!({"content": [{"type": "text", "text": "a"}, {"type": "text", "text": "b"}]}));
+        assert_eq!(plain["response_text"], json!("a\nb"));
+        assert_eq!(plain["success"], json!(true));
+
+        let structured = result_to_output(&json!({"content": [], "structuredContent": {"count": 2}}));
+        assert_eq!(structured["count"], json!(2));
+    }
+}
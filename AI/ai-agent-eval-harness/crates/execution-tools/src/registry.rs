@@ -10,6 +10,7 @@ use std::{collections::HashMap,
 /// 도구는 네임스페이스 키(`<domain>__<name>`) 로 저장되어, 여러 도메인이
 /// 동일한 도구 이름을 갖더라도 충돌 없이 공존한다. 기본 파일 도구는 도메인
 /// 프리픽스 없이 `"general"` 도메인으로 등록된다.
+#[derive(Clone)]
 pub struct ToolRegistry {
     tools: HashMap<String, Arc<dyn BaseTool>>,
     /// 각 레지스트리 키 → 도메인 이름. LLM 시스템 프롬프트에 surface 되어
@@ -0,0 +1,321 @@
+// =============================================================================
+// @trace SPEC-045
+// @trace PRD: PRD-045
+// @trace FR: PRD-045/FR-1, PRD-045/FR-2, PRD-045/FR-3
+// @trace file-type: impl
+// =============================================================================
+//
+// 시나리오 실행 1회의 격리된 작업 공간. 러너가 임시 디렉토리를 만들어
+// 시나리오 fixture 로 채우고 `scoped` 로 현재 스레드에 설치하면, 내장 파일
+// 도구는 모든 경로를 이 루트 기준으로 해석한다. `..` 이나 심볼릭 링크로
+// 루트를 벗어나는 경로와 크기 제한을 넘는 읽기/쓰기는 거부한다. 실행이
+// 끝나면 `diff` 로 최종 상태를 기대 파일과 비교한다.
+
+use std::{cell::RefCell,
+          collections::BTreeMap,
+          path::{Component,
+                 Path,
+                 PathBuf},
+          sync::Arc};
+
+/// 파일 하나와 작업 공간 전체의 크기 상한(바이트).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkspaceLimits {
+    pub max_file_bytes: u64,
+    pub max_total_bytes: u64,
+}
+
+impl Default for WorkspaceLimits {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: 1024 * 1024,
+            max_total_bytes: 10 * 1024 * 1024,
+        }
+    }
+}
+
+/// 기대 파일 하나의 비교 결과. `expected` 가 None 이면 파일이 없어야 한다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileCheck {
+    pub path: String,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub passed: bool,
+}
+
+/// 드롭되면 임시 디렉토리와 내용이 함께 삭제된다.
+#[derive(Debug)]
+pub struct Workspace {
+    dir: tempfile::TempDir,
+    root: PathBuf,
+    limits: WorkspaceLimits,
+}
+
+impl Workspace {
+    pub fn new(limits: WorkspaceLimits) -> std::io::Result<Self> {
+        let dir = tempfile::Builder::new().prefix("eval-workspace-").tempdir()?;
+        // macOS 의 /var → /private/var 처럼 임시 경로 자체가 링크일 수 있어
+        // 비교 기준은 정규화된 루트로 둔다.
+        let root = dir.path().canonicalize()?;
+        Ok(Self {
+            dir,
+            root,
+            limits,
+        })
+    }
+
+    pub fn root(&self) -> &Path { &self.root }
+
+    pub fn limits(&self) -> WorkspaceLimits { self.limits }
+
+    /// fixture 를 작업 공간에 쓴다. 경로 규칙과 크기 제한은 도구 쓰기와 같다.
+    ///
+    /// @trace SPEC: SPEC-045
+    /// @trace FR: PRD-045/FR-1
+    pub fn seed(&self, fixtures: &BTreeMap<String, String>) -> Result<(), String> {
+        for (path, content) in fixtures {
+            self.write(path, content)?;
+        }
+        Ok(())
+    }
+
+    /// 도구가 받은 경로를 작업 공간 안의 실제 경로로 바꾼다. 상대 경로는
+    /// 루트 기준이고, 절대 경로는 루트 아래일 때만 받는다. 존재하는 가장
+    /// 깊은 조상을 정규화해 심볼릭 링크가 루트 밖을 가리키면 거부한다.
+    ///
+    /// @trace SPEC: SPEC-045
+    /// @trace FR: PRD-045/FR-2
+    pub fn resolve(&self, path: &str) -> Result<PathBuf, String> {
+        let raw = Path::new(path);
+        let relative = if raw.is_absolute() {
+            raw.strip_prefix(&self.root)
+                .or_else(|_| raw.strip_prefix(self.dir.path()))
+                .map_err(|_| format!("작업 공간 밖 경로입니다: {}", path))?
+        } else {
+            raw
+        };
+
+        let mut normalized = PathBuf::new();
+        for component in relative.components() {
+            match component {
+                | Component::Normal(part) => normalized.push(part),
+                | Component::CurDir => {},
+                | Component::ParentDir =>
+                    if !normalized.pop() {
+                        return Err(format!("작업 공간 밖 경로입니다: {}", path));
+                    },
+                | Component::RootDir | Component::Prefix(_) => return Err(format!("작업 공간 밖 경로입니다: {}", path)),
+            }
+        }
+
+        let target = self.root.join(&normalized);
+        let existing = target.ancestors().find(|p| p.symlink_metadata().is_ok()).unwrap_or(&self.root);
+        match existing.canonicalize() {
+            | Ok(real) if real.starts_with(&self.root) => Ok(target),
+            | _ => Err(format!("작업 공간 밖을 가리키는 링크입니다: {}", path)),
+        }
+    }
+
+    /// 루트 기준 상대 경로. 루트 자체는 `.`.
+    pub fn relative(&self, path: &Path) -> String {
+        match path.strip_prefix(&self.root) {
+            | Ok(rel) if rel.as_os_str().is_empty() => ".".into(),
+            | Ok(rel) => rel.to_string_lossy().into_owned(),
+            | Err(_) => path.to_string_lossy().into_owned(),
+        }
+    }
+
+    /// @trace SPEC: SPEC-045
+    /// @trace FR: PRD-045/FR-2
+    pub fn read(&self, path: &str) -> Result<(PathBuf, String), String> {
+        let resolved = self.resolve(path)?;
+        let meta = std::fs::metadata(&resolved).map_err(|_| format!("파일이 존재하지 않습니다: {}", path))?;
+        if meta.len() > self.limits.max_file_bytes {
+            return Err(format!("파일 크기 제한 초과: {} ({} > {} bytes)", path, meta.len(), self.limits.max_file_bytes));
+        }
+        let content = std::fs::read_to_string(&resolved).map_err(|e| format!("파일 읽기 실패: {}", e))?;
+        Ok((resolved, content))
+    }
+
+    /// 파일 크기와, 쓴 뒤의 작업 공간 전체 크기가 제한을 넘으면 거부한다.
+    ///
+    /// @trace SPEC: SPEC-045
+    /// @trace FR: PRD-045/FR-2
+    pub fn write(&self, path: &str, content: &str) -> Result<PathBuf, String> {
+        let resolved = self.resolve(path)?;
+        let size = content.len() as u64;
+        if size > self.limits.max_file_bytes {
+            return Err(format!("파일 크기 제한 초과: {} ({} > {} bytes)", path, size, self.limits.max_file_bytes));
+        }
+        let previous = std::fs::symlink_metadata(&resolved).map(|m| m.len()).unwrap_or(0);
+        let total = self.total_bytes() - previous + size;
+        if total > self.limits.max_total_bytes {
+            return Err(format!("작업 공간 크기 제한 초과: {} > {} bytes", total, self.limits.max_total_bytes));
+        }
+        if let Some(parent) = resolved.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("디렉토리 생성 실패: {}", e))?;
+        }
+        std::fs::write(&resolved, content).map_err(|e| format!("파일 쓰기 실패: {}", e))?;
+        Ok(resolved)
+    }
+
+    /// 작업 공간 안 일반 파일 크기의 합. 링크는 따라가지 않는다.
+    pub fn total_bytes(&self) -> u64 { self.files().iter().map(|p| std::fs::symlink_metadata(p).map(|m| m.len()).unwrap_or(0)).sum() }
+
+    /// 작업 공간의 일반 파일. 링크 디렉토리 안으로는 들어가지 않는다.
+    fn files(&self) -> Vec<PathBuf> {
+        fn walk(dir: &Path, out: &mut Vec<PathBuf>) {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                return;
+            };
+            for entry in entries.flatten() {
+                match entry.file_type() {
+                    | Ok(t) if t.is_dir() => walk(&entry.path(), out),
+                    | Ok(t) if t.is_file() => out.push(entry.path()),
+                    | _ => {},
+                }
+            }
+        }
+        let mut paths = Vec::new();
+        walk(&self.root, &mut paths);
+        paths
+    }
+
+    /// 최종 상태를 기대 파일과 비교한다. 내용은 앞뒤 공백을 무시하고
+    /// 일치해야 통과한다.
+    ///
+    /// @trace SPEC: SPEC-045
+    /// @trace FR: PRD-045/FR-3
+    pub fn diff(&self, expected: &BTreeMap<String, Option<String>>) -> Vec<FileCheck> {
+        expected
+            .iter()
+            .map(|(path, want)| {
+                let actual = self
+                    .resolve(path)
+                    .ok()
+                    .filter(|p| p.symlink_metadata().is_ok_and(|m| m.is_file()))
+                    .map(|p| std::fs::read(p).map(|b| String::from_utf8_lossy(&b).into_owned()).unwrap_or_default());
+                let passed = match (want, &actual) {
+                    | (Some(w), Some(a)) => w.trim() == a.trim(),
+                    | (None, None) => true,
+                    | _ => false,
+                };
+                FileCheck {
+                    path: path.clone(),
+                    expected: want.clone(),
+                    actual,
+                    passed,
+                }
+            })
+            .collect()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<Workspace>>> = const { RefCell::new(None) };
+}
+
+/// 이전 작업 공간을 되돌리는 guard. `f` 가 패닉해도 복원된다.
+struct Restore(Option<Arc<Workspace>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        CURRENT.with(|c| *c.borrow_mut() = previous);
+    }
+}
+
+/// 현재 스레드에 `workspace` 를 설치한 채로 `f` 를 실행한다.
+///
+/// @trace SPEC: SPEC-045
+/// @trace FR: PRD-045/FR-1
+pub fn scoped<R>(workspace: Option<Arc<Workspace>>, f: impl FnOnce() -> R) -> R {
+    let previous = CURRENT.with(|c| std::mem::replace(&mut *c.borrow_mut(), workspace));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// 현재 스레드의 작업 공간. 없으면 파일 도구는 경로를 그대로 쓴다.
+pub fn current() -> Option<Arc<Workspace>> { CURRENT.with(|c| c.borrow().clone()) }
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-045
+    // @trace PRD: PRD-045
+    // @trace FR: PRD-045/FR-1, PRD-045/FR-2, PRD-045/FR-3
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+
+    fn workspace() -> Workspace {
+        let ws = Workspace::new(WorkspaceLimits {
+            max_file_bytes: 16,
+            max_total_bytes: 24,
+        })
+        .unwrap();
+        ws.seed(&BTreeMap::from([("docs/a.txt".to_string(), "hello".to_string())])).unwrap();
+        ws
+    }
+
+    /// @trace TC: SPEC-045/TC-1
+    /// @trace FR: PRD-045/FR-2
+    #[test]
+    fn spec045_tc_1_rejects_traversal_symlink_escape_and_oversize() {
+        let ws = workspace();
+        assert_eq!(ws.resolve("docs/../docs/a.txt").unwrap(), ws.root().join("docs/a.txt"));
+        assert_eq!(
+            ws.resolve(&ws.root().join("docs/a.txt").to_string_lossy()).unwrap(),
+            ws.root().join("docs/a.txt")
+        );
+        assert!(ws.resolve("../outside.txt").is_err());
+        assert!(ws.resolve("docs/../../outside.txt").is_err());
+        assert!(ws.resolve("/etc/passwd").is_err());
+
+        #[cfg(unix)]
+        {
+            let outside = tempfile::tempdir().unwrap();
+            std::os::unix::fs::symlink(outside.path(), ws.root().join("escape")).unwrap();
+            std::os::unix::fs::symlink(outside.path().join("missing"), ws.root().join("dangling")).unwrap();
+            assert!(ws.resolve("escape/secret.txt").is_err());
+            assert!(ws.write("dangling", "x").is_err(), "끊어진 링크를 따라 밖에 쓰면 안 된다");
+            assert!(!outside.path().join("missing").exists());
+        }
+
+        assert!(ws.write("big.txt", &"x".repeat(17)).is_err(), "파일 크기 제한");
+        ws.write("b.txt", &"x".repeat(16)).unwrap();
+        assert!(ws.write("c.txt", "xxxx").is_err(), "전체 크기 제한 (5 + 16 + 4 > 24)");
+        ws.write("b.txt", "short").unwrap();
+        assert_eq!(ws.total_bytes(), 10, "덮어쓴 파일은 이전 크기를 빼고 센다");
+    }
+
+    /// @trace TC: SPEC-045/TC-2
+    /// @trace FR: PRD-045/FR-1, PRD-045/FR-3
+    #[test]
+    fn spec045_tc_2_diff_against_expected_files() {
+        let ws = workspace();
+        ws.write("out/report.txt", "total: 3\n").unwrap();
+        let checks = ws.diff(&BTreeMap::from([
+            ("out/report.txt".to_string(), Some("total: 3".to_string())),
+            ("docs/a.txt".to_string(), Some("changed".to_string())),
+            ("docs/a.txt.bak".to_string(), None),
+            ("missing.txt".to_string(), Some("x".to_string())),
+        ]));
+        let passed: Vec<(&str, bool)> = checks.iter().map(|c| (c.path.as_str(), c.passed)).collect();
+        assert_eq!(
+            passed,
+            vec![
+                ("docs/a.txt", false),
+                ("docs/a.txt.bak", true),
+                ("missing.txt", false),
+                ("out/report.txt", true)
+            ]
+        );
+        assert_eq!(checks[0].actual.as_deref(), Some("hello"));
+
+        let root = ws.root().to_path_buf();
+        drop(ws);
+        assert!(!root.exists(), "드롭되면 작업 공간이 삭제된다");
+    }
+}
@@ -39,6 +39,7 @@ impl BaseAgent for PassthroughAgent {
             },
             tool_calls: Vec::new(),
             duration_ms: Some(0.0),
+            token_usage: None,
         };
 
         let policy_step = PpaStep {
@@ -59,6 +60,7 @@ impl BaseAgent for PassthroughAgent {
             },
             tool_calls: Vec::new(),
             duration_ms: Some(0.0),
+            token_usage: None,
         };
 
         Trajectory {
@@ -68,6 +70,11 @@ impl BaseAgent for PassthroughAgent {
             end_time: Some(now),
             steps: vec![perceive_step, policy_step],
             prompt_set_id: None,
+            injected_faults: Vec::new(),
+            token_usage: None,
+            tool_calling_mode: None,
+            llm_model: None,
+            domain_routing: None,
             final_state: Some(AgentState {
                 current_stage: PpaStage::Policy,
                 iteration: 1,
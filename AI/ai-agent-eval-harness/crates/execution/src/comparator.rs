@@ -1,35 +1,75 @@
-use crate::models::{ComparisonResult,
-                    EvaluationReport,
-                    MetricDelta};
+use crate::{models::{ComparisonResult,
+                     EvaluationReport,
+                     MetricDelta,
+                     PassAtKDelta},
+            stats};
 use anyhow::Result;
 use chrono::Utc;
 use colored::*;
-use std::collections::HashSet;
+use std::collections::{BTreeMap,
+                       BTreeSet,
+                       HashSet};
+
+/// SPEC-031, SPEC-035: 값이 낮을수록 좋은 메트릭.
+const LOWER_IS_BETTER: &[&str] = &[
+    "fault_silent_acceptance_rate",
+    "prompt_tokens",
+    "completion_tokens",
+    "total_tokens",
+    "cost_usd",
+    "cost_per_success_usd",
+];
+/// SPEC-031: 방향성이 없어 회귀 판정에서 제외하는 참고용 메트릭.
+const INFORMATIONAL: &[&str] = &["fault_retries_after_failure"];
+/// SPEC-038: 유의성 검정에 필요한 한쪽 최소 표본 수.
+const MIN_SAMPLES: usize = 2;
 
 pub struct ReportComparator {
     pub threshold_percent: f64,
+    pub alpha: f64,
 }
 
 impl ReportComparator {
     pub fn new(threshold_percent: f64) -> Self {
         Self {
             threshold_percent,
+            alpha: 0.05,
         }
     }
 
+    /// 회귀 판정 유의수준. 시행별 표본이 있는 메트릭은 임계값을 넘게
+    /// 나빠졌고 Mann-Whitney p-value 가 이보다 작을 때만 회귀다.
+    ///
+    /// @trace SPEC: SPEC-038
+    /// @trace FR: PRD-038/FR-4
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
     pub fn compare(&self, baseline: &EvaluationReport, current: &EvaluationReport) -> ComparisonResult {
         let all_keys: HashSet<&String> = baseline.average_metrics.keys().chain(current.average_metrics.keys()).collect();
         let mut sorted_keys: Vec<&&String> = all_keys.iter().collect();
         sorted_keys.sort();
 
+        let mut untestable = false;
         let metric_deltas: Vec<MetricDelta> = sorted_keys
             .iter()
             .map(|key| {
                 let baseline_val = baseline.average_metrics.get(**key).copied();
                 let current_val = current.average_metrics.get(**key).copied();
-                self.compute_delta(key, baseline_val, current_val)
+                let mut delta = self.compute_delta(key, baseline_val, current_val);
+                untestable |= !self.apply_significance(&mut delta, &samples(baseline, key), &samples(current, key));
+                delta
             })
             .collect();
+        let mut warnings = Vec::new();
+        if untestable {
+            warnings.push(format!(
+                "시행 수(베이스라인 {}회, 현재 {}회)로는 유의수준 {} 에 도달할 수 없어 유의성 검정 없이 임계값으로 판정했습니다.",
+                baseline.trials, current.trials, self.alpha
+            ));
+        }
 
         let regression_count = metric_deltas.iter().filter(|d| d.is_regression).count();
         let improvement_count = metric_deltas.iter().filter(|d| d.direction == "improved").count();
@@ -46,22 +86,58 @@ impl ReportComparator {
             improvement_count,
             verdict,
             threshold_percent: self.threshold_percent,
+            alpha: self.alpha,
+            pass_at_k: pass_at_k_deltas(baseline, current),
             summary,
+            warnings,
+        }
+    }
+
+    /// 두 리포트에 시행별 표본이 있으면 신뢰구간과 p-value 를 채우고,
+    /// 유의하지 않은 변화는 회귀에서 제외한다. 표본이 적어 완전히 갈라져도
+    /// 유의수준에 못 미치면 검정이 회귀를 지울 수 없으므로 임계값 판정을
+    /// 그대로 둔다. 그렇게 남은 회귀가 있으면 경고하도록 false 를 돌려준다.
+    ///
+    /// @trace SPEC: SPEC-038
+    /// @trace FR: PRD-038/FR-2, PRD-038/FR-4
+    fn apply_significance(&self, delta: &mut MetricDelta, baseline: &[f64], current: &[f64]) -> bool {
+        delta.baseline_ci = stats::mean_ci95(baseline);
+        delta.current_ci = stats::mean_ci95(current);
+        if baseline.len() < MIN_SAMPLES || current.len() < MIN_SAMPLES {
+            return true;
+        }
+        delta.p_value = stats::mann_whitney_p(baseline, current);
+        if stats::mann_whitney_min_p(baseline.len(), current.len()).is_some_and(|p| p >= self.alpha) {
+            return !delta.is_regression;
         }
+        if delta.p_value.is_some_and(|p| p >= self.alpha) {
+            delta.is_regression = false;
+        }
+        true
     }
 
     fn compute_delta(&self, name: &str, baseline: Option<f64>, current: Option<f64>) -> MetricDelta {
         match (baseline, current) {
             | (Some(b), Some(c)) => {
                 let delta = c - b;
-                let delta_percent = if b != 0.0 { delta / b * 100.0 } else { 0.0 };
-                let is_regression = delta_percent < -self.threshold_percent;
-                let direction = if delta > 0.0 {
-                    "improved"
-                } else if delta < 0.0 {
-                    "degraded"
+                let lower_is_better = LOWER_IS_BETTER.contains(&name);
+                let delta_percent = if b != 0.0 {
+                    delta / b * 100.0
+                } else if lower_is_better && delta != 0.0 {
+                    // 0 이 최선인 비율 메트릭은 0 에서 벗어나는 것 자체가 변화.
+                    delta.signum() * 100.0
                 } else {
+                    0.0
+                };
+                // 좋아진 방향을 양수로 맞춘 변화율
+                let gain_percent = if lower_is_better { -delta_percent } else { delta_percent };
+                let is_regression = !INFORMATIONAL.contains(&name) && gain_percent < -self.threshold_percent;
+                let direction = if INFORMATIONAL.contains(&name) || delta == 0.0 {
                     "unchanged"
+                } else if (delta > 0.0) != lower_is_better {
+                    "improved"
+                } else {
+                    "degraded"
                 };
                 MetricDelta {
                     metric_name: name.to_string(),
@@ -71,6 +147,9 @@ impl ReportComparator {
                     delta_percent: Some(delta_percent),
                     is_regression,
                     direction: direction.to_string(),
+                    baseline_ci: None,
+                    current_ci: None,
+                    p_value: None,
                 }
             },
             | _ => MetricDelta {
@@ -81,13 +160,19 @@ impl ReportComparator {
                 delta_percent: None,
                 is_regression: false,
                 direction: "unchanged".to_string(),
+                baseline_ci: None,
+                current_ci: None,
+                p_value: None,
             },
         }
     }
 
     fn build_summary(&self, regressions: usize, improvements: usize, verdict: &str) -> String {
         if verdict == "fail" {
-            format!("회귀 감지: {}개 메트릭이 {:.1}% 이상 하락했습니다.", regressions, self.threshold_percent)
+            format!(
+                "회귀 감지: {}개 메트릭이 {:.1}% 이상 하락했습니다 (유의수준 {}).",
+                regressions, self.threshold_percent, self.alpha
+            )
         } else {
             format!("통과: {}개 개선, 회귀 없음 (임계값: {:.1}%)", improvements, self.threshold_percent)
         }
@@ -106,14 +191,18 @@ impl ReportComparator {
             println!("\n{}", "판정: FAIL".red().bold());
         }
         println!("{}", result.summary);
+        for warning in &result.warnings {
+            println!("{}", format!("경고: {warning}").yellow());
+        }
 
-        println!("\n{:<30} {:>12} {:>12} {:>10} 상태", "메트릭", "베이스라인", "현재", "변화");
-        println!("{}", "-".repeat(80));
+        println!("\n{:<30} {:>12} {:>12} {:>10} {:>8} 상태", "메트릭", "베이스라인", "현재", "변화", "p");
+        println!("{}", "-".repeat(90));
 
         for d in &result.metric_deltas {
             let baseline_str = d.baseline_value.map(|v| format!("{:.3}", v)).unwrap_or_else(|| "-".into());
             let current_str = d.current_value.map(|v| format!("{:.3}", v)).unwrap_or_else(|| "-".into());
             let delta_str = d.delta_percent.map(|v| format!("{:+.1}%", v)).unwrap_or_else(|| "-".into());
+            let p_str = d.p_value.map(|p| format!("{:.3}", p)).unwrap_or_else(|| "-".into());
 
             let status = if d.is_regression {
                 "REGRESSION".red().bold().to_string()
@@ -125,7 +214,18 @@ impl ReportComparator {
                 "OK".dimmed().to_string()
             };
 
-            println!("{:<30} {:>12} {:>12} {:>10} {}", d.metric_name, baseline_str, current_str, delta_str, status);
+            println!(
+                "{:<30} {:>12} {:>12} {:>10} {:>8} {}",
+                d.metric_name, baseline_str, current_str, delta_str, p_str, status
+            );
+        }
+
+        if !result.pass_at_k.is_empty() {
+            println!("\n{:<30} {:>12} {:>12}", "pass@k", "베이스라인", "현재");
+            let fmt = |v: Option<f64>| v.map(|v| format!("{:.1}%", v * 100.0)).unwrap_or_else(|| "-".into());
+            for p in &result.pass_at_k {
+                println!("{:<30} {:>12} {:>12}", format!("pass@{}", p.k), fmt(p.baseline), fmt(p.current));
+            }
         }
     }
 
@@ -144,3 +244,211 @@ impl ReportComparator {
 impl Default for ReportComparator {
     fn default() -> Self { Self::new(5.0) }
 }
+
+/// 리포트의 시행(`trial`)별 `metric` 평균. 시나리오마다 난이도가 달라
+/// 시나리오 값을 한 표본으로 섞으면 검정이 무의미하므로, 시행 하나를
+/// 표본 하나로 본다. 단일 시행 리포트는 표본이 없어 검정을 건너뛴다.
+fn samples(report: &EvaluationReport, metric: &str) -> Vec<f64> {
+    if report.trials <= 1 {
+        return Vec::new();
+    }
+    let mut by_trial: BTreeMap<usize, Vec<f64>> = BTreeMap::new();
+    for s in &report.scenarios {
+        if let Some(v) = s.metrics.get(metric).copied().flatten() {
+            by_trial.entry(s.trial).or_default().push(v);
+        }
+    }
+    by_trial.values().filter_map(|vs| stats::mean(vs)).collect()
+}
+
+/// 두 리포트의 pass@k 를 k 별로 맞춘다. 저장된 값이 없으면 실행별 결과로
+/// 계산한다.
+fn pass_at_k_deltas(baseline: &EvaluationReport, current: &EvaluationReport) -> Vec<PassAtKDelta> {
+    let of = |r: &EvaluationReport| {
+        if r.pass_at_k.is_empty() {
+            stats::pass_at_k_by_scenario(&r.scenarios)
+        } else {
+            r.pass_at_k.clone()
+        }
+    };
+    let (b, c) = (of(baseline), of(current));
+    let ks: BTreeSet<usize> = b.keys().chain(c.keys()).copied().collect();
+    ks.into_iter()
+        .map(|k| PassAtKDelta {
+            k,
+            baseline: b.get(&k).copied(),
+            current: c.get(&k).copied(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-031
+    // @trace PRD: PRD-031
+    // @trace FR: PRD-031/FR-4
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+
+    /// @trace TC: SPEC-031/TC-7
+    #[test]
+    fn spec031_tc_7_fault_metric_polarity() {
+        let cmp = ReportComparator::new(5.0);
+
+        let d = cmp.compute_delta("fault_recovery_rate", Some(0.8), Some(0.5));
+        assert!(d.is_regression);
+        assert_eq!(d.direction, "degraded");
+
+        // 무비판 수용률은 오르면 회귀, 0 에서 오른 경우도 회귀
+        let d = cmp.compute_delta("fault_silent_acceptance_rate", Some(0.2), Some(0.4));
+        assert!(d.is_regression);
+        assert_eq!(d.direction, "degraded");
+        assert!(cmp.compute_delta("fault_silent_acceptance_rate", Some(0.0), Some(0.5)).is_regression);
+        let d = cmp.compute_delta("fault_silent_acceptance_rate", Some(0.5), Some(0.0));
+        assert!(!d.is_regression);
+        assert_eq!(d.direction, "improved");
+
+        // 재시도 횟수는 참고용
+        let d = cmp.compute_delta("fault_retries_after_failure", Some(3.0), Some(0.5));
+        assert!(!d.is_regression);
+        assert_eq!(d.direction, "unchanged");
+    }
+
+    /// @trace TC: SPEC-035/TC-4
+    #[test]
+    fn spec035_tc_4_cost_metric_polarity() {
+        let cmp = ReportComparator::new(5.0);
+
+        let d = cmp.compute_delta("cost_per_success_usd", Some(0.010), Some(0.020));
+        assert!(d.is_regression);
+        assert_eq!(d.direction, "degraded");
+
+        let d = cmp.compute_delta("total_tokens", Some(1200.0), Some(900.0));
+        assert!(!d.is_regression);
+        assert_eq!(d.direction, "improved");
+    }
+
+    fn report_with_runs(metric: &str, values: &[f64]) -> EvaluationReport {
+        let scenarios = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| crate::models::ScenarioResult {
+                task_id: format!("t{i}"),
+                task_description: "단리 계산".into(),
+                success: *v >= 0.5,
+                total_iterations: 1,
+                metrics: std::collections::HashMap::from([(metric.to_string(), Some(*v))]),
+                domain: "financial".into(),
+                scenario_id: "fin_001".into(),
+                trial: i,
+            })
+            .collect();
+        EvaluationReport {
+            version: "1.0".into(),
+            timestamp: "t".into(),
+            agent_name: "ppa".into(),
+            eval_scenario: "financial".into(),
+            total_scenarios: values.len(),
+            success_count: values.iter().filter(|v| **v >= 0.5).count(),
+            success_rate: 0.0,
+            average_metrics: std::collections::HashMap::from([(metric.to_string(), stats::mean(values).unwrap())]),
+            scenarios,
+            trials: values.len(),
+            pass_at_k: Default::default(),
+        }
+    }
+
+    /// @trace TC: SPEC-038/TC-3
+    #[test]
+    fn spec038_tc_3_verdict_fails_only_on_significant_regression() {
+        let cmp = ReportComparator::new(5.0);
+
+        // 평균은 10% 넘게 떨어졌지만 시행 간 편차가 커서 유의하지 않음
+        let noisy_base = report_with_runs("overall_score", &[0.9, 0.4, 0.8, 0.5, 0.9]);
+        let noisy_cur = report_with_runs("overall_score", &[0.8, 0.3, 0.9, 0.4, 0.6]);
+        let r = cmp.compare(&noisy_base, &noisy_cur);
+        let d = &r.metric_deltas[0];
+        assert_eq!(d.direction, "degraded");
+        assert!(d.p_value.unwrap() >= 0.05, "{:?}", d.p_value);
+        assert!(!d.is_regression);
+        assert_eq!(r.verdict, "pass");
+        assert!(d.baseline_ci.is_some() && d.current_ci.is_some());
+
+        // 모든 시행이 분리될 만큼 떨어지면 회귀
+        let base = report_with_runs("overall_score", &[0.90, 0.92, 0.88, 0.95, 0.91, 0.93]);
+        let cur = report_with_runs("overall_score", &[0.60, 0.62, 0.58, 0.65, 0.61, 0.63]);
+        let r = cmp.compare(&base, &cur);
+        assert!(r.metric_deltas[0].p_value.unwrap() < 0.05);
+        assert_eq!(r.verdict, "fail");
+        assert_eq!(r.alpha, 0.05);
+        assert_eq!(r.pass_at_k.len(), 6);
+        assert_eq!(r.pass_at_k[0].baseline, Some(1.0));
+        assert_eq!(r.pass_at_k[0].current, Some(1.0));
+
+        assert!(r.warnings.is_empty());
+
+        // 6회로는 도달할 수 없는 유의수준이면 임계값 판정을 유지하고 경고
+        let strict = ReportComparator::new(5.0).with_alpha(0.001).compare(&base, &cur);
+        assert_eq!(strict.verdict, "fail");
+        assert_eq!(strict.warnings.len(), 1);
+    }
+
+    /// @trace TC: SPEC-038/TC-3
+    #[test]
+    fn spec038_tc_3_few_trials_keep_threshold_verdict() {
+        // 3회 대 3회는 완전히 갈라져도 p 가 0.05 에 못 미친다.
+        assert!(stats::mann_whitney_min_p(3, 3).unwrap() >= 0.05);
+        let base = report_with_runs("success_rate", &[1.0, 1.0, 1.0]);
+        let cur = report_with_runs("success_rate", &[0.0, 0.0, 0.0]);
+        let r = ReportComparator::new(5.0).compare(&base, &cur);
+        assert!(r.metric_deltas[0].is_regression);
+        assert_eq!(r.verdict, "fail");
+        assert_eq!(r.warnings.len(), 1, "{:?}", r.warnings);
+
+        // 개선은 검정할 수 없어도 경고하지 않는다.
+        assert!(ReportComparator::new(5.0).compare(&cur, &base).warnings.is_empty());
+    }
+
+    fn single_trial_report(metric: &str, values: &[f64]) -> EvaluationReport {
+        let mut r = report_with_runs(metric, values);
+        for (i, s) in r.scenarios.iter_mut().enumerate() {
+            s.scenario_id = format!("fin_{i:03}");
+            s.trial = 0;
+        }
+        r.trials = 1;
+        r
+    }
+
+    /// @trace TC: SPEC-038/TC-3
+    #[test]
+    fn spec038_tc_3_single_trial_report_keeps_threshold_verdict() {
+        let cmp = ReportComparator::new(5.0);
+
+        // 시나리오별 난이도가 달라 값이 흩어져 있어도, 단일 시행이면
+        // 유의성 검정 없이 임계값만으로 회귀를 판정한다.
+        let base = single_trial_report("overall_score", &[0.9, 0.4, 0.8, 0.5, 0.9]);
+        let cur = single_trial_report("overall_score", &[0.8, 0.3, 0.7, 0.4, 0.6]);
+        let r = cmp.compare(&base, &cur);
+        let d = &r.metric_deltas[0];
+        assert!(d.p_value.is_none());
+        assert!(d.is_regression);
+        assert_eq!(r.verdict, "fail");
+    }
+
+    /// @trace TC: SPEC-038/TC-3
+    #[test]
+    fn spec038_tc_3_samples_are_per_trial_means() {
+        // 시나리오 2개 × 시행 3회: 시행별 평균 3개가 표본이 된다.
+        let mut r = report_with_runs("overall_score", &[0.25, 0.75, 0.5, 1.0, 0.0, 0.5]);
+        for (i, s) in r.scenarios.iter_mut().enumerate() {
+            s.scenario_id = format!("fin_{:03}", i % 2);
+            s.trial = i / 2;
+        }
+        r.trials = 3;
+        assert_eq!(samples(&r, "overall_score"), vec![0.5, 0.75, 0.25]);
+        assert!(samples(&r, "missing").is_empty());
+    }
+}
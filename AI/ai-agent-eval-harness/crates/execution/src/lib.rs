@@ -2,5 +2,7 @@ pub mod agent_registry;
 pub mod base_agent;
 pub mod comparator;
 pub mod models;
+pub mod report_export;
 pub mod report_renderer;
 pub mod runner;
+pub mod stats;
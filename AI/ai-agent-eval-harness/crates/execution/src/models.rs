@@ -1,6 +1,7 @@
 use serde::{Deserialize,
             Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap,
+                       HashMap};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScenarioResult {
@@ -13,6 +14,9 @@ pub struct ScenarioResult {
     pub domain: String,
     #[serde(default)]
     pub scenario_id: String,
+    /// SPEC-038: 같은 시나리오의 몇 번째 시행인지 (0 부터).
+    #[serde(default)]
+    pub trial: usize,
 }
 
 fn default_domain() -> String { "general".into() }
@@ -33,8 +37,15 @@ pub struct EvaluationReport {
     pub average_metrics: HashMap<String, f64>,
     #[serde(default)]
     pub scenarios: Vec<ScenarioResult>,
+    /// SPEC-038: 시나리오당 반복 시행 수. `scenarios` 는 시행마다 1행이다.
+    #[serde(default = "default_trials")]
+    pub trials: usize,
+    /// SPEC-038: k → 시나리오 평균 pass@k (k = 1..=trials).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pass_at_k: BTreeMap<usize, f64>,
 }
 
+fn default_trials() -> usize { 1 }
 fn default_version() -> String { "1.0".into() }
 fn default_agent() -> String { "ppa".into() }
 fn default_eval_scenario() -> String { "all".into() }
@@ -48,6 +59,23 @@ pub struct MetricDelta {
     pub delta_percent: Option<f64>,
     pub is_regression: bool,
     pub direction: String,
+    /// SPEC-038: 실행별 표본이 있을 때의 평균 95% 신뢰구간.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline_ci: Option<[f64; 2]>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_ci: Option<[f64; 2]>,
+    /// SPEC-038: 두 표본의 Mann-Whitney U 검정 p-value. 표본이 없으면 None
+    /// 이고, 이때 회귀 판정은 변화율 임계값만 본다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p_value: Option<f64>,
+}
+
+/// SPEC-038: 같은 k 의 pass@k 비교 행.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PassAtKDelta {
+    pub k: usize,
+    pub baseline: Option<f64>,
+    pub current: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,5 +91,15 @@ pub struct ComparisonResult {
     pub improvement_count: usize,
     pub verdict: String,
     pub threshold_percent: f64,
+    /// SPEC-038: 회귀 판정 유의수준.
+    #[serde(default = "default_alpha")]
+    pub alpha: f64,
+    #[serde(default)]
+    pub pass_at_k: Vec<PassAtKDelta>,
     pub summary: String,
+    /// 판정에 대한 경고 (예: 시행 수가 적어 유의성 검정을 못 한 경우).
+    #[serde(default)]
+    pub warnings: Vec<String>,
 }
+
+fn default_alpha() -> f64 { 0.05 }
@@ -0,0 +1,544 @@
+// =============================================================================
+// @trace SPEC-039
+// @trace PRD: PRD-039
+// @trace FR: PRD-039/FR-1, PRD-039/FR-2, PRD-039/FR-3
+// @trace file-type: impl
+// =============================================================================
+//
+// EvaluationReport 를 CI 대시보드용 JUnit XML 과 단일 파일 HTML 로 내보낸다.
+// 시나리오 실행 1건이 testcase 1개가 되고, 궤적(task_id 로 조회)이 있으면
+// 소요 시간·도구 호출 타임라인을 함께 싣는다. HTML 은 외부 CSS/JS 없이
+// 인라인 스타일과 SVG 만 사용한다.
+
+use crate::models::{EvaluationReport,
+                    ScenarioResult};
+use agent_models::models::Trajectory;
+use std::{collections::{BTreeMap,
+                        HashMap},
+          fmt::Write};
+
+/// 리포트 내보내기 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Junit,
+    Html,
+}
+
+impl ExportFormat {
+    /// `json` | `junit` (`xml`) | `html`. 대소문자 무시.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            | "json" => Some(Self::Json),
+            | "junit" | "xml" => Some(Self::Junit),
+            | "html" => Some(Self::Html),
+            | _ => None,
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            | Self::Json => "application/json",
+            | Self::Junit => "application/xml; charset=utf-8",
+            | Self::Html => "text/html; charset=utf-8",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            | Self::Json => "json",
+            | Self::Junit => "xml",
+            | Self::Html => "html",
+        }
+    }
+}
+
+/// 지정 형식으로 렌더링. `trajectories` 는 task_id → 궤적이며 비어 있어도 된다.
+///
+/// @trace SPEC: SPEC-039
+/// @trace FR: PRD-039/FR-1, PRD-039/FR-2
+pub fn export(report: &EvaluationReport, format: ExportFormat, trajectories: &HashMap<String, Trajectory>) -> String {
+    match format {
+        | ExportFormat::Json => serde_json::to_string_pretty(report).unwrap_or_default(),
+        | ExportFormat::Junit => to_junit_xml(report, trajectories),
+        | ExportFormat::Html => to_html(report, trajectories),
+    }
+}
+
+/// 도메인마다 `<testsuite>` 하나, 시나리오 실행마다 `<testcase>` 하나.
+/// 실패한 실행은 `<failure>` 에 오류 메시지와 실패한 도구 호출을 담고,
+/// 메트릭은 testcase 의 `<properties>` 로 남긴다.
+///
+/// @trace SPEC: SPEC-039
+/// @trace FR: PRD-039/FR-1
+pub fn to_junit_xml(report: &EvaluationReport, trajectories: &HashMap<String, Trajectory>) -> String {
+    let mut suites: BTreeMap<&str, Vec<&ScenarioResult>> = BTreeMap::new();
+    for s in &report.scenarios {
+        suites.entry(s.domain.as_str()).or_default().push(s);
+    }
+    let total_time: f64 = report.scenarios.iter().filter_map(|s| duration_secs(trajectories.get(&s.task_id))).sum();
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        out,
+        "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">",
+        xml_escape(&format!("eval-harness {} ({})", report.eval_scenario, report.agent_name)),
+        report.scenarios.len(),
+        report.scenarios.iter().filter(|s| !s.success).count(),
+        total_time,
+    );
+    for (domain, cases) in suites {
+        let time: f64 = cases.iter().filter_map(|s| duration_secs(trajectories.get(&s.task_id))).sum();
+        let _ = writeln!(
+            out,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\" time=\"{:.3}\" timestamp=\"{}\">",
+            xml_escape(domain),
+            cases.len(),
+            cases.iter().filter(|s| !s.success).count(),
+            time,
+            xml_escape(&report.timestamp),
+        );
+        for s in cases {
+            let trajectory = trajectories.get(&s.task_id);
+            let _ = writeln!(
+                out,
+                "    <testcase classname=\"{}.{}\" name=\"{}\" time=\"{:.3}\">",
+                xml_escape(&report.eval_scenario),
+                xml_escape(domain),
+                xml_escape(&case_name(report, s)),
+                duration_secs(trajectory).unwrap_or(0.0),
+            );
+            out.push_str("      <properties>\n");
+            let _ = writeln!(out, "        <property name=\"task_id\" value=\"{}\"/>", xml_escape(&s.task_id));
+            let _ = writeln!(out, "        <property name=\"total_iterations\" value=\"{}\"/>", s.total_iterations);
+            for (name, value) in sorted_metrics(s) {
+                let _ = writeln!(out, "        <property name=\"{}\" value=\"{}\"/>", xml_escape(name), value);
+            }
+            out.push_str("      </properties>\n");
+            if !s.success {
+                let (message, detail) = failure_detail(trajectory);
+                let _ = writeln!(
+                    out,
+                    "      <failure message=\"{}\" type=\"ScenarioFailed\">{}</failure>",
+                    xml_escape(&message),
+                    xml_escape(&detail)
+                );
+            }
+            let _ = writeln!(out, "      <system-out>{}</system-out>", xml_escape(&s.task_description));
+            out.push_str("    </testcase>\n");
+        }
+        out.push_str("  </testsuite>\n");
+    }
+    out.push_str("</testsuites>\n");
+    out
+}
+
+/// 요약·평균 메트릭 차트·시나리오별 메트릭과 도구 호출 타임라인을 담은
+/// 단일 HTML 문서.
+///
+/// @trace SPEC: SPEC-039
+/// @trace FR: PRD-039/FR-2
+pub fn to_html(report: &EvaluationReport, trajectories: &HashMap<String, Trajectory>) -> String {
+    let title = format!("평가 리포트 - {} ({})", report.eval_scenario, report.agent_name);
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "<!DOCTYPE html>\n<html lang=\"ko\">\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" \
+         content=\"width=device-width, initial-scale=1\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n<h1>{}</h1>\n",
+        html_escape(&title),
+        HTML_STYLE,
+        html_escape(&title),
+    );
+
+    // 요약
+    out.push_str("<section><h2>요약</h2><table class=\"kv\">\n");
+    let mut summary = vec![
+        ("타임스탬프", report.timestamp.clone()),
+        ("총 시나리오", report.total_scenarios.to_string()),
+        ("성공", report.success_count.to_string()),
+        ("실패", report.total_scenarios.saturating_sub(report.success_count).to_string()),
+        ("성공률", format!("{:.1}%", report.success_rate * 100.0)),
+    ];
+    if report.trials > 1 {
+        summary.push(("반복 시행", report.trials.to_string()));
+        for (k, v) in &report.pass_at_k {
+            summary.push(("pass@k", format!("k={k}: {:.1}%", v * 100.0)));
+        }
+    }
+    for (k, v) in summary {
+        let _ = writeln!(out, "<tr><th>{}</th><td>{}</td></tr>", k, html_escape(&v));
+    }
+    out.push_str("</table></section>\n");
+
+    // 평균 메트릭: 0~1 비율 메트릭은 막대 차트, 토큰·비용은 값만.
+    if !report.average_metrics.is_empty() {
+        let mut names: Vec<&String> = report.average_metrics.keys().collect();
+        names.sort();
+        let (ratios, absolutes): (Vec<&String>, Vec<&String>) = names.into_iter().partition(|n| !is_absolute_metric(n));
+        out.push_str("<section><h2>평균 메트릭</h2>\n");
+        let bars: Vec<(&str, f64)> = ratios.iter().map(|n| (n.as_str(), report.average_metrics[*n])).collect();
+        out.push_str(&bar_chart_svg(&bars));
+        if !absolutes.is_empty() {
+            out.push_str("<table class=\"kv\">\n");
+            for n in absolutes {
+                let _ = writeln!(
+                    out,
+                    "<tr><th>{}</th><td>{}</td></tr>",
+                    html_escape(n),
+                    format_absolute(n, report.average_metrics[n])
+                );
+            }
+            out.push_str("</table>\n");
+        }
+        out.push_str("</section>\n");
+    }
+
+    // 시나리오별
+    out.push_str("<section><h2>시나리오별 결과</h2>\n");
+    for s in &report.scenarios {
+        let trajectory = trajectories.get(&s.task_id);
+        let badge = if s.success {
+            "<span class=\"ok\">성공</span>"
+        } else {
+            "<span class=\"fail\">실패</span>"
+        };
+        let duration = duration_secs(trajectory).map(|d| format!(" · {d:.2}s")).unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "<details{}><summary>{} <b>{}</b> <span class=\"muted\">{} · 반복 {}{}</span></summary>",
+            if s.success { "" } else { " open" },
+            badge,
+            html_escape(&case_name(report, s)),
+            html_escape(&s.domain),
+            s.total_iterations,
+            duration,
+        );
+        let _ = writeln!(out, "<p>{}</p>", html_escape(&s.task_description));
+        let metrics: Vec<(&str, f64)> = sorted_metrics(s).into_iter().filter(|(n, _)| !is_absolute_metric(n)).collect();
+        if !metrics.is_empty() {
+            out.push_str(&bar_chart_svg(&metrics));
+        }
+        match trajectory {
+            | Some(t) => out.push_str(&timeline_html(t)),
+            | None => out.push_str("<p class=\"muted\">궤적 없음</p>\n"),
+        }
+        out.push_str("</details>\n");
+    }
+    out.push_str("</section>\n</body>\n</html>\n");
+    out
+}
+
+const HTML_STYLE: &str = "body{font-family:system-ui,sans-serif;margin:2rem;color:#222;max-width:1100px}\
+h1{font-size:1.4rem}h2{font-size:1.1rem;border-bottom:1px solid #ddd;padding-bottom:.3rem}\
+table{border-collapse:collapse;font-size:.9rem;margin:.5rem 0}th,td{border:1px solid #ddd;padding:.25rem .5rem;text-align:left;vertical-align:top}\
+table.kv th{background:#f6f6f6}details{border:1px solid #ddd;border-radius:4px;margin:.5rem 0;padding:.4rem .8rem}\
+summary{cursor:pointer}.ok{color:#1a7f37;font-weight:bold}.fail{color:#cf222e;font-weight:bold}.muted{color:#777;font-size:.85rem}\
+pre{margin:0;white-space:pre-wrap;word-break:break-all;font-size:.8rem;max-height:12rem;overflow:auto}svg text{font-size:12px}";
+
+/// 0~1 값의 가로 막대 차트 (80% 이상 녹색, 60% 이상 노랑, 그 외 빨강).
+fn bar_chart_svg(bars: &[(&str, f64)]) -> String {
+    if bars.is_empty() {
+        return String::new();
+    }
+    const ROW: usize = 20;
+    const LABEL: usize = 260;
+    const WIDTH: usize = 300;
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" role=\"img\">\n",
+        LABEL + WIDTH + 60,
+        bars.len() * ROW + 4
+    );
+    for (i, (name, value)) in bars.iter().enumerate() {
+        let y = i * ROW;
+        let clamped = value.clamp(0.0, 1.0);
+        let color = if clamped >= 0.8 {
+            "#2da44e"
+        } else if clamped >= 0.6 {
+            "#d4a72c"
+        } else {
+            "#cf222e"
+        };
+        let _ = writeln!(
+            out,
+            "<text x=\"0\" y=\"{}\">{}</text><rect x=\"{LABEL}\" y=\"{}\" width=\"{WIDTH}\" height=\"14\" fill=\"#eee\"/><rect x=\"{LABEL}\" \
+             y=\"{}\" width=\"{:.1}\" height=\"14\" fill=\"{color}\"/><text x=\"{}\" y=\"{}\">{:.1}%</text>",
+            y + 14,
+            html_escape(name),
+            y + 3,
+            y + 3,
+            clamped * WIDTH as f64,
+            LABEL + WIDTH + 6,
+            y + 14,
+            value * 100.0,
+        );
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// 궤적 단계별 타임라인. 시작 기준 경과 시간, 단계, 소요 시간, 도구 호출을
+/// 한 행에 표시한다.
+fn timeline_html(t: &Trajectory) -> String {
+    let mut out = String::from("<table><tr><th>+ms</th><th>단계</th><th>반복</th><th>소요(ms)</th><th>도구 호출</th></tr>\n");
+    for step in &t.steps {
+        let offset = (step.timestamp - t.start_time).num_milliseconds();
+        let mut calls = String::new();
+        for call in &step.tool_calls {
+            let status = if call.success {
+                "<span class=\"ok\">✓</span>"
+            } else {
+                "<span class=\"fail\">✗</span>"
+            };
+            let params = serde_json::to_string(&call.parameters).unwrap_or_default();
+            let outcome = match (&call.error, &call.result) {
+                | (Some(e), _) => e.clone(),
+                | (None, Some(r)) => r.to_string(),
+                | (None, None) => String::new(),
+            };
+            let _ = write!(
+                calls,
+                "<div>{} <b>{}</b><pre>{}</pre><pre>{}</pre></div>",
+                status,
+                html_escape(&call.tool_name),
+                html_escape(&params),
+                html_escape(&truncate(&outcome, 2000)),
+            );
+        }
+        let _ = writeln!(
+            out,
+            "<tr><td>{}</td><td>{:?}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            offset,
+            step.stage,
+            step.iteration,
+            step.duration_ms.map(|d| format!("{d:.0}")).unwrap_or_default(),
+            calls,
+        );
+    }
+    out.push_str("</table>\n");
+    if let Some(err) = t.final_state.as_ref().and_then(|s| s.error_message.as_deref()) {
+        let _ = writeln!(out, "<p class=\"fail\">{}</p>", html_escape(err));
+    }
+    out
+}
+
+/// 같은 시나리오의 반복 시행을 구분할 수 있도록 시행 번호를 붙인다.
+fn case_name(report: &EvaluationReport, s: &ScenarioResult) -> String {
+    let base = if s.scenario_id.is_empty() {
+        s.task_description.as_str()
+    } else {
+        s.scenario_id.as_str()
+    };
+    if report.trials > 1 {
+        format!("{base} [trial {}]", s.trial)
+    } else {
+        base.to_string()
+    }
+}
+
+fn sorted_metrics(s: &ScenarioResult) -> Vec<(&str, f64)> {
+    let mut metrics: Vec<(&str, f64)> = s.metrics.iter().filter_map(|(k, v)| v.map(|v| (k.as_str(), v))).collect();
+    metrics.sort_by(|a, b| a.0.cmp(b.0));
+    metrics
+}
+
+/// 토큰 수·비용 등 0~1 비율이 아닌 메트릭 (SPEC-035).
+fn is_absolute_metric(name: &str) -> bool { name.ends_with("_tokens") || name.ends_with("_usd") || name == "fault_retries_after_failure" }
+
+fn format_absolute(name: &str, value: f64) -> String {
+    if name.ends_with("_usd") {
+        format!("${value:.4}")
+    } else {
+        format!("{value:.0}")
+    }
+}
+
+fn duration_secs(trajectory: Option<&Trajectory>) -> Option<f64> {
+    let t = trajectory?;
+    Some((t.end_time? - t.start_time).num_milliseconds().max(0) as f64 / 1000.0)
+}
+
+/// 실패 메시지와 상세 (오류 메시지, 실패한 도구 호출 목록).
+fn failure_detail(trajectory: Option<&Trajectory>) -> (String, String) {
+    let Some(t) = trajectory else {
+        return ("시나리오 실패".into(), String::new());
+    };
+    let error = t.final_state.as_ref().and_then(|s| s.error_message.clone());
+    let failed: Vec<String> = t
+        .steps
+        .iter()
+        .flat_map(|s| &s.tool_calls)
+        .filter(|c| !c.success)
+        .map(|c| format!("{}: {}", c.tool_name, c.error.as_deref().unwrap_or("실패")))
+        .collect();
+    let message = error.clone().unwrap_or_else(|| "시나리오 실패".into());
+    let mut detail = error.into_iter().collect::<Vec<_>>();
+    detail.extend(failed);
+    (message, detail.join("\n"))
+}
+
+fn truncate(s: &str, max_chars: usize) -> String {
+    match s.char_indices().nth(max_chars) {
+        | Some((idx, _)) => format!("{}...", &s[.. idx]),
+        | None => s.to_string(),
+    }
+}
+
+fn html_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            | '&' => out.push_str("&amp;"),
+            | '<' => out.push_str("&lt;"),
+            | '>' => out.push_str("&gt;"),
+            | '"' => out.push_str("&quot;"),
+            | '\'' => out.push_str("&#39;"),
+            | c => out.push(c),
+        }
+    }
+    out
+}
+
+/// XML 1.0 에서 허용되지 않는 제어 문자는 제거한다.
+fn xml_escape(s: &str) -> String {
+    html_escape(s)
+        .replace("&#39;", "&apos;")
+        .chars()
+        .filter(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t'))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-039
+    // @trace PRD: PRD-039
+    // @trace FR: PRD-039/FR-1, PRD-039/FR-2
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use agent_models::models::{AgentState,
+                               PpaStage,
+                               PpaStep,
+                               ToolCall};
+    use chrono::{Duration,
+                 Utc};
+
+    fn scenario(id: &str, success: bool, trial: usize) -> ScenarioResult {
+        ScenarioResult {
+            task_id: format!("task-{id}-{trial}"),
+            task_description: format!("{id} <설명> & \"따옴표\""),
+            success,
+            total_iterations: 2,
+            metrics: HashMap::from([
+                ("overall_score".to_string(), Some(if success { 0.9 } else { 0.3 })),
+                ("total_tokens".to_string(), Some(120.0)),
+            ]),
+            domain: "financial".into(),
+            scenario_id: id.into(),
+            trial,
+        }
+    }
+
+    fn report(scenarios: Vec<ScenarioResult>, trials: usize) -> EvaluationReport {
+        EvaluationReport {
+            version: "1.0".into(),
+            timestamp: "20260101_000000".into(),
+            agent_name: "ppa".into(),
+            eval_scenario: "financial".into(),
+            total_scenarios: scenarios.len(),
+            success_count: scenarios.iter().filter(|s| s.success).count(),
+            success_rate: 0.5,
+            average_metrics: HashMap::from([("overall_score".to_string(), 0.6), ("cost_usd".to_string(), 0.0123)]),
+            scenarios,
+            trials,
+            pass_at_k: BTreeMap::from([(1, 0.5)]),
+        }
+    }
+
+    fn failed_trajectory(task_id: &str) -> Trajectory {
+        let start = Utc::now();
+        let mut call = ToolCall::new("calculator".into(), HashMap::from([("expr".to_string(), serde_json::json!("1/0"))]));
+        call.success = false;
+        call.error = Some("division by zero".into());
+        let mut state = AgentState::new("t".into());
+        state.error_message = Some("최대 반복 초과".into());
+        Trajectory {
+            task_id: task_id.into(),
+            task_description: "t".into(),
+            start_time: start,
+            end_time: Some(start + Duration::milliseconds(1500)),
+            steps: vec![PpaStep {
+                stage: PpaStage::Action,
+                iteration: 1,
+                timestamp: start + Duration::milliseconds(200),
+                input_data: HashMap::new(),
+                output_data: HashMap::new(),
+                tool_calls: vec![call],
+                duration_ms: Some(12.0),
+                token_usage: None,
+            }],
+            final_state: Some(state),
+            success: false,
+            total_iterations: 1,
+            prompt_set_id: None,
+            injected_faults: Vec::new(),
+            token_usage: None,
+            tool_calling_mode: None,
+            llm_model: None,
+            domain_routing: None,
+        }
+    }
+
+    /// @trace TC: SPEC-039/TC-1
+    #[test]
+    fn spec039_tc_1_junit_xml_has_testcase_per_run_with_failures() {
+        let r = report(vec![scenario("fin_001", true, 0), scenario("fin_001", false, 1)], 2);
+        let trajectories = HashMap::from([("task-fin_001-1".to_string(), failed_trajectory("task-fin_001-1"))]);
+        let xml = to_junit_xml(&r, &trajectories);
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(xml.contains("<testsuites name=\"eval-harness financial (ppa)\" tests=\"2\" failures=\"1\" time=\"1.500\">"));
+        assert!(xml.contains("<testsuite name=\"financial\" tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("<testcase classname=\"financial.financial\" name=\"fin_001 [trial 0]\" time=\"0.000\">"));
+        assert!(xml.contains("name=\"fin_001 [trial 1]\" time=\"1.500\""));
+        assert_eq!(xml.matches("<failure ").count(), 1);
+        assert!(xml.contains("<failure message=\"최대 반복 초과\" type=\"ScenarioFailed\">최대 반복 초과\ncalculator: division by zero</failure>"));
+        assert!(xml.contains("<property name=\"overall_score\" value=\"0.9\"/>"));
+        assert!(xml.contains("&lt;설명&gt; &amp; &quot;따옴표&quot;"), "텍스트는 이스케이프");
+        assert_eq!(xml.matches("<testcase ").count(), xml.matches("</testcase>").count());
+    }
+
+    /// @trace TC: SPEC-039/TC-2
+    #[test]
+    fn spec039_tc_2_html_is_self_contained_with_timeline_and_charts() {
+        let r = report(vec![scenario("fin_001", true, 0), scenario("fin_002", false, 0)], 1);
+        let trajectories = HashMap::from([("task-fin_002-0".to_string(), failed_trajectory("task-fin_002-0"))]);
+        let html = to_html(&r, &trajectories);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(
+            !html.contains("<script") && !html.contains("<link") && !html.contains("src="),
+            "외부 리소스 없음"
+        );
+        assert!(html.contains("<svg"), "메트릭 차트");
+        assert!(html.contains("$0.0123"), "비용 메트릭은 값으로");
+        assert_eq!(html.matches("<details").count(), 2);
+        assert!(html.contains("<details open><summary><span class=\"fail\">실패</span> <b>fin_002</b>"));
+        assert!(html.contains("<td>200</td><td>Action</td><td>1</td><td>12</td>"), "타임라인 경과·소요 시간");
+        assert!(html.contains("<b>calculator</b>") && html.contains("division by zero"));
+        assert!(html.contains("궤적 없음"), "궤적이 없는 시나리오");
+        assert!(!html.contains("<설명>"), "텍스트는 이스케이프");
+    }
+
+    #[test]
+    fn export_format_parse() {
+        assert_eq!(ExportFormat::parse("JUnit"), Some(ExportFormat::Junit));
+        assert_eq!(ExportFormat::parse("xml"), Some(ExportFormat::Junit));
+        assert_eq!(ExportFormat::parse("html").map(ExportFormat::extension), Some("html"));
+        assert_eq!(ExportFormat::parse("pdf"), None);
+        let r = report(Vec::new(), 1);
+        let json: serde_json::Value = serde_json::from_str(&export(&r, ExportFormat::Json, &HashMap::new())).unwrap();
+        assert_eq!(json["agent_name"], "ppa");
+    }
+}
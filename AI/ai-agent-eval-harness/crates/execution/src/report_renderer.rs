@@ -28,6 +28,13 @@ impl ReportRenderer {
         println!("{:<20} {}", "성공:", report.success_count);
         println!("{:<20} {}", "실패:", report.total_scenarios - report.success_count);
         println!("{:<20} {:.1}%", "성공률:", report.success_rate * 100.0);
+        // SPEC-038: 반복 시행이면 시나리오당 시행 수와 pass@k 를 함께 표시.
+        if report.trials > 1 {
+            println!("{:<20} {}", "반복 시행:", report.trials);
+            for (k, v) in &report.pass_at_k {
+                println!("{:<20} {:.1}%", format!("pass@{k}:"), v * 100.0);
+            }
+        }
     }
 
     fn render_average_metrics(&self, report: &EvaluationReport) {
@@ -40,7 +47,14 @@ impl ReportRenderer {
         keys.sort();
         for name in keys {
             let val = report.average_metrics[name];
-            let formatted = self.format_score(val);
+            // SPEC-035: 토큰 수와 비용은 비율이 아니므로 그대로 표시한다.
+            let formatted = if name.ends_with("_tokens") {
+                format!("{:.0}", val)
+            } else if name.ends_with("_usd") {
+                format!("${:.4}", val)
+            } else {
+                self.format_score(val)
+            };
             println!("  {:<35} {}", name, formatted);
         }
     }
@@ -1,24 +1,76 @@
 use crate::{models::{EvaluationReport,
                      ScenarioResult},
-            report_renderer::ReportRenderer};
-use agent_models::{base_agent::BaseAgent,
-                   domain_config::DomainConfig};
-use eval_models::models::EvaluationResult;
+            report_renderer::ReportRenderer,
+            stats};
+use agent_models::{approval::{self,
+                              ApprovalGate,
+                              ApprovalPolicy},
+                   base_agent::BaseAgent,
+                   domain_config::DomainConfig,
+                   models::{AgentState,
+                            Trajectory},
+                   progress::{self,
+                              CancelScope,
+                              ProgressEvent,
+                              ProgressSink}};
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{DateTime,
+             Utc};
 use colored::*;
 use data_scenarios::{loader::ScenarioLoader,
                      models::Scenario};
+use eval_models::{models::{CriteriaCheckResult,
+                           EvaluationResult},
+                  pricing::PriceTable,
+                  traits::TrajectoryJudge};
+use execution_multi_turn::{conversation_manager::ConversationManager,
+                           models::{Conversation,
+                                    ConversationScenario,
+                                    TurnConfig}};
+use execution_tools::workspace::{self,
+                                 FileCheck,
+                                 Workspace,
+                                 WorkspaceLimits};
 use reporting::logger::TrajectoryLogger;
-use scoring::evaluator::TrajectoryEvaluator;
+use scoring::{evaluator::TrajectoryEvaluator,
+              golden_set_validator::GoldenSetValidator};
 use std::{collections::HashMap,
-          path::Path};
+          path::Path,
+          sync::{Arc,
+                 atomic::{AtomicUsize,
+                          Ordering},
+                 mpsc},
+          time::Duration};
+
+/// 시나리오 하나의 실행 결과. 멀티턴 시나리오는 대화 전체를 담는다.
+enum Executed {
+    Task(Trajectory),
+    Conversation(Conversation),
+}
+
+/// 실행 결과와, 작업 공간을 지우기 전에 비교한 기대 파일 결과.
+type RunOutput = (Executed, Vec<FileCheck>);
+
+/// 실행 1회의 평가 결과와 그 출처.
+struct RunRecord {
+    domain: String,
+    scenario_id: String,
+    trial: usize,
+    evaluation: EvaluationResult,
+}
 
 pub struct HarnessRunner {
     output_dir: std::path::PathBuf,
     logger: TrajectoryLogger,
     evaluator: TrajectoryEvaluator,
-    results: Vec<EvaluationResult>,
+    results: Vec<RunRecord>,
+    concurrency: usize,
+    scenario_timeout: Option<Duration>,
+    pricing: Option<PriceTable>,
+    trials: usize,
+    progress: Option<Arc<dyn ProgressSink>>,
+    scenario_ids: Vec<String>,
+    approval: ApprovalPolicy,
 }
 
 impl HarnessRunner {
@@ -32,19 +84,121 @@ impl HarnessRunner {
             logger: TrajectoryLogger::new(output_dir, output_dir),
             evaluator: TrajectoryEvaluator::new(),
             results: Vec::new(),
+            concurrency: 1,
+            scenario_timeout: None,
+            pricing: None,
+            trials: 1,
+            progress: None,
+            scenario_ids: Vec::new(),
+            approval: ApprovalPolicy::default(),
         }
     }
 
+    /// 시나리오 평가 시 LLM judge 채점을 함께 수행하도록 설정한다.
+    ///
+    /// @trace SPEC: SPEC-026
+    /// @trace FR: PRD-026/FR-3
+    pub fn with_judge(mut self, judge: Arc<dyn TrajectoryJudge>) -> Self {
+        self.evaluator = TrajectoryEvaluator::with_judge(judge);
+        self
+    }
+
+    /// 한 도메인 안에서 동시에 실행할 시나리오 수. 0 은 1 로 취급한다.
+    ///
+    /// @trace SPEC: SPEC-028
+    /// @trace FR: PRD-028/FR-1
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// 시나리오 하나의 최대 실행 시간. 초과하면 실패 궤적으로 기록하고 다음
+    /// 시나리오로 넘어간다.
+    ///
+    /// @trace SPEC: SPEC-028
+    /// @trace FR: PRD-028/FR-2
+    pub fn with_scenario_timeout(mut self, timeout: Duration) -> Self {
+        self.scenario_timeout = Some(timeout);
+        self
+    }
+
+    /// 토큰 사용량을 비용(`cost_usd`)으로 환산할 모델 단가표.
+    ///
+    /// @trace SPEC: SPEC-035
+    /// @trace FR: PRD-035/FR-2
+    pub fn with_pricing(mut self, pricing: PriceTable) -> Self {
+        self.pricing = Some(pricing);
+        self
+    }
+
+    /// 시나리오마다 반복 실행할 횟수. 0 은 1 로 취급한다. 시행별 결과가
+    /// 리포트에 각각 남아 비교 시 표본으로 쓰인다.
+    ///
+    /// @trace SPEC: SPEC-038
+    /// @trace FR: PRD-038/FR-1
+    pub fn with_trials(mut self, trials: usize) -> Self {
+        self.trials = trials.max(1);
+        self
+    }
+
+    /// 시나리오 시작·종료와 에이전트의 PPA 단계·도구 호출을 `sink` 로
+    /// 보고하고, `sink` 가 취소를 알리면 남은 시나리오를 건너뛴다.
+    ///
+    /// @trace SPEC: SPEC-040
+    /// @trace FR: PRD-040/FR-2, PRD-040/FR-4
+    pub fn with_progress(mut self, sink: Arc<dyn ProgressSink>) -> Self {
+        self.progress = Some(sink);
+        self
+    }
+
+    /// 평가 시나리오 중 `ids` 에 있는 시나리오만 실행한다. 비어 있으면 전체.
+    ///
+    /// @trace SPEC: SPEC-041
+    /// @trace FR: PRD-041/FR-2
+    pub fn with_scenario_ids(mut self, ids: Vec<String>) -> Self {
+        self.scenario_ids = ids;
+        self
+    }
+
+    /// `requires_approval` 도구의 기본 승인 정책. 시나리오에 `approval` 이
+    /// 있으면 그쪽이 우선한다.
+    ///
+    /// @trace SPEC: SPEC-044
+    /// @trace FR: PRD-044/FR-1
+    pub fn with_approval(mut self, policy: ApprovalPolicy) -> Self {
+        self.approval = policy;
+        self
+    }
+
+    fn is_cancelled(&self) -> bool { self.progress.as_ref().is_some_and(|p| p.is_cancelled()) }
+
     pub fn run_scenario(&self, scenario: &Scenario, agent: &dyn BaseAgent) -> EvaluationResult {
         let _meta = agent.metadata();
         println!("\n  {}", scenario.name.cyan());
 
         let start = std::time::Instant::now();
-        let trajectory = agent.execute_task(&scenario.task_description, Some(scenario.initial_environment.clone()));
+        let (executed, file_checks) = progress::scoped(self.progress.clone(), || execute(scenario, agent, &self.approval));
         let elapsed = start.elapsed().as_secs_f64();
         println!("  실행 시간: {:.2}초", elapsed);
 
-        let evaluation = self.evaluator.evaluate(&trajectory, Some(scenario), None);
+        self.finish_scenario(scenario, executed, &file_checks)
+    }
+
+    /// 실행이 끝난(또는 타임아웃된) 궤적을 평가하고 저장한다.
+    fn finish_scenario(&self, scenario: &Scenario, executed: Executed, file_checks: &[FileCheck]) -> EvaluationResult {
+        let (trajectory, mut evaluation) = match executed {
+            | Executed::Task(trajectory) => {
+                let evaluation = self.evaluator.evaluate(&trajectory, Some(scenario), None);
+                (trajectory, evaluation)
+            },
+            | Executed::Conversation(conversation) => self.evaluate_conversation(scenario, conversation),
+        };
+        // SPEC-035: 토큰 메트릭은 평가기가 채우고, 비용은 실행 설정의
+        // 단가표로 환산한다.
+        if let (Some(pricing), Some(usage)) = (&self.pricing, trajectory.token_usage) {
+            evaluation.metrics.cost_usd = pricing.cost(trajectory.llm_model.as_deref(), &usage);
+        }
+        apply_file_checks(&mut evaluation, file_checks);
         self.logger.save_trajectory(&trajectory).ok();
         self.logger.save_evaluation(&evaluation).ok();
 
@@ -54,7 +208,67 @@ impl HarnessRunner {
         evaluation
     }
 
-    pub fn run_eval_scenario(&mut self, eval_scenario_name: &str, agent: &dyn BaseAgent, scenarios_dir: &str) -> Result<EvaluationReport> {
+    /// 각 턴을 그 턴의 기대 도구/성공 조건으로 채점한 뒤, 턴을 이어 붙인
+    /// 대화 전체 궤적을 시나리오 기준으로 채점한다. 턴별 결과는
+    /// `analysis["conversation"]` 에, 턴 집계는 `conversation_*` 메트릭에
+    /// 담는다.
+    ///
+    /// @trace SPEC: SPEC-032
+    /// @trace FR: PRD-032/FR-3, PRD-032/FR-4
+    fn evaluate_conversation(&self, scenario: &Scenario, mut conversation: Conversation) -> (Trajectory, EvaluationResult) {
+        let turn_evals: Vec<Option<EvaluationResult>> = conversation
+            .turns
+            .iter()
+            .map(|turn| turn.trajectory.as_ref().map(|t| self.evaluator.evaluate(t, Some(turn), None)))
+            .collect();
+        for (turn, eval) in conversation.turns.iter_mut().zip(turn_evals) {
+            turn.evaluation_result = eval;
+        }
+
+        let combined = conversation.combined_trajectory(&scenario.task_description);
+        let mut evaluation = self.evaluator.evaluate(&combined, Some(scenario), None);
+
+        let total = conversation.turns.len();
+        let mean = |values: Vec<f64>| (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64);
+        let succeeded = conversation.turns.iter().filter(|t| t.trajectory.as_ref().is_some_and(|tr| tr.success)).count();
+        evaluation.metrics.conversation_turn_success_rate = (total > 0).then(|| succeeded as f64 / total as f64);
+        evaluation.metrics.conversation_turn_score = mean(
+            conversation
+                .turns
+                .iter()
+                .filter_map(|t| t.evaluation_result.as_ref().and_then(|e| e.metrics.golden_set_score))
+                .collect(),
+        );
+        evaluation.metrics.conversation_context_retention = mean(conversation.context_retention_scores.clone());
+
+        let turns: Vec<serde_json::Value> = conversation
+            .turns
+            .iter()
+            .zip(&conversation.context_retention_scores)
+            .map(|(t, retention)| {
+                serde_json::json!({
+                    "turn_id": t.turn_id,
+                    "user_input": t.user_input,
+                    "task_id": t.trajectory.as_ref().map(|tr| tr.task_id.clone()),
+                    "success": t.trajectory.as_ref().is_some_and(|tr| tr.success),
+                    "turn_score": t.evaluation_result.as_ref().and_then(|e| e.metrics.golden_set_score),
+                    "context_retention": retention,
+                })
+            })
+            .collect();
+        evaluation.analysis.insert(
+            "conversation".into(),
+            serde_json::json!({
+                "conversation_id": conversation.conversation_id,
+                "overall_success": conversation.overall_success,
+                "turns": turns,
+            }),
+        );
+        println!("  대화: {}턴 중 {}턴 성공", total, succeeded);
+        (combined, evaluation)
+    }
+
+    pub fn run_eval_scenario(&mut self, eval_scenario_name: &str, agent: Arc<dyn BaseAgent>, scenarios_dir: &str) -> Result<EvaluationReport> {
         let loader = ScenarioLoader::new();
         let scenarios_path = Path::new(scenarios_dir);
 
@@ -74,6 +288,7 @@ impl HarnessRunner {
             let scenarios: Vec<Scenario> = config
                 .scenarios
                 .iter()
+                .filter(|s| self.scenario_ids.is_empty() || self.scenario_ids.contains(&s.id))
                 .map(|s| Scenario {
                     id: s.id.clone(),
                     name: s.name.clone(),
@@ -84,6 +299,9 @@ impl HarnessRunner {
                     success_criteria: s.success_criteria.clone(),
                     difficulty: s.difficulty.clone(),
                     domain: config.name.clone(),
+                    conversation: s.conversation.clone(),
+                    approval: s.approval.clone(),
+                    workspace: s.workspace.clone(),
                 })
                 .collect();
             total_count += scenarios.len();
@@ -94,43 +312,194 @@ impl HarnessRunner {
         println!("에이전트: {}", agent.metadata().name);
         println!("평가 시나리오: {}", eval_scenario_name);
         println!("총 {}개 시나리오\n", total_count);
+        if self.concurrency > 1 {
+            println!("동시 실행: {}개", self.concurrency);
+        }
+        // SPEC-038: 시행은 같은 시나리오를 연달아 복제해 실행하고, 결과
+        // 순서로 시행 번호를 되찾는다.
+        if self.trials > 1 {
+            println!("반복 시행: 시나리오당 {}회", self.trials);
+        }
+        let trials = self.trials;
+        let total_runs = total_count * trials;
 
+        // SPEC-028: 도메인 그룹은 순차로 진행해 load_domain_tools 경계를
+        // 유지하고, 그룹 안에서만 최대 concurrency 개를 동시에 실행한다.
+        // 결과는 시나리오 인덱스 슬롯에 담아 순차 실행과 같은 순서로 모은다.
         self.results.clear();
-        let mut idx = 0usize;
+        let mut offset = 0usize;
         for (config, scenarios) in &grouped {
             agent.load_domain_tools(config);
-            for scenario in scenarios {
-                idx += 1;
-                println!("[{}/{}] {}", idx, total_count, scenario.name);
-                match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.run_scenario(scenario, agent))) {
-                    | Ok(eval) => self.results.push(eval),
-                    | Err(e) => eprintln!("  {}: {:?}", "오류".red(), e),
+            let runs: Vec<Scenario> = scenarios.iter().flat_map(|s| std::iter::repeat_n(s, trials)).cloned().collect();
+            let outcomes = self.run_group(&runs, &agent, offset, total_runs);
+            for (i, outcome) in outcomes.into_iter().enumerate() {
+                if let Some(evaluation) = outcome {
+                    self.results.push(RunRecord {
+                        domain: runs[i].domain.clone(),
+                        scenario_id: runs[i].id.clone(),
+                        trial: i % trials,
+                        evaluation,
+                    });
                 }
             }
+            offset += runs.len();
+        }
+        if self.is_cancelled() {
+            anyhow::bail!("실행이 취소되었습니다 ({}/{} 완료)", self.results.len(), total_runs);
         }
 
         let report = self.build_report(eval_scenario_name, &agent.metadata().name);
         Ok(report)
     }
 
+    /// 한 도메인의 시나리오를 worker 스레드 `concurrency` 개로 나눠 실행한다.
+    /// 반환 벡터는 입력 순서와 같고, 패닉한 시나리오는 `None`.
+    ///
+    /// @trace SPEC: SPEC-028
+    /// @trace FR: PRD-028/FR-1, PRD-028/FR-3
+    fn run_group(&self, scenarios: &[Scenario], agent: &Arc<dyn BaseAgent>, offset: usize, total_count: usize) -> Vec<Option<EvaluationResult>> {
+        let workers = self.concurrency.min(scenarios.len());
+        if workers <= 1 {
+            return scenarios
+                .iter()
+                .enumerate()
+                .map(|(i, scenario)| {
+                    if self.is_cancelled() {
+                        return None;
+                    }
+                    println!("[{}/{}] {}", offset + i + 1, total_count, scenario.name);
+                    self.run_scenario_reported(scenario, agent, offset + i, total_count)
+                })
+                .collect();
+        }
+
+        let next = AtomicUsize::new(0);
+        let (tx, rx) = mpsc::channel();
+        std::thread::scope(|scope| {
+            for _ in 0 .. workers {
+                let tx = tx.clone();
+                let next = &next;
+                scope.spawn(move || {
+                    loop {
+                        let i = next.fetch_add(1, Ordering::SeqCst);
+                        let Some(scenario) = scenarios.get(i) else {
+                            break;
+                        };
+                        if self.is_cancelled() {
+                            break;
+                        }
+                        println!("[{}/{}] {}", offset + i + 1, total_count, scenario.name);
+                        let _ = tx.send((i, self.run_scenario_reported(scenario, agent, offset + i, total_count)));
+                    }
+                });
+            }
+        });
+        drop(tx);
+
+        let mut slots: Vec<Option<EvaluationResult>> = (0 .. scenarios.len()).map(|_| None).collect();
+        for (i, outcome) in rx {
+            slots[i] = outcome;
+        }
+        slots
+    }
+
+    /// `run_scenario_guarded` 앞뒤로 시나리오 시작·종료 이벤트를 보낸다.
+    fn run_scenario_reported(&self, scenario: &Scenario, agent: &Arc<dyn BaseAgent>, index: usize, total: usize) -> Option<EvaluationResult> {
+        let Some(sink) = &self.progress else {
+            return self.run_scenario_guarded(scenario, agent);
+        };
+        sink.emit(ProgressEvent::ScenarioStarted {
+            index,
+            total,
+            domain: scenario.domain.clone(),
+            scenario_id: scenario.id.clone(),
+            name: scenario.name.clone(),
+        });
+        let start = std::time::Instant::now();
+        let outcome = self.run_scenario_guarded(scenario, agent);
+        sink.emit(ProgressEvent::ScenarioFinished {
+            index,
+            total,
+            domain: scenario.domain.clone(),
+            scenario_id: scenario.id.clone(),
+            task_id: outcome.as_ref().map(|e| e.trajectory.task_id.clone()),
+            success: outcome.as_ref().is_some_and(|e| e.trajectory.success),
+            duration_ms: start.elapsed().as_secs_f64() * 1000.0,
+        });
+        outcome
+    }
+
+    /// 패닉을 격리하고, 타임아웃이 설정돼 있으면 별도 스레드에서 실행해
+    /// 제한 시간까지만 기다린다. 시간을 넘긴 실행 스레드는 분리(detach)되어
+    /// 결과가 버려지며, 그 스레드의 취소 scope 를 닫아 다음 반복 경계에서
+    /// 멈추게 하고 이후 진행 이벤트는 내보내지 않는다.
+    ///
+    /// @trace SPEC: SPEC-028
+    /// @trace FR: PRD-028/FR-2
+    fn run_scenario_guarded(&self, scenario: &Scenario, agent: &Arc<dyn BaseAgent>) -> Option<EvaluationResult> {
+        let Some(timeout) = self.scenario_timeout else {
+            return match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.run_scenario(scenario, agent.as_ref()))) {
+                | Ok(eval) => Some(eval),
+                | Err(e) => {
+                    eprintln!("  {}: {:?}", "오류".red(), e);
+                    None
+                },
+            };
+        };
+
+        println!("\n  {}", scenario.name.cyan());
+        let (tx, rx) = mpsc::channel();
+        let task_agent = Arc::clone(agent);
+        let task_scenario = scenario.clone();
+        let scope = CancelScope::new(self.progress.clone());
+        let task_progress: Option<Arc<dyn ProgressSink>> = Some(scope.clone());
+        let task_approval = self.approval.clone();
+        let start = std::time::Instant::now();
+        std::thread::spawn(move || {
+            let _ = tx.send(progress::scoped(task_progress, || execute(&task_scenario, task_agent.as_ref(), &task_approval)));
+        });
+        let (executed, file_checks) = match rx.recv_timeout(timeout) {
+            | Ok(output) => output,
+            | Err(mpsc::RecvTimeoutError::Timeout) => {
+                scope.cancel();
+                eprintln!("  {}: {} ({}초 초과)", "타임아웃".red(), scenario.name, timeout.as_secs_f64());
+                (Executed::Task(timed_out_trajectory(scenario, timeout)), Vec::new())
+            },
+            | Err(mpsc::RecvTimeoutError::Disconnected) => {
+                eprintln!("  {}: {} 실행 중 패닉", "오류".red(), scenario.name);
+                return None;
+            },
+        };
+        println!("  실행 시간: {:.2}초", start.elapsed().as_secs_f64());
+        Some(self.finish_scenario(scenario, executed, &file_checks))
+    }
+
     fn build_report(&self, eval_scenario_name: &str, agent_name: &str) -> EvaluationReport {
-        let avg_metrics = self.calculate_average_metrics();
-        let success_count = self.results.iter().filter(|r| r.trajectory.success).count();
+        let mut avg_metrics = self.calculate_average_metrics();
+        let success_count = self.results.iter().filter(|r| r.evaluation.trajectory.success).count();
+        // SPEC-035: 실패한 시나리오의 비용까지 성공 건수로 나눈 값. 성공이
+        // 없으면 정의되지 않으므로 기록하지 않는다.
+        let costs: Vec<f64> = self.results.iter().filter_map(|r| r.evaluation.metrics.cost_usd).collect();
+        if !costs.is_empty() && success_count > 0 {
+            avg_metrics.insert("cost_per_success_usd".into(), costs.iter().sum::<f64>() / success_count as f64);
+        }
         let total = self.results.len();
 
         let scenarios: Vec<ScenarioResult> = self
             .results
             .iter()
             .map(|r| ScenarioResult {
-                task_id: r.trajectory.task_id.clone(),
-                task_description: r.trajectory.task_description.clone(),
-                success: r.trajectory.success,
-                total_iterations: r.trajectory.total_iterations,
-                metrics: r.metrics.to_map(),
-                domain: "general".into(),
-                scenario_id: String::new(),
+                task_id: r.evaluation.trajectory.task_id.clone(),
+                task_description: r.evaluation.trajectory.task_description.clone(),
+                success: r.evaluation.trajectory.success,
+                total_iterations: r.evaluation.trajectory.total_iterations,
+                metrics: r.evaluation.metrics.to_map(),
+                domain: r.domain.clone(),
+                scenario_id: r.scenario_id.clone(),
+                trial: r.trial,
             })
             .collect();
+        let pass_at_k = stats::pass_at_k_by_scenario(&scenarios);
 
         EvaluationReport {
             version: "1.0".into(),
@@ -142,6 +511,8 @@ impl HarnessRunner {
             success_rate: if total > 0 { success_count as f64 / total as f64 } else { 0.0 },
             average_metrics: avg_metrics,
             scenarios,
+            trials: self.trials,
+            pass_at_k,
         }
     }
 
@@ -150,7 +521,7 @@ impl HarnessRunner {
         let mut count: HashMap<String, usize> = HashMap::new();
 
         for result in &self.results {
-            for (key, val) in result.metrics.to_map() {
+            for (key, val) in result.evaluation.metrics.to_map() {
                 if let Some(v) = val {
                     *sum.entry(key.clone()).or_default() += v;
                     *count.entry(key).or_default() += 1;
@@ -180,3 +551,678 @@ impl HarnessRunner {
         Ok(filepath)
     }
 }
+
+/// 단일 턴이면 에이전트를 한 번, 멀티턴이면 대화 전체를 실행한다. 실행
+/// 동안 시나리오(없으면 `approval`) 정책의 승인 게이트와, fixture 로 채운
+/// 새 작업 공간을 설치한다. 작업 공간은 기대 파일을 비교한 뒤 삭제된다.
+/// 작업 공간을 준비하지 못하면 파일 도구가 호스트 경로에 쓰지 않도록
+/// 에이전트를 실행하지 않고 실패로 끝낸다.
+///
+/// @trace SPEC: SPEC-045
+/// @trace FR: PRD-045/FR-1, PRD-045/FR-3
+fn execute(scenario: &Scenario, agent: &dyn BaseAgent, approval: &ApprovalPolicy) -> RunOutput {
+    let policy = scenario.approval.clone().unwrap_or_else(|| approval.clone());
+    let started = Utc::now();
+    let workspace = match prepare_workspace(scenario) {
+        | Ok(ws) => Arc::new(ws),
+        | Err(e) => {
+            eprintln!("  {}: {}", "오류".red(), e);
+            return (Executed::Task(failed_trajectory(scenario, e, started)), Vec::new());
+        },
+    };
+    let executed = workspace::scoped(Some(workspace.clone()), || {
+        approval::scoped(Some(Arc::new(ApprovalGate::new(policy))), || execute_inner(scenario, agent))
+    });
+    let file_checks = match &scenario.workspace {
+        | Some(config) => workspace.diff(&config.expected_files),
+        | None => Vec::new(),
+    };
+    (executed, file_checks)
+}
+
+/// 시나리오의 작업 공간을 만들고 fixture 를 쓴다.
+fn prepare_workspace(scenario: &Scenario) -> Result<Workspace, String> {
+    let config = scenario.workspace.clone().unwrap_or_default();
+    let defaults = WorkspaceLimits::default();
+    let limits = WorkspaceLimits {
+        max_file_bytes: config.max_file_bytes.unwrap_or(defaults.max_file_bytes),
+        max_total_bytes: config.max_total_bytes.unwrap_or(defaults.max_total_bytes),
+    };
+    let workspace = Workspace::new(limits).map_err(|e| format!("작업 공간 생성 실패: {e}"))?;
+    workspace.seed(&config.fixtures).map_err(|e| format!("fixture 준비 실패: {e}"))?;
+    Ok(workspace)
+}
+
+/// 기대 파일 비교 결과를 `workspace:<경로>` 성공 조건으로 골든셋 점수에
+/// 합치고, 일치 비율과 파일별 결과를 메트릭·분석에 남긴다.
+///
+/// @trace SPEC: SPEC-045
+/// @trace FR: PRD-045/FR-3
+fn apply_file_checks(evaluation: &mut EvaluationResult, file_checks: &[FileCheck]) {
+    if file_checks.is_empty() {
+        return;
+    }
+    let passed = file_checks.iter().filter(|c| c.passed).count();
+    evaluation.metrics.workspace_match_score = Some(passed as f64 / file_checks.len() as f64);
+    evaluation.analysis.insert(
+        "workspace".into(),
+        serde_json::json!({
+            "files": file_checks
+                .iter()
+                .map(|c| serde_json::json!({
+                    "path": c.path,
+                    "expected": c.expected,
+                    "actual": c.actual,
+                    "passed": c.passed,
+                }))
+                .collect::<Vec<_>>(),
+        }),
+    );
+    if passed < file_checks.len() {
+        evaluation
+            .recommendations
+            .push(format!("작업 공간 최종 상태가 기대 파일과 다릅니다 ({}/{} 일치)", passed, file_checks.len()));
+    }
+    if let Some(result) = evaluation.golden_set_result.as_mut() {
+        GoldenSetValidator::merge_criteria(
+            result,
+            file_checks.iter().map(|c| {
+                let key = format!("workspace:{}", c.path);
+                CriteriaCheckResult {
+                    key,
+                    expected: c.expected.clone().map_or(serde_json::Value::Null, serde_json::Value::String),
+                    actual: c.actual.clone().map(serde_json::Value::String),
+                    passed: c.passed,
+                    match_type: if c.actual.is_none() && c.expected.is_some() {
+                        "missing".into()
+                    } else {
+                        "workspace_file".into()
+                    },
+                }
+            }),
+        );
+        evaluation.metrics.golden_set_score = Some(result.overall_score);
+    }
+}
+
+fn execute_inner(scenario: &Scenario, agent: &dyn BaseAgent) -> Executed {
+    let Some(config) = &scenario.conversation else {
+        return Executed::Task(agent.execute_task(&scenario.task_description, Some(scenario.initial_environment.clone())));
+    };
+    // 스크립트 턴이 없으면 시나리오 자체가 첫 턴이다.
+    let opening = TurnConfig {
+        user_input: scenario.task_description.clone(),
+        initial_environment: None,
+        expected_context_keys: Vec::new(),
+        scenario_id: None,
+        expected_tools: scenario.expected_tools.clone(),
+        success_criteria: scenario.success_criteria.clone(),
+    };
+    let conversation_scenario = ConversationScenario::from_config(
+        &scenario.id,
+        &scenario.domain,
+        &scenario.description,
+        config,
+        opening,
+        scenario.initial_environment.clone(),
+    );
+    Executed::Conversation(ConversationManager::new(agent).run_conversation(&conversation_scenario))
+}
+
+/// 타임아웃된 시나리오를 대신할 실패 궤적. 스텝 없이 사유만 남긴다.
+fn timed_out_trajectory(scenario: &Scenario, timeout: Duration) -> Trajectory {
+    let started = Utc::now() - chrono::Duration::from_std(timeout).unwrap_or_default();
+    failed_trajectory(scenario, format!("시나리오 타임아웃: {:.1}초 초과", timeout.as_secs_f64()), started)
+}
+
+/// 에이전트 실행 없이 `error` 로 실패한 궤적.
+fn failed_trajectory(scenario: &Scenario, error: String, start_time: DateTime<Utc>) -> Trajectory {
+    let mut state = AgentState::new(scenario.task_description.clone());
+    state.error_message = Some(error);
+    Trajectory {
+        task_id: uuid::Uuid::new_v4().to_string(),
+        task_description: scenario.task_description.clone(),
+        start_time,
+        end_time: Some(Utc::now()),
+        steps: Vec::new(),
+        final_state: Some(state),
+        success: false,
+        total_iterations: 0,
+        prompt_set_id: None,
+        injected_faults: Vec::new(),
+        token_usage: None,
+        tool_calling_mode: None,
+        llm_model: None,
+        domain_routing: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-028
+    // @trace PRD: PRD-028
+    // @trace FR: PRD-028/FR-1, PRD-028/FR-2, PRD-028/FR-3
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use crate::base_agent::PassthroughAgent;
+    use agent_models::base_agent::AgentMetadata;
+    use std::sync::Mutex;
+
+    /// 작업 설명에 따라 지연을 주는 에이전트. 앞쪽 시나리오일수록 오래 걸려
+    /// 동시 실행 시 완료 순서가 입력 순서와 달라진다.
+    struct SlowAgent {
+        delays: HashMap<String, Duration>,
+        loaded_domains: Mutex<Vec<String>>,
+    }
+
+    impl BaseAgent for SlowAgent {
+        fn metadata(&self) -> AgentMetadata {
+            AgentMetadata {
+                name: "slow".into(),
+                description: "테스트용 지연 에이전트".into(),
+                version: "0.1.0".into(),
+            }
+        }
+
+        fn execute_task(&self, task_description: &str, initial_environment: Option<HashMap<String, serde_json::Value>>) -> Trajectory {
+            if let Some(d) = self.delays.get(task_description) {
+                std::thread::sleep(*d);
+            }
+            PassthroughAgent.execute_task(task_description, initial_environment)
+        }
+
+        fn load_domain_tools(&self, domain_config: &DomainConfig) { self.loaded_domains.lock().unwrap().push(domain_config.name.clone()); }
+    }
+
+    fn seed_tasks(domain: &str) -> Vec<String> {
+        let config = ScenarioLoader::new().load_domain_config(&format!("{domain}.yaml")).unwrap();
+        config.scenarios.iter().map(|s| s.task_description.clone()).collect()
+    }
+
+    fn slow_agent(delays: HashMap<String, Duration>) -> Arc<SlowAgent> {
+        Arc::new(SlowAgent {
+            delays,
+            loaded_domains: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// @trace TC: SPEC-028/TC-1
+    #[test]
+    fn spec028_tc_1_concurrent_run_preserves_report_order() {
+        let tasks = seed_tasks("customer_service");
+        let n = tasks.len() as u64;
+        let delays = tasks
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (t.clone(), Duration::from_millis((n - i as u64) * 30)))
+            .collect();
+        let agent = slow_agent(delays);
+        let out = tempfile::tempdir().unwrap();
+
+        let mut runner = HarnessRunner::new(out.path().to_str().unwrap()).with_concurrency(4);
+        let report = runner.run_eval_scenario("customer_service", agent.clone(), "").unwrap();
+
+        let got: Vec<String> = report.scenarios.iter().map(|s| s.task_description.clone()).collect();
+        assert_eq!(got, tasks);
+        assert_eq!(*agent.loaded_domains.lock().unwrap(), vec!["customer_service".to_string()]);
+    }
+
+    /// @trace TC: SPEC-028/TC-2
+    #[test]
+    fn spec028_tc_2_all_domains_load_tools_per_group() {
+        let agent = slow_agent(HashMap::new());
+        let out = tempfile::tempdir().unwrap();
+
+        let mut runner = HarnessRunner::new(out.path().to_str().unwrap()).with_concurrency(3);
+        let report = runner.run_eval_scenario("all", agent.clone(), "").unwrap();
+
+        let loaded = agent.loaded_domains.lock().unwrap().clone();
+        let mut expected: Vec<String> = Vec::new();
+        for domain in &loaded {
+            expected.extend(seed_tasks(domain));
+        }
+        let got: Vec<String> = report.scenarios.iter().map(|s| s.task_description.clone()).collect();
+        assert!(loaded.len() >= 2, "all 은 도메인마다 load_domain_tools 를 호출해야 한다: {loaded:?}");
+        assert_eq!(got, expected);
+    }
+
+    /// @trace TC: SPEC-028/TC-3
+    #[test]
+    fn spec028_tc_3_timed_out_scenario_is_recorded_as_failure() {
+        let tasks = seed_tasks("financial");
+        let mut delays = HashMap::new();
+        delays.insert(tasks[0].clone(), Duration::from_secs(5));
+        let agent = slow_agent(delays);
+        let out = tempfile::tempdir().unwrap();
+
+        let mut runner = HarnessRunner::new(out.path().to_str().unwrap())
+            .with_concurrency(2)
+            .with_scenario_timeout(Duration::from_millis(300));
+        let started = std::time::Instant::now();
+        let report = runner.run_eval_scenario("financial", agent, "").unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(4), "타임아웃된 실행을 기다리면 안 된다");
+        assert_eq!(report.total_scenarios, tasks.len());
+        assert_eq!(report.scenarios[0].task_description, tasks[0]);
+        assert!(!report.scenarios[0].success);
+        assert!(report.scenarios[1 ..].iter().all(|s| s.success));
+    }
+
+    /// 지정한 작업에서 제한 시간을 넘긴 뒤 진행 이벤트를 보내고, 그때
+    /// 취소가 보였는지 기록하는 에이전트.
+    struct LateAgent {
+        slow_task: String,
+        saw_cancel: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    impl BaseAgent for LateAgent {
+        fn metadata(&self) -> AgentMetadata {
+            AgentMetadata {
+                name: "late".into(),
+                description: "테스트용 지연 보고 에이전트".into(),
+                version: "0.1.0".into(),
+            }
+        }
+
+        fn execute_task(&self, task_description: &str, initial_environment: Option<HashMap<String, serde_json::Value>>) -> Trajectory {
+            if task_description == self.slow_task {
+                std::thread::sleep(Duration::from_millis(400));
+                self.saw_cancel.store(progress::is_cancelled(), Ordering::SeqCst);
+                progress::emit(ProgressEvent::Step {
+                    task_id: "late".into(),
+                    stage: agent_models::models::PpaStage::Perceive,
+                    iteration: 1,
+                    duration_ms: None,
+                });
+            }
+            PassthroughAgent.execute_task(task_description, initial_environment)
+        }
+
+        fn load_domain_tools(&self, _domain_config: &DomainConfig) {}
+    }
+
+    #[derive(Default)]
+    struct CollectEvents(Mutex<Vec<ProgressEvent>>);
+
+    impl ProgressSink for CollectEvents {
+        fn emit(&self, event: ProgressEvent) { self.0.lock().unwrap().push(event); }
+    }
+
+    /// 타임아웃된 실행 스레드는 취소를 보고, 그 뒤 보낸 이벤트는 버려진다.
+    ///
+    /// @trace TC: SPEC-028/TC-3
+    #[test]
+    fn spec028_tc_3_timed_out_thread_is_cancelled_and_silenced() {
+        let tasks = seed_tasks("financial");
+        let saw_cancel = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let agent = Arc::new(LateAgent {
+            slow_task: tasks[0].clone(),
+            saw_cancel: saw_cancel.clone(),
+        });
+        let sink = Arc::new(CollectEvents::default());
+        let out = tempfile::tempdir().unwrap();
+
+        let mut runner = HarnessRunner::new(out.path().to_str().unwrap())
+            .with_scenario_timeout(Duration::from_millis(100))
+            .with_progress(sink.clone());
+        let report = runner.run_eval_scenario("financial", agent, "").unwrap();
+        assert!(!report.scenarios[0].success);
+
+        // 분리된 스레드가 깨어나 이벤트를 보낼 때까지 기다린다.
+        std::thread::sleep(Duration::from_millis(600));
+        assert!(saw_cancel.load(Ordering::SeqCst), "타임아웃 후 is_cancelled 가 보여야 한다");
+        let events = sink.0.lock().unwrap();
+        assert!(events.iter().all(|e| !matches!(e, ProgressEvent::Step { .. })), "{events:?}");
+        assert_eq!(events.len(), 2 * tasks.len(), "시나리오 시작·종료 이벤트만 남는다");
+    }
+
+    /// @trace TC: SPEC-032/TC-4
+    #[test]
+    fn spec032_tc_4_conversation_scenario_scored_per_turn_and_overall() {
+        use agent_models::domain_config::{ConversationConfig,
+                                          ConversationTurnConfig};
+
+        let turn = |input: &str, expected_tools: Vec<String>| ConversationTurnConfig {
+            user_input: input.into(),
+            expected_tools,
+            expected_context_keys: vec!["customer_id".into()],
+            ..Default::default()
+        };
+        let scenario = Scenario {
+            id: "cs_conv_001".into(),
+            name: "환불 후속 문의".into(),
+            description: "멀티턴".into(),
+            task_description: "환불 요청 후 처리 상태를 확인한다".into(),
+            initial_environment: HashMap::from([("customer_id".to_string(), serde_json::json!("C1"))]),
+            expected_tools: vec![],
+            success_criteria: HashMap::new(),
+            difficulty: "medium".into(),
+            domain: "customer_service".into(),
+            conversation: Some(ConversationConfig {
+                turns: vec![turn("환불해 주세요", vec![]), turn("처리 상태는요?", vec!["classify_inquiry".into()])],
+                ..Default::default()
+            }),
+            approval: None,
+            workspace: None,
+        };
+        let out = tempfile::tempdir().unwrap();
+        let runner = HarnessRunner::new(out.path().to_str().unwrap());
+        let eval = runner.run_scenario(&scenario, &PassthroughAgent);
+
+        // 패스스루는 도구를 호출하지 않으므로 두 번째 턴만 기대 도구 미충족.
+        let turns = eval.analysis["conversation"]["turns"].as_array().unwrap();
+        assert_eq!(turns.len(), 2);
+        let first = turns[0]["turn_score"].as_f64().unwrap();
+        let second = turns[1]["turn_score"].as_f64().unwrap();
+        assert!(first > second, "{first} vs {second}");
+        assert_eq!(eval.metrics.conversation_turn_success_rate, Some(1.0));
+        assert_eq!(eval.metrics.conversation_context_retention, Some(1.0));
+        assert!(eval.metrics.conversation_turn_score.is_some());
+        assert!(eval.trajectory.success);
+        assert_eq!(eval.trajectory.steps.len(), 4, "두 턴의 스텝이 한 궤적으로 합쳐진다");
+    }
+
+    /// 고정 토큰 사용량과 모델 이름을 기록하는 에이전트.
+    struct MeteredAgent;
+
+    impl BaseAgent for MeteredAgent {
+        fn metadata(&self) -> AgentMetadata {
+            AgentMetadata {
+                name: "metered".into(),
+                description: "테스트용 토큰 기록 에이전트".into(),
+                version: "0.1.0".into(),
+            }
+        }
+
+        fn execute_task(&self, task_description: &str, initial_environment: Option<HashMap<String, serde_json::Value>>) -> Trajectory {
+            let mut trajectory = PassthroughAgent.execute_task(task_description, initial_environment);
+            trajectory.llm_model = Some("gpt-4o-mini-2024-07-18".into());
+            trajectory.token_usage = Some(agent_models::models::TokenUsage {
+                prompt_tokens: 1_000,
+                completion_tokens: 500,
+                total_tokens: 1_500,
+            });
+            trajectory
+        }
+
+        fn load_domain_tools(&self, _domain_config: &DomainConfig) {}
+    }
+
+    /// @trace TC: SPEC-035/TC-3
+    #[test]
+    fn spec035_tc_3_report_records_tokens_and_cost_per_success() {
+        let pricing = PriceTable {
+            models: HashMap::from([(
+                "gpt-4o-mini".to_string(),
+                eval_models::pricing::ModelPrice {
+                    prompt_per_million: 1.0,
+                    completion_per_million: 2.0,
+                },
+            )]),
+            default: None,
+        };
+        let out = tempfile::tempdir().unwrap();
+        let mut runner = HarnessRunner::new(out.path().to_str().unwrap()).with_pricing(pricing);
+        let report = runner.run_eval_scenario("financial", Arc::new(MeteredAgent), "").unwrap();
+
+        // (1000 * 1.0 + 500 * 2.0) / 1e6
+        let per_scenario = 0.002;
+        for s in &report.scenarios {
+            assert_eq!(s.metrics["total_tokens"], Some(1_500.0));
+            assert!((s.metrics["cost_usd"].unwrap() - per_scenario).abs() < 1e-12);
+        }
+        assert_eq!(report.average_metrics["prompt_tokens"], 1_000.0);
+        let expected = per_scenario * report.total_scenarios as f64 / report.success_count as f64;
+        assert!((report.average_metrics["cost_per_success_usd"] - expected).abs() < 1e-12);
+    }
+
+    /// 호출마다 성공/실패를 번갈아 내는 에이전트. 순차 실행에서 시나리오당
+    /// 2회 시행이면 첫 시행만 성공한다.
+    struct FlakyAgent(AtomicUsize);
+
+    impl BaseAgent for FlakyAgent {
+        fn metadata(&self) -> AgentMetadata {
+            AgentMetadata {
+                name: "flaky".into(),
+                description: "테스트용 교대 성공 에이전트".into(),
+                version: "0.1.0".into(),
+            }
+        }
+
+        fn execute_task(&self, task_description: &str, initial_environment: Option<HashMap<String, serde_json::Value>>) -> Trajectory {
+            let mut trajectory = PassthroughAgent.execute_task(task_description, initial_environment);
+            trajectory.success = self.0.fetch_add(1, Ordering::SeqCst) % 2 == 0;
+            trajectory
+        }
+
+        fn load_domain_tools(&self, _domain_config: &DomainConfig) {}
+    }
+
+    /// @trace TC: SPEC-038/TC-4
+    #[test]
+    fn spec038_tc_4_trials_record_per_trial_results_and_pass_at_k() {
+        let out = tempfile::tempdir().unwrap();
+        let mut runner = HarnessRunner::new(out.path().to_str().unwrap()).with_trials(2);
+        let report = runner.run_eval_scenario("financial", Arc::new(FlakyAgent(AtomicUsize::new(0))), "").unwrap();
+
+        let n = seed_tasks("financial").len();
+        assert_eq!(report.trials, 2);
+        assert_eq!(report.total_scenarios, 2 * n);
+        assert_eq!(report.success_count, n);
+        assert_eq!(
+            report.scenarios.iter().map(|s| s.trial).collect::<Vec<_>>(),
+            (0 .. n).flat_map(|_| [0, 1]).collect::<Vec<_>>()
+        );
+        assert!(report.scenarios.iter().all(|s| s.domain == "financial" && !s.scenario_id.is_empty()));
+        assert_eq!(report.scenarios[0].scenario_id, report.scenarios[1].scenario_id);
+        assert_eq!(report.pass_at_k.get(&1), Some(&0.5));
+        assert_eq!(report.pass_at_k.get(&2), Some(&1.0));
+    }
+
+    /// 첫 시나리오가 끝나면 취소를 요청하는 sink.
+    #[derive(Default)]
+    struct CancelAfterFirst {
+        events: Mutex<Vec<ProgressEvent>>,
+        cancel: bool,
+    }
+
+    impl ProgressSink for CancelAfterFirst {
+        fn emit(&self, event: ProgressEvent) { self.events.lock().unwrap().push(event); }
+
+        fn is_cancelled(&self) -> bool { self.cancel && self.events.lock().unwrap().iter().any(|e| matches!(e, ProgressEvent::ScenarioFinished { .. })) }
+    }
+
+    /// @trace TC: SPEC-040/TC-4
+    #[test]
+    fn spec040_tc_4_progress_events_and_cancellation() {
+        let out = tempfile::tempdir().unwrap();
+        let n = seed_tasks("financial").len();
+
+        let sink = Arc::new(CancelAfterFirst::default());
+        let mut runner = HarnessRunner::new(out.path().to_str().unwrap()).with_progress(sink.clone());
+        let report = runner.run_eval_scenario("financial", Arc::new(PassthroughAgent), "").unwrap();
+        let events = sink.events.lock().unwrap();
+        assert_eq!(events.len(), 2 * n, "시나리오마다 시작·종료");
+        for (i, pair) in events.chunks(2).enumerate() {
+            let [
+                ProgressEvent::ScenarioStarted {
+                    index: a,
+                    total,
+                    domain,
+                    ..
+                },
+                ProgressEvent::ScenarioFinished {
+                    index: b,
+                    task_id,
+                    success,
+                    ..
+                },
+            ] = pair
+            else {
+                panic!("unexpected {pair:?}");
+            };
+            assert_eq!((*a, *b, *total), (i, i, n));
+            assert_eq!(domain, "financial");
+            assert_eq!(task_id.as_deref(), Some(report.scenarios[i].task_id.as_str()));
+            assert!(*success);
+        }
+        drop(events);
+
+        let sink = Arc::new(CancelAfterFirst {
+            cancel: true,
+            ..Default::default()
+        });
+        let mut runner = HarnessRunner::new(out.path().to_str().unwrap()).with_progress(sink.clone());
+        let err = runner.run_eval_scenario("financial", Arc::new(PassthroughAgent), "").unwrap_err();
+        assert!(err.to_string().contains("취소"), "{err}");
+        assert_eq!(sink.events.lock().unwrap().len(), 2, "첫 시나리오 이후 중단");
+    }
+
+    /// @trace TC: SPEC-041/TC-1
+    #[test]
+    fn spec041_tc_1_scenario_ids_limit_the_run() {
+        let out = tempfile::tempdir().unwrap();
+        let config = ScenarioLoader::new().load_domain_config("financial.yaml").unwrap();
+        let ids: Vec<String> = config.scenarios.iter().map(|s| s.id.clone()).collect();
+        assert!(ids.len() > 1);
+
+        let mut runner = HarnessRunner::new(out.path().to_str().unwrap()).with_scenario_ids(vec![ids[1].clone()]);
+        let report = runner.run_eval_scenario("financial", Arc::new(PassthroughAgent), "").unwrap();
+        assert_eq!(report.total_scenarios, 1);
+        assert_eq!(report.scenarios[0].scenario_id, ids[1]);
+    }
+
+    /// 작업 공간의 입력 파일을 읽어 요약 파일을 쓰는 에이전트. 실행한
+    /// 작업 공간 루트를 기록한다.
+    struct FileAgent {
+        summary_prefix: &'static str,
+        roots: Mutex<Vec<std::path::PathBuf>>,
+    }
+
+    impl BaseAgent for FileAgent {
+        fn metadata(&self) -> AgentMetadata {
+            AgentMetadata {
+                name: "file".into(),
+                description: "테스트용 파일 에이전트".into(),
+                version: "0.1.0".into(),
+            }
+        }
+
+        fn execute_task(&self, task_description: &str, initial_environment: Option<HashMap<String, serde_json::Value>>) -> Trajectory {
+            use execution_tools::{base::BaseTool,
+                                  file_tools::{ReadFileTool,
+                                               WriteFileTool}};
+            let params = |pairs: &[(&str, &str)]| pairs.iter().map(|(k, v)| (k.to_string(), serde_json::json!(v))).collect();
+            self.roots.lock().unwrap().push(workspace::current().unwrap().root().to_path_buf());
+            let read = ReadFileTool::new().execute(&params(&[("file_path", "input.txt")]));
+            let summary = format!("{}{}", self.summary_prefix, read["content"].as_str().unwrap_or_default());
+            WriteFileTool::new().execute(&params(&[("file_path", "out/summary.txt"), ("content", &summary)]));
+            let escape = WriteFileTool::new().execute(&params(&[("file_path", "../escape.txt"), ("content", "x")]));
+            assert_eq!(escape["success"], serde_json::Value::Bool(false));
+            PassthroughAgent.execute_task(task_description, initial_environment)
+        }
+
+        fn load_domain_tools(&self, _domain_config: &DomainConfig) {}
+    }
+
+    /// @trace TC: SPEC-045/TC-5
+    /// @trace FR: PRD-045/FR-1, PRD-045/FR-3
+    #[test]
+    fn spec045_tc_5_each_run_gets_seeded_workspace_scored_against_expected_files() {
+        use agent_models::domain_config::WorkspaceConfig;
+        use std::collections::BTreeMap;
+
+        let scenario = Scenario {
+            id: "fin_files".into(),
+            name: "파일 요약".into(),
+            description: "작업 공간".into(),
+            task_description: "input.txt 를 요약한다".into(),
+            initial_environment: HashMap::new(),
+            expected_tools: vec![],
+            success_criteria: HashMap::new(),
+            difficulty: "easy".into(),
+            domain: "financial".into(),
+            conversation: None,
+            approval: None,
+            workspace: Some(WorkspaceConfig {
+                fixtures: BTreeMap::from([("input.txt".to_string(), "100".to_string())]),
+                expected_files: BTreeMap::from([
+                    ("out/summary.txt".to_string(), Some("total: 100".to_string())),
+                    ("escape.txt".to_string(), None),
+                ]),
+                ..Default::default()
+            }),
+        };
+        let out = tempfile::tempdir().unwrap();
+        let runner = HarnessRunner::new(out.path().to_str().unwrap());
+        let agent = FileAgent {
+            summary_prefix: "total: ",
+            roots: Mutex::new(Vec::new()),
+        };
+        let good = runner.run_scenario(&scenario, &agent);
+        let again = runner.run_scenario(&scenario, &agent);
+        assert_eq!(good.metrics.workspace_match_score, Some(1.0));
+        assert_eq!(again.metrics.workspace_match_score, Some(1.0), "두 번째 실행도 새 fixture 에서 시작");
+        let criteria = &good.golden_set_result.as_ref().unwrap().criteria_results;
+        assert!(criteria["workspace:out/summary.txt"].passed);
+        assert_eq!(good.metrics.golden_set_score, Some(1.0));
+        assert_eq!(good.analysis["workspace"]["files"].as_array().unwrap().len(), 2);
+
+        let roots = agent.roots.lock().unwrap().clone();
+        assert_ne!(roots[0], roots[1], "실행마다 다른 작업 공간");
+        assert!(roots.iter().all(|r| !r.exists()), "실행이 끝나면 작업 공간을 지운다");
+
+        let wrong = FileAgent {
+            summary_prefix: "sum=",
+            roots: Mutex::new(Vec::new()),
+        };
+        let bad = runner.run_scenario(&scenario, &wrong);
+        assert_eq!(bad.metrics.workspace_match_score, Some(0.5));
+        assert_eq!(bad.analysis["workspace"]["files"][1]["actual"], serde_json::json!("sum=100"));
+        let result = bad.golden_set_result.as_ref().unwrap();
+        assert_eq!(result.criteria_score, 0.5);
+        assert!(bad.metrics.golden_set_score.unwrap() < 1.0);
+    }
+
+    /// fixture 를 쓸 수 없으면 작업 공간 없이 실행하지 않고 실패로 끝낸다.
+    ///
+    /// @trace TC: SPEC-045/TC-6
+    /// @trace FR: PRD-045/FR-1
+    #[test]
+    fn spec045_tc_6_unusable_workspace_fails_without_running_agent() {
+        use agent_models::domain_config::WorkspaceConfig;
+        use std::collections::BTreeMap;
+
+        let scenario = Scenario {
+            id: "fin_escape".into(),
+            name: "fixture 탈출".into(),
+            description: "작업 공간".into(),
+            task_description: "input.txt 를 요약한다".into(),
+            initial_environment: HashMap::new(),
+            expected_tools: vec![],
+            success_criteria: HashMap::new(),
+            difficulty: "easy".into(),
+            domain: "financial".into(),
+            conversation: None,
+            approval: None,
+            workspace: Some(WorkspaceConfig {
+                fixtures: BTreeMap::from([("../outside.txt".to_string(), "x".to_string())]),
+                ..Default::default()
+            }),
+        };
+        let out = tempfile::tempdir().unwrap();
+        let runner = HarnessRunner::new(out.path().to_str().unwrap());
+        let agent = FileAgent {
+            summary_prefix: "total: ",
+            roots: Mutex::new(Vec::new()),
+        };
+        let eval = runner.run_scenario(&scenario, &agent);
+        assert!(agent.roots.lock().unwrap().is_empty(), "에이전트를 실행하지 않는다");
+        assert!(!eval.trajectory.success);
+        let error = eval.trajectory.final_state.as_ref().and_then(|s| s.error_message.clone()).unwrap_or_default();
+        assert!(error.contains("fixture"), "{error}");
+    }
+}
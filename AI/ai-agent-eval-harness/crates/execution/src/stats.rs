@@ -0,0 +1,204 @@
+// =============================================================================
+// @trace SPEC-038
+// @trace PRD: PRD-038
+// @trace FR: PRD-038/FR-2, PRD-038/FR-3
+// @trace file-type: impl
+// =============================================================================
+//
+// 반복 시행 리포트 비교용 통계. 에이전트 실행은 비결정적이므로 평균 하나의
+// 변화율 대신 실행별 표본으로 신뢰구간과 Mann-Whitney U 검정을 계산하고,
+// 시나리오별 반복 성공 수로 pass@k 를 추정한다. 외부 의존성 없이 정규근사를
+// 쓴다.
+
+use crate::models::ScenarioResult;
+use std::collections::BTreeMap;
+
+/// 자유도 1..=30 의 양측 95% t 임계값. 그 이상은 1.96.
+const T95: [f64; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160, 2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074,
+    2.069, 2.064, 2.060, 2.056, 2.052, 2.048, 2.045, 2.042,
+];
+
+pub fn mean(xs: &[f64]) -> Option<f64> { (!xs.is_empty()).then(|| xs.iter().sum::<f64>() / xs.len() as f64) }
+
+/// 평균의 95% 신뢰구간 (t 분포). 표본이 2개 미만이면 None.
+///
+/// @trace SPEC: SPEC-038
+/// @trace FR: PRD-038/FR-2
+pub fn mean_ci95(xs: &[f64]) -> Option<[f64; 2]> {
+    let n = xs.len();
+    if n < 2 {
+        return None;
+    }
+    let m = mean(xs)?;
+    let var = xs.iter().map(|x| (x - m).powi(2)).sum::<f64>() / (n - 1) as f64;
+    let t = T95.get(n - 2).copied().unwrap_or(1.96);
+    let half = t * (var / n as f64).sqrt();
+    Some([m - half, m + half])
+}
+
+/// 양측 Mann-Whitney U 검정의 p-value. 동점 보정과 연속성 보정을 한
+/// 정규근사이며, 어느 한쪽이 비었으면 None, 모든 값이 같으면 1.
+///
+/// @trace SPEC: SPEC-038
+/// @trace FR: PRD-038/FR-2
+pub fn mann_whitney_p(a: &[f64], b: &[f64]) -> Option<f64> {
+    let (n1, n2) = (a.len(), b.len());
+    if n1 == 0 || n2 == 0 {
+        return None;
+    }
+    let mut pooled: Vec<(f64, bool)> = a.iter().map(|&x| (x, true)).chain(b.iter().map(|&x| (x, false))).collect();
+    pooled.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+    // 동점 묶음에는 평균 순위를 준다.
+    let n = pooled.len();
+    let mut rank_sum_a = 0.0;
+    let mut tie_term = 0.0;
+    let mut i = 0;
+    while i < n {
+        let mut j = i;
+        while j + 1 < n && pooled[j + 1].0 == pooled[i].0 {
+            j += 1;
+        }
+        let avg_rank = (i + j) as f64 / 2.0 + 1.0;
+        let t = (j - i + 1) as f64;
+        tie_term += t.powi(3) - t;
+        rank_sum_a += pooled[i ..= j].iter().filter(|(_, from_a)| *from_a).count() as f64 * avg_rank;
+        i = j + 1;
+    }
+
+    let (n1, n2, n) = (n1 as f64, n2 as f64, n as f64);
+    let u = rank_sum_a - n1 * (n1 + 1.0) / 2.0;
+    let mu = n1 * n2 / 2.0;
+    let sigma = (n1 * n2 / 12.0 * ((n + 1.0) - tie_term / (n * (n - 1.0)))).sqrt();
+    if sigma == 0.0 || !sigma.is_finite() {
+        return Some(1.0);
+    }
+    let z = ((u - mu).abs() - 0.5).max(0.0) / sigma;
+    Some((2.0 * (1.0 - normal_cdf(z))).clamp(0.0, 1.0))
+}
+
+/// 두 표본이 완전히 갈라졌을 때의 p-value, 즉 크기 `n1`, `n2` 로 얻을 수
+/// 있는 가장 작은 p. 이보다 작은 유의수준은 검정으로 도달할 수 없다.
+///
+/// @trace SPEC: SPEC-038
+/// @trace FR: PRD-038/FR-4
+pub fn mann_whitney_min_p(n1: usize, n2: usize) -> Option<f64> {
+    let a: Vec<f64> = (0 .. n1).map(|i| i as f64).collect();
+    let b: Vec<f64> = (n1 .. n1 + n2).map(|i| i as f64).collect();
+    mann_whitney_p(&a, &b)
+}
+
+/// 표준정규 누적분포. Abramowitz & Stegun 7.1.26 erf 근사 (오차 < 1.5e-7).
+fn normal_cdf(z: f64) -> f64 {
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let poly = t * (0.254_829_592 + t * (-0.284_496_736 + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erf = 1.0 - poly * (-x * x).exp();
+    if z >= 0.0 { 0.5 * (1.0 + erf) } else { 0.5 * (1.0 - erf) }
+}
+
+/// n 번 중 c 번 성공한 시나리오의 unbiased pass@k 추정치
+/// `1 - C(n-c, k) / C(n, k)`.
+pub fn pass_at_k(n: usize, c: usize, k: usize) -> f64 {
+    if k == 0 || k > n {
+        return 0.0;
+    }
+    if n - c < k {
+        return 1.0;
+    }
+    1.0 - ((n - c + 1) ..= n).map(|i| 1.0 - k as f64 / i as f64).product::<f64>()
+}
+
+/// 시나리오별로 시행을 묶어 k = 1..=최소 시행 수 의 평균 pass@k 를 구한다.
+/// 시나리오는 `domain/scenario_id`(없으면 작업 설명)로 식별한다.
+///
+/// @trace SPEC: SPEC-038
+/// @trace FR: PRD-038/FR-3
+pub fn pass_at_k_by_scenario(results: &[ScenarioResult]) -> BTreeMap<usize, f64> {
+    let mut groups: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    for r in results {
+        let key = if r.scenario_id.is_empty() {
+            r.task_description.clone()
+        } else {
+            format!("{}/{}", r.domain, r.scenario_id)
+        };
+        let entry = groups.entry(key).or_default();
+        entry.0 += 1;
+        entry.1 += usize::from(r.success);
+    }
+    let Some(min_trials) = groups.values().map(|(n, _)| *n).min() else {
+        return BTreeMap::new();
+    };
+    (1 ..= min_trials)
+        .map(|k| {
+            let total: f64 = groups.values().map(|&(n, c)| pass_at_k(n, c, k)).sum();
+            (k, total / groups.len() as f64)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-038
+    // @trace PRD: PRD-038
+    // @trace FR: PRD-038/FR-2, PRD-038/FR-3
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use std::collections::HashMap;
+
+    /// @trace TC: SPEC-038/TC-1
+    #[test]
+    fn spec038_tc_1_pass_at_k_estimator() {
+        assert_eq!(pass_at_k(5, 0, 1), 0.0);
+        assert_eq!(pass_at_k(5, 5, 3), 1.0);
+        assert!((pass_at_k(5, 2, 1) - 0.4).abs() < 1e-12);
+        // 1 - C(3,2)/C(5,2) = 1 - 3/10
+        assert!((pass_at_k(5, 2, 2) - 0.7).abs() < 1e-12);
+        assert_eq!(pass_at_k(5, 4, 2), 1.0);
+
+        let run = |id: &str, success: bool| ScenarioResult {
+            task_id: String::new(),
+            task_description: String::new(),
+            success,
+            total_iterations: 1,
+            metrics: HashMap::new(),
+            domain: "financial".into(),
+            scenario_id: id.into(),
+            trial: 0,
+        };
+        let results = vec![run("a", true), run("a", false), run("b", false), run("b", false)];
+        let p = pass_at_k_by_scenario(&results);
+        assert_eq!(p.keys().copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert!((p[&1] - 0.25).abs() < 1e-12);
+        assert!((p[&2] - 0.5).abs() < 1e-12);
+    }
+
+    /// @trace TC: SPEC-038/TC-2
+    #[test]
+    fn spec038_tc_2_mann_whitney_and_ci() {
+        // 완전히 분리된 두 표본 (n=8 씩) → 유의
+        let a = [0.90, 0.92, 0.88, 0.95, 0.91, 0.93, 0.89, 0.94];
+        let b = [0.60, 0.62, 0.58, 0.65, 0.61, 0.63, 0.59, 0.64];
+        let p = mann_whitney_p(&a, &b).unwrap();
+        assert!(p < 0.01, "p={p}");
+        assert!((p - mann_whitney_p(&b, &a).unwrap()).abs() < 1e-12, "양측 검정은 대칭");
+
+        // 섞인 표본 → 유의하지 않음, 전부 동점 → 1
+        let c = [0.9, 0.6, 0.8, 0.7];
+        let d = [0.85, 0.65, 0.75, 0.7];
+        assert!(mann_whitney_p(&c, &d).unwrap() > 0.5);
+        assert_eq!(mann_whitney_p(&[1.0, 1.0], &[1.0, 1.0]), Some(1.0));
+        assert_eq!(mann_whitney_p(&[], &[1.0]), None);
+
+        let [lo, hi] = mean_ci95(&[1.0, 2.0, 3.0]).unwrap();
+        // mean 2, s = 1, t(2) = 4.303 → ±2.484
+        assert!((lo - (2.0 - 4.303 / 3f64.sqrt())).abs() < 1e-9);
+        assert!((hi - (2.0 + 4.303 / 3f64.sqrt())).abs() < 1e-9);
+        assert_eq!(mean_ci95(&[1.0]), None);
+        assert!((normal_cdf(1.96) - 0.975).abs() < 1e-4);
+    }
+}
@@ -162,6 +162,13 @@ impl TrajectoryLog for SqliteLogger {
             ),
             | None => (None, None, None, None),
         };
+        // SPEC-026: LLM judge 결과는 judge_results 에 루브릭별로 보관.
+        let judge = evaluation.golden_set_result.as_ref().and_then(|g| {
+            let score = g.llm_judge_score?;
+            let detail = g.llm_judge_detail.as_ref()?;
+            let rubric = detail.get("rubric_name").and_then(|v| v.as_str()).unwrap_or("default").to_string();
+            Some((rubric, score, detail.to_string()))
+        });
         let store = self.store.clone();
         Self::run(async move {
             store
@@ -178,7 +185,11 @@ impl TrajectoryLog for SqliteLogger {
                     &metrics_json,
                     golden_set_result_json.as_deref(),
                 )
-                .await
+                .await?;
+            if let Some((rubric, score, detail_json)) = judge {
+                store.upsert_judge_result(&task_id, &rubric, score, &detail_json).await?;
+            }
+            Ok::<(), data_scenarios::sqlite_store::StoreError>(())
         })?;
         Ok(())
     }
@@ -317,6 +328,7 @@ mod spec025_tests {
             output_data: HashMap::new(),
             tool_calls: Vec::new(),
             duration_ms: Some(1.0),
+            token_usage: None,
         };
         let mut final_state = AgentState::new("e2e-test".into());
         final_state.perceived_info.insert("domain".into(), serde_json::Value::String(domain.into()));
@@ -331,6 +343,11 @@ mod spec025_tests {
             success: true,
             total_iterations: 1,
             prompt_set_id,
+            injected_faults: Vec::new(),
+            token_usage: None,
+            tool_calling_mode: None,
+            llm_model: None,
+            domain_routing: None,
         }
     }
 
@@ -0,0 +1,341 @@
+// =============================================================================
+// @trace SPEC-026
+// @trace PRD: PRD-026
+// @trace FR: PRD-026/FR-1, PRD-026/FR-2, PRD-026/FR-3
+// @trace file-type: impl
+// =============================================================================
+//
+// 루브릭 기반 LLM-as-judge. 궤적을 사람이 읽을 수 있는 요약으로 직렬화해
+// `LlmClient` 로 채점을 요청하고, 차원별 점수/근거를 파싱해 가중 점수를
+// 계산한다. `eval_models::traits::TrajectoryJudge` 를 구현하므로 `scoring`
+// 의 골든셋 검증 단계에 그대로 꽂힌다.
+
+use crate::models::{DimensionScore,
+                    EvaluationRubric,
+                    RubricDimension,
+                    RubricJudgeResult};
+use agent_core::llm_client::{LlmClient,
+                             Message};
+use agent_models::models::Trajectory;
+use anyhow::{Context,
+             Result};
+use eval_models::{models::JudgeVerdict,
+                  traits::TrajectoryJudge};
+use std::{collections::HashMap,
+          path::Path,
+          sync::Arc};
+
+/// 궤적 요약에서 도구 결과/파라미터 JSON 을 자르는 최대 길이.
+const MAX_VALUE_CHARS: usize = 400;
+
+/// 채점 system 프롬프트. 루브릭 본문은 `{rubric}` 슬롯에 주입된다.
+const JUDGE_SYSTEM: &str = "당신은 AI Agent 실행 궤적을 평가하는 엄격한 심사관입니다.\n\
+     주어진 루브릭의 각 차원에 대해 정수 점수와 근거를 제시하세요.\n\
+     점수는 반드시 1 이상 각 차원의 scale 이하 정수여야 합니다.\n\n\
+     루브릭:\n{rubric}\n\n\
+     출력 형식 (JSON):\n\
+     {\"dimension_scores\": [{\"dimension_name\": \"...\", \"score\": 1, \"reasoning\": \"...\"}], \"overall_reasoning\": \"...\"}";
+
+/// 기본 범용 루브릭. `--rubric` 파일이 없을 때 사용한다.
+///
+/// @trace SPEC: SPEC-026
+/// @trace FR: PRD-026/FR-1
+pub fn default_rubric() -> EvaluationRubric {
+    let dim = |name: &str, description: &str, weight: f64, low: &str, high: &str| RubricDimension {
+        name: name.into(),
+        description: description.into(),
+        weight,
+        scale: 5,
+        criteria: HashMap::from([(1, low.to_string()), (5, high.to_string())]),
+    };
+    EvaluationRubric {
+        name: "general".into(),
+        domain: "general".into(),
+        dimensions: vec![
+            dim(
+                "task_completion",
+                "작업 목표를 실제로 달성했는가",
+                0.4,
+                "목표와 무관하거나 미완료",
+                "목표를 완전히 달성하고 결과가 정확함",
+            ),
+            dim(
+                "tool_use",
+                "적절한 도구를 올바른 파라미터로 호출했는가",
+                0.3,
+                "잘못된 도구 선택 또는 반복 실패",
+                "필요한 도구만 정확한 파라미터로 호출",
+            ),
+            dim(
+                "reasoning",
+                "각 단계의 판단 근거가 일관되고 타당한가",
+                0.3,
+                "근거가 없거나 모순됨",
+                "근거가 명확하고 단계 간 일관됨",
+            ),
+        ],
+    }
+}
+
+/// YAML(또는 JSON) 루브릭 파일 로드 + 가중치 검증.
+///
+/// @trace SPEC: SPEC-026
+/// @trace FR: PRD-026/FR-1
+pub fn load_rubric(path: &Path) -> Result<EvaluationRubric> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("루브릭 파일 읽기 실패: {}", path.display()))?;
+    let rubric: EvaluationRubric = serde_yaml::from_str(&text).with_context(|| format!("루브릭 파싱 실패: {}", path.display()))?;
+    rubric.validate_weights()?;
+    Ok(rubric)
+}
+
+pub(crate) fn truncate(s: &str) -> String {
+    if s.chars().count() <= MAX_VALUE_CHARS {
+        return s.to_string();
+    }
+    let head: String = s.chars().take(MAX_VALUE_CHARS).collect();
+    format!("{head}…")
+}
+
+pub(crate) fn format_rubric(rubric: &EvaluationRubric) -> String {
+    let mut out = String::new();
+    for d in &rubric.dimensions {
+        out.push_str(&format!("- {} (weight={}, scale=1-{}): {}\n", d.name, d.weight, d.scale, d.description));
+        let mut levels: Vec<_> = d.criteria.iter().collect();
+        levels.sort_by_key(|(k, _)| **k);
+        for (level, text) in levels {
+            out.push_str(&format!("    {level}: {text}\n"));
+        }
+    }
+    out
+}
+
+/// 궤적을 단계별 한 줄 요약으로 직렬화한다. 도구 결과는 길이 제한.
+///
+/// @trace SPEC: SPEC-026
+/// @trace FR: PRD-026/FR-2
+pub fn format_trajectory(trajectory: &Trajectory) -> String {
+    let mut out = format!(
+        "작업: {}\n성공 여부: {}\n반복 횟수: {}\n\n단계:\n",
+        trajectory.task_description, trajectory.success, trajectory.total_iterations
+    );
+    for step in &trajectory.steps {
+        let stage = serde_json::to_string(&step.stage).unwrap_or_default();
+        let output = serde_json::to_string(&step.output_data).unwrap_or_default();
+        out.push_str(&format!("[{}] {} output={}\n", step.iteration, stage.trim_matches('"'), truncate(&output)));
+        for tc in &step.tool_calls {
+            let params = serde_json::to_string(&tc.parameters).unwrap_or_default();
+            let outcome = match (&tc.result, &tc.error) {
+                | (_, Some(err)) => format!("error={}", truncate(err)),
+                | (Some(r), None) => format!("result={}", truncate(&r.to_string())),
+                | (None, None) => String::new(),
+            };
+            out.push_str(&format!(
+                "    tool {} success={} params={} {}\n",
+                tc.tool_name,
+                tc.success,
+                truncate(&params),
+                outcome
+            ));
+        }
+    }
+    out
+}
+
+/// 채점 요청 메시지 생성.
+///
+/// @trace SPEC: SPEC-026
+/// @trace FR: PRD-026/FR-2
+pub fn build_judge_messages(rubric: &EvaluationRubric, trajectory: &Trajectory) -> Vec<Message> {
+    let system = JUDGE_SYSTEM.replace("{rubric}", &format_rubric(rubric));
+    let user = format!("다음 궤적을 루브릭에 따라 채점하여 JSON 으로 출력하세요.\n\n{}", format_trajectory(trajectory));
+    vec![Message::system(system), Message::user(user)]
+}
+
+/// LLM 응답을 `RubricJudgeResult` 로 변환한다. 루브릭의 모든 차원이
+/// 응답에 있어야 하며, 점수는 `1..=scale` 로 clamp 한다. 가중 점수는
+/// `Σ weight × (score / scale)` 를 가중치 합으로 나눈 값(0.0-1.0).
+///
+/// @trace SPEC: SPEC-026
+/// @trace FR: PRD-026/FR-2, PRD-026/FR-3
+pub fn parse_judge_response(rubric: &EvaluationRubric, text: &str) -> Result<RubricJudgeResult> {
+    let parsed = LlmClient::parse_json_response(text);
+    let Some(items) = parsed.get("dimension_scores").and_then(|v| v.as_array()) else {
+        anyhow::bail!("judge 응답에 dimension_scores 배열이 없습니다: {}", truncate(text));
+    };
+
+    let mut dimension_scores = Vec::with_capacity(rubric.dimensions.len());
+    let mut weighted_sum = 0.0;
+    let mut weight_total = 0.0;
+    for dim in &rubric.dimensions {
+        let Some(item) = items
+            .iter()
+            .find(|i| i.get("dimension_name").and_then(|v| v.as_str()) == Some(dim.name.as_str()))
+        else {
+            anyhow::bail!("judge 응답에 차원 '{}' 이(가) 없습니다", dim.name);
+        };
+        let raw = item
+            .get("score")
+            .and_then(|v| v.as_f64())
+            .with_context(|| format!("차원 '{}' 의 score 가 숫자가 아닙니다", dim.name))?;
+        let scale = dim.scale.max(1);
+        let score = (raw.round().max(1.0) as u32).min(scale);
+        let normalized_score = score as f64 / scale as f64;
+        weighted_sum += dim.weight * normalized_score;
+        weight_total += dim.weight;
+        dimension_scores.push(DimensionScore {
+            dimension_name: dim.name.clone(),
+            score,
+            max_score: scale,
+            normalized_score,
+            reasoning: item.get("reasoning").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+        });
+    }
+
+    Ok(RubricJudgeResult {
+        rubric_name: rubric.name.clone(),
+        dimension_scores,
+        weighted_score: if weight_total > 0.0 { weighted_sum / weight_total } else { 0.0 },
+        overall_reasoning: parsed.get("overall_reasoning").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+    })
+}
+
+/// 루브릭 1개를 들고 궤적을 채점하는 LLM judge.
+pub struct LlmJudge {
+    llm: Arc<LlmClient>,
+    rubric: EvaluationRubric,
+}
+
+impl LlmJudge {
+    /// 루브릭 가중치 합이 1.0 이 아니면 에러.
+    pub fn new(llm: Arc<LlmClient>, rubric: EvaluationRubric) -> Result<Self> {
+        rubric.validate_weights()?;
+        Ok(Self {
+            llm,
+            rubric,
+        })
+    }
+
+    pub fn rubric(&self) -> &EvaluationRubric { &self.rubric }
+
+    /// 궤적 1건 채점.
+    ///
+    /// @trace SPEC: SPEC-026
+    /// @trace FR: PRD-026/FR-2
+    pub async fn judge_trajectory(&self, trajectory: &Trajectory) -> Result<RubricJudgeResult> {
+        let messages = build_judge_messages(&self.rubric, trajectory);
+        let text = self.llm.invoke(messages).await?;
+        parse_judge_response(&self.rubric, &text)
+    }
+
+    fn run<F: std::future::Future>(fut: F) -> F::Output {
+        match tokio::runtime::Handle::try_current() {
+            | Ok(handle) => tokio::task::block_in_place(|| handle.block_on(fut)),
+            | Err(_) => tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to build tokio runtime")
+                .block_on(fut),
+        }
+    }
+}
+
+impl TrajectoryJudge for LlmJudge {
+    fn judge(&self, trajectory: &Trajectory) -> Result<JudgeVerdict, String> {
+        let result = Self::run(self.judge_trajectory(trajectory)).map_err(|e| e.to_string())?;
+        let detail = serde_json::to_value(&result).map_err(|e| e.to_string())?;
+        Ok(JudgeVerdict {
+            rubric_name: result.rubric_name,
+            score: result.weighted_score,
+            reasoning: result.overall_reasoning,
+            detail,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-026
+    // @trace PRD: PRD-026
+    // @trace FR: PRD-026/FR-1, PRD-026/FR-2, PRD-026/FR-3
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use crate::test_support::{client_for,
+                              sample_trajectory,
+                              spawn_mock_chat_server};
+
+    const GOOD_REPLY: &str = r#"{"dimension_scores":[
+        {"dimension_name":"task_completion","score":5,"reasoning":"완료"},
+        {"dimension_name":"tool_use","score":4,"reasoning":"적절"},
+        {"dimension_name":"reasoning","score":3,"reasoning":"보통"}],
+        "overall_reasoning":"양호"}"#;
+
+    /// @trace TC: SPEC-026/TC-1
+    #[test]
+    fn spec026_tc_1_default_rubric_weights_valid() {
+        assert!(default_rubric().validate_weights().is_ok());
+    }
+
+    /// @trace TC: SPEC-026/TC-2
+    #[test]
+    fn spec026_tc_2_parse_computes_weighted_score() {
+        let r = parse_judge_response(&default_rubric(), GOOD_REPLY).unwrap();
+        assert_eq!(r.dimension_scores.len(), 3);
+        assert_eq!(r.dimension_scores[1].score, 4);
+        assert_eq!(r.dimension_scores[1].reasoning, "적절");
+        // 0.4*1.0 + 0.3*0.8 + 0.3*0.6 = 0.82
+        assert!((r.weighted_score - 0.82).abs() < 1e-9, "got {}", r.weighted_score);
+        assert_eq!(r.overall_reasoning, "양호");
+    }
+
+    /// @trace TC: SPEC-026/TC-3
+    #[test]
+    fn spec026_tc_3_parse_rejects_missing_dimension_and_clamps() {
+        let missing = r#"{"dimension_scores":[{"dimension_name":"task_completion","score":5}]}"#;
+        assert!(parse_judge_response(&default_rubric(), missing).is_err());
+        assert!(parse_judge_response(&default_rubric(), "not json").is_err());
+
+        let out_of_range = r#"```json
+        {"dimension_scores":[
+            {"dimension_name":"task_completion","score":9},
+            {"dimension_name":"tool_use","score":0},
+            {"dimension_name":"reasoning","score":5}]}
+        ```"#;
+        let r = parse_judge_response(&default_rubric(), out_of_range).unwrap();
+        assert_eq!(r.dimension_scores[0].score, 5);
+        assert_eq!(r.dimension_scores[1].score, 1);
+    }
+
+    #[test]
+    fn format_trajectory_includes_tool_calls() {
+        let text = format_trajectory(&sample_trajectory("t-1", "financial__calculate_simple_interest"));
+        assert!(text.contains("financial__calculate_simple_interest"));
+        assert!(text.contains("50000"));
+    }
+
+    /// @trace TC: SPEC-026/TC-4
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn spec026_tc_4_judge_against_mock_chat_server() {
+        let (endpoint, req_rx) = spawn_mock_chat_server(vec![GOOD_REPLY.to_string()]);
+        let judge = LlmJudge::new(client_for(endpoint), default_rubric()).unwrap();
+
+        // TrajectoryJudge(동기) 경로로 호출 — 런타임 안에서 block_in_place.
+        let verdict = judge.judge(&sample_trajectory("t-1", "financial__calculate_simple_interest")).unwrap();
+        assert_eq!(verdict.rubric_name, "general");
+        assert!((verdict.score - 0.82).abs() < 1e-9);
+        assert_eq!(verdict.detail["dimension_scores"][0]["dimension_name"], "task_completion");
+
+        let request = req_rx.recv().unwrap().body;
+        assert!(request.contains("task_completion"), "루브릭이 프롬프트에 포함되어야 함");
+        assert!(request.contains("financial__calculate_simple_interest"));
+    }
+
+    #[test]
+    fn new_rejects_invalid_weights() {
+        let mut rubric = default_rubric();
+        rubric.dimensions[0].weight = 0.9;
+        assert!(LlmJudge::new(client_for("http://127.0.0.1:1".into()), rubric).is_err());
+    }
+}
@@ -1 +1,5 @@
+pub mod judge;
 pub mod models;
+pub mod pairwise;
+#[cfg(test)]
+mod test_support;
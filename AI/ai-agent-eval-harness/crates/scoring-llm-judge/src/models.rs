@@ -49,6 +49,7 @@ pub struct RubricJudgeResult {
     pub overall_reasoning: String,
 }
 
+/// 두 궤적 비교 결과. `winner` 는 `"a"`, `"b"`, `"tie"` 중 하나.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PairwiseComparison {
     pub trajectory_a_id: String,
@@ -56,4 +57,16 @@ pub struct PairwiseComparison {
     pub winner: String,
     pub confidence: f64,
     pub reasoning: String,
+    /// A/B 순서를 바꿔 두 번 판정했을 때 결론이 일치했는지. false 면 위치
+    /// 편향으로 보고 winner 를 tie 로 강제한다.
+    #[serde(default)]
+    pub position_consistent: bool,
+}
+
+/// 단일 순서(A, B) 판정. `winner` 는 제시 순서 기준 `"A"`, `"B"`, `"tie"`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PairwiseVerdict {
+    pub winner: String,
+    pub confidence: f64,
+    pub reasoning: String,
 }
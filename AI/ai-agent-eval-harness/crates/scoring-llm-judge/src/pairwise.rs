@@ -0,0 +1,218 @@
+// =============================================================================
+// @trace SPEC-027
+// @trace PRD: PRD-027
+// @trace FR: PRD-027/FR-1, PRD-027/FR-2
+// @trace file-type: impl
+// =============================================================================
+//
+// 두 궤적을 한 프롬프트에 나란히 놓고 어느 쪽이 더 나은지 판정한다. LLM 의
+// 위치 편향(먼저/나중에 제시된 쪽 선호)을 제어하기 위해 A/B 순서를 바꿔 두
+// 번 묻고, 두 판정이 엇갈리면 tie 로 보고한다.
+
+use crate::{judge::{format_rubric,
+                    format_trajectory,
+                    truncate},
+            models::{EvaluationRubric,
+                     PairwiseComparison,
+                     PairwiseVerdict}};
+use agent_core::llm_client::{LlmClient,
+                             Message};
+use agent_models::models::Trajectory;
+use anyhow::Result;
+use std::sync::Arc;
+
+const PAIRWISE_SYSTEM: &str = "당신은 AI Agent 실행 궤적 두 개를 비교하는 공정한 심사관입니다.\n\
+     같은 작업에 대한 궤적 A 와 궤적 B 중 어느 쪽이 작업을 더 잘 수행했는지 판단하세요.\n\
+     제시 순서나 길이에 영향을 받지 말고 아래 기준으로만 판단하세요.\n\n\
+     평가 기준:\n{rubric}\n\n\
+     출력 형식 (JSON):\n\
+     {\"winner\": \"A\" | \"B\" | \"tie\", \"confidence\": 0.0-1.0, \"reasoning\": \"...\"}";
+
+/// 제시 순서대로 (first, second) 를 A/B 로 배치한 메시지.
+///
+/// @trace SPEC: SPEC-027
+/// @trace FR: PRD-027/FR-1
+pub fn build_pairwise_messages(rubric: &EvaluationRubric, first: &Trajectory, second: &Trajectory) -> Vec<Message> {
+    let system = PAIRWISE_SYSTEM.replace("{rubric}", &format_rubric(rubric));
+    let user = format!(
+        "[궤적 A]\n{}\n[궤적 B]\n{}\n두 궤적을 비교하여 JSON 으로 출력하세요.",
+        format_trajectory(first),
+        format_trajectory(second)
+    );
+    vec![Message::system(system), Message::user(user)]
+}
+
+/// LLM 응답을 단일 순서 판정으로 변환. winner 는 대소문자 무시.
+///
+/// @trace SPEC: SPEC-027
+/// @trace FR: PRD-027/FR-1
+pub fn parse_pairwise_response(text: &str) -> Result<PairwiseVerdict> {
+    let parsed = LlmClient::parse_json_response(text);
+    let winner = match parsed.get("winner").and_then(|v| v.as_str()).map(|s| s.trim().to_ascii_lowercase()) {
+        | Some(w) if w == "a" => "A",
+        | Some(w) if w == "b" => "B",
+        | Some(w) if w == "tie" => "tie",
+        | _ => anyhow::bail!("pairwise 응답의 winner 가 A/B/tie 가 아닙니다: {}", truncate(text)),
+    };
+    Ok(PairwiseVerdict {
+        winner: winner.to_string(),
+        confidence: parsed.get("confidence").and_then(|v| v.as_f64()).unwrap_or(0.5).clamp(0.0, 1.0),
+        reasoning: parsed.get("reasoning").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+    })
+}
+
+/// 순서 교체 두 판정을 합친다. `forward` 는 (a, b) 순서, `swapped` 는 (b, a)
+/// 순서로 물은 결과. 원래 궤적 기준으로 같은 결론이면 채택하고 confidence
+/// 는 평균, 엇갈리면 tie + confidence 0.
+///
+/// @trace SPEC: SPEC-027
+/// @trace FR: PRD-027/FR-2
+pub fn resolve_swapped(a_id: &str, b_id: &str, forward: &PairwiseVerdict, swapped: &PairwiseVerdict) -> PairwiseComparison {
+    let forward_winner = match forward.winner.as_str() {
+        | "A" => "a",
+        | "B" => "b",
+        | _ => "tie",
+    };
+    let swapped_winner = match swapped.winner.as_str() {
+        | "A" => "b",
+        | "B" => "a",
+        | _ => "tie",
+    };
+    let position_consistent = forward_winner == swapped_winner;
+    let (winner, confidence) = if position_consistent {
+        (forward_winner, (forward.confidence + swapped.confidence) / 2.0)
+    } else {
+        ("tie", 0.0)
+    };
+    let mut reasoning = String::new();
+    if !position_consistent {
+        reasoning.push_str(&format!("위치 편향: 순서 교체 시 판정 불일치 ({forward_winner} vs {swapped_winner}) → tie\n"));
+    }
+    reasoning.push_str(&format!("[A={a_id}, B={b_id}] {}\n", forward.reasoning));
+    reasoning.push_str(&format!("[A={b_id}, B={a_id}] {}", swapped.reasoning));
+
+    PairwiseComparison {
+        trajectory_a_id: a_id.to_string(),
+        trajectory_b_id: b_id.to_string(),
+        winner: winner.to_string(),
+        confidence,
+        reasoning,
+        position_consistent,
+    }
+}
+
+/// 루브릭 기준으로 두 궤적을 비교하는 pairwise judge.
+pub struct PairwiseJudge {
+    llm: Arc<LlmClient>,
+    rubric: EvaluationRubric,
+}
+
+impl PairwiseJudge {
+    pub fn new(llm: Arc<LlmClient>, rubric: EvaluationRubric) -> Self {
+        Self {
+            llm,
+            rubric,
+        }
+    }
+
+    async fn judge_once(&self, first: &Trajectory, second: &Trajectory) -> Result<PairwiseVerdict> {
+        let text = self.llm.invoke(build_pairwise_messages(&self.rubric, first, second)).await?;
+        parse_pairwise_response(&text)
+    }
+
+    /// (a, b) 와 (b, a) 두 순서로 판정한 뒤 `resolve_swapped` 로 합친다.
+    ///
+    /// @trace SPEC: SPEC-027
+    /// @trace FR: PRD-027/FR-1, PRD-027/FR-2
+    pub async fn compare(&self, a: &Trajectory, b: &Trajectory) -> Result<PairwiseComparison> {
+        let forward = self.judge_once(a, b).await?;
+        let swapped = self.judge_once(b, a).await?;
+        Ok(resolve_swapped(&a.task_id, &b.task_id, &forward, &swapped))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // =============================================================================
+    // @trace SPEC-027
+    // @trace PRD: PRD-027
+    // @trace FR: PRD-027/FR-1, PRD-027/FR-2
+    // @trace file-type: test
+    // =============================================================================
+
+    use super::*;
+    use crate::{judge::default_rubric,
+                test_support::{client_for,
+                               sample_trajectory,
+                               spawn_mock_chat_server}};
+
+    fn verdict(winner: &str, confidence: f64) -> PairwiseVerdict {
+        PairwiseVerdict {
+            winner: winner.into(),
+            confidence,
+            reasoning: format!("{winner} 선택"),
+        }
+    }
+
+    /// @trace TC: SPEC-027/TC-1
+    #[test]
+    fn spec027_tc_1_consistent_verdicts_pick_winner() {
+        // forward 에서 A(=a), swapped 에서 B(=a) → a 승
+        let c = resolve_swapped("ta", "tb", &verdict("A", 0.8), &verdict("B", 0.6));
+        assert_eq!(c.winner, "a");
+        assert!(c.position_consistent);
+        assert!((c.confidence - 0.7).abs() < 1e-9);
+    }
+
+    /// @trace TC: SPEC-027/TC-2
+    #[test]
+    fn spec027_tc_2_inconsistent_verdicts_become_tie() {
+        // 두 번 모두 "먼저 제시된 쪽" 선택 → 위치 편향
+        let c = resolve_swapped("ta", "tb", &verdict("A", 0.9), &verdict("A", 0.9));
+        assert_eq!(c.winner, "tie");
+        assert!(!c.position_consistent);
+        assert_eq!(c.confidence, 0.0);
+        assert!(c.reasoning.contains("위치 편향"));
+    }
+
+    #[test]
+    fn both_tie_is_consistent_tie() {
+        let c = resolve_swapped("ta", "tb", &verdict("tie", 0.4), &verdict("tie", 0.6));
+        assert_eq!(c.winner, "tie");
+        assert!(c.position_consistent);
+    }
+
+    /// @trace TC: SPEC-027/TC-3
+    #[test]
+    fn spec027_tc_3_parse_normalizes_winner() {
+        let v = parse_pairwise_response(r#"{"winner":"b","confidence":1.7,"reasoning":"x"}"#).unwrap();
+        assert_eq!(v.winner, "B");
+        assert_eq!(v.confidence, 1.0);
+        assert!(parse_pairwise_response(r#"{"winner":"both"}"#).is_err());
+    }
+
+    /// @trace TC: SPEC-027/TC-4
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn spec027_tc_4_compare_swaps_order_against_mock_server() {
+        let replies = vec![
+            r#"{"winner":"A","confidence":0.9,"reasoning":"A 가 정확"}"#.to_string(),
+            r#"{"winner":"B","confidence":0.7,"reasoning":"B 가 정확"}"#.to_string(),
+        ];
+        let (endpoint, req_rx) = spawn_mock_chat_server(replies);
+        let judge = PairwiseJudge::new(client_for(endpoint), default_rubric());
+        let a = sample_trajectory("traj-a", "financial__calculate_simple_interest");
+        let b = sample_trajectory("traj-b", "financial__calculate_compound_interest");
+
+        let c = judge.compare(&a, &b).await.unwrap();
+        assert_eq!(c.winner, "a");
+        assert!(c.position_consistent);
+        assert_eq!(c.trajectory_a_id, "traj-a");
+
+        // 두 번째 요청은 b 의 궤적이 A 자리에 먼저 와야 한다.
+        let first = req_rx.recv().unwrap().body;
+        let second = req_rx.recv().unwrap().body;
+        let pos = |req: &str, needle: &str| req.find(needle).unwrap();
+        assert!(pos(&first, "simple_interest") < pos(&first, "compound_interest"));
+        assert!(pos(&second, "compound_interest") < pos(&second, "simple_interest"));
+    }
+}
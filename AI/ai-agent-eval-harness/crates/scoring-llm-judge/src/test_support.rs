@@ -0,0 +1,61 @@
+// =============================================================================
+// @trace SPEC-026
+// @trace PRD: PRD-026
+// @trace file-type: test
+// =============================================================================
+//
+// judge 테스트 공용 헬퍼: mock 서버로 향하는 클라이언트 + 샘플 궤적.
+
+/// 워크스페이스 공용 mock chat-completions 서버.
+pub use ::test_support::spawn_mock_chat_server;
+use agent_core::{config::AzureOpenAiConfig,
+                 llm_client::LlmClient};
+use agent_models::models::{PpaStage,
+                           PpaStep,
+                           ToolCall,
+                           Trajectory};
+use chrono::Utc;
+use std::{collections::HashMap,
+          sync::Arc};
+
+pub fn client_for(endpoint: String) -> Arc<LlmClient> {
+    Arc::new(LlmClient::new(AzureOpenAiConfig {
+        azure_openai_endpoint: endpoint,
+        azure_openai_api_key: "test".into(),
+        azure_openai_deployment: "judge".into(),
+        azure_openai_api_version: "2024-12-01-preview".into(),
+        azure_openai_region: "local".into(),
+        temperature: 1.0,
+        max_tokens: None,
+    }))
+}
+
+pub fn sample_trajectory(task_id: &str, tool_name: &str) -> Trajectory {
+    let mut tc = ToolCall::new(tool_name.into(), HashMap::new());
+    tc.result = Some(serde_json::json!({"interest": 50000}));
+    Trajectory {
+        task_id: task_id.into(),
+        task_description: "이자 계산".into(),
+        start_time: Utc::now(),
+        end_time: None,
+        steps: vec![PpaStep {
+            stage: PpaStage::Action,
+            iteration: 1,
+            timestamp: Utc::now(),
+            input_data: HashMap::new(),
+            output_data: HashMap::new(),
+            tool_calls: vec![tc],
+            duration_ms: None,
+            token_usage: None,
+        }],
+        final_state: None,
+        success: true,
+        total_iterations: 1,
+        prompt_set_id: None,
+        injected_faults: Vec::new(),
+        token_usage: None,
+        tool_calling_mode: None,
+        llm_model: None,
+        domain_routing: None,
+    }
+}
@@ -3,7 +3,8 @@ use agent_models::models::{PpaStage,
 use eval_models::{models::{CriteriaCheckResult,
                            GoldenSetResult},
                   traits::{EvalContext,
-                           GoldenSetContext}};
+                           GoldenSetContext,
+                           TrajectoryJudge}};
 use std::collections::HashMap;
 
 pub struct GoldenSetValidator {
@@ -17,7 +18,13 @@ impl GoldenSetValidator {
         }
     }
 
-    pub fn validate(&self, trajectory: &Trajectory, scenario: &dyn EvalContext, _enable_llm_judge: bool, _llm_client: Option<()>) -> GoldenSetResult {
+    pub fn validate(
+        &self,
+        trajectory: &Trajectory,
+        scenario: &dyn EvalContext,
+        enable_llm_judge: bool,
+        judge: Option<&dyn TrajectoryJudge>,
+    ) -> GoldenSetResult {
         let criteria_results = self.validate_tool_results(trajectory, scenario.success_criteria());
         let criteria_score = if criteria_results.is_empty() {
             1.0
@@ -27,7 +34,12 @@ impl GoldenSetValidator {
 
         let (tool_seq_score, actual_tools, _, _) = self.validate_tool_sequence(trajectory, scenario.expected_tools());
 
-        let overall = criteria_score * 0.7 + tool_seq_score * 0.3;
+        // SPEC-036: 시나리오의 도메인은 라우터를 쓴 실행만 채점한다. 라우터가
+        // 없는 에이전트의 첫 도구 도메인까지 채점하면 정확도가 왜곡된다.
+        let expected_domain = trajectory.domain_routing.as_ref().and(scenario.expected_domain());
+        let (domain_routing_score, actual_first_domain) = Self::validate_domain_routing(trajectory, expected_domain);
+        let overall = overall_score(criteria_score, tool_seq_score);
+        let (llm_judge_score, llm_judge_reasoning, llm_judge_detail) = Self::run_llm_judge(trajectory, enable_llm_judge, judge);
 
         GoldenSetResult {
             criteria_results,
@@ -35,20 +47,35 @@ impl GoldenSetValidator {
             expected_tools: scenario.expected_tools().to_vec(),
             actual_tools,
             tool_sequence_score: tool_seq_score,
-            llm_judge_score: None,
-            llm_judge_reasoning: None,
+            llm_judge_score,
+            llm_judge_reasoning,
             overall_score: overall,
-            domain_routing_score: None,
-            actual_first_domain: None,
+            domain_routing_score,
+            actual_first_domain: expected_domain.and(actual_first_domain),
+            llm_judge_detail,
         }
     }
 
+    /// 실행 후에야 알 수 있는 조건(예: SPEC-045 작업 공간 기대 파일)을
+    /// 성공 조건에 더하고 criteria/overall 점수를 다시 계산한다.
+    ///
+    /// @trace SPEC: SPEC-045
+    /// @trace FR: PRD-045/FR-3
+    pub fn merge_criteria(result: &mut GoldenSetResult, extra: impl IntoIterator<Item = CriteriaCheckResult>) {
+        result.criteria_results.extend(extra.into_iter().map(|c| (c.key.clone(), c)));
+        if !result.criteria_results.is_empty() {
+            let passed = result.criteria_results.values().filter(|r| r.passed).count();
+            result.criteria_score = passed as f64 / result.criteria_results.len() as f64;
+        }
+        result.overall_score = overall_score(result.criteria_score, result.tool_sequence_score);
+    }
+
     pub fn validate_with_golden_entry(
         &self,
         trajectory: &Trajectory,
         entry: &dyn GoldenSetContext,
-        _enable_llm_judge: bool,
-        _llm_client: Option<()>,
+        enable_llm_judge: bool,
+        judge: Option<&dyn TrajectoryJudge>,
     ) -> GoldenSetResult {
         let tolerance = entry.tolerance();
         let validator = GoldenSetValidator::new(tolerance);
@@ -91,7 +118,8 @@ impl GoldenSetValidator {
 
         let (tool_seq_score, actual_tools, _, _) = self.validate_tool_sequence(trajectory, entry.tool_sequence());
         let (domain_routing_score, actual_first_domain) = Self::validate_domain_routing(trajectory, entry.expected_domain());
-        let overall = criteria_score * 0.7 + tool_seq_score * 0.3;
+        let overall = overall_score(criteria_score, tool_seq_score);
+        let (llm_judge_score, llm_judge_reasoning, llm_judge_detail) = Self::run_llm_judge(trajectory, enable_llm_judge, judge);
 
         GoldenSetResult {
             criteria_results,
@@ -99,11 +127,32 @@ impl GoldenSetValidator {
             expected_tools: entry.tool_sequence().to_vec(),
             actual_tools,
             tool_sequence_score: tool_seq_score,
-            llm_judge_score: None,
-            llm_judge_reasoning: None,
+            llm_judge_score,
+            llm_judge_reasoning,
             overall_score: overall,
             domain_routing_score,
             actual_first_domain,
+            llm_judge_detail,
+        }
+    }
+
+    /// judge 가 주어지고 활성화된 경우에만 궤적을 채점한다. judge 호출
+    /// 실패는 평가 전체를 막지 않도록 점수 None + 실패 사유로 기록한다.
+    /// overall_score 에는 합산하지 않는다(결정적 점수와 분리 보관).
+    ///
+    /// @trace SPEC: SPEC-026
+    /// @trace FR: PRD-026/FR-3
+    fn run_llm_judge(
+        trajectory: &Trajectory,
+        enable_llm_judge: bool,
+        judge: Option<&dyn TrajectoryJudge>,
+    ) -> (Option<f64>, Option<String>, Option<serde_json::Value>) {
+        let Some(judge) = judge.filter(|_| enable_llm_judge) else {
+            return (None, None, None);
+        };
+        match judge.judge(trajectory) {
+            | Ok(v) => (Some(v.score), Some(v.reasoning), Some(v.detail)),
+            | Err(e) => (None, Some(format!("LLM judge 실패: {e}")), None),
         }
     }
 
@@ -142,15 +191,20 @@ impl GoldenSetValidator {
 
     /// 에이전트가 처음으로 성공 호출한 도구의 도메인이 `expected_domain` 과
     /// 일치하는지 검사한다. `expected_domain` 이 None 이면 스코어 생성 안함.
+    /// SPEC-036: 궤적에 라우터 결정(`domain_routing`)이 있으면 첫 도구 대신
+    /// 라우터의 top-1 도메인을 채점한다. 라우터가 아무것도 고르지 못했으면
+    /// 첫 도구 도메인으로 폴백.
     ///
     /// 반환: `(score, actual_first_domain)`
     /// - score: 1.0 == 일치, 0.0 == 불일치 또는 tool call 없음
-    /// - actual_first_domain: 첫 tool call 의 도메인 (None 이면 tool call 없음)
+    /// - actual_first_domain: 라우터 top-1 또는 첫 tool call 의 도메인 (None
+    ///   이면 둘 다 없음)
     ///
-    /// @trace SPEC: SPEC-020
-    /// @trace FR: PRD-020/FR-2
+    /// @trace SPEC: SPEC-020, SPEC-036
+    /// @trace FR: PRD-020/FR-2, PRD-036/FR-4
     pub fn validate_domain_routing(trajectory: &Trajectory, expected_domain: Option<&str>) -> (Option<f64>, Option<String>) {
-        let actual_first_domain = Self::first_tool_domain(trajectory);
+        let routed = trajectory.domain_routing.as_ref().and_then(|r| r.domains.first().cloned());
+        let actual_first_domain = routed.or_else(|| Self::first_tool_domain(trajectory));
         let Some(expected) = expected_domain else {
             return (None, actual_first_domain);
         };
@@ -265,6 +319,9 @@ impl Default for GoldenSetValidator {
     fn default() -> Self { Self::new(0.01) }
 }
 
+/// 결정적 골든셋 점수. 성공 조건 70%, 도구 순서 30%.
+fn overall_score(criteria_score: f64, tool_seq_score: f64) -> f64 { criteria_score * 0.7 + tool_seq_score * 0.3 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -283,6 +340,7 @@ mod tests {
                 success,
                 result,
                 error: None,
+                approval: None,
             })
             .collect();
 
@@ -294,6 +352,7 @@ mod tests {
             output_data: HashMap::new(),
             tool_calls: calls,
             duration_ms: None,
+            token_usage: None,
         };
 
         Trajectory {
@@ -306,6 +365,11 @@ mod tests {
             success: true,
             total_iterations: 1,
             prompt_set_id: None,
+            injected_faults: Vec::new(),
+            token_usage: None,
+            tool_calling_mode: None,
+            llm_model: None,
+            domain_routing: None,
         }
     }
 
@@ -416,4 +480,89 @@ mod tests {
         assert_eq!(score, Some(1.0));
         assert_eq!(actual.as_deref(), Some("financial"));
     }
+
+    struct FixedJudge(Result<f64, String>);
+
+    impl TrajectoryJudge for FixedJudge {
+        fn judge(&self, _trajectory: &Trajectory) -> Result<eval_models::models::JudgeVerdict, String> {
+            self.0.clone().map(|score| eval_models::models::JudgeVerdict {
+                rubric_name: "fixed".into(),
+                score,
+                reasoning: "ok".into(),
+                detail: serde_json::json!({"rubric_name": "fixed"}),
+            })
+        }
+    }
+
+    /// @trace TC: SPEC-026/TC-5
+    /// @trace FR: PRD-026/FR-3
+    #[test]
+    fn llm_judge_fills_score_only_when_enabled() {
+        let traj = make_trajectory(vec![("read_file", true, None)]);
+        let judge = FixedJudge(Ok(0.8));
+        let (score, reasoning, detail) = GoldenSetValidator::run_llm_judge(&traj, true, Some(&judge));
+        assert_eq!(score, Some(0.8));
+        assert_eq!(reasoning.as_deref(), Some("ok"));
+        assert_eq!(detail.unwrap()["rubric_name"], "fixed");
+
+        let (score, reasoning, _) = GoldenSetValidator::run_llm_judge(&traj, false, Some(&judge));
+        assert!(score.is_none() && reasoning.is_none());
+    }
+
+    /// @trace TC: SPEC-026/TC-6
+    /// @trace FR: PRD-026/FR-3
+    #[test]
+    fn llm_judge_failure_is_recorded_as_reasoning() {
+        let traj = make_trajectory(vec![]);
+        let judge = FixedJudge(Err("timeout".into()));
+        let (score, reasoning, detail) = GoldenSetValidator::run_llm_judge(&traj, true, Some(&judge));
+        assert!(score.is_none());
+        assert!(detail.is_none());
+        assert!(reasoning.unwrap().contains("timeout"));
+    }
+
+    struct DomainScenario {
+        domain: &'static str,
+        expected_tools: Vec<String>,
+        success_criteria: HashMap<String, serde_json::Value>,
+    }
+
+    impl EvalContext for DomainScenario {
+        fn expected_tools(&self) -> &[String] { &self.expected_tools }
+
+        fn success_criteria(&self) -> &HashMap<String, serde_json::Value> { &self.success_criteria }
+
+        fn expected_domain(&self) -> Option<&str> { Some(self.domain) }
+    }
+
+    /// @trace TC: SPEC-036/TC-7
+    /// @trace FR: PRD-036/FR-4
+    #[test]
+    fn spec036_tc_7_router_decision_is_scored_before_first_tool() {
+        let mut traj = make_trajectory(vec![("customer_service__classify_inquiry", true, None)]);
+        let scenario = DomainScenario {
+            domain: "financial",
+            expected_tools: vec![],
+            success_criteria: HashMap::new(),
+        };
+
+        // 라우터를 쓰지 않은 실행은 시나리오 도메인으로 채점하지 않는다.
+        let r = GoldenSetValidator::new(0.01).validate(&traj, &scenario, false, None);
+        assert!(r.domain_routing_score.is_none());
+
+        traj.domain_routing = Some(agent_models::models::DomainRouting {
+            method: "embedding".into(),
+            domains: vec!["financial".into()],
+            scores: vec![0.82],
+        });
+        let r = GoldenSetValidator::new(0.01).validate(&traj, &scenario, false, None);
+        assert_eq!(r.domain_routing_score, Some(1.0));
+        assert_eq!(r.actual_first_domain.as_deref(), Some("financial"));
+
+        // 라우터가 아무것도 고르지 못하면 첫 도구 도메인으로 폴백
+        traj.domain_routing.as_mut().unwrap().domains.clear();
+        let (score, actual) = GoldenSetValidator::validate_domain_routing(&traj, Some("financial"));
+        assert_eq!(score, Some(0.0));
+        assert_eq!(actual.as_deref(), Some("customer_service"));
+    }
 }
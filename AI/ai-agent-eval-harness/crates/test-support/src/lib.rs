@@ -0,0 +1,137 @@
+// =============================================================================
+// @trace file-type: test
+// =============================================================================
+//
+// 워크스페이스 테스트 공용 헬퍼 (dev-dependency 전용). LLM·임베딩·MCP HTTP
+// 클라이언트 테스트가 함께 쓰는 로컬 mock HTTP 서버.
+//
+// 서버는 std 스레드에서 돌기 때문에 동기 테스트와 `#[tokio::test]` 양쪽에서
+// 쓸 수 있다. 요청은 응답을 쓰기 전에 채널로 넘기므로, 클라이언트가 응답을
+// 받은 뒤의 `recv()` 는 막히지 않는다.
+
+use std::{io::{Read,
+               Write},
+          net::{TcpListener,
+                TcpStream},
+          sync::mpsc};
+
+/// mock 서버가 받은 요청 1건.
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    /// 요청 줄 + 헤더 + 본문 원문.
+    pub raw: String,
+    pub body: String,
+}
+
+/// mock 서버의 응답 1건.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: String,
+    pub content_type: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    pub fn new(content_type: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            status: "200 OK".into(),
+            content_type: content_type.into(),
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    pub fn json(value: &serde_json::Value) -> Self { Self::new("application/json", value.to_string()) }
+
+    pub fn with_status(mut self, status: impl Into<String>) -> Self {
+        self.status = status.into();
+        self
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// chat-completions 응답 본문. `content` 가 assistant 메시지가 된다.
+pub fn chat_completion(content: &str) -> serde_json::Value { serde_json::json!({"choices": [{"message": {"role": "assistant", "content": content}}]}) }
+
+/// 연결 `n` 개를 차례로 받아 `respond(요청)` 을 돌려주는 mock 서버. 주소
+/// (`http://127.0.0.1:<port>`)와 수신 요청 채널을 반환한다.
+pub fn spawn_mock_server<F>(n: usize, mut respond: F) -> (String, mpsc::Receiver<MockRequest>)
+where
+    F: FnMut(&MockRequest) -> MockResponse + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        for _ in 0 .. n {
+            let Ok((mut sock, _)) = listener.accept() else {
+                return;
+            };
+            let request = read_request(&mut sock);
+            let response = respond(&request);
+            tx.send(request).ok();
+            write_response(&mut sock, &response);
+        }
+    });
+    (format!("http://{addr}"), rx)
+}
+
+/// `bodies` 를 순서대로 1건씩 JSON 으로 돌려주는 mock 서버.
+pub fn spawn_json_server(bodies: Vec<serde_json::Value>) -> (String, mpsc::Receiver<MockRequest>) {
+    let n = bodies.len();
+    let mut bodies = bodies.into_iter();
+    spawn_mock_server(n, move |_| MockResponse::json(&bodies.next().unwrap()))
+}
+
+/// `replies` 를 순서대로 chat-completions 응답으로 돌려주는 mock 서버.
+pub fn spawn_mock_chat_server(replies: Vec<String>) -> (String, mpsc::Receiver<MockRequest>) {
+    spawn_json_server(replies.iter().map(|r| chat_completion(r)).collect())
+}
+
+/// 헤더 끝과 `Content-Length` 만큼의 본문을 다 받을 때까지 읽는다.
+fn read_request(sock: &mut TcpStream) -> MockRequest {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 8192];
+    loop {
+        let n = sock.read(&mut chunk).unwrap_or(0);
+        buf.extend_from_slice(&chunk[.. n]);
+        let text = String::from_utf8_lossy(&buf);
+        if let Some(idx) = text.find("\r\n\r\n") {
+            let len = text[.. idx]
+                .lines()
+                .find_map(|l| {
+                    l.to_ascii_lowercase()
+                        .strip_prefix("content-length:")
+                        .map(|v| v.trim().parse::<usize>().unwrap())
+                })
+                .unwrap_or(0);
+            if buf.len() >= idx + 4 + len || n == 0 {
+                return MockRequest {
+                    raw: text.into_owned(),
+                    body: String::from_utf8_lossy(&buf[idx + 4 ..]).into_owned(),
+                };
+            }
+        }
+        if n == 0 {
+            return MockRequest {
+                raw: text.into_owned(),
+                body: String::new(),
+            };
+        }
+    }
+}
+
+fn write_response(sock: &mut TcpStream, response: &MockResponse) {
+    let mut head = format!("HTTP/1.1 {}\r\nContent-Type: {}\r\n", response.status, response.content_type);
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str(&format!("Content-Length: {}\r\nConnection: close\r\n\r\n", response.body.len()));
+    sock.write_all(head.as_bytes()).ok();
+    sock.write_all(response.body.as_bytes()).ok();
+}
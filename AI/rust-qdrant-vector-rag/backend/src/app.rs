@@ -5,8 +5,9 @@ use crate::repository::{QdrantRepository, VectorRepository};
 use crate::services::document::DocumentServiceImpl;
 use crate::services::embedding::EmbeddingServiceImpl;
 use crate::services::rag::RAGServiceImpl;
+use crate::services::reranker::LlmReranker;
 use crate::services::vector_search::VectorSearchServiceImpl;
-use crate::services::{ChunkingConfig, DocumentService, EmbeddingService, RAGService, VectorSearchService};
+use crate::services::{ChunkingConfig, DocumentService, EmbeddingService, KeywordIndex, RAGService, VectorSearchService};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::signal;
@@ -18,6 +19,7 @@ pub struct AppContainer {
     pub config: AppConfig,
     pub azure_client: AzureOpenAIClient,
     pub vector_repository: Arc<dyn VectorRepository>,
+    pub keyword_index: Arc<KeywordIndex>,
     pub embedding_service: Arc<dyn EmbeddingService>,
     pub vector_search_service: Arc<dyn VectorSearchService>,
     pub document_service: Arc<dyn DocumentService>,
@@ -36,8 +38,9 @@ impl AppContainer {
         let vector_repository = Self::init_vector_repository(&config).await?;
 
         // Initialize services with dependency injection
+        let keyword_index = Arc::new(KeywordIndex::new());
         let embedding_service = Self::init_embedding_service(azure_client.clone());
-        let vector_search_service = Self::init_vector_search_service(vector_repository.clone());
+        let vector_search_service = Self::init_vector_search_service(vector_repository.clone(), keyword_index.clone());
         let document_service = Self::init_document_service(embedding_service.clone(), vector_repository.clone());
         let rag_service = Self::init_rag_service(embedding_service.clone(), vector_search_service.clone(), azure_client.clone());
 
@@ -47,6 +50,7 @@ impl AppContainer {
             config,
             azure_client,
             vector_repository,
+            keyword_index,
             embedding_service,
             vector_search_service,
             document_service,
@@ -103,10 +107,10 @@ impl AppContainer {
         Arc::new(EmbeddingServiceImpl::new(azure_client))
     }
 
-    /// Initialize vector search service
-    fn init_vector_search_service(vector_repository: Arc<dyn VectorRepository>) -> Arc<dyn VectorSearchService> {
+    /// Initialize vector search service backed by the shared keyword index
+    fn init_vector_search_service(vector_repository: Arc<dyn VectorRepository>, keyword_index: Arc<KeywordIndex>) -> Arc<dyn VectorSearchService> {
         info!("Initializing vector search service...");
-        Arc::new(VectorSearchServiceImpl::new(vector_repository))
+        Arc::new(VectorSearchServiceImpl::new(vector_repository).with_keyword_index(keyword_index))
     }
 
     /// Initialize document service with chunking configuration
@@ -131,7 +135,8 @@ impl AppContainer {
         azure_client: AzureOpenAIClient,
     ) -> Arc<dyn RAGService> {
         info!("Initializing RAG service...");
-        Arc::new(RAGServiceImpl::new(embedding_service, vector_search_service, azure_client))
+        let reranker = Arc::new(LlmReranker::new(azure_client.clone()));
+        Arc::new(RAGServiceImpl::new(embedding_service, vector_search_service, azure_client).with_reranker(reranker))
     }
 
     /// Perform comprehensive health checks on all services
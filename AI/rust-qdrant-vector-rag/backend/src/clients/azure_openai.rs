@@ -1,12 +1,21 @@
 use crate::config::AzureOpenAIConfig;
 use crate::models::ServiceError;
 use crate::monitoring::{Metrics, PerformanceTimer};
+use futures::StreamExt;
+use futures::stream::BoxStream;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::time::Duration;
 use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 
+/// Stream of answer text deltas from a streaming chat completion
+///
+/// Dropping the stream closes the underlying HTTP response, which cancels
+/// generation upstream.
+pub type ChatCompletionStream = BoxStream<'static, Result<String, ServiceError>>;
+
 /// Azure OpenAI client for handling embeddings and chat completions
 #[derive(Debug, Clone)]
 pub struct AzureOpenAIClient {
@@ -55,6 +64,8 @@ struct ChatCompletionRequest {
     temperature: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream: Option<bool>,
 }
 
 /// Chat message structure
@@ -81,6 +92,83 @@ struct ChatChoice {
     finish_reason: Option<String>,
 }
 
+/// Chunk of a streaming chat completion (`stream: true`)
+#[derive(Debug, Deserialize)]
+struct ChatCompletionChunk {
+    #[serde(default)]
+    choices: Vec<ChatChunkChoice>,
+}
+
+/// Individual choice within a streaming chunk
+#[derive(Debug, Deserialize)]
+struct ChatChunkChoice {
+    #[serde(default)]
+    delta: ChatDelta,
+}
+
+/// Incremental message content within a streaming chunk
+#[derive(Debug, Default, Deserialize)]
+struct ChatDelta {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+/// Incremental decoder for the server-sent `data:` lines of a streaming chat
+/// completion
+#[derive(Debug, Default)]
+pub(crate) struct ChatStreamDecoder {
+    buffer: Vec<u8>,
+    done: bool,
+}
+
+impl ChatStreamDecoder {
+    /// Feeds raw response bytes and returns the content deltas of every
+    /// complete line; partial lines are kept until the next call
+    pub(crate) fn feed(&mut self, bytes: &[u8]) -> Result<Vec<String>, ServiceError> {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut deltas = Vec::new();
+        while let Some(newline) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..= newline).collect();
+            if self.done {
+                continue;
+            }
+
+            let line = String::from_utf8_lossy(&line);
+            let Some(data) = line.trim_end().strip_prefix("data:") else {
+                // Blank separators, comments and other SSE fields carry no
+                // content
+                continue;
+            };
+
+            let data = data.trim();
+            if data == "[DONE]" {
+                self.done = true;
+                continue;
+            }
+
+            let chunk: ChatCompletionChunk =
+                serde_json::from_str(data).map_err(|e| ServiceError::serialization(format!("Failed to parse chat completion chunk: {}", e)))?;
+
+            deltas.extend(
+                chunk
+                    .choices
+                    .into_iter()
+                    .filter_map(|choice| choice.delta.content)
+                    .filter(|content| !content.is_empty()),
+            );
+        }
+
+        Ok(deltas)
+    }
+
+    /// Whether the `[DONE]` marker was received or the stream was aborted
+    pub(crate) fn is_done(&self) -> bool { self.done }
+
+    /// Stops decoding any further data
+    pub(crate) fn finish(&mut self) { self.done = true; }
+}
+
 /// Usage statistics
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
@@ -255,6 +343,7 @@ impl AzureOpenAIClient {
             max_tokens,
             temperature,
             user: None,
+            stream: None,
         };
 
         let result = self.execute_with_retry(|| self.create_chat_completion_request(&request)).await;
@@ -297,6 +386,67 @@ impl AzureOpenAIClient {
         Ok(content)
     }
 
+    /// Generate a chat completion as a stream of text deltas
+    ///
+    /// Only establishing the connection is retried; once tokens start
+    /// flowing, errors are yielded as the last stream item.
+    pub async fn stream_chat_completion(
+        &self,
+        messages: Vec<ChatMessage>,
+        max_tokens: Option<u32>,
+        temperature: Option<f32>,
+    ) -> Result<ChatCompletionStream, ServiceError> {
+        Metrics::increment_azure_openai_requests("chat_completion_stream");
+
+        debug!("Streaming chat completion for {} messages", messages.len());
+
+        let request = ChatCompletionRequest {
+            messages,
+            max_tokens,
+            temperature,
+            user: None,
+            stream: Some(true),
+        };
+
+        let response = self
+            .execute_with_retry(|| self.create_chat_completion_stream_request(&request))
+            .await
+            .inspect_err(|_| Metrics::increment_azure_openai_errors("chat_completion_stream", "other"))?;
+
+        let stream = futures::stream::unfold(
+            (response.bytes_stream().boxed(), ChatStreamDecoder::default(), VecDeque::new()),
+            |(mut bytes, mut decoder, mut pending)| async move {
+                loop {
+                    if let Some(delta) = pending.pop_front() {
+                        return Some((Ok(delta), (bytes, decoder, pending)));
+                    }
+
+                    if decoder.is_done() {
+                        return None;
+                    }
+
+                    match bytes.next().await {
+                        | Some(Ok(chunk)) => match decoder.feed(&chunk) {
+                            | Ok(deltas) => pending.extend(deltas),
+                            | Err(e) => {
+                                decoder.finish();
+                                return Some((Err(e), (bytes, decoder, pending)));
+                            },
+                        },
+                        | Some(Err(e)) => {
+                            decoder.finish();
+                            let error = ServiceError::network(format!("Chat completion stream interrupted: {}", e));
+                            return Some((Err(error), (bytes, decoder, pending)));
+                        },
+                        | None => return None,
+                    }
+                }
+            },
+        );
+
+        Ok(stream.boxed())
+    }
+
     /// Execute a request with retry logic and exponential backoff
     async fn execute_with_retry<F, Fut, T>(&self, mut request_fn: F) -> Result<T, ServiceError>
     where
@@ -381,6 +531,38 @@ impl AzureOpenAIClient {
         self.handle_response(response).await
     }
 
+    /// Create streaming chat completion request and check its status
+    async fn create_chat_completion_stream_request(&self, request: &ChatCompletionRequest) -> Result<reqwest::Response, ServiceError> {
+        let url = format!(
+            "{}/deployments/{}/chat/completions?api-version={}",
+            self.config.base_url(),
+            self.config.chat_deployment,
+            self.config.api_version
+        );
+
+        let response = self
+            .client
+            .post(&url)
+            .header("api-key", &self.config.api_key)
+            .header("Content-Type", "application/json")
+            .header("Accept", "text/event-stream")
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| ServiceError::network(format!("Failed to send streaming chat completion request: {}", e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let response_text = response
+                .text()
+                .await
+                .map_err(|e| ServiceError::network(format!("Failed to read response body: {}", e)))?;
+            return self.handle_error_response(status.as_u16(), &response_text);
+        }
+
+        Ok(response)
+    }
+
     /// Handle HTTP response and parse JSON
     async fn handle_response<T>(&self, response: reqwest::Response) -> Result<T, ServiceError>
     where
@@ -492,6 +674,41 @@ mod tests {
         assert_eq!(client.config.base_url(), "https://test.openai.azure.com/openai");
     }
 
+    #[test]
+    fn test_chat_stream_decoder() {
+        let mut decoder = ChatStreamDecoder::default();
+
+        // Azure sends a first chunk with prompt filter results and no choices
+        let deltas = decoder.feed(b"data: {\"choices\":[],\"prompt_filter_results\":[]}\n\n").unwrap();
+        assert!(deltas.is_empty());
+
+        // Lines may be split across network chunks
+        let deltas = decoder
+            .feed(b"data: {\"choices\":[{\"delta\":{\"role\":\"assistant\",\"content\":\"Hel")
+            .unwrap();
+        assert!(deltas.is_empty());
+        let deltas = decoder
+            .feed(b"lo\"}}]}\r\n\r\ndata: {\"choices\":[{\"delta\":{\"content\":\" world\"}}]}\n\ndata: {\"choices\":[{\"delta\":{},\"finish_reason\":\"stop\"}]}\n\n")
+            .unwrap();
+        assert_eq!(deltas, vec!["Hello".to_string(), " world".to_string()]);
+        assert!(!decoder.is_done());
+
+        let deltas = decoder
+            .feed(b"data: [DONE]\n\ndata: {\"choices\":[{\"delta\":{\"content\":\"ignored\"}}]}\n")
+            .unwrap();
+        assert!(deltas.is_empty());
+        assert!(decoder.is_done());
+    }
+
+    #[test]
+    fn test_chat_stream_decoder_invalid_chunk() {
+        let mut decoder = ChatStreamDecoder::default();
+
+        let result = decoder.feed(b"data: {not json}\n");
+
+        assert!(matches!(result, Err(ServiceError::Serialization(_))));
+    }
+
     // Integration tests would go here but require actual API credentials
     // These should be run separately with real credentials
 }
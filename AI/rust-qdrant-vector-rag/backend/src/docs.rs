@@ -14,9 +14,14 @@ use utoipa::OpenApi;
         crate::handlers::health::simple_health_handler,
         crate::handlers::query::query_handler,
         crate::handlers::query::simple_query_handler,
+        crate::handlers::query::query_stream_handler,
         crate::handlers::upload::upload_json_handler,
         crate::handlers::upload::upload_handler,
         crate::handlers::upload::upload_handler_root,
+        crate::handlers::documents::list_documents_handler,
+        crate::handlers::documents::get_document_handler,
+        crate::handlers::documents::reindex_document_handler,
+        crate::handlers::documents::delete_document_handler,
         crate::handlers::monitoring::metrics_handler,
         crate::handlers::monitoring::prometheus_metrics_handler,
         crate::handlers::monitoring::cache_stats_handler,
@@ -25,11 +30,13 @@ use utoipa::OpenApi;
         crate::handlers::monitoring::health_with_performance_handler,
         crate::handlers::query::query_handler_root,
         crate::handlers::query::simple_query_handler_root,
+        crate::handlers::query::query_stream_handler_root,
     ),
     components(
         schemas(
             crate::models::RAGResponse,
             crate::models::SourceReference,
+            crate::models::RAGStreamEvent,
             crate::models::UploadResponse,
             crate::models::UploadStatus,
             crate::models::HealthResponse,
@@ -37,7 +44,15 @@ use utoipa::OpenApi;
             crate::models::ServiceHealthStatus,
             crate::handlers::query::QueryRequest,
             crate::handlers::query::QueryConfig,
+            crate::models::SearchMode,
             crate::handlers::upload::UploadRequest,
+            crate::handlers::documents::ReindexRequest,
+            crate::models::DocumentSummary,
+            crate::models::DocumentListResponse,
+            crate::models::DocumentChunkResponse,
+            crate::models::DocumentDetailResponse,
+            crate::models::DeleteDocumentResponse,
+            crate::models::ChunkType,
             crate::handlers::monitoring::PerformanceMetricsResponse,
             crate::handlers::monitoring::SystemMetrics,
             crate::handlers::monitoring::ApplicationMetrics,
@@ -53,6 +68,7 @@ use utoipa::OpenApi;
         (name = "health", description = "헬스체크 API"),
         (name = "query", description = "질의/응답 API"),
         (name = "upload", description = "문서 업로드 API"),
+        (name = "documents", description = "문서 관리(목록/조회/재색인/삭제) API"),
         (name = "monitoring", description = "모니터링 및 메트릭 API")
     )
 )]
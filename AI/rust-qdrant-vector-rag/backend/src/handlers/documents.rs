@@ -0,0 +1,167 @@
+use crate::models::{DeleteDocumentResponse, DocumentChunkResponse, DocumentDetailResponse, DocumentListResponse, ServiceError, UploadResponse};
+use crate::services::document::{ReindexOutcome, summarize_documents};
+use crate::services::{DocumentService, KeywordIndex};
+use actix_web::{HttpResponse, ResponseError, Result, web};
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{error, info, warn};
+use utoipa::ToSchema;
+
+/// Request structure for re-indexing a document in place
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReindexRequest {
+    pub content: String,
+    /// New filename; keeps the current one when omitted
+    #[serde(default)]
+    pub filename: Option<String>,
+}
+
+/// 업로드된 문서 목록(청크 수, 업로드 시각)을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/documents",
+    tag = "documents",
+    responses(
+        (status = 200, description = "문서 목록 조회 성공", body = DocumentListResponse)
+    )
+)]
+pub async fn list_documents_handler(document_service: web::Data<Arc<dyn DocumentService>>) -> Result<HttpResponse> {
+    match document_service.list_documents().await {
+        | Ok(documents) => {
+            info!("Listed {} documents", documents.len());
+            Ok(HttpResponse::Ok().json(DocumentListResponse::new(documents)))
+        },
+        | Err(e) => {
+            error!("Failed to list documents: {}", e);
+            Ok(e.error_response())
+        },
+    }
+}
+
+/// 문서 하나와 그 청크들을 문서 순서대로 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/documents/{document_id}",
+    tag = "documents",
+    params(
+        ("document_id" = String, Path, description = "문서 ID")
+    ),
+    responses(
+        (status = 200, description = "문서 조회 성공", body = DocumentDetailResponse),
+        (status = 404, description = "문서 없음")
+    )
+)]
+pub async fn get_document_handler(document_id: web::Path<String>, document_service: web::Data<Arc<dyn DocumentService>>) -> Result<HttpResponse> {
+    let document_id = document_id.into_inner();
+
+    let mut chunks = match document_service.get_document_chunks(document_id.clone()).await {
+        | Ok(chunks) => chunks,
+        | Err(e) => {
+            error!("Failed to get document {}: {}", document_id, e);
+            return Ok(e.error_response());
+        },
+    };
+
+    chunks.sort_by_key(|chunk| chunk.metadata.chunk_index);
+
+    // 청크가 없으면 존재하지 않는 문서로 간주
+    let Some(document) = summarize_documents(chunks.clone()).into_iter().next() else {
+        warn!("Document not found: {}", document_id);
+        return Ok(ServiceError::not_found(format!("Document '{}' does not exist", document_id)).error_response());
+    };
+
+    let response = DocumentDetailResponse {
+        document,
+        chunks: chunks.into_iter().map(DocumentChunkResponse::from).collect(),
+    };
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// 문서를 같은 ID로 재색인합니다. 내용 해시가 같으면 건너뜁니다.
+#[utoipa::path(
+    put,
+    path = "/api/v1/documents/{document_id}",
+    tag = "documents",
+    params(
+        ("document_id" = String, Path, description = "문서 ID")
+    ),
+    request_body = ReindexRequest,
+    responses(
+        (status = 200, description = "재색인 완료 또는 변경 없음", body = UploadResponse),
+        (status = 400, description = "유효성 검사 실패"),
+        (status = 404, description = "문서 없음")
+    )
+)]
+pub async fn reindex_document_handler(
+    document_id: web::Path<String>,
+    request: web::Json<ReindexRequest>,
+    document_service: web::Data<Arc<dyn DocumentService>>,
+    keyword_index: web::Data<Arc<KeywordIndex>>,
+) -> Result<HttpResponse> {
+    let start_time = Instant::now();
+    let document_id = document_id.into_inner();
+    let request = request.into_inner();
+
+    info!("Processing re-index request for document: {}", document_id);
+
+    match document_service.reindex_document(document_id.clone(), request.content, request.filename).await {
+        | Ok(ReindexOutcome::Unchanged {
+            filename,
+            chunk_count,
+        }) => {
+            let processing_time = start_time.elapsed().as_millis() as u64;
+            Ok(HttpResponse::Ok().json(UploadResponse::unchanged(document_id, filename, chunk_count, processing_time)))
+        },
+        | Ok(ReindexOutcome::Reindexed {
+            filename,
+            chunks,
+        }) => {
+            // The shared keyword index loads from Qdrant only once, on first
+            // search, so keep it current here
+            keyword_index.remove_document(&document_id).await;
+            keyword_index.insert(&chunks).await;
+
+            let processing_time = start_time.elapsed().as_millis() as u64;
+            info!("Re-indexed document {} in {}ms with {} chunks", document_id, processing_time, chunks.len());
+            Ok(HttpResponse::Ok().json(UploadResponse::success(document_id, filename, chunks.len(), processing_time)))
+        },
+        | Err(e) => {
+            error!("Failed to re-index document {}: {}", document_id, e);
+            Ok(e.error_response())
+        },
+    }
+}
+
+/// 문서와 모든 청크를 삭제합니다.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/documents/{document_id}",
+    tag = "documents",
+    params(
+        ("document_id" = String, Path, description = "문서 ID")
+    ),
+    responses(
+        (status = 200, description = "삭제 완료", body = DeleteDocumentResponse),
+        (status = 404, description = "문서 없음")
+    )
+)]
+pub async fn delete_document_handler(
+    document_id: web::Path<String>,
+    document_service: web::Data<Arc<dyn DocumentService>>,
+    keyword_index: web::Data<Arc<KeywordIndex>>,
+) -> Result<HttpResponse> {
+    let document_id = document_id.into_inner();
+
+    match document_service.delete_document(document_id.clone()).await {
+        | Ok(chunks_deleted) => {
+            keyword_index.remove_document(&document_id).await;
+            Ok(HttpResponse::Ok().json(DeleteDocumentResponse::new(document_id, chunks_deleted)))
+        },
+        | Err(e) => {
+            error!("Failed to delete document {}: {}", document_id, e);
+            Ok(e.error_response())
+        },
+    }
+}
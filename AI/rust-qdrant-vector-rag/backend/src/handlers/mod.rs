@@ -1,9 +1,11 @@
+pub mod documents;
 pub mod health;
 pub mod monitoring;
 pub mod query;
 pub mod upload;
 
+pub use documents::{delete_document_handler, get_document_handler, list_documents_handler, reindex_document_handler};
 pub use health::{health_handler, simple_health_handler};
 pub use monitoring::*;
-pub use query::{query_handler, simple_query_handler};
+pub use query::{query_handler, query_stream_handler, simple_query_handler};
 pub use upload::{upload_handler, upload_json_handler};
@@ -1,13 +1,17 @@
 use crate::clients::AzureOpenAIClient;
 use crate::config::AppConfig;
-use crate::models::ServiceError;
+use crate::models::{SearchMode, ServiceError};
 use crate::repository::{QdrantRepository, VectorRepository};
-use crate::services::RAGService;
 use crate::services::embedding::EmbeddingServiceImpl;
 use crate::services::rag::{RAGConfig, RAGServiceImpl};
+use crate::services::reranker::LlmReranker;
 use crate::services::vector_search::VectorSearchServiceImpl;
+use crate::services::{KeywordIndex, RAGService};
+use actix_web::http::header::{CacheControl, CacheDirective, ContentEncoding};
 use actix_web::{HttpResponse, ResponseError, Result, web};
+use futures::StreamExt;
 use serde::Deserialize;
+use std::sync::Arc;
 use std::time::Instant;
 use tracing::{debug, error, info, warn};
 use utoipa::ToSchema;
@@ -33,6 +37,16 @@ pub struct QueryConfig {
     pub temperature: Option<f32>,
     /// Whether to include low-confidence answers
     pub include_low_confidence: Option<bool>,
+    /// Retrieval strategy: vector, keyword or hybrid
+    pub search_mode: Option<SearchMode>,
+    /// Weight of the vector ranking in hybrid search
+    pub vector_weight: Option<f32>,
+    /// Weight of the keyword ranking in hybrid search
+    pub keyword_weight: Option<f32>,
+    /// Whether to rerank retrieved chunks with the LLM reranker
+    pub rerank: Option<bool>,
+    /// Number of candidates retrieved for reranking
+    pub rerank_candidates: Option<usize>,
 }
 
 impl From<QueryConfig> for RAGConfig {
@@ -54,6 +68,21 @@ impl From<QueryConfig> for RAGConfig {
         if let Some(include_low) = config.include_low_confidence {
             rag_config.include_low_confidence = include_low;
         }
+        if let Some(search_mode) = config.search_mode {
+            rag_config.search_mode = search_mode;
+        }
+        if let Some(vector_weight) = config.vector_weight {
+            rag_config.vector_weight = vector_weight;
+        }
+        if let Some(keyword_weight) = config.keyword_weight {
+            rag_config.keyword_weight = keyword_weight;
+        }
+        if let Some(rerank) = config.rerank {
+            rag_config.rerank = rerank;
+        }
+        if let Some(rerank_candidates) = config.rerank_candidates {
+            rag_config.rerank_candidates = rerank_candidates;
+        }
 
         rag_config
     }
@@ -71,25 +100,23 @@ impl From<QueryConfig> for RAGConfig {
         (status = 400, description = "유효성 검사 실패")
     )
 )]
-pub async fn query_handler(request: web::Json<QueryRequest>, config: web::Data<AppConfig>, azure_client: web::Data<AzureOpenAIClient>) -> Result<HttpResponse> {
+pub async fn query_handler(
+    request: web::Json<QueryRequest>,
+    config: web::Data<AppConfig>,
+    azure_client: web::Data<AzureOpenAIClient>,
+    keyword_index: web::Data<Arc<KeywordIndex>>,
+) -> Result<HttpResponse> {
     let start_time = Instant::now();
 
     info!("Processing query request: {}", request.question);
 
     // Validate input
-    if request.question.trim().is_empty() {
-        warn!("Empty question received in query request");
-        return Ok(ServiceError::validation("Question cannot be empty").error_response());
-    }
-
-    // Check question length (reasonable limit)
-    if request.question.len() > 1000 {
-        warn!("Question too long: {} characters", request.question.len());
-        return Ok(ServiceError::validation("Question is too long (maximum 1000 characters)").error_response());
+    if let Err(e) = validate_question(&request.question) {
+        return Ok(e.error_response());
     }
 
     // Create RAG service with dependencies
-    let rag_service = create_rag_service(&config, &azure_client).await?;
+    let rag_service = create_rag_service(&config, &azure_client, keyword_index.get_ref().clone()).await?;
 
     // Convert query config if provided
     let rag_config = request.config.as_ref().map(|c| RAGConfig::from(c.clone()));
@@ -142,13 +169,14 @@ pub async fn simple_query_handler(
     question: web::Path<String>,
     config: web::Data<AppConfig>,
     azure_client: web::Data<AzureOpenAIClient>,
+    keyword_index: web::Data<Arc<KeywordIndex>>,
 ) -> Result<HttpResponse> {
     let request = QueryRequest {
         question: question.into_inner(),
         config: None,
     };
 
-    query_handler(web::Json(request), config, azure_client).await
+    query_handler(web::Json(request), config, azure_client, keyword_index).await
 }
 
 /// 루트 경로용 래퍼: POST /query
@@ -167,8 +195,9 @@ pub async fn query_handler_root(
     request: web::Json<QueryRequest>,
     config: web::Data<AppConfig>,
     azure_client: web::Data<AzureOpenAIClient>,
+    keyword_index: web::Data<Arc<KeywordIndex>>,
 ) -> Result<HttpResponse> {
-    query_handler(request, config, azure_client).await
+    query_handler(request, config, azure_client, keyword_index).await
 }
 
 /// 루트 경로용 래퍼: GET /query/{question}
@@ -189,12 +218,98 @@ pub async fn simple_query_handler_root(
     question: web::Path<String>,
     config: web::Data<AppConfig>,
     azure_client: web::Data<AzureOpenAIClient>,
+    keyword_index: web::Data<Arc<KeywordIndex>>,
+) -> Result<HttpResponse> {
+    simple_query_handler(question, config, azure_client, keyword_index).await
+}
+
+/// Streaming query handler that answers over Server-Sent Events
+/// 출처(sources) → 답변 토큰(token) → 메타데이터(done) 순서로 전송합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/query/stream",
+    tag = "query",
+    request_body = QueryRequest,
+    responses(
+        (status = 200, description = "SSE 이벤트 스트림 (sources, token, done, error)", body = crate::models::RAGStreamEvent, content_type = "text/event-stream"),
+        (status = 400, description = "유효성 검사 실패")
+    )
+)]
+pub async fn query_stream_handler(
+    request: web::Json<QueryRequest>,
+    config: web::Data<AppConfig>,
+    azure_client: web::Data<AzureOpenAIClient>,
+    keyword_index: web::Data<Arc<KeywordIndex>>,
+) -> Result<HttpResponse> {
+    info!("Processing streaming query request: {}", request.question);
+
+    if let Err(e) = validate_question(&request.question) {
+        return Ok(e.error_response());
+    }
+
+    let rag_service = create_rag_service(&config, &azure_client, keyword_index.get_ref().clone()).await?;
+
+    let rag_config = request.config.as_ref().map(|c| RAGConfig::from(c.clone())).unwrap_or_default();
+
+    // 검색/검증 오류는 스트림 시작 전에 일반 오류 응답으로 반환
+    let events = match rag_service.answer_question_stream(request.question.clone(), rag_config).await {
+        | Ok(events) => events,
+        | Err(e) => {
+            error!("Failed to start streaming query '{}': {}", request.question, e);
+            return Ok(e.error_response());
+        },
+    };
+
+    // 연결이 끊기면 바디 스트림이 drop되어 답변 생성도 취소됨
+    let body = events.map(|event| Ok::<_, actix_web::Error>(web::Bytes::from(event.to_sse())));
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(CacheControl(vec![CacheDirective::NoCache]))
+        // 압축 미들웨어가 이벤트를 버퍼링하지 않도록 인코딩을 고정
+        .insert_header(ContentEncoding::Identity)
+        .streaming(body))
+}
+
+/// 루트 경로용 래퍼: POST /query/stream
+/// 기존 핸들러를 재사용하여 동일한 동작을 제공합니다.
+#[utoipa::path(
+    post,
+    path = "/query/stream",
+    tag = "query",
+    request_body = QueryRequest,
+    responses(
+        (status = 200, description = "SSE 이벤트 스트림 (sources, token, done, error)", body = crate::models::RAGStreamEvent, content_type = "text/event-stream"),
+        (status = 400, description = "유효성 검사 실패")
+    )
+)]
+pub async fn query_stream_handler_root(
+    request: web::Json<QueryRequest>,
+    config: web::Data<AppConfig>,
+    azure_client: web::Data<AzureOpenAIClient>,
+    keyword_index: web::Data<Arc<KeywordIndex>>,
 ) -> Result<HttpResponse> {
-    simple_query_handler(question, config, azure_client).await
+    query_stream_handler(request, config, azure_client, keyword_index).await
+}
+
+/// Validates the question shared by the query handlers
+fn validate_question(question: &str) -> Result<(), ServiceError> {
+    if question.trim().is_empty() {
+        warn!("Empty question received in query request");
+        return Err(ServiceError::validation("Question cannot be empty"));
+    }
+
+    // Check question length (reasonable limit)
+    if question.len() > 1000 {
+        warn!("Question too long: {} characters", question.len());
+        return Err(ServiceError::validation("Question is too long (maximum 1000 characters)"));
+    }
+
+    Ok(())
 }
 
 /// Helper function to create RAG service with all dependencies
-async fn create_rag_service(config: &AppConfig, azure_client: &AzureOpenAIClient) -> Result<RAGServiceImpl, ServiceError> {
+async fn create_rag_service(config: &AppConfig, azure_client: &AzureOpenAIClient, keyword_index: Arc<KeywordIndex>) -> Result<RAGServiceImpl, ServiceError> {
     // Qdrant 리포지토리 생성
     let qdrant = QdrantRepository::new(config.qdrant.clone())
         .await
@@ -206,15 +321,16 @@ async fn create_rag_service(config: &AppConfig, azure_client: &AzureOpenAIClient
         qdrant.initialize_collection().await?;
     }
 
-    let qdrant_repo = std::sync::Arc::new(qdrant) as std::sync::Arc<dyn VectorRepository>;
+    let qdrant_repo = Arc::new(qdrant) as Arc<dyn VectorRepository>;
 
     // Create embedding service
-    let embedding_service = std::sync::Arc::new(EmbeddingServiceImpl::new(azure_client.clone())) as std::sync::Arc<dyn crate::services::EmbeddingService>;
+    let embedding_service = Arc::new(EmbeddingServiceImpl::new(azure_client.clone())) as Arc<dyn crate::services::EmbeddingService>;
 
-    // Create vector search service
+    // 요청마다 재구성하지 않도록 전역 키워드 인덱스를 공유
     let vector_search_service =
-        std::sync::Arc::new(VectorSearchServiceImpl::new(qdrant_repo.clone())) as std::sync::Arc<dyn crate::services::VectorSearchService>;
+        Arc::new(VectorSearchServiceImpl::new(qdrant_repo.clone()).with_keyword_index(keyword_index)) as Arc<dyn crate::services::VectorSearchService>;
 
-    // Create RAG service
-    Ok(RAGServiceImpl::new(embedding_service, vector_search_service, azure_client.clone()))
+    // Create RAG service (리랭커는 요청 config의 rerank 옵션으로 활성화)
+    let reranker = Arc::new(LlmReranker::new(azure_client.clone())) as Arc<dyn crate::services::Reranker>;
+    Ok(RAGServiceImpl::new(embedding_service, vector_search_service, azure_client.clone()).with_reranker(reranker))
 }
@@ -2,13 +2,14 @@ use crate::clients::AzureOpenAIClient;
 use crate::config::AppConfig;
 use crate::models::{ServiceError, UploadResponse};
 use crate::repository::{QdrantRepository, VectorRepository};
-use crate::services::DocumentService;
 use crate::services::document::DocumentServiceImpl;
 use crate::services::embedding::EmbeddingServiceImpl;
+use crate::services::{DocumentFormat, DocumentParser, DocumentService, KeywordIndex};
 use actix_multipart::Multipart;
 use actix_web::{HttpResponse, ResponseError, Result, web};
 use futures_util::TryStreamExt;
 use serde::Deserialize;
+use std::sync::Arc;
 use std::time::Instant;
 use tracing::{debug, error, info, warn};
 use utoipa::ToSchema;
@@ -35,12 +36,13 @@ pub async fn upload_handler_root(
     payload: Multipart,
     config: web::Data<AppConfig>,
     azure_client: web::Data<AzureOpenAIClient>,
+    keyword_index: web::Data<Arc<KeywordIndex>>,
 ) -> Result<HttpResponse> {
     // 원본 핸들러는 가변 payload를 요구하므로, 이 래퍼는 동일 시그니처로 위임합니다.
-    upload_handler(payload, config, azure_client).await
+    upload_handler(payload, config, azure_client, keyword_index).await
 }
 
-/// 멀티파트 기반 마크다운 파일 업로드 엔드포인트
+/// 멀티파트 기반 문서 파일(Markdown, HTML, 텍스트, PDF) 업로드 엔드포인트
 /// Swagger 표시를 위해 OpenAPI 메타데이터를 추가합니다.
 #[utoipa::path(
     post,
@@ -51,14 +53,19 @@ pub async fn upload_handler_root(
         (status = 400, description = "유효성 검사 실패")
     )
 )]
-pub async fn upload_handler(mut payload: Multipart, config: web::Data<AppConfig>, azure_client: web::Data<AzureOpenAIClient>) -> Result<HttpResponse> {
+pub async fn upload_handler(
+    mut payload: Multipart,
+    config: web::Data<AppConfig>,
+    azure_client: web::Data<AzureOpenAIClient>,
+    keyword_index: web::Data<Arc<KeywordIndex>>,
+) -> Result<HttpResponse> {
     let start_time = Instant::now();
 
     info!("Processing file upload request");
 
     // Extract file from multipart form data
     let mut filename = String::new();
-    let mut content = String::new();
+    let mut file_content = Vec::new();
 
     while let Some(mut field) = payload.try_next().await.map_err(|e| {
         error!("Failed to read multipart field: {}", e);
@@ -72,16 +79,9 @@ pub async fn upload_handler(mut payload: Multipart, config: web::Data<AppConfig>
                 if let Some(file_name) = content_disposition.get_filename() {
                     filename = file_name.to_string();
                     debug!("Processing file: {}", filename);
-
-                    // Validate file extension
-                    if !filename.to_lowercase().ends_with(".md") && !filename.to_lowercase().ends_with(".markdown") {
-                        warn!("Invalid file type uploaded: {}", filename);
-                        return Ok(ServiceError::validation("Only markdown files (.md, .markdown) are supported").error_response());
-                    }
                 }
 
                 // Read file content
-                let mut file_content = Vec::new();
                 while let Some(chunk) = field.try_next().await.map_err(|e| {
                     error!("Failed to read file chunk: {}", e);
                     ServiceError::validation(format!("Failed to read file content: {}", e))
@@ -96,12 +96,6 @@ pub async fn upload_handler(mut payload: Multipart, config: web::Data<AppConfig>
                         );
                     }
                 }
-
-                // Convert to UTF-8 string
-                content = String::from_utf8(file_content).map_err(|e| {
-                    error!("Invalid UTF-8 content in file: {}", e);
-                    ServiceError::validation("File must contain valid UTF-8 text")
-                })?;
             },
             | Some("filename") => {
                 // Alternative way to get filename
@@ -129,24 +123,51 @@ pub async fn upload_handler(mut payload: Multipart, config: web::Data<AppConfig>
         return Ok(ServiceError::validation("Filename is required").error_response());
     }
 
-    if content.is_empty() {
+    if file_content.is_empty() {
         warn!("Empty file content uploaded: {}", filename);
         return Ok(ServiceError::validation("File content cannot be empty").error_response());
     }
 
-    info!("Processing file: {} ({} bytes)", filename, content.len());
+    // Validate file type by extension, falling back to the content signature
+    let Some(format) = DocumentFormat::detect(&filename, &file_content) else {
+        warn!("Invalid file type uploaded: {}", filename);
+        return Ok(unsupported_format_error().error_response());
+    };
+
+    // PDF 텍스트 추출은 CPU 작업이므로 블로킹 스레드에서 수행
+    let content = match web::block(move || DocumentParser::new().extract_text(&file_content, format)).await {
+        | Ok(Ok(content)) => content,
+        | Ok(Err(e)) => {
+            warn!("Failed to extract text from {}: {}", filename, e);
+            return Ok(e.error_response());
+        },
+        | Err(e) => {
+            error!("Text extraction task failed for {}: {}", filename, e);
+            return Ok(ServiceError::internal("Text extraction failed").error_response());
+        },
+    };
+
+    info!("Processing {:?} file: {} ({} bytes of text)", format, filename, content.len());
 
     // Create document service with dependencies
     let document_service = create_document_service(&config, &azure_client).await?;
 
+    if let Some(response) = unchanged_upload_response(&document_service, &content, &filename, start_time).await {
+        return Ok(response);
+    }
+
     // Process the document
     match document_service.process_document(content, filename.clone()).await {
         | Ok(document_id) => {
             let processing_time = start_time.elapsed().as_millis() as u64;
 
-            // Get chunk count for response
+            // Get chunk count for response and add the chunks to the shared
+            // keyword index; it loads from Qdrant only once, on first search
             let chunks_created = match document_service.get_document_chunks(document_id.clone()).await {
-                | Ok(chunks) => chunks.len(),
+                | Ok(chunks) => {
+                    keyword_index.insert(&chunks).await;
+                    chunks.len()
+                },
                 | Err(e) => {
                     warn!("Failed to get chunk count for document {}: {}", document_id, e);
                     0 // Don't fail the request, just return 0
@@ -186,6 +207,7 @@ pub async fn upload_json_handler(
     request: web::Json<UploadRequest>,
     config: web::Data<AppConfig>,
     azure_client: web::Data<AzureOpenAIClient>,
+    keyword_index: web::Data<Arc<KeywordIndex>>,
 ) -> Result<HttpResponse> {
     let start_time = Instant::now();
 
@@ -200,9 +222,13 @@ pub async fn upload_json_handler(
         return Ok(ServiceError::validation("File content cannot be empty").error_response());
     }
 
-    // Validate file extension
-    if !request.filename.to_lowercase().ends_with(".md") && !request.filename.to_lowercase().ends_with(".markdown") {
-        return Ok(ServiceError::validation("Only markdown files (.md, .markdown) are supported").error_response());
+    // Validate file type; PDFs are binary and must be sent as multipart
+    match DocumentFormat::detect(&request.filename, request.content.as_bytes()) {
+        | None => return Ok(unsupported_format_error().error_response()),
+        | Some(DocumentFormat::Pdf) => {
+            return Ok(ServiceError::validation("PDF files must be uploaded as multipart form data").error_response());
+        },
+        | Some(_) => {},
     }
 
     // Check content size
@@ -213,14 +239,22 @@ pub async fn upload_json_handler(
     // Create document service with dependencies
     let document_service = create_document_service(&config, &azure_client).await?;
 
+    if let Some(response) = unchanged_upload_response(&document_service, &request.content, &request.filename, start_time).await {
+        return Ok(response);
+    }
+
     // Process the document
     match document_service.process_document(request.content.clone(), request.filename.clone()).await {
         | Ok(document_id) => {
             let processing_time = start_time.elapsed().as_millis() as u64;
 
-            // Get chunk count for response
+            // Get chunk count for response and add the chunks to the shared
+            // keyword index; it loads from Qdrant only once, on first search
             let chunks_created = match document_service.get_document_chunks(document_id.clone()).await {
-                | Ok(chunks) => chunks.len(),
+                | Ok(chunks) => {
+                    keyword_index.insert(&chunks).await;
+                    chunks.len()
+                },
                 | Err(e) => {
                     warn!("Failed to get chunk count for document {}: {}", document_id, e);
                     0
@@ -244,6 +278,30 @@ pub async fn upload_json_handler(
     }
 }
 
+/// Returns an `Unchanged` response when a document with the same filename and
+/// content is already indexed, so re-uploads do not create duplicates
+async fn unchanged_upload_response(document_service: &DocumentServiceImpl, content: &str, filename: &str, start_time: Instant) -> Option<HttpResponse> {
+    let existing = match document_service.find_unchanged_document(content, filename).await {
+        | Ok(existing) => existing?,
+        | Err(e) => {
+            // The lookup only avoids duplicates; ingest the upload anyway
+            warn!("Failed to look up existing documents for {}: {}", filename, e);
+            return None;
+        },
+    };
+
+    info!("Document {} already contains {}, skipping upload", existing.document_id, filename);
+    let processing_time = start_time.elapsed().as_millis() as u64;
+    let response = UploadResponse::unchanged(existing.document_id, filename.to_string(), existing.chunk_count, processing_time);
+    Some(HttpResponse::Ok().json(response))
+}
+
+/// Validation error listing the supported file extensions
+fn unsupported_format_error() -> ServiceError {
+    let extensions: Vec<String> = DocumentFormat::SUPPORTED_EXTENSIONS.iter().map(|ext| format!(".{}", ext)).collect();
+    ServiceError::validation(format!("Unsupported file type. Supported types: {}", extensions.join(", ")))
+}
+
 /// Helper function to create document service with all dependencies
 async fn create_document_service(config: &AppConfig, azure_client: &AzureOpenAIClient) -> Result<DocumentServiceImpl, ServiceError> {
     // Qdrant 리포지토리 생성 및 컬렉션 초기화 보장
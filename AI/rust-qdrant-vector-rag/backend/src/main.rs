@@ -3,7 +3,7 @@ use actix_web::middleware::{Compress, DefaultHeaders, Logger};
 use actix_web::{App, HttpResponse, HttpServer, web, http::header};
 use backend::app::{AppContainer, ShutdownHandler};
 use backend::config::AppConfig;
-use backend::handlers::{benchmark_handler, cache_stats_handler, clear_cache_handler, health_handler, health_with_performance_handler, metrics_handler, prometheus_metrics_handler, query_handler, simple_health_handler, simple_query_handler, upload_handler, upload_json_handler};
+use backend::handlers::{benchmark_handler, cache_stats_handler, clear_cache_handler, delete_document_handler, get_document_handler, health_handler, health_with_performance_handler, list_documents_handler, metrics_handler, prometheus_metrics_handler, query_handler, query_stream_handler, reindex_document_handler, simple_health_handler, simple_query_handler, upload_handler, upload_json_handler};
 use backend::middleware::{ErrorHandlerMiddleware, RequestLoggerMiddleware};
 use backend::monitoring::{PerformanceMonitor, init_metrics};
 use backend::services::cache::CacheManager;
@@ -89,6 +89,7 @@ async fn main() -> std::io::Result<()> {
     let rag_service_data = web::Data::new(container.rag_service.clone());
     let embedding_service_data = web::Data::new(container.embedding_service.clone());
     let vector_search_service_data = web::Data::new(container.vector_search_service.clone());
+    let keyword_index_data = web::Data::new(container.keyword_index.clone());
     let performance_monitor_data = web::Data::new(performance_monitor);
     let cache_manager_data = web::Data::new(cache_manager);
 
@@ -109,6 +110,7 @@ async fn main() -> std::io::Result<()> {
             .app_data(rag_service_data.clone())
             .app_data(embedding_service_data.clone())
             .app_data(vector_search_service_data.clone())
+            .app_data(keyword_index_data.clone())
             .app_data(performance_monitor_data.clone())
             .app_data(cache_manager_data.clone())
             
@@ -152,7 +154,12 @@ async fn main() -> std::io::Result<()> {
                     .route("/health/performance", web::get().to(health_with_performance_handler))
                     .route("/upload", web::post().to(upload_handler))
                     .route("/upload/json", web::post().to(upload_json_handler))
+                    .route("/documents", web::get().to(list_documents_handler))
+                    .route("/documents/{document_id}", web::get().to(get_document_handler))
+                    .route("/documents/{document_id}", web::put().to(reindex_document_handler))
+                    .route("/documents/{document_id}", web::delete().to(delete_document_handler))
                     .route("/query", web::post().to(query_handler))
+                    .route("/query/stream", web::post().to(query_stream_handler))
                     .route("/query/{question}", web::get().to(simple_query_handler))
                     .route("/metrics", web::get().to(metrics_handler))
                     .route("/metrics/prometheus", web::get().to(prometheus_metrics_handler))
@@ -173,6 +180,7 @@ async fn main() -> std::io::Result<()> {
             .route("/upload", web::post().to(upload_handler))
             .route("/upload/json", web::post().to(upload_json_handler))
             .route("/query", web::post().to(query_handler))
+            .route("/query/stream", web::post().to(query_stream_handler))
             .route("/query/{question}", web::get().to(simple_query_handler))
             .default_service(web::route().to(not_found_handler))
     })
@@ -227,8 +235,14 @@ async fn not_found_handler() -> HttpResponse {
             "POST /api/v1/upload",
             "POST /upload/json",
             "POST /api/v1/upload/json",
+            "GET /api/v1/documents",
+            "GET /api/v1/documents/{document_id}",
+            "PUT /api/v1/documents/{document_id}",
+            "DELETE /api/v1/documents/{document_id}",
             "POST /query",
             "POST /api/v1/query",
+            "POST /query/stream",
+            "POST /api/v1/query/stream",
             "GET /query/{question}",
             "GET /api/v1/query/{question}"
         ]
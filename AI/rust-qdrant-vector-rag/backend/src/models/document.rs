@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// Represents a chunk of a document with its content and metadata
@@ -50,6 +51,10 @@ pub struct ChunkMetadata {
     pub start_position: Option<usize>,
     pub end_position: Option<usize>,
     pub parent_section: Option<String>,
+    /// SHA-256 of the whole source document, used to skip unchanged
+    /// re-uploads
+    #[serde(default)]
+    pub content_hash: Option<String>,
 }
 
 impl ChunkMetadata {
@@ -63,6 +68,7 @@ impl ChunkMetadata {
             start_position: None,
             end_position: None,
             parent_section: None,
+            content_hash: None,
         }
     }
 
@@ -85,10 +91,16 @@ impl ChunkMetadata {
         self.parent_section = Some(parent);
         self
     }
+
+    /// Sets the hash of the source document
+    pub fn with_content_hash(mut self, content_hash: String) -> Self {
+        self.content_hash = Some(content_hash);
+        self
+    }
 }
 
 /// Types of content chunks that can be extracted from markdown
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
 pub enum ChunkType {
     Text,
     CodeBlock,
@@ -107,6 +119,13 @@ impl Default for ChunkType {
 pub struct SearchResult {
     pub chunk: DocumentChunk,
     pub relevance_score: f32,
+    /// Score assigned by a reranker, if one reordered the results
+    #[serde(default)]
+    pub rerank_score: Option<f32>,
+    /// Best vector similarity or keyword relevance behind a fused result,
+    /// whose `relevance_score` only reflects its ranks
+    #[serde(default)]
+    pub match_score: Option<f32>,
 }
 
 impl SearchResult {
@@ -115,8 +134,26 @@ impl SearchResult {
         Self {
             chunk,
             relevance_score,
+            rerank_score: None,
+            match_score: None,
         }
     }
+
+    /// Similarity-like score of the match itself, in [0, 1]
+    pub fn match_score(&self) -> f32 { self.match_score.unwrap_or(self.relevance_score) }
+}
+
+/// Retrieval strategy used to collect context chunks for a question
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchMode {
+    /// Dense embedding similarity only
+    #[default]
+    Vector,
+    /// BM25 keyword matching only
+    Keyword,
+    /// Vector and keyword rankings fused with reciprocal rank fusion
+    Hybrid,
 }
 
 /// Represents a document identifier
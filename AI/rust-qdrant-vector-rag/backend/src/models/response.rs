@@ -1,3 +1,4 @@
+use crate::models::{ChunkType, DocumentChunk};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -52,6 +53,9 @@ pub struct SourceReference {
     pub source_file: String,
     pub chunk_index: usize,
     pub headers: Vec<String>,
+    /// Reranker score (0.0 to 1.0) when the context was reranked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rerank_score: Option<f32>,
 }
 
 impl SourceReference {
@@ -65,6 +69,7 @@ impl SourceReference {
             source_file,
             chunk_index,
             headers: Vec::new(),
+            rerank_score: None,
         }
     }
 
@@ -74,6 +79,12 @@ impl SourceReference {
         self
     }
 
+    /// Sets the reranker score for the source reference
+    pub fn with_rerank_score(mut self, rerank_score: Option<f32>) -> Self {
+        self.rerank_score = rerank_score;
+        self
+    }
+
     /// Returns a truncated snippet if it exceeds the given length
     #[allow(dead_code)]
     pub fn truncated_snippet(&self, max_length: usize) -> String {
@@ -85,6 +96,57 @@ impl SourceReference {
     }
 }
 
+/// Event sent by the streaming query endpoint
+///
+/// Events arrive in order: one `sources` event, any number of `token`
+/// events, then either `done` or `error`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RAGStreamEvent {
+    /// Chunks retrieved as context for the answer
+    Sources { sources: Vec<SourceReference> },
+    /// Next piece of the generated answer
+    Token { content: String },
+    /// Final metadata once the answer is complete
+    Done {
+        confidence: f32,
+        /// True when confidence is below the configured threshold; unlike
+        /// the non-streaming endpoint the answer has already been sent
+        low_confidence: bool,
+        query: String,
+        source_count: usize,
+        response_time_ms: u64,
+    },
+    /// Generation failed after streaming started
+    Error { message: String },
+}
+
+impl RAGStreamEvent {
+    /// SSE event name
+    pub fn event_name(&self) -> &'static str {
+        match self {
+            | RAGStreamEvent::Sources {
+                ..
+            } => "sources",
+            | RAGStreamEvent::Token {
+                ..
+            } => "token",
+            | RAGStreamEvent::Done {
+                ..
+            } => "done",
+            | RAGStreamEvent::Error {
+                ..
+            } => "error",
+        }
+    }
+
+    /// Formats the event as a Server-Sent Events frame
+    pub fn to_sse(&self) -> String {
+        let data = serde_json::to_string(self).unwrap_or_else(|e| format!("{{\"type\":\"error\",\"message\":\"{}\"}}", e));
+        format!("event: {}\ndata: {}\n\n", self.event_name(), data)
+    }
+}
+
 /// Response for document upload operations
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UploadResponse {
@@ -111,6 +173,19 @@ impl UploadResponse {
         }
     }
 
+    /// Creates a response for a re-upload whose content did not change
+    pub fn unchanged(document_id: String, filename: String, chunk_count: usize, processing_time_ms: u64) -> Self {
+        Self {
+            document_id,
+            filename,
+            chunks_created: chunk_count,
+            processing_time_ms,
+            status: UploadStatus::Unchanged,
+            message: "Document content unchanged, re-indexing skipped".to_string(),
+            timestamp: Utc::now(),
+        }
+    }
+
     /// Creates a failed upload response
     pub fn failure(filename: String, error_message: String) -> Self {
         Self {
@@ -131,6 +206,87 @@ pub enum UploadStatus {
     Success,
     Failed,
     Processing,
+    /// Content hash matched the stored document, so nothing was re-indexed
+    Unchanged,
+}
+
+/// Summary of an ingested document
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, ToSchema)]
+pub struct DocumentSummary {
+    pub document_id: String,
+    pub filename: String,
+    pub chunk_count: usize,
+    /// Time the current version of the document was indexed
+    pub uploaded_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+}
+
+/// Response listing ingested documents
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DocumentListResponse {
+    pub documents: Vec<DocumentSummary>,
+    pub total: usize,
+}
+
+impl DocumentListResponse {
+    /// Creates a document list response
+    pub fn new(documents: Vec<DocumentSummary>) -> Self {
+        Self {
+            total: documents.len(),
+            documents,
+        }
+    }
+}
+
+/// A stored chunk of a document, without its embedding
+#[derive(Debug, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct DocumentChunkResponse {
+    pub id: String,
+    pub chunk_index: usize,
+    pub content: String,
+    pub chunk_type: ChunkType,
+    pub headers: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<DocumentChunk> for DocumentChunkResponse {
+    fn from(chunk: DocumentChunk) -> Self {
+        Self {
+            id: chunk.id,
+            chunk_index: chunk.metadata.chunk_index,
+            content: chunk.content,
+            chunk_type: chunk.metadata.chunk_type,
+            headers: chunk.metadata.headers,
+            created_at: chunk.created_at,
+        }
+    }
+}
+
+/// Response with a document and its chunks in document order
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DocumentDetailResponse {
+    pub document: DocumentSummary,
+    pub chunks: Vec<DocumentChunkResponse>,
+}
+
+/// Response for document deletion
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeleteDocumentResponse {
+    pub document_id: String,
+    pub chunks_deleted: usize,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl DeleteDocumentResponse {
+    /// Creates a document deletion response
+    pub fn new(document_id: String, chunks_deleted: usize) -> Self {
+        Self {
+            document_id,
+            chunks_deleted,
+            timestamp: Utc::now(),
+        }
+    }
 }
 
 /// Health check response
@@ -231,6 +231,30 @@ mod tests {
         assert_eq!(response.message, "Invalid file format");
     }
 
+    #[test]
+    fn test_upload_response_unchanged() {
+        let response = UploadResponse::unchanged("doc123".to_string(), "test.md".to_string(), 4, 12);
+
+        assert_eq!(response.document_id, "doc123");
+        assert_eq!(response.chunks_created, 4);
+        assert_eq!(response.status, UploadStatus::Unchanged);
+    }
+
+    #[test]
+    fn test_document_chunk_response_omits_embedding() {
+        let metadata = ChunkMetadata::new("test.md".to_string(), 2, ChunkType::CodeBlock).with_headers(vec!["Intro".to_string()]);
+        let chunk = DocumentChunk::new("doc123".to_string(), "fn main() {}".to_string(), metadata).with_embedding(vec![0.1; 4]);
+        let chunk_id = chunk.id.clone();
+
+        let response = DocumentChunkResponse::from(chunk);
+
+        assert_eq!(response.id, chunk_id);
+        assert_eq!(response.chunk_index, 2);
+        assert_eq!(response.chunk_type, ChunkType::CodeBlock);
+        assert_eq!(response.headers, vec!["Intro".to_string()]);
+        assert!(!serde_json::to_string(&response).unwrap().contains("embedding"));
+    }
+
     #[test]
     fn test_health_response() {
         let services = ServiceHealthStatus::new(true, false);
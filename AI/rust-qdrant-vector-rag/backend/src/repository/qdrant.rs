@@ -2,13 +2,19 @@ use crate::config::QdrantConfig;
 use crate::models::{DocumentChunk, SearchResult, ServiceError};
 use async_trait::async_trait;
 use qdrant_client::Qdrant;
-use qdrant_client::qdrant::{CollectionInfo, Condition, CreateCollection, Distance, FieldCondition, Filter, Match, PointStruct, PointsIdsList, PointsSelector, ScoredPoint, Value, VectorParams, VectorsConfig};
+use qdrant_client::qdrant::{
+    CollectionInfo, Condition, CreateCollection, Distance, FieldCondition, Filter, Match, PointId, PointStruct, PointsIdsList, PointsSelector, ScoredPoint, ScrollPointsBuilder,
+    Value, VectorParams, VectorsConfig, VectorsOutput,
+};
 use std::collections::HashMap;
 use std::time::Duration;
 use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 // Metrics integration will be added when needed
 
+/// Number of points fetched per scroll request when listing the collection
+const SCROLL_PAGE_SIZE: u32 = 256;
+
 /// Repository trait for vector database operations
 #[async_trait]
 pub trait VectorRepository: Send + Sync {
@@ -24,17 +30,26 @@ pub trait VectorRepository: Send + Sync {
     /// Search for similar chunks using vector similarity
     async fn search_similar(&self, query_embedding: Vec<f32>, limit: usize, score_threshold: Option<f32>) -> Result<Vec<SearchResult>, ServiceError>;
 
+    /// List every stored chunk without its embedding, paging through the
+    /// whole collection
+    async fn list_chunks(&self) -> Result<Vec<DocumentChunk>, ServiceError>;
+
     /// Search for chunks by document ID
     async fn get_chunks_by_document_id(&self, document_id: &str) -> Result<Vec<DocumentChunk>, ServiceError>;
 
     /// Delete chunks by document ID
-    #[allow(dead_code)]
     async fn delete_chunks_by_document_id(&self, document_id: &str) -> Result<(), ServiceError>;
 
     /// Delete a specific chunk by ID
-    #[allow(dead_code)]
     async fn delete_chunk(&self, chunk_id: &str) -> Result<(), ServiceError>;
 
+    /// Delete several chunks by ID in a single request
+    async fn delete_chunks(&self, chunk_ids: &[String]) -> Result<(), ServiceError>;
+
+    /// Find one chunk stored from the given source file with the given
+    /// content hash, without its embedding
+    async fn find_chunk_by_content_hash(&self, source_file: &str, content_hash: &str) -> Result<Option<DocumentChunk>, ServiceError>;
+
     /// Get collection statistics
     async fn get_collection_info(&self) -> Result<CollectionInfo, ServiceError>;
 
@@ -156,6 +171,10 @@ impl QdrantRepository {
             payload.insert("end_position".to_string(), Value::from(end_pos as i64));
         }
 
+        if let Some(content_hash) = &chunk.metadata.content_hash {
+            payload.insert("content_hash".to_string(), Value::from(content_hash.clone()));
+        }
+
         Ok(PointStruct::new(chunk.id.clone(), embedding.clone(), payload))
     }
 
@@ -166,8 +185,10 @@ impl QdrantRepository {
     }
 
     /// Convert ScoredPoint to DocumentChunk
-    fn point_to_chunk(&self, point: ScoredPoint) -> Result<DocumentChunk, ServiceError> {
-        let payload = point.payload;
+    fn point_to_chunk(&self, point: ScoredPoint) -> Result<DocumentChunk, ServiceError> { self.payload_to_chunk(point.id, point.payload, point.vectors) }
+
+    /// Convert the id, payload and vectors of a Qdrant point to DocumentChunk
+    fn payload_to_chunk(&self, id: Option<PointId>, payload: HashMap<String, Value>, vectors: Option<VectorsOutput>) -> Result<DocumentChunk, ServiceError> {
 
         let document_id = payload
             .get("document_id")
@@ -228,21 +249,25 @@ impl QdrantRepository {
 
         let end_position = payload.get("end_position").and_then(|v| v.as_integer()).map(|i| i as usize);
 
+        let content_hash = payload.get("content_hash").and_then(|v| v.as_str()).map(|s| s.to_string());
+
         let mut metadata = crate::models::ChunkMetadata::new(source_file, chunk_index, chunk_type);
         metadata.headers = headers;
         metadata.parent_section = parent_section;
         metadata.start_position = start_position;
         metadata.end_position = end_position;
+        metadata.content_hash = content_hash;
 
         // Get embedding from vectors
-        let embedding = point.vectors.and_then(|vectors| vectors.vectors_options).and_then(|options| match options {
+        let embedding = vectors.and_then(|vectors| vectors.vectors_options).and_then(|options| match options {
             | qdrant_client::qdrant::vectors_output::VectorsOptions::Vector(vector) => Some(vector.data),
             | _ => None,
         });
 
-        let chunk_id = match point.id.unwrap().point_id_options.unwrap() {
-            | qdrant_client::qdrant::point_id::PointIdOptions::Uuid(uuid) => uuid,
-            | qdrant_client::qdrant::point_id::PointIdOptions::Num(num) => num.to_string(),
+        let chunk_id = match id.and_then(|id| id.point_id_options) {
+            | Some(qdrant_client::qdrant::point_id::PointIdOptions::Uuid(uuid)) => uuid,
+            | Some(qdrant_client::qdrant::point_id::PointIdOptions::Num(num)) => num.to_string(),
+            | None => return Err(ServiceError::database("Missing point id")),
         };
 
         let chunk = DocumentChunk {
@@ -413,6 +438,44 @@ impl VectorRepository for QdrantRepository {
         .await
     }
 
+    async fn list_chunks(&self) -> Result<Vec<DocumentChunk>, ServiceError> {
+        debug!("Listing all chunks in collection: {}", self.config.collection_name);
+
+        self.retry_operation(|| async {
+            let mut chunks = Vec::new();
+            let mut offset: Option<PointId> = None;
+
+            loop {
+                let mut request = ScrollPointsBuilder::new(self.config.collection_name.clone())
+                    .limit(SCROLL_PAGE_SIZE)
+                    .with_payload(true)
+                    .with_vectors(false);
+                if let Some(offset) = offset.take() {
+                    request = request.offset(offset);
+                }
+
+                let response = self
+                    .client
+                    .scroll(request)
+                    .await
+                    .map_err(|e| ServiceError::database(format!("Failed to scroll points: {}", e)))?;
+
+                for point in response.result {
+                    chunks.push(self.payload_to_chunk(point.id, point.payload, point.vectors)?);
+                }
+
+                match response.next_page_offset {
+                    | Some(next) => offset = Some(next),
+                    | None => break,
+                }
+            }
+
+            debug!("Listed {} chunks", chunks.len());
+            Ok(chunks)
+        })
+        .await
+    }
+
     async fn get_chunks_by_document_id(&self, document_id: &str) -> Result<Vec<DocumentChunk>, ServiceError> {
         debug!("Getting chunks for document ID: {}", document_id);
 
@@ -560,6 +623,76 @@ impl VectorRepository for QdrantRepository {
         .await
     }
 
+    async fn delete_chunks(&self, chunk_ids: &[String]) -> Result<(), ServiceError> {
+        if chunk_ids.is_empty() {
+            return Ok(());
+        }
+        info!("Deleting {} chunks", chunk_ids.len());
+
+        self.retry_operation(|| async {
+            let selector = PointsSelector {
+                points_selector_one_of: Some(qdrant_client::qdrant::points_selector::PointsSelectorOneOf::Points(PointsIdsList {
+                    ids: chunk_ids.iter().map(|id| id.as_str().into()).collect(),
+                })),
+            };
+
+            let delete_request = qdrant_client::qdrant::DeletePoints {
+                collection_name: self.config.collection_name.clone(),
+                wait: Some(true),
+                points: Some(selector),
+                ordering: None,
+                shard_key_selector: None,
+            };
+
+            let response = self
+                .client
+                .delete_points(delete_request)
+                .await
+                .map_err(|e| ServiceError::database(format!("Failed to delete points: {}", e)))?;
+
+            if response.result.is_none() {
+                return Err(ServiceError::database("Delete operation failed: no result returned".to_string()));
+            }
+
+            info!("Successfully deleted {} chunks", chunk_ids.len());
+            Ok(())
+        })
+        .await
+    }
+
+    async fn find_chunk_by_content_hash(&self, source_file: &str, content_hash: &str) -> Result<Option<DocumentChunk>, ServiceError> {
+        debug!("Looking up a chunk of '{}' with content hash {}", source_file, content_hash);
+
+        self.retry_operation(|| async {
+            let filter = Filter {
+                should: vec![],
+                must: vec![keyword_condition("source_file", source_file), keyword_condition("content_hash", content_hash)],
+                must_not: vec![],
+                min_should: None,
+            };
+
+            let request = ScrollPointsBuilder::new(self.config.collection_name.clone())
+                .filter(filter)
+                .limit(1)
+                .with_payload(true)
+                .with_vectors(false);
+
+            let response = self
+                .client
+                .scroll(request)
+                .await
+                .map_err(|e| ServiceError::database(format!("Failed to scroll points: {}", e)))?;
+
+            response
+                .result
+                .into_iter()
+                .next()
+                .map(|point| self.payload_to_chunk(point.id, point.payload, point.vectors))
+                .transpose()
+        })
+        .await
+    }
+
     async fn get_collection_info(&self) -> Result<CollectionInfo, ServiceError> {
         self.retry_operation(|| async {
             let response = self
@@ -584,6 +717,26 @@ impl VectorRepository for QdrantRepository {
     }
 }
 
+/// Filter condition matching a keyword payload field exactly
+fn keyword_condition(key: &str, value: &str) -> Condition {
+    Condition {
+        condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(FieldCondition {
+            key: key.to_string(),
+            r#match: Some(Match {
+                match_value: Some(qdrant_client::qdrant::r#match::MatchValue::Keyword(value.to_string())),
+            }),
+            range: None,
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            values_count: None,
+            is_empty: None,
+            is_null: None,
+            datetime_range: None,
+        })),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
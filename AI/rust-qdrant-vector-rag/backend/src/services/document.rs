@@ -1,17 +1,68 @@
-use crate::models::{DocumentChunk, ServiceError};
+use crate::models::{DocumentChunk, DocumentSummary, ServiceError};
 use crate::repository::VectorRepository;
-use crate::services::{ChunkingConfig, DocumentChunker, DocumentParser, EmbeddingService};
+use crate::services::{ChunkingConfig, DocumentChunker, DocumentFormat, DocumentParser, EmbeddingService};
 use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 pub type DocumentId = String;
 
+/// Result of re-indexing a document in place
+#[derive(Debug)]
+pub enum ReindexOutcome {
+    /// Content hash and filename matched the stored document; nothing was
+    /// re-embedded
+    Unchanged { filename: String, chunk_count: usize },
+    /// Document was re-chunked and re-embedded under the same ID
+    Reindexed { filename: String, chunks: Vec<DocumentChunk> },
+}
+
 #[async_trait]
 pub trait DocumentService: Send + Sync {
     async fn process_document(&self, content: String, filename: String) -> Result<DocumentId, ServiceError>;
     async fn get_document_chunks(&self, doc_id: DocumentId) -> Result<Vec<DocumentChunk>, ServiceError>;
+
+    /// Lists ingested documents, most recently indexed first
+    async fn list_documents(&self) -> Result<Vec<DocumentSummary>, ServiceError>;
+
+    /// Finds an ingested document with the same filename and content hash, so
+    /// a repeated upload can be skipped instead of duplicated
+    async fn find_unchanged_document(&self, content: &str, filename: &str) -> Result<Option<DocumentSummary>, ServiceError>;
+
+    /// Deletes every chunk of a document and returns how many were removed
+    async fn delete_document(&self, doc_id: DocumentId) -> Result<usize, ServiceError>;
+
+    /// Replaces a document's chunks with ones built from new content, keeping
+    /// its ID; skipped when the content hash is unchanged
+    async fn reindex_document(&self, doc_id: DocumentId, content: String, filename: Option<String>) -> Result<ReindexOutcome, ServiceError>;
+}
+
+/// Hex-encoded SHA-256 of document content
+pub fn content_hash(content: &str) -> String { format!("{:x}", Sha256::digest(content.as_bytes())) }
+
+/// Groups chunks into per-document summaries, most recently indexed first
+pub fn summarize_documents(chunks: Vec<DocumentChunk>) -> Vec<DocumentSummary> {
+    let mut documents: HashMap<String, DocumentSummary> = HashMap::new();
+
+    for chunk in chunks {
+        let summary = documents.entry(chunk.document_id.clone()).or_insert_with(|| DocumentSummary {
+            document_id: chunk.document_id.clone(),
+            filename: chunk.metadata.source_file.clone(),
+            chunk_count: 0,
+            uploaded_at: chunk.created_at,
+            content_hash: chunk.metadata.content_hash.clone(),
+        });
+
+        summary.chunk_count += 1;
+        summary.uploaded_at = summary.uploaded_at.min(chunk.created_at);
+    }
+
+    let mut documents: Vec<DocumentSummary> = documents.into_values().collect();
+    documents.sort_by(|a, b| b.uploaded_at.cmp(&a.uploaded_at).then_with(|| a.document_id.cmp(&b.document_id)));
+    documents
 }
 
 pub struct DocumentServiceImpl {
@@ -54,9 +105,9 @@ impl DocumentServiceImpl {
             return Err(ServiceError::validation("Filename cannot be empty"));
         }
 
-        // Check if filename has markdown extension
-        if !filename.to_lowercase().ends_with(".md") && !filename.to_lowercase().ends_with(".markdown") {
-            warn!("File '{}' does not have a markdown extension, processing anyway", filename);
+        // Unknown formats are parsed as markdown
+        if DocumentFormat::detect(filename, content.as_bytes()).is_none() {
+            warn!("File '{}' does not have a supported extension, processing as markdown", filename);
         }
 
         // Check content size (reasonable limit to prevent memory issues)
@@ -72,6 +123,44 @@ impl DocumentServiceImpl {
         Ok(())
     }
 
+    /// Parses, chunks and embeds content for the given document ID
+    ///
+    /// Every chunk records the hash of the whole content so later re-uploads
+    /// can be skipped when nothing changed.
+    async fn build_chunks(&self, content: &str, document_id: &str, filename: &str) -> Result<Vec<DocumentChunk>, ServiceError> {
+        // Step 1: Parse the content in its source format
+        debug!("Parsing document content");
+        let _parsed_elements = self
+            .parser
+            .parse(content, filename.to_string())
+            .map_err(|e| ServiceError::document_processing(format!("Failed to parse document: {}", e)))?;
+
+        // Step 2: Chunk the document
+        debug!("Chunking document into optimal sizes");
+        let content_hash = content_hash(content);
+        let chunks: Vec<DocumentChunk> = self
+            .chunker
+            .chunk_document(content, document_id.to_string(), filename.to_string())
+            .map_err(|e| ServiceError::document_processing(format!("Failed to chunk document: {}", e)))?
+            .into_iter()
+            .map(|mut chunk| {
+                chunk.metadata = chunk.metadata.with_content_hash(content_hash.clone());
+                chunk
+            })
+            .collect();
+
+        if chunks.is_empty() {
+            warn!("Document '{}' produced no chunks after processing", filename);
+            return Ok(chunks);
+        }
+
+        info!("Created {} chunks from document '{}'", chunks.len(), filename);
+
+        // Step 3: Generate embeddings for chunks in batches
+        const EMBEDDING_BATCH_SIZE: usize = 10; // Process 10 chunks at a time
+        self.process_chunks_in_batches(chunks, EMBEDDING_BATCH_SIZE).await
+    }
+
     /// Processes chunks in batches to avoid overwhelming the embedding service
     async fn process_chunks_in_batches(&self, mut chunks: Vec<DocumentChunk>, batch_size: usize) -> Result<Vec<DocumentChunk>, ServiceError> {
         if chunks.is_empty() {
@@ -133,31 +222,12 @@ impl DocumentService for DocumentServiceImpl {
         let document_id = Uuid::new_v4().to_string();
         debug!("Generated document ID: {}", document_id);
 
-        // Step 1: Parse the markdown content
-        debug!("Parsing markdown content");
-        let _parsed_elements = self
-            .parser
-            .parse(&content, filename.clone())
-            .map_err(|e| ServiceError::document_processing(format!("Failed to parse document: {}", e)))?;
-
-        // Step 2: Chunk the document
-        debug!("Chunking document into optimal sizes");
-        let chunks = self
-            .chunker
-            .chunk_document(&content, document_id.clone(), filename.clone())
-            .map_err(|e| ServiceError::document_processing(format!("Failed to chunk document: {}", e)))?;
-
-        if chunks.is_empty() {
-            warn!("Document '{}' produced no chunks after processing", filename);
+        // Steps 1-3: Parse, chunk and embed the content
+        let chunks_with_embeddings = self.build_chunks(&content, &document_id, &filename).await?;
+        if chunks_with_embeddings.is_empty() {
             return Ok(document_id);
         }
 
-        info!("Created {} chunks from document '{}'", chunks.len(), filename);
-
-        // Step 3: Generate embeddings for chunks in batches
-        const EMBEDDING_BATCH_SIZE: usize = 10; // Process 10 chunks at a time
-        let chunks_with_embeddings = self.process_chunks_in_batches(chunks, EMBEDDING_BATCH_SIZE).await?;
-
         // Step 4: Store chunks in vector database
         debug!("Storing {} chunks in vector database", chunks_with_embeddings.len());
         self.vector_repository
@@ -185,6 +255,96 @@ impl DocumentService for DocumentServiceImpl {
         info!("Retrieved {} chunks for document ID: {}", chunks.len(), doc_id);
         Ok(chunks)
     }
+
+    async fn list_documents(&self) -> Result<Vec<DocumentSummary>, ServiceError> {
+        let chunks = self
+            .vector_repository
+            .list_chunks()
+            .await
+            .map_err(|e| ServiceError::document_processing(format!("Failed to list chunks: {}", e)))?;
+
+        let documents = summarize_documents(chunks);
+        debug!("Listed {} documents", documents.len());
+        Ok(documents)
+    }
+
+    async fn find_unchanged_document(&self, content: &str, filename: &str) -> Result<Option<DocumentSummary>, ServiceError> {
+        let hash = content_hash(content);
+        let Some(chunk) = self
+            .vector_repository
+            .find_chunk_by_content_hash(filename, &hash)
+            .await
+            .map_err(|e| ServiceError::document_processing(format!("Failed to look up document by content hash: {}", e)))?
+        else {
+            return Ok(None);
+        };
+
+        let chunks = self.get_document_chunks(chunk.document_id).await?;
+        Ok(summarize_documents(chunks).into_iter().next())
+    }
+
+    async fn delete_document(&self, doc_id: DocumentId) -> Result<usize, ServiceError> {
+        let chunks = self.get_document_chunks(doc_id.clone()).await?;
+        if chunks.is_empty() {
+            return Err(ServiceError::not_found(format!("Document '{}' does not exist", doc_id)));
+        }
+
+        self.vector_repository
+            .delete_chunks_by_document_id(&doc_id)
+            .await
+            .map_err(|e| ServiceError::document_processing(format!("Failed to delete chunks: {}", e)))?;
+
+        info!("Deleted document {} ({} chunks)", doc_id, chunks.len());
+        Ok(chunks.len())
+    }
+
+    async fn reindex_document(&self, doc_id: DocumentId, content: String, filename: Option<String>) -> Result<ReindexOutcome, ServiceError> {
+        let existing_chunks = self.get_document_chunks(doc_id.clone()).await?;
+        let Some(first_chunk) = existing_chunks.first() else {
+            return Err(ServiceError::not_found(format!("Document '{}' does not exist", doc_id)));
+        };
+
+        let filename = filename.unwrap_or_else(|| first_chunk.metadata.source_file.clone());
+        self.validate_input(&content, &filename)?;
+
+        let new_hash = content_hash(&content);
+        let unchanged = existing_chunks
+            .iter()
+            .all(|chunk| chunk.metadata.content_hash.as_deref() == Some(new_hash.as_str()) && chunk.metadata.source_file == filename);
+        if unchanged {
+            info!("Document {} is unchanged, skipping re-index", doc_id);
+            return Ok(ReindexOutcome::Unchanged {
+                filename,
+                chunk_count: existing_chunks.len(),
+            });
+        }
+
+        info!("Re-indexing document {} ({} existing chunks)", doc_id, existing_chunks.len());
+        let chunks = self.build_chunks(&content, &doc_id, &filename).await?;
+        if chunks.is_empty() {
+            return Err(ServiceError::validation("New content produced no chunks; the document was left unchanged"));
+        }
+
+        // Store the new chunks before removing the old ones so a failure
+        // never leaves the document without chunks, then remove all old
+        // chunks in one request so none of them can be left behind
+        self.vector_repository
+            .store_chunks(chunks.clone())
+            .await
+            .map_err(|e| ServiceError::document_processing(format!("Failed to store chunks: {}", e)))?;
+
+        let outdated_ids: Vec<String> = existing_chunks.iter().map(|chunk| chunk.id.clone()).collect();
+        self.vector_repository
+            .delete_chunks(&outdated_ids)
+            .await
+            .map_err(|e| ServiceError::document_processing(format!("Failed to delete outdated chunks: {}", e)))?;
+
+        info!("Re-indexed document {} with {} chunks", doc_id, chunks.len());
+        Ok(ReindexOutcome::Reindexed {
+            filename,
+            chunks,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -250,6 +410,7 @@ mod tests {
     struct MockVectorRepository {
         stored_chunks: Arc<Mutex<HashMap<String, Vec<DocumentChunk>>>>,
         call_count: Arc<Mutex<usize>>,
+        scan_count: Arc<Mutex<usize>>,
     }
 
     impl MockVectorRepository {
@@ -257,6 +418,7 @@ mod tests {
             Self {
                 stored_chunks: Arc::new(Mutex::new(HashMap::new())),
                 call_count: Arc::new(Mutex::new(0)),
+                scan_count: Arc::new(Mutex::new(0)),
             }
         }
 
@@ -289,14 +451,48 @@ mod tests {
             Ok(Vec::new())
         }
 
+        async fn list_chunks(&self) -> Result<Vec<DocumentChunk>, ServiceError> {
+            *self.scan_count.lock().await += 1;
+            let stored = self.stored_chunks.lock().await;
+            Ok(stored.values().flatten().cloned().collect())
+        }
+
         async fn get_chunks_by_document_id(&self, document_id: &str) -> Result<Vec<DocumentChunk>, ServiceError> {
             let stored = self.stored_chunks.lock().await;
             Ok(stored.get(document_id).cloned().unwrap_or_default())
         }
 
-        async fn delete_chunks_by_document_id(&self, _document_id: &str) -> Result<(), ServiceError> { Ok(()) }
+        async fn delete_chunks_by_document_id(&self, document_id: &str) -> Result<(), ServiceError> {
+            self.stored_chunks.lock().await.remove(document_id);
+            Ok(())
+        }
 
-        async fn delete_chunk(&self, _chunk_id: &str) -> Result<(), ServiceError> { Ok(()) }
+        async fn delete_chunk(&self, chunk_id: &str) -> Result<(), ServiceError> {
+            let mut stored = self.stored_chunks.lock().await;
+            for chunks in stored.values_mut() {
+                chunks.retain(|chunk| chunk.id != chunk_id);
+            }
+            stored.retain(|_, chunks| !chunks.is_empty());
+            Ok(())
+        }
+
+        async fn delete_chunks(&self, chunk_ids: &[String]) -> Result<(), ServiceError> {
+            let mut stored = self.stored_chunks.lock().await;
+            for chunks in stored.values_mut() {
+                chunks.retain(|chunk| !chunk_ids.contains(&chunk.id));
+            }
+            stored.retain(|_, chunks| !chunks.is_empty());
+            Ok(())
+        }
+
+        async fn find_chunk_by_content_hash(&self, source_file: &str, content_hash: &str) -> Result<Option<DocumentChunk>, ServiceError> {
+            let stored = self.stored_chunks.lock().await;
+            Ok(stored
+                .values()
+                .flatten()
+                .find(|chunk| chunk.metadata.source_file == source_file && chunk.metadata.content_hash.as_deref() == Some(content_hash))
+                .cloned())
+        }
 
         async fn get_collection_info(&self) -> Result<qdrant_client::qdrant::CollectionInfo, ServiceError> {
             Err(ServiceError::internal("Not implemented in mock"))
@@ -305,6 +501,9 @@ mod tests {
         async fn health_check(&self) -> Result<bool, ServiceError> { Ok(true) }
     }
 
+    /// Markdown long enough to clear the default minimum chunk size
+    fn document_text(body: &str) -> String { format!("# Test\n\n{}", body.repeat(10)) }
+
     fn create_test_service() -> (DocumentServiceImpl, Arc<MockEmbeddingService>, Arc<MockVectorRepository>) {
         let embedding_service = Arc::new(MockEmbeddingService::new());
         let vector_repository = Arc::new(MockVectorRepository::new());
@@ -465,6 +664,23 @@ mod tests {
         assert!(result.is_ok(), "Non-markdown extension should still work");
     }
 
+    #[tokio::test]
+    async fn test_process_html_document() {
+        let (service, _, vector_repository) = create_test_service();
+
+        let content = format!(
+            "<html><body><h1>Guide</h1><h2>Setup</h2><p>{}</p></body></html>",
+            "Install the toolchain and run the server. ".repeat(5)
+        );
+
+        let document_id = service.process_document(content, "guide.html".to_string()).await.unwrap();
+
+        let chunks = vector_repository.stored_chunks.lock().await.get(&document_id).cloned().unwrap_or_default();
+        assert!(!chunks.is_empty(), "HTML document should produce chunks");
+        assert!(chunks.iter().all(|c| !c.content.contains("<p>")), "Markup should not be indexed");
+        assert_eq!(chunks[0].metadata.headers, vec!["Guide".to_string(), "Setup".to_string()]);
+    }
+
     #[tokio::test]
     async fn test_chunking_configuration() {
         let embedding_service = Arc::new(MockEmbeddingService::new());
@@ -507,4 +723,152 @@ mod tests {
         // The exact number depends on chunking, but should be reasonable
         assert!(embedding_calls < 50, "Should use batch processing to limit calls");
     }
+
+    #[tokio::test]
+    async fn test_list_documents() {
+        let (service, _, _) = create_test_service();
+
+        let first_id = service
+            .process_document(document_text("First document. "), "first.md".to_string())
+            .await
+            .unwrap();
+        let second_id = service
+            .process_document("# Second\n\n".to_string() + &"Second document content. ".repeat(100), "second.md".to_string())
+            .await
+            .unwrap();
+
+        let documents = service.list_documents().await.unwrap();
+
+        assert_eq!(documents.len(), 2);
+        for document in &documents {
+            let chunks = service.get_document_chunks(document.document_id.clone()).await.unwrap();
+            assert_eq!(document.chunk_count, chunks.len());
+            assert!(document.content_hash.is_some(), "Processed documents should record a content hash");
+        }
+
+        let second = documents.iter().find(|d| d.document_id == second_id).unwrap();
+        assert_eq!(second.filename, "second.md");
+        assert!(documents.iter().any(|d| d.document_id == first_id));
+    }
+
+    #[tokio::test]
+    async fn test_delete_document() {
+        let (service, _, vector_repository) = create_test_service();
+
+        let document_id = service
+            .process_document(document_text("Content to delete. "), "test.md".to_string())
+            .await
+            .unwrap();
+        let stored = vector_repository.get_stored_chunks_count().await;
+
+        let deleted = service.delete_document(document_id.clone()).await.unwrap();
+
+        assert_eq!(deleted, stored);
+        assert_eq!(vector_repository.get_stored_chunks_count().await, 0);
+
+        let result = service.delete_document(document_id).await;
+        assert!(matches!(result, Err(ServiceError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reindex_unchanged_document_is_skipped() {
+        let (service, embedding_service, _) = create_test_service();
+
+        let content = document_text("Unchanged content. ");
+        let document_id = service.process_document(content.clone(), "test.md".to_string()).await.unwrap();
+        let embedding_calls = embedding_service.get_call_count().await;
+
+        let outcome = service.reindex_document(document_id, content, None).await.unwrap();
+
+        assert!(matches!(outcome, ReindexOutcome::Unchanged { ref filename, chunk_count } if filename == "test.md" && chunk_count > 0));
+        assert_eq!(
+            embedding_service.get_call_count().await,
+            embedding_calls,
+            "Unchanged documents should not be re-embedded"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reindex_changed_document_in_place() {
+        let (service, _, vector_repository) = create_test_service();
+
+        let document_id = service
+            .process_document(document_text("Original content. "), "test.md".to_string())
+            .await
+            .unwrap();
+        let old_chunk_ids: Vec<String> = service
+            .get_document_chunks(document_id.clone())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+
+        let outcome = service
+            .reindex_document(document_id.clone(), document_text("Updated content. "), Some("renamed.md".to_string()))
+            .await
+            .unwrap();
+
+        let ReindexOutcome::Reindexed {
+            filename,
+            chunks,
+        } = outcome
+        else {
+            panic!("Changed content should be re-indexed");
+        };
+        assert_eq!(filename, "renamed.md");
+        assert!(chunks.iter().all(|c| c.document_id == document_id));
+
+        let stored = service.get_document_chunks(document_id).await.unwrap();
+        assert_eq!(stored.len(), vector_repository.get_stored_chunks_count().await);
+        assert!(stored.iter().all(|c| !old_chunk_ids.contains(&c.id)), "Outdated chunks should be removed");
+        assert!(stored.iter().any(|c| c.content.contains("Updated content")));
+        assert_eq!(stored[0].metadata.content_hash, Some(content_hash(&document_text("Updated content. "))));
+    }
+
+    #[tokio::test]
+    async fn test_reindex_without_chunks_keeps_document() {
+        let (service, _, vector_repository) = create_test_service();
+
+        let document_id = service
+            .process_document(document_text("Setup instructions. "), "guide.md".to_string())
+            .await
+            .unwrap();
+        let stored = vector_repository.get_stored_chunks_count().await;
+        assert!(stored > 0);
+
+        // Too short to produce a chunk
+        let result = service.reindex_document(document_id.clone(), "# Hi".to_string(), None).await;
+
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+        assert_eq!(vector_repository.get_stored_chunks_count().await, stored, "Old chunks should be kept");
+    }
+
+    #[tokio::test]
+    async fn test_find_unchanged_document() {
+        let (service, _, vector_repository) = create_test_service();
+
+        let content = document_text("Setup instructions. ");
+        let document_id = service.process_document(content.clone(), "guide.md".to_string()).await.unwrap();
+        let chunk_count = vector_repository.get_stored_chunks_count().await;
+
+        let found = service.find_unchanged_document(&content, "guide.md").await.unwrap().unwrap();
+        assert_eq!(found.document_id, document_id);
+        assert_eq!(found.chunk_count, chunk_count);
+        assert!(service.find_unchanged_document(&content, "other.md").await.unwrap().is_none());
+        let changed = document_text("Changed. ");
+        assert!(service.find_unchanged_document(&changed, "guide.md").await.unwrap().is_none());
+
+        // The lookup filters in the repository instead of scanning it
+        assert_eq!(*vector_repository.scan_count.lock().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_reindex_nonexistent_document() {
+        let (service, _, _) = create_test_service();
+
+        let result = service.reindex_document("missing".to_string(), "# Test".to_string(), None).await;
+
+        assert!(matches!(result, Err(ServiceError::NotFound(_))));
+    }
 }
@@ -0,0 +1,276 @@
+use crate::models::{DocumentChunk, SearchResult};
+use std::collections::{HashMap, HashSet};
+use tokio::sync::RwLock;
+use tracing::debug;
+
+/// BM25 term frequency saturation parameter
+const BM25_K1: f32 = 1.2;
+/// BM25 document length normalization parameter
+const BM25_B: f32 = 0.75;
+
+/// Query terms too common to say anything about relevance
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "can", "do", "does", "for", "from", "how", "i", "in", "is", "it", "of", "on", "or", "that", "the", "this",
+    "to", "was", "what", "when", "where", "which", "who", "why", "with",
+];
+
+/// In-memory BM25 inverted index over chunk content
+///
+/// Dense embeddings tend to blur exact identifiers, error codes and rare
+/// terms; this index gives the RAG pipeline a lexical ranking to fuse with
+/// vector similarity.
+#[derive(Default)]
+pub struct KeywordIndex {
+    state: RwLock<IndexState>,
+}
+
+#[derive(Default)]
+struct IndexState {
+    /// term -> (chunk id -> term frequency)
+    postings: HashMap<String, HashMap<String, u32>>,
+    /// chunk id -> indexed chunk
+    chunks: HashMap<String, IndexedChunk>,
+    total_length: usize,
+    /// Whether the index has been loaded from the repository
+    synced: bool,
+}
+
+struct IndexedChunk {
+    chunk: DocumentChunk,
+    term_frequencies: HashMap<String, u32>,
+    length: usize,
+}
+
+impl IndexState {
+    fn insert(&mut self, chunk: &DocumentChunk) {
+        self.remove(&chunk.id);
+
+        let tokens = tokenize(&chunk.content);
+        let mut term_frequencies: HashMap<String, u32> = HashMap::new();
+        for token in &tokens {
+            *term_frequencies.entry(token.clone()).or_insert(0) += 1;
+        }
+
+        for (term, frequency) in &term_frequencies {
+            self.postings.entry(term.clone()).or_default().insert(chunk.id.clone(), *frequency);
+        }
+
+        // Embeddings are not needed for lexical scoring
+        let mut chunk = chunk.clone();
+        chunk.embedding = None;
+
+        self.total_length += tokens.len();
+        self.chunks.insert(
+            chunk.id.clone(),
+            IndexedChunk {
+                chunk,
+                term_frequencies,
+                length: tokens.len(),
+            },
+        );
+    }
+
+    fn remove(&mut self, chunk_id: &str) {
+        let Some(indexed) = self.chunks.remove(chunk_id) else {
+            return;
+        };
+
+        for term in indexed.term_frequencies.keys() {
+            if let Some(posting) = self.postings.get_mut(term) {
+                posting.remove(chunk_id);
+                if posting.is_empty() {
+                    self.postings.remove(term);
+                }
+            }
+        }
+
+        self.total_length -= indexed.length;
+    }
+}
+
+impl KeywordIndex {
+    pub fn new() -> Self { Self::default() }
+
+    /// Number of indexed chunks
+    pub async fn len(&self) -> usize { self.state.read().await.chunks.len() }
+
+    /// Returns true when no chunk has been indexed yet
+    pub async fn is_empty(&self) -> bool { self.state.read().await.chunks.is_empty() }
+
+    /// Returns true once the index has been loaded from the repository
+    ///
+    /// Chunks inserted before that, e.g. by an upload right after a restart,
+    /// do not count: the index is not empty but still misses older documents.
+    pub async fn is_synced(&self) -> bool { self.state.read().await.synced }
+
+    /// Records that the repository had nothing to load
+    pub async fn mark_synced(&self) { self.state.write().await.synced = true; }
+
+    /// Replaces the whole index with the given chunks and marks it synced
+    pub async fn rebuild(&self, chunks: Vec<DocumentChunk>) {
+        let mut state = self.state.write().await;
+        *state = IndexState {
+            synced: true,
+            ..IndexState::default()
+        };
+        for chunk in &chunks {
+            state.insert(chunk);
+        }
+        debug!("KeywordIndex: rebuilt with {} chunks and {} terms", state.chunks.len(), state.postings.len());
+    }
+
+    /// Adds chunks to the index, replacing any chunk with the same ID
+    pub async fn insert(&self, chunks: &[DocumentChunk]) {
+        let mut state = self.state.write().await;
+        for chunk in chunks {
+            state.insert(chunk);
+        }
+    }
+
+    /// Removes all chunks of a document and returns how many were removed
+    pub async fn remove_document(&self, document_id: &str) -> usize {
+        let mut state = self.state.write().await;
+        let chunk_ids: Vec<String> = state
+            .chunks
+            .values()
+            .filter(|indexed| indexed.chunk.document_id == document_id)
+            .map(|indexed| indexed.chunk.id.clone())
+            .collect();
+
+        for chunk_id in &chunk_ids {
+            state.remove(chunk_id);
+        }
+
+        chunk_ids.len()
+    }
+
+    /// Ranks chunks against the query with BM25
+    ///
+    /// The relevance score of each result is the raw BM25 score; chunks that
+    /// share no term with the query are not returned. Stop words in the query
+    /// are ignored.
+    pub async fn search(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        let state = self.state.read().await;
+
+        let total_chunks = state.chunks.len();
+        if total_chunks == 0 || limit == 0 {
+            return Vec::new();
+        }
+
+        let average_length = (state.total_length as f32 / total_chunks as f32).max(1.0);
+        let query_terms: HashSet<String> = tokenize(query).into_iter().filter(|term| !STOP_WORDS.contains(&term.as_str())).collect();
+
+        let mut scores: HashMap<&str, f32> = HashMap::new();
+        for term in &query_terms {
+            let Some(posting) = state.postings.get(term) else {
+                continue;
+            };
+
+            let document_frequency = posting.len() as f32;
+            let idf = (1.0 + (total_chunks as f32 - document_frequency + 0.5) / (document_frequency + 0.5)).ln();
+
+            for (chunk_id, frequency) in posting {
+                let length = state.chunks[chunk_id].length as f32;
+                let frequency = *frequency as f32;
+                let normalization = BM25_K1 * (1.0 - BM25_B + BM25_B * length / average_length);
+                *scores.entry(chunk_id.as_str()).or_insert(0.0) += idf * frequency * (BM25_K1 + 1.0) / (frequency + normalization);
+            }
+        }
+
+        let mut ranked: Vec<(&str, f32)> = scores.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal).then_with(|| a.0.cmp(b.0)));
+        ranked.truncate(limit);
+
+        ranked
+            .into_iter()
+            .map(|(chunk_id, score)| SearchResult::new(state.chunks[chunk_id].chunk.clone(), score))
+            .collect()
+    }
+}
+
+/// Splits text into lowercase terms
+///
+/// Underscores are kept inside terms so snake_case identifiers and codes such
+/// as `ERR_TIMEOUT` match as a whole.
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ChunkMetadata, ChunkType};
+    use chrono::Utc;
+
+    fn create_test_chunk(id: &str, document_id: &str, content: &str) -> DocumentChunk {
+        DocumentChunk {
+            id: id.to_string(),
+            document_id: document_id.to_string(),
+            content: content.to_string(),
+            metadata: ChunkMetadata::new("test.md".to_string(), 0, ChunkType::Text),
+            embedding: Some(vec![0.1; 8]),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_tokenize_keeps_identifiers() {
+        let tokens = tokenize("Call `get_chunks_by_document_id()` on ERR_TIMEOUT, v2.");
+
+        assert_eq!(tokens, vec!["call", "get_chunks_by_document_id", "on", "err_timeout", "v2"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_ranks_exact_identifier_first() {
+        let index = KeywordIndex::new();
+        index
+            .insert(&[
+                create_test_chunk("c1", "doc1", "Retries are configured through the client settings."),
+                create_test_chunk("c2", "doc1", "Set QDRANT_MAX_RETRIES to control how often the client retries."),
+                create_test_chunk("c3", "doc2", "The client connects to the vector database on startup."),
+            ])
+            .await;
+
+        let results = index.search("What does QDRANT_MAX_RETRIES do?", 10).await;
+
+        assert!(!results.is_empty());
+        assert_eq!(results[0].chunk.id, "c2");
+        assert!(results[0].chunk.embedding.is_none(), "Indexed chunks should not keep embeddings");
+        assert!(results.iter().all(|r| r.relevance_score > 0.0));
+    }
+
+    #[tokio::test]
+    async fn test_search_without_matching_terms() {
+        let index = KeywordIndex::new();
+        index
+            .insert(&[create_test_chunk("c1", "doc1", "What is the vector search with embeddings")])
+            .await;
+
+        assert!(index.search("kubernetes", 5).await.is_empty());
+        assert!(index.search("What is the", 5).await.is_empty(), "Stop words alone should not match");
+        assert!(KeywordIndex::new().search("vector", 5).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_insert_replaces_and_remove_document() {
+        let index = KeywordIndex::new();
+        index
+            .insert(&[create_test_chunk("c1", "doc1", "alpha beta"), create_test_chunk("c2", "doc2", "gamma delta")])
+            .await;
+        index.insert(&[create_test_chunk("c1", "doc1", "epsilon")]).await;
+
+        assert_eq!(index.len().await, 2);
+        assert!(index.search("alpha", 5).await.is_empty());
+        assert_eq!(index.search("epsilon", 5).await.len(), 1);
+
+        assert_eq!(index.remove_document("doc1").await, 1);
+        assert_eq!(index.len().await, 1);
+        assert!(index.search("epsilon", 5).await.is_empty());
+
+        index.rebuild(vec![]).await;
+        assert!(index.is_empty().await);
+    }
+}
@@ -2,8 +2,10 @@ pub mod cache;
 pub mod chunker;
 pub mod document;
 pub mod embedding;
+pub mod keyword_index;
 pub mod parser;
 pub mod rag;
+pub mod reranker;
 pub mod resilience;
 pub mod vector_search;
 
@@ -18,7 +20,9 @@ pub use cache::*;
 pub use chunker::{ChunkingConfig, DocumentChunker};
 pub use document::DocumentService;
 pub use embedding::EmbeddingService;
-pub use parser::DocumentParser;
+pub use keyword_index::KeywordIndex;
+pub use parser::{DocumentFormat, DocumentParser};
 pub use rag::RAGService;
+pub use reranker::Reranker;
 pub use resilience::{ResilienceConfig, ResilienceService};
 pub use vector_search::VectorSearchService;
@@ -1,8 +1,64 @@
 use crate::models::{ChunkType, ServiceError};
 use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Parser, Tag};
+use scraper::node::Node;
+use scraper::{ElementRef, Html};
 use std::collections::VecDeque;
+use std::path::Path;
 
-/// Parsed markdown element with its content and metadata
+/// HTML elements whose content is never indexed
+const HTML_SKIPPED_ELEMENTS: &[&str] = &["head", "script", "style", "noscript", "template", "svg", "iframe"];
+
+/// Longest line treated as a heading when parsing plain or PDF text
+const MAX_TEXT_HEADING_LENGTH: usize = 80;
+
+/// Source formats the parser understands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    Markdown,
+    Html,
+    PlainText,
+    Pdf,
+}
+
+impl DocumentFormat {
+    /// File extensions accepted for upload
+    pub const SUPPORTED_EXTENSIONS: &'static [&'static str] = &["md", "markdown", "html", "htm", "txt", "text", "pdf"];
+
+    /// Detects the format from the file extension
+    pub fn from_filename(filename: &str) -> Option<Self> {
+        let extension = Path::new(filename).extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            | "md" | "markdown" => Some(Self::Markdown),
+            | "html" | "htm" => Some(Self::Html),
+            | "txt" | "text" => Some(Self::PlainText),
+            | "pdf" => Some(Self::Pdf),
+            | _ => None,
+        }
+    }
+
+    /// Detects the format from the leading bytes of the content
+    ///
+    /// Only PDF and HTML have reliable signatures; anything else returns
+    /// `None`.
+    pub fn sniff(content: &[u8]) -> Option<Self> {
+        if content.starts_with(b"%PDF-") {
+            return Some(Self::Pdf);
+        }
+
+        let head = String::from_utf8_lossy(&content[.. content.len().min(512)]).trim_start().to_lowercase();
+        if head.starts_with("<!doctype html") || head.starts_with("<html") {
+            return Some(Self::Html);
+        }
+
+        None
+    }
+
+    /// Detects the format from the file extension, falling back to the
+    /// content signature
+    pub fn detect(filename: &str, content: &[u8]) -> Option<Self> { Self::from_filename(filename).or_else(|| Self::sniff(content)) }
+}
+
+/// Parsed document element with its content and metadata
 #[derive(Debug, Clone, PartialEq)]
 pub struct ParsedElement {
     pub content: String,
@@ -29,7 +85,8 @@ impl ParsedElement {
     }
 }
 
-/// Document parser that extracts structured content from markdown
+/// Document parser that extracts structured content from Markdown, HTML,
+/// plain text and text extracted from PDFs
 pub struct DocumentParser {
     preserve_structure: bool,
 }
@@ -50,8 +107,48 @@ impl DocumentParser {
         }
     }
 
-    /// Parses markdown content and returns structured elements
-    pub fn parse(&self, markdown: &str, _source_file: String) -> Result<Vec<ParsedElement>, ServiceError> {
+    /// Parses content and returns structured elements
+    ///
+    /// The format is detected from the source file name and content; unknown
+    /// formats are parsed as Markdown. PDF content must already be extracted
+    /// with [`DocumentParser::extract_text`].
+    pub fn parse(&self, content: &str, source_file: String) -> Result<Vec<ParsedElement>, ServiceError> {
+        let format = DocumentFormat::detect(&source_file, content.as_bytes()).unwrap_or(DocumentFormat::Markdown);
+        self.parse_with_format(content, format)
+    }
+
+    /// Parses content in the given format and returns structured elements
+    pub fn parse_with_format(&self, content: &str, format: DocumentFormat) -> Result<Vec<ParsedElement>, ServiceError> {
+        match format {
+            | DocumentFormat::Markdown => self.parse_markdown(content),
+            | DocumentFormat::Html => Ok(self.parse_html(content)),
+            | DocumentFormat::PlainText | DocumentFormat::Pdf => Ok(self.parse_text(content)),
+        }
+    }
+
+    /// Converts raw file bytes into the text that `parse` expects
+    ///
+    /// PDFs have their text layer extracted; every other format must be valid
+    /// UTF-8.
+    pub fn extract_text(&self, bytes: &[u8], format: DocumentFormat) -> Result<String, ServiceError> {
+        match format {
+            | DocumentFormat::Pdf => {
+                // pdf-extract panics on some malformed files
+                let result = std::panic::catch_unwind(|| pdf_extract::extract_text_from_mem(bytes))
+                    .map_err(|_| ServiceError::document_processing("Failed to extract text from PDF: malformed document"))?;
+                let text = result.map_err(|e| ServiceError::document_processing(format!("Failed to extract text from PDF: {}", e)))?;
+
+                if text.trim().is_empty() {
+                    return Err(ServiceError::validation("PDF contains no extractable text"));
+                }
+                Ok(text)
+            },
+            | _ => String::from_utf8(bytes.to_vec()).map_err(|_| ServiceError::validation("File must contain valid UTF-8 text")),
+        }
+    }
+
+    /// Parses Markdown content with pulldown-cmark
+    fn parse_markdown(&self, markdown: &str) -> Result<Vec<ParsedElement>, ServiceError> {
         let parser = Parser::new(markdown);
         let mut elements = Vec::new();
         let mut current_content = String::new();
@@ -189,7 +286,7 @@ impl DocumentParser {
                 let content = current_content.trim().to_string();
                 if !content.is_empty() {
                     // Update header stack based on level
-                    self.update_header_stack(header_stack, &content, level);
+                    self.update_header_stack(header_stack, &content, heading_level_number(level));
 
                     let element = ParsedElement::new(content.clone(), ChunkType::Header, start_position, end_position)
                         .with_headers(header_stack.iter().cloned().collect());
@@ -266,16 +363,7 @@ impl DocumentParser {
         Ok(None)
     }
 
-    fn update_header_stack(&self, header_stack: &mut VecDeque<String>, content: &str, level: HeadingLevel) {
-        let level_num = match level {
-            | HeadingLevel::H1 => 1,
-            | HeadingLevel::H2 => 2,
-            | HeadingLevel::H3 => 3,
-            | HeadingLevel::H4 => 4,
-            | HeadingLevel::H5 => 5,
-            | HeadingLevel::H6 => 6,
-        };
-
+    fn update_header_stack(&self, header_stack: &mut VecDeque<String>, content: &str, level_num: usize) {
         // Remove headers at the same level or deeper
         while header_stack.len() >= level_num {
             header_stack.pop_back();
@@ -285,6 +373,145 @@ impl DocumentParser {
         header_stack.push_back(content.to_string());
     }
 
+    /// Parses HTML into elements, mapping headings, paragraphs, lists,
+    /// tables, preformatted blocks and blockquotes to their chunk types
+    ///
+    /// Positions are offsets into the extracted text rather than the HTML
+    /// source.
+    fn parse_html(&self, html: &str) -> Vec<ParsedElement> {
+        let document = Html::parse_document(html);
+        let mut state = HtmlParseState::default();
+
+        self.walk_html(document.root_element(), &mut state);
+        state.flush_inline();
+
+        state.elements
+    }
+
+    fn walk_html(&self, element: ElementRef, state: &mut HtmlParseState) {
+        for child in element.children() {
+            match child.value() {
+                | Node::Text(text) => state.inline_text.push_str(text),
+                | Node::Element(_) => {
+                    let Some(child) = ElementRef::wrap(child) else {
+                        continue;
+                    };
+                    self.handle_html_element(child, state);
+                },
+                | _ => {},
+            }
+        }
+    }
+
+    fn handle_html_element(&self, element: ElementRef, state: &mut HtmlParseState) {
+        let name = element.value().name();
+
+        if HTML_SKIPPED_ELEMENTS.contains(&name) {
+            return;
+        }
+
+        match name {
+            | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                state.flush_inline();
+                let content = collapse_whitespace(&element.text().collect::<String>());
+                if !content.is_empty() {
+                    let level = name[1 ..].parse::<usize>().unwrap_or(1);
+                    self.update_header_stack(&mut state.header_stack, &content, level);
+                    state.push(content, ChunkType::Header);
+                }
+            },
+            | "p" => {
+                state.flush_inline();
+                state.push(collapse_whitespace(&element.text().collect::<String>()), ChunkType::Text);
+            },
+            | "ul" | "ol" => {
+                state.flush_inline();
+                let ordered = name == "ol";
+                let items: Vec<String> = element
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .filter(|item| item.value().name() == "li")
+                    .map(|item| collapse_whitespace(&item.text().collect::<String>()))
+                    .filter(|item| !item.is_empty())
+                    .enumerate()
+                    .map(|(i, item)| if ordered { format!("{}. {}", i + 1, item) } else { format!("- {}", item) })
+                    .collect();
+                state.push(items.join("\n"), ChunkType::List);
+            },
+            | "table" => {
+                state.flush_inline();
+                let rows: Vec<String> = element
+                    .descendants()
+                    .filter_map(ElementRef::wrap)
+                    .filter(|row| row.value().name() == "tr")
+                    .map(|row| {
+                        row.children()
+                            .filter_map(ElementRef::wrap)
+                            .filter(|cell| matches!(cell.value().name(), "td" | "th"))
+                            .map(|cell| collapse_whitespace(&cell.text().collect::<String>()))
+                            .collect::<Vec<_>>()
+                            .join(" | ")
+                    })
+                    .filter(|row| !row.is_empty())
+                    .collect();
+                state.push(rows.join("\n"), ChunkType::Table);
+            },
+            | "pre" => {
+                state.flush_inline();
+                // Preformatted text keeps its whitespace
+                let content = element.text().collect::<String>();
+                state.push(content.trim_matches('\n').trim_end().to_string(), ChunkType::CodeBlock);
+            },
+            | "blockquote" => {
+                state.flush_inline();
+                state.push(collapse_whitespace(&element.text().collect::<String>()), ChunkType::Quote);
+            },
+            | "br" => state.inline_text.push('\n'),
+            | "html" | "body" | "main" | "article" | "section" | "div" | "header" | "footer" | "nav" | "aside" | "figure" | "form" | "details" | "li"
+            | "dl" | "dd" | "dt" => {
+                // Block containers end any loose text before and after them
+                state.flush_inline();
+                self.walk_html(element, state);
+                state.flush_inline();
+            },
+            | _ => self.walk_html(element, state),
+        }
+    }
+
+    /// Parses plain text, including text extracted from PDFs, into
+    /// blank-line separated blocks
+    ///
+    /// Underlined, numbered (`2.1 Setup`) and all-caps lines become headers;
+    /// bulleted or numbered lines become lists and indented blocks become
+    /// code.
+    fn parse_text(&self, text: &str) -> Vec<ParsedElement> {
+        let mut elements = Vec::new();
+        let mut header_stack: VecDeque<String> = VecDeque::new();
+
+        for (block, start, end) in text_blocks(text) {
+            let lines: Vec<&str> = block.lines().map(str::trim_end).collect();
+
+            let (content, element_type) = if let Some((heading, level)) = text_heading(&lines) {
+                self.update_header_stack(&mut header_stack, &heading, level);
+                (heading, ChunkType::Header)
+            } else if lines.iter().all(|line| is_list_item(line)) {
+                (lines.iter().map(|line| line.trim()).collect::<Vec<_>>().join("\n"), ChunkType::List)
+            } else if lines.iter().all(|line| line.starts_with('\t') || line.starts_with("    ")) {
+                let code: Vec<&str> = lines
+                    .iter()
+                    .map(|line| line.strip_prefix('\t').or_else(|| line.strip_prefix("    ")).unwrap_or(*line))
+                    .collect();
+                (code.join("\n"), ChunkType::CodeBlock)
+            } else {
+                (lines.iter().map(|line| line.trim()).collect::<Vec<_>>().join("\n"), ChunkType::Text)
+            };
+
+            elements.push(ParsedElement::new(content, element_type, start, end).with_headers(header_stack.iter().cloned().collect()));
+        }
+
+        elements
+    }
+
     /// Extracts plain text content from markdown, stripping all formatting
     #[allow(dead_code)]
     pub fn extract_plain_text(&self, markdown: &str) -> Result<String, ServiceError> {
@@ -311,6 +538,145 @@ impl Default for DocumentParser {
     fn default() -> Self { Self::new() }
 }
 
+fn heading_level_number(level: HeadingLevel) -> usize {
+    match level {
+        | HeadingLevel::H1 => 1,
+        | HeadingLevel::H2 => 2,
+        | HeadingLevel::H3 => 3,
+        | HeadingLevel::H4 => 4,
+        | HeadingLevel::H5 => 5,
+        | HeadingLevel::H6 => 6,
+    }
+}
+
+/// Accumulates elements while walking an HTML document
+#[derive(Default)]
+struct HtmlParseState {
+    elements: Vec<ParsedElement>,
+    header_stack: VecDeque<String>,
+    /// Text found directly inside block containers, outside any paragraph
+    inline_text: String,
+    position: usize,
+}
+
+impl HtmlParseState {
+    fn push(&mut self, content: String, element_type: ChunkType) {
+        if content.trim().is_empty() {
+            return;
+        }
+
+        let start = self.position;
+        let end = start + content.len();
+        self.position = end + 1;
+
+        let element = ParsedElement::new(content, element_type, start, end).with_headers(self.header_stack.iter().cloned().collect());
+        self.elements.push(element);
+    }
+
+    fn flush_inline(&mut self) {
+        let text = collapse_whitespace(&std::mem::take(&mut self.inline_text));
+        self.push(text, ChunkType::Text);
+    }
+}
+
+/// Collapses runs of whitespace into single spaces
+fn collapse_whitespace(text: &str) -> String { text.split_whitespace().collect::<Vec<_>>().join(" ") }
+
+/// Splits text into blocks separated by blank lines or page breaks, with the
+/// byte range of each block
+fn text_blocks(text: &str) -> Vec<(&str, usize, usize)> {
+    let mut blocks = Vec::new();
+    let mut block_start: Option<usize> = None;
+    let mut block_end = 0;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+
+        // PDF text marks page breaks with form feeds, which also end a block
+        let is_blank = line.trim().is_empty();
+        if is_blank || line.starts_with('\u{c}') {
+            if let Some(start) = block_start.take() {
+                blocks.push((&text[start .. block_end], start, block_end));
+            }
+        }
+        if is_blank {
+            continue;
+        }
+
+        if block_start.is_none() {
+            block_start = Some(line_start + (line.len() - line.trim_start_matches('\u{c}').len()));
+        }
+        block_end = line_start + line.trim_end().len();
+    }
+
+    if let Some(start) = block_start {
+        blocks.push((&text[start .. block_end], start, block_end));
+    }
+
+    blocks
+}
+
+/// Recognises a block as a heading and returns its text and level
+fn text_heading(lines: &[&str]) -> Option<(String, usize)> {
+    // Setext-style underline: `Title` followed by `=====` or `-----`
+    if let [title, underline] = lines {
+        let underline = underline.trim();
+        if underline.len() >= 3 && !title.trim().is_empty() {
+            if underline.chars().all(|c| c == '=') {
+                return Some((title.trim().to_string(), 1));
+            }
+            if underline.chars().all(|c| c == '-') {
+                return Some((title.trim().to_string(), 2));
+            }
+        }
+    }
+
+    let [line] = lines else {
+        return None;
+    };
+    let line = line.trim();
+    if line.len() > MAX_TEXT_HEADING_LENGTH || line.ends_with(['.', ',', ';', ':', '!', '?']) {
+        return None;
+    }
+
+    // A lone bullet is a one-item list, never a heading
+    if is_list_item(line) && !line.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    // Numbered section such as `3 Results` or `2.1. Setup`; the depth of the
+    // numbering is the heading level
+    if let Some((number, title)) = line.split_once(' ') {
+        let parts: Vec<&str> = number.trim_end_matches('.').split('.').collect();
+        let is_section_number = parts.iter().all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()));
+        if is_section_number && title.chars().next().is_some_and(|c| c.is_alphabetic()) {
+            return Some((line.to_string(), parts.len().min(6)));
+        }
+    }
+
+    // Short all-caps line such as `INTRODUCTION`
+    let letters: Vec<char> = line.chars().filter(|c| c.is_alphabetic()).collect();
+    if letters.len() >= 2 && letters.iter().all(|c| c.is_uppercase()) {
+        return Some((line.to_string(), 1));
+    }
+
+    None
+}
+
+/// Returns true for bulleted (`-`, `*`, `+`, `•`) or numbered (`1.`, `1)`)
+/// list lines
+fn is_list_item(line: &str) -> bool {
+    let line = line.trim_start();
+    if ["- ", "* ", "+ ", "• ", "◦ ", "▪ "].iter().any(|bullet| line.starts_with(bullet)) {
+        return true;
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    digits > 0 && (line[digits ..].starts_with(". ") || line[digits ..].starts_with(") "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -497,4 +863,121 @@ This is **bold** and *italic* text with `code`.
         let result = parser.parse("   \n\n   ", "test.md".to_string()).unwrap();
         assert!(result.is_empty());
     }
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(DocumentFormat::from_filename("notes.MD"), Some(DocumentFormat::Markdown));
+        assert_eq!(DocumentFormat::from_filename("page.htm"), Some(DocumentFormat::Html));
+        assert_eq!(DocumentFormat::from_filename("readme.txt"), Some(DocumentFormat::PlainText));
+        assert_eq!(DocumentFormat::from_filename("paper.pdf"), Some(DocumentFormat::Pdf));
+        assert_eq!(DocumentFormat::from_filename("archive.zip"), None);
+
+        assert_eq!(DocumentFormat::detect("upload", b"%PDF-1.7\n..."), Some(DocumentFormat::Pdf));
+        assert_eq!(DocumentFormat::detect("upload", b"  <!DOCTYPE html><html></html>"), Some(DocumentFormat::Html));
+        assert_eq!(DocumentFormat::detect("upload", b"just text"), None);
+    }
+
+    #[test]
+    fn test_parse_html_structure() {
+        let parser = DocumentParser::new();
+        let html = r#"<!DOCTYPE html>
+<html>
+<head><title>Ignored</title><style>body { color: red; }</style></head>
+<body>
+  <h1>Guide</h1>
+  <p>Intro with <strong>bold</strong>
+     text.</p>
+  <h2>Install</h2>
+  <ul><li>Download</li><li>Run <code>setup</code></li></ul>
+  <pre><code>cargo build
+cargo run</code></pre>
+  <table><tr><th>Name</th><th>Age</th></tr><tr><td>John</td><td>25</td></tr></table>
+  <blockquote>Quoted advice</blockquote>
+  <script>alert("skip me")</script>
+</body>
+</html>"#;
+
+        let result = parser.parse(html, "guide.html".to_string()).unwrap();
+
+        let types: Vec<ChunkType> = result.iter().map(|e| e.element_type.clone()).collect();
+        assert_eq!(
+            types,
+            vec![
+                ChunkType::Header,
+                ChunkType::Text,
+                ChunkType::Header,
+                ChunkType::List,
+                ChunkType::CodeBlock,
+                ChunkType::Table,
+                ChunkType::Quote,
+            ]
+        );
+
+        assert_eq!(result[1].content, "Intro with bold text.");
+        assert_eq!(result[3].content, "- Download\n- Run setup");
+        assert_eq!(result[3].headers, vec!["Guide".to_string(), "Install".to_string()]);
+        assert_eq!(result[4].content, "cargo build\ncargo run");
+        assert_eq!(result[5].content, "Name | Age\nJohn | 25");
+        assert!(result.iter().all(|e| !e.content.contains("skip me") && !e.content.contains("Ignored")));
+    }
+
+    #[test]
+    fn test_parse_plain_text_structure() {
+        let parser = DocumentParser::new();
+        let text = "User Guide\n==========\n\nThis guide explains the setup.\nIt wraps across lines.\n\n1 Installation\n\n- Download the binary\n- Add it to PATH\n\n    ./install.sh --prefix /usr\n\n1.1 Requirements\n\nRust 1.80 or newer.";
+
+        let result = parser.parse(text, "guide.txt".to_string()).unwrap();
+
+        let types: Vec<ChunkType> = result.iter().map(|e| e.element_type.clone()).collect();
+        assert_eq!(
+            types,
+            vec![
+                ChunkType::Header,
+                ChunkType::Text,
+                ChunkType::Header,
+                ChunkType::List,
+                ChunkType::CodeBlock,
+                ChunkType::Header,
+                ChunkType::Text,
+            ]
+        );
+
+        assert_eq!(result[0].content, "User Guide");
+        assert_eq!(result[4].content, "./install.sh --prefix /usr");
+        assert_eq!(result[6].headers, vec!["1 Installation".to_string(), "1.1 Requirements".to_string()]);
+
+        // Positions point back into the source text
+        assert_eq!(
+            &text[result[1].start_position .. result[1].end_position],
+            "This guide explains the setup.\nIt wraps across lines."
+        );
+    }
+
+    #[test]
+    fn test_parse_pdf_text_page_breaks() {
+        let parser = DocumentParser::new();
+        let text = "INTRODUCTION\nThe first page ends here.\n\u{c}The second page starts here.";
+
+        let result = parser.parse(text, "paper.pdf".to_string()).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].element_type, ChunkType::Text);
+        assert_eq!(result[1].content, "The second page starts here.");
+
+        let result = parser.parse("INTRODUCTION\n\nBody text.", "paper.pdf".to_string()).unwrap();
+        assert_eq!(result[0].element_type, ChunkType::Header);
+        assert_eq!(result[1].headers, vec!["INTRODUCTION".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_text() {
+        let parser = DocumentParser::new();
+
+        assert_eq!(parser.extract_text("# Title".as_bytes(), DocumentFormat::Markdown).unwrap(), "# Title");
+        assert!(matches!(
+            parser.extract_text(&[0xff, 0xfe, 0x00], DocumentFormat::PlainText),
+            Err(ServiceError::Validation(_))
+        ));
+        assert!(parser.extract_text(b"%PDF-1.4 not really a pdf", DocumentFormat::Pdf).is_err());
+    }
 }
@@ -1,11 +1,17 @@
-use crate::clients::azure_openai::{AzureOpenAIClient, ChatMessage};
-use crate::models::{RAGResponse, ServiceError, SourceReference};
-use crate::services::{EmbeddingService, VectorSearchService};
+use crate::clients::azure_openai::{AzureOpenAIClient, ChatCompletionStream, ChatMessage};
+use crate::models::{RAGResponse, RAGStreamEvent, SearchMode, SearchResult, ServiceError, SourceReference};
+use crate::services::vector_search::FusionWeights;
+use crate::services::{EmbeddingService, Reranker, VectorSearchService};
 use async_trait::async_trait;
+use futures::StreamExt;
+use futures::stream::BoxStream;
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::{debug, info, warn};
 
+/// Stream of events produced by `RAGServiceImpl::answer_question_stream`
+pub type RAGEventStream = BoxStream<'static, RAGStreamEvent>;
+
 #[async_trait]
 pub trait RAGService: Send + Sync {
     async fn answer_question(&self, question: String) -> Result<RAGResponse, ServiceError>;
@@ -29,6 +35,18 @@ pub struct RAGConfig {
     pub include_low_confidence: bool,
     /// Minimum confidence threshold for answers
     pub min_confidence_threshold: f32,
+    /// Retrieval strategy for context chunks
+    pub search_mode: SearchMode,
+    /// Weight of the vector ranking in hybrid search
+    pub vector_weight: f32,
+    /// Weight of the keyword ranking in hybrid search
+    pub keyword_weight: f32,
+    /// Rank offset for reciprocal rank fusion in hybrid search
+    pub rrf_k: f32,
+    /// Whether to rerank retrieved candidates (requires a configured reranker)
+    pub rerank: bool,
+    /// Number of candidates retrieved for reranking before keeping `max_chunks`
+    pub rerank_candidates: usize,
 }
 
 impl Default for RAGConfig {
@@ -41,6 +59,23 @@ impl Default for RAGConfig {
             max_snippet_length: 200,
             include_low_confidence: false,
             min_confidence_threshold: 0.6,
+            search_mode: SearchMode::Vector,
+            vector_weight: 1.0,
+            keyword_weight: 1.0,
+            rrf_k: 60.0,
+            rerank: false,
+            rerank_candidates: 20,
+        }
+    }
+}
+
+impl RAGConfig {
+    /// Fusion weights used when `search_mode` is hybrid
+    pub fn fusion_weights(&self) -> FusionWeights {
+        FusionWeights {
+            vector_weight: self.vector_weight,
+            keyword_weight: self.keyword_weight,
+            rrf_k: self.rrf_k,
         }
     }
 }
@@ -49,6 +84,7 @@ pub struct RAGServiceImpl {
     embedding_service: Arc<dyn EmbeddingService>,
     vector_search_service: Arc<dyn VectorSearchService>,
     azure_client: AzureOpenAIClient,
+    reranker: Option<Arc<dyn Reranker>>,
     default_config: RAGConfig,
 }
 
@@ -58,6 +94,7 @@ impl RAGServiceImpl {
             embedding_service,
             vector_search_service,
             azure_client,
+            reranker: None,
             default_config: RAGConfig::default(),
         }
     }
@@ -73,10 +110,17 @@ impl RAGServiceImpl {
             embedding_service,
             vector_search_service,
             azure_client,
+            reranker: None,
             default_config: config,
         }
     }
 
+    /// Sets the reranker applied when `RAGConfig::rerank` is enabled
+    pub fn with_reranker(mut self, reranker: Arc<dyn Reranker>) -> Self {
+        self.reranker = Some(reranker);
+        self
+    }
+
     /// Validates the input question
     fn validate_question(&self, question: &str) -> Result<(), ServiceError> {
         if question.trim().is_empty() {
@@ -121,11 +165,88 @@ impl RAGServiceImpl {
             return Err(ServiceError::validation("max_response_tokens must be between 1 and 4000"));
         }
 
+        config.fusion_weights().validate()?;
+
+        if config.rerank && (config.rerank_candidates < config.max_chunks || config.rerank_candidates > 100) {
+            return Err(ServiceError::validation("rerank_candidates must be between max_chunks and 100"));
+        }
+
         Ok(())
     }
 
+    /// Retrieves context chunks, over-fetching and reranking them when
+    /// reranking is enabled and a reranker is configured
+    async fn retrieve_context(&self, question: &str, config: &RAGConfig) -> Result<Vec<SearchResult>, ServiceError> {
+        let reranker = match (&self.reranker, config.rerank) {
+            | (Some(reranker), true) => reranker,
+            | (None, true) => {
+                warn!("RAGService: reranking requested but no reranker is configured");
+                return self.retrieve_chunks(question, config, config.max_chunks).await;
+            },
+            | (_, false) => return self.retrieve_chunks(question, config, config.max_chunks).await,
+        };
+
+        let candidates = self.retrieve_chunks(question, config, config.rerank_candidates).await?;
+        debug!("RAGService: reranking {} candidates with {} reranker", candidates.len(), reranker.name());
+
+        match reranker.rerank(question, candidates.clone(), config.max_chunks).await {
+            | Ok(reranked) => Ok(reranked),
+            | Err(e) => {
+                // Reranking only refines the order; fall back to retrieval ranking
+                warn!("RAGService: {} reranker failed, using retrieval order: {}", reranker.name(), e);
+                let mut candidates = candidates;
+                candidates.truncate(config.max_chunks);
+                Ok(candidates)
+            },
+        }
+    }
+
+    /// Retrieves context chunks for the question using the configured search
+    /// mode
+    async fn retrieve_chunks(&self, question: &str, config: &RAGConfig, limit: usize) -> Result<Vec<SearchResult>, ServiceError> {
+        let search_results = match config.search_mode {
+            | SearchMode::Vector => {
+                let question_embedding = self.embed_question(question).await?;
+
+                debug!("RAGService: searching for similar chunks with threshold {}", config.similarity_threshold);
+                self.vector_search_service
+                    .search_similar_with_threshold(question_embedding, limit, config.similarity_threshold)
+                    .await
+            },
+            | SearchMode::Keyword => {
+                debug!("RAGService: searching for keyword matches");
+                self.vector_search_service.keyword_search(question, limit).await
+            },
+            | SearchMode::Hybrid => {
+                let question_embedding = self.embed_question(question).await?;
+
+                debug!("RAGService: hybrid search with threshold {}", config.similarity_threshold);
+                self.vector_search_service
+                    .hybrid_search(
+                        question,
+                        question_embedding,
+                        limit,
+                        config.similarity_threshold,
+                        config.fusion_weights(),
+                    )
+                    .await
+            },
+        };
+
+        search_results.map_err(|e| ServiceError::internal(format!("Failed to search for similar chunks: {}", e)))
+    }
+
+    /// Generates the embedding for the question
+    async fn embed_question(&self, question: &str) -> Result<Vec<f32>, ServiceError> {
+        debug!("RAGService: generating embedding for question");
+        self.embedding_service
+            .generate_embedding(question)
+            .await
+            .map_err(|e| ServiceError::internal(format!("Failed to generate question embedding: {}", e)))
+    }
+
     /// Constructs context from retrieved chunks
-    fn construct_context(&self, search_results: &[crate::models::SearchResult], config: &RAGConfig) -> String {
+    fn construct_context(&self, search_results: &[SearchResult], config: &RAGConfig) -> String {
         if search_results.is_empty() {
             return String::new();
         }
@@ -165,7 +286,7 @@ impl RAGServiceImpl {
     }
 
     /// Creates source references from search results
-    fn create_source_references(&self, search_results: &[crate::models::SearchResult], config: &RAGConfig) -> Vec<SourceReference> {
+    fn create_source_references(&self, search_results: &[SearchResult], config: &RAGConfig) -> Vec<SourceReference> {
         search_results
             .iter()
             .map(|result| {
@@ -190,55 +311,13 @@ impl RAGServiceImpl {
                     chunk.metadata.chunk_index,
                 )
                 .with_headers(chunk.metadata.headers.clone())
+                .with_rerank_score(result.rerank_score)
             })
             .collect()
     }
 
     /// Estimates confidence based on search results and answer quality
-    fn estimate_confidence(&self, search_results: &[crate::models::SearchResult], answer: &str) -> f32 {
-        if search_results.is_empty() {
-            return 0.0;
-        }
-
-        // Base confidence on the highest similarity score
-        let max_similarity = search_results.iter().map(|r| r.relevance_score).fold(0.0, f32::max);
-
-        // Adjust based on number of sources
-        let source_factor = match search_results.len() {
-            | 0 => 0.0,
-            | 1 => 0.8,
-            | 2 ..= 3 => 1.0,
-            | _ => 0.95, // Too many sources might indicate scattered information
-        };
-
-        // Adjust based on answer length (very short answers might be less reliable)
-        let length_factor = if answer.len() < 50 {
-            0.7
-        } else if answer.len() > 1000 {
-            0.9 // Very long answers might be less focused
-        } else {
-            1.0
-        };
-
-        // Check for uncertainty indicators in the answer
-        let uncertainty_factor = if answer.to_lowercase().contains("i don't know")
-            || answer.to_lowercase().contains("i'm not sure")
-            || answer.to_lowercase().contains("unclear")
-            || answer.to_lowercase().contains("cannot determine")
-        {
-            0.3
-        } else if answer.to_lowercase().contains("might") || answer.to_lowercase().contains("possibly") || answer.to_lowercase().contains("perhaps") {
-            0.7
-        } else {
-            1.0
-        };
-
-        // Combine factors
-        let confidence = max_similarity * source_factor * length_factor * uncertainty_factor;
-
-        // Clamp to [0.0, 1.0]
-        confidence.max(0.0).min(1.0)
-    }
+    fn estimate_confidence(&self, search_results: &[SearchResult], answer: &str) -> f32 { confidence_from_results(search_results, answer) }
 
     /// Creates the system prompt for the RAG pipeline
     fn create_system_prompt(&self) -> String {
@@ -257,6 +336,20 @@ Remember: Your knowledge is limited to the provided context. Do not use external
             .to_string()
     }
 
+    /// Creates the chat messages for the answer generation step
+    fn create_messages(&self, context: &str, question: &str) -> Vec<ChatMessage> {
+        vec![
+            ChatMessage {
+                role: "system".to_string(),
+                content: self.create_system_prompt(),
+            },
+            ChatMessage {
+                role: "user".to_string(),
+                content: self.create_user_prompt(context, question),
+            },
+        ]
+    }
+
     /// Creates the user prompt with context and question
     fn create_user_prompt(&self, context: &str, question: &str) -> String {
         if context.trim().is_empty() {
@@ -270,6 +363,167 @@ Remember: Your knowledge is limited to the provided context. Do not use external
     }
 }
 
+impl RAGServiceImpl {
+    /// Answers a question as a stream of events: the retrieved sources
+    /// first, then answer tokens as the model produces them, then the final
+    /// confidence
+    ///
+    /// Retrieval errors are returned before streaming starts. Dropping the
+    /// returned stream (e.g. when the HTTP client disconnects) cancels the
+    /// upstream chat completion.
+    pub async fn answer_question_stream(&self, question: String, config: RAGConfig) -> Result<RAGEventStream, ServiceError> {
+        let start_time = Instant::now();
+
+        debug!("RAGService: streaming answer for question of length {}", question.len());
+
+        self.validate_question(&question)?;
+        self.validate_config(&config)?;
+
+        let search_results = self.retrieve_context(&question, &config).await?;
+        info!("RAGService: found {} relevant chunks for streaming answer", search_results.len());
+
+        let context = self.construct_context(&search_results, &config);
+        let messages = self.create_messages(&context, &question);
+
+        let tokens = self
+            .azure_client
+            .stream_chat_completion(messages, Some(config.max_response_tokens), Some(config.temperature))
+            .await
+            .map_err(|e| ServiceError::internal(format!("Failed to start answer stream: {}", e)))?;
+
+        let sources = self.create_source_references(&search_results, &config);
+
+        Ok(build_event_stream(question, sources, search_results, tokens, &config, start_time))
+    }
+}
+
+/// Estimates confidence based on search results and answer quality
+fn confidence_from_results(search_results: &[SearchResult], answer: &str) -> f32 {
+    if search_results.is_empty() {
+        return 0.0;
+    }
+
+    // Base confidence on the highest similarity score. Fused hybrid scores
+    // only reflect ranks, so use the vector or keyword score behind them.
+    let max_similarity = search_results.iter().map(SearchResult::match_score).fold(0.0, f32::max);
+
+    // Adjust based on number of sources
+    let source_factor = match search_results.len() {
+        | 0 => 0.0,
+        | 1 => 0.8,
+        | 2 ..= 3 => 1.0,
+        | _ => 0.95, // Too many sources might indicate scattered information
+    };
+
+    // Adjust based on answer length (very short answers might be less reliable)
+    let length_factor = if answer.len() < 50 {
+        0.7
+    } else if answer.len() > 1000 {
+        0.9 // Very long answers might be less focused
+    } else {
+        1.0
+    };
+
+    // Check for uncertainty indicators in the answer
+    let uncertainty_factor = if answer.to_lowercase().contains("i don't know")
+        || answer.to_lowercase().contains("i'm not sure")
+        || answer.to_lowercase().contains("unclear")
+        || answer.to_lowercase().contains("cannot determine")
+    {
+        0.3
+    } else if answer.to_lowercase().contains("might") || answer.to_lowercase().contains("possibly") || answer.to_lowercase().contains("perhaps") {
+        0.7
+    } else {
+        1.0
+    };
+
+    // Combine factors
+    let confidence = max_similarity * source_factor * length_factor * uncertainty_factor;
+
+    // Clamp to [0.0, 1.0]
+    confidence.max(0.0).min(1.0)
+}
+
+/// Turns retrieved sources and a token stream into RAG stream events
+fn build_event_stream(
+    question: String,
+    sources: Vec<SourceReference>,
+    search_results: Vec<SearchResult>,
+    tokens: ChatCompletionStream,
+    config: &RAGConfig,
+    start_time: Instant,
+) -> RAGEventStream {
+    struct StreamState {
+        question: String,
+        sources: Option<Vec<SourceReference>>,
+        search_results: Vec<SearchResult>,
+        tokens: Option<ChatCompletionStream>,
+        answer: String,
+        min_confidence_threshold: f32,
+        start_time: Instant,
+    }
+
+    let state = StreamState {
+        question,
+        sources: Some(sources),
+        search_results,
+        tokens: Some(tokens),
+        answer: String::new(),
+        min_confidence_threshold: config.min_confidence_threshold,
+        start_time,
+    };
+
+    futures::stream::unfold(state, |mut state| async move {
+        if let Some(sources) = state.sources.take() {
+            return Some((
+                RAGStreamEvent::Sources {
+                    sources,
+                },
+                state,
+            ));
+        }
+
+        let tokens = state.tokens.as_mut()?;
+        match tokens.next().await {
+            | Some(Ok(content)) => {
+                state.answer.push_str(&content);
+                Some((
+                    RAGStreamEvent::Token {
+                        content,
+                    },
+                    state,
+                ))
+            },
+            | Some(Err(e)) => {
+                warn!("RAGService: answer stream failed: {}", e);
+                state.tokens = None;
+                Some((
+                    RAGStreamEvent::Error {
+                        message: e.to_string(),
+                    },
+                    state,
+                ))
+            },
+            | None => {
+                state.tokens = None;
+                let confidence = confidence_from_results(&state.search_results, &state.answer);
+                let response_time_ms = state.start_time.elapsed().as_millis() as u64;
+                info!("RAGService: streamed answer in {}ms with confidence {:.2}", response_time_ms, confidence);
+
+                let event = RAGStreamEvent::Done {
+                    confidence,
+                    low_confidence: confidence < state.min_confidence_threshold,
+                    query: std::mem::take(&mut state.question),
+                    source_count: state.search_results.len(),
+                    response_time_ms,
+                };
+                Some((event, state))
+            },
+        }
+    })
+    .boxed()
+}
+
 #[async_trait]
 impl RAGService for RAGServiceImpl {
     async fn answer_question(&self, question: String) -> Result<RAGResponse, ServiceError> {
@@ -285,42 +539,22 @@ impl RAGService for RAGServiceImpl {
         self.validate_question(&question)?;
         self.validate_config(&config)?;
 
-        // Step 1: Generate embedding for the question
-        debug!("RAGService: generating embedding for question");
-        let question_embedding = self
-            .embedding_service
-            .generate_embedding(&question)
-            .await
-            .map_err(|e| ServiceError::internal(format!("Failed to generate question embedding: {}", e)))?;
+        // Steps 1-2: Embed the question (unless keyword-only), retrieve and
+        // optionally rerank chunks
+        let search_results = self.retrieve_context(&question, &config).await?;
 
-        // Step 2: Search for similar chunks
-        debug!("RAGService: searching for similar chunks with threshold {}", config.similarity_threshold);
-        let search_results = self
-            .vector_search_service
-            .search_similar_with_threshold(question_embedding, config.max_chunks, config.similarity_threshold)
-            .await
-            .map_err(|e| ServiceError::internal(format!("Failed to search for similar chunks: {}", e)))?;
-
-        info!("RAGService: found {} relevant chunks", search_results.len());
+        info!(
+            "RAGService: found {} relevant chunks using {:?} search",
+            search_results.len(),
+            config.search_mode
+        );
 
         // Step 3: Construct context from retrieved chunks
         let context = self.construct_context(&search_results, &config);
         debug!("RAGService: constructed context of length {}", context.len());
 
         // Step 4: Generate answer using Azure OpenAI
-        let system_prompt = self.create_system_prompt();
-        let user_prompt = self.create_user_prompt(&context, &question);
-
-        let messages = vec![
-            ChatMessage {
-                role: "system".to_string(),
-                content: system_prompt,
-            },
-            ChatMessage {
-                role: "user".to_string(),
-                content: user_prompt,
-            },
-        ];
+        let messages = self.create_messages(&context, &question);
 
         debug!("RAGService: generating answer with Azure OpenAI");
         let answer = self
@@ -364,6 +598,7 @@ mod tests {
     use crate::clients::azure_openai::{AzureOpenAIClient, ChatMessage};
     use crate::config::AzureOpenAIConfig;
     use crate::models::{ChunkMetadata, ChunkType, DocumentChunk, SearchResult};
+    use crate::services::reranker::LexicalOverlapReranker;
     use crate::services::{EmbeddingService, VectorSearchService};
     use async_trait::async_trait;
     use chrono::Utc;
@@ -468,6 +703,28 @@ mod tests {
             Ok(results)
         }
 
+        async fn keyword_search(&self, _query_text: &str, limit: usize) -> Result<Vec<SearchResult>, ServiceError> {
+            if self.should_fail {
+                return Err(ServiceError::vector_search("Mock search failure"));
+            }
+
+            let search_results = self.search_results.lock().await;
+            let mut results = search_results.clone();
+            results.truncate(limit);
+            Ok(results)
+        }
+
+        async fn hybrid_search(
+            &self,
+            _query_text: &str,
+            query_embedding: Vec<f32>,
+            limit: usize,
+            score_threshold: f32,
+            _weights: FusionWeights,
+        ) -> Result<Vec<SearchResult>, ServiceError> {
+            self.search_similar_with_threshold(query_embedding, limit, score_threshold).await
+        }
+
         async fn store_embeddings(&self, _chunks: Vec<DocumentChunk>) -> Result<(), ServiceError> { Ok(()) }
 
         async fn delete_document_embeddings(&self, _document_id: &str) -> Result<(), ServiceError> { Ok(()) }
@@ -533,9 +790,10 @@ mod tests {
         SearchResult::new(chunk, score)
     }
 
-    fn create_test_service() -> RAGServiceImpl {
+    fn create_test_service() -> RAGServiceImpl { create_test_service_with(Arc::new(MockVectorSearchService::new())) }
+
+    fn create_test_service_with(vector_search_service: Arc<MockVectorSearchService>) -> RAGServiceImpl {
         let embedding_service = Arc::new(MockEmbeddingService::new());
-        let vector_search_service = Arc::new(MockVectorSearchService::new());
 
         let config = AzureOpenAIConfig {
             endpoint: "https://test.openai.azure.com".to_string(),
@@ -777,6 +1035,62 @@ mod tests {
 
         invalid_config.temperature = 1.5;
         assert!(service.validate_config(&invalid_config).is_err());
+
+        // Invalid fusion weights
+        invalid_config = RAGConfig::default();
+        invalid_config.vector_weight = -1.0;
+        assert!(service.validate_config(&invalid_config).is_err());
+
+        invalid_config = RAGConfig::default();
+        invalid_config.vector_weight = 0.0;
+        invalid_config.keyword_weight = 0.0;
+        assert!(service.validate_config(&invalid_config).is_err());
+
+        invalid_config = RAGConfig::default();
+        invalid_config.rrf_k = 0.0;
+        assert!(service.validate_config(&invalid_config).is_err());
+
+        // Invalid rerank candidates (fewer than max_chunks)
+        invalid_config = RAGConfig::default();
+        invalid_config.rerank = true;
+        invalid_config.rerank_candidates = 2;
+        assert!(service.validate_config(&invalid_config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_context_with_reranker() {
+        let vector_search_service = Arc::new(MockVectorSearchService::new());
+        vector_search_service
+            .set_search_results(vec![
+                create_test_search_result("General overview of the project", 0.95, "overview.md"),
+                create_test_search_result("Chunk overlap is configured with overlap_size", 0.9, "chunking.md"),
+                create_test_search_result("Deployment notes", 0.85, "deploy.md"),
+            ])
+            .await;
+
+        let config = RAGConfig {
+            max_chunks: 1,
+            rerank: true,
+            rerank_candidates: 3,
+            ..RAGConfig::default()
+        };
+
+        // Without a reranker the retrieval order is kept
+        let service = create_test_service_with(vector_search_service.clone());
+        let results = service.retrieve_context("How is chunk overlap configured?", &config).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].chunk.metadata.source_file, "overview.md");
+        assert!(results[0].rerank_score.is_none());
+
+        let service = create_test_service_with(vector_search_service).with_reranker(Arc::new(LexicalOverlapReranker::new()));
+        let results = service.retrieve_context("How is chunk overlap configured?", &config).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].chunk.metadata.source_file, "chunking.md");
+        assert!(results[0].rerank_score.unwrap() > 0.5);
+
+        let sources = service.create_source_references(&results, &config);
+        assert_eq!(sources[0].rerank_score, results[0].rerank_score);
+        assert_eq!(sources[0].relevance_score, 0.9);
     }
 
     #[tokio::test]
@@ -858,6 +1172,21 @@ mod tests {
         assert!(confidence < 0.8);
     }
 
+    #[test]
+    fn test_confidence_uses_match_score_of_fused_results() {
+        let answer = "The identifier is documented in the configuration guide.";
+
+        // An exact identifier found only by keyword search: its fused rank
+        // score is capped near 0.5, but the keyword match itself is strong
+        let mut keyword_only = create_test_search_result("Set EMBED_BATCH_SIZE to 32", 0.5, "config.md");
+        keyword_only.match_score = Some(0.85);
+        let mut vector_only = create_test_search_result("Embedding overview", 0.49, "embedding.md");
+        vector_only.match_score = Some(0.72);
+
+        let confidence = confidence_from_results(&[keyword_only, vector_only], answer);
+        assert!(confidence >= 0.8, "Expected confidence from the keyword score, got {}", confidence);
+    }
+
     #[tokio::test]
     async fn test_create_system_prompt() {
         let service = create_test_service();
@@ -906,6 +1235,12 @@ mod tests {
             max_snippet_length: 100,
             include_low_confidence: true,
             min_confidence_threshold: 0.3,
+            search_mode: SearchMode::Hybrid,
+            vector_weight: 1.0,
+            keyword_weight: 2.0,
+            rrf_k: 60.0,
+            rerank: true,
+            rerank_candidates: 10,
         };
 
         let result = service.answer_question_with_config("Test question".to_string(), custom_config).await;
@@ -914,4 +1249,106 @@ mod tests {
         let response = result.unwrap();
         assert_eq!(response.answer, "Custom answer");
     }
+
+    /// Token stream that records when it is dropped
+    struct DropFlagStream {
+        tokens: std::collections::VecDeque<Result<String, ServiceError>>,
+        dropped: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    impl futures::Stream for DropFlagStream {
+        type Item = Result<String, ServiceError>;
+
+        fn poll_next(mut self: std::pin::Pin<&mut Self>, _cx: &mut std::task::Context<'_>) -> std::task::Poll<Option<Self::Item>> {
+            std::task::Poll::Ready(self.tokens.pop_front())
+        }
+    }
+
+    impl Drop for DropFlagStream {
+        fn drop(&mut self) { self.dropped.store(true, std::sync::atomic::Ordering::SeqCst); }
+    }
+
+    fn create_token_stream(tokens: Vec<Result<String, ServiceError>>) -> (ChatCompletionStream, Arc<std::sync::atomic::AtomicBool>) {
+        let dropped = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let stream = DropFlagStream {
+            tokens: tokens.into(),
+            dropped: dropped.clone(),
+        };
+        (stream.boxed(), dropped)
+    }
+
+    #[tokio::test]
+    async fn test_event_stream_orders_sources_tokens_done() {
+        let service = create_test_service();
+        let config = RAGConfig::default();
+        let search_results = vec![create_test_search_result("Rust is a systems language", 0.9, "rust.md")];
+        let sources = service.create_source_references(&search_results, &config);
+        let (tokens, _) = create_token_stream(vec![Ok("Rust is ".to_string()), Ok("a systems language.".to_string())]);
+
+        let events: Vec<RAGStreamEvent> = build_event_stream("What is Rust?".to_string(), sources, search_results.clone(), tokens, &config, Instant::now())
+            .collect()
+            .await;
+
+        assert_eq!(events.len(), 4);
+        assert!(matches!(&events[0], RAGStreamEvent::Sources { sources } if sources.len() == 1));
+        assert_eq!(
+            events[1],
+            RAGStreamEvent::Token {
+                content: "Rust is ".to_string()
+            }
+        );
+        assert_eq!(
+            events[2],
+            RAGStreamEvent::Token {
+                content: "a systems language.".to_string()
+            }
+        );
+
+        let expected_confidence = service.estimate_confidence(&search_results, "Rust is a systems language.");
+        match &events[3] {
+            | RAGStreamEvent::Done {
+                confidence,
+                query,
+                source_count,
+                ..
+            } => {
+                assert_eq!(*confidence, expected_confidence);
+                assert_eq!(query, "What is Rust?");
+                assert_eq!(*source_count, 1);
+            },
+            | other => panic!("Expected done event, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_stream_ends_after_error() {
+        let config = RAGConfig::default();
+        let (tokens, _) = create_token_stream(vec![
+            Ok("Partial".to_string()),
+            Err(ServiceError::external_api("connection reset")),
+            Ok("never sent".to_string()),
+        ]);
+
+        let events: Vec<RAGStreamEvent> = build_event_stream("Question".to_string(), Vec::new(), Vec::new(), tokens, &config, Instant::now())
+            .collect()
+            .await;
+
+        let names: Vec<&str> = events.iter().map(|e| e.event_name()).collect();
+        assert_eq!(names, vec!["sources", "token", "error"]);
+    }
+
+    #[tokio::test]
+    async fn test_dropping_event_stream_cancels_token_stream() {
+        let config = RAGConfig::default();
+        let (tokens, dropped) = create_token_stream(vec![Ok("a".to_string()), Ok("b".to_string())]);
+
+        let mut events = build_event_stream("Question".to_string(), Vec::new(), Vec::new(), tokens, &config, Instant::now());
+        assert_eq!(events.next().await.map(|e| e.event_name()), Some("sources"));
+        assert_eq!(events.next().await.map(|e| e.event_name()), Some("token"));
+        assert!(!dropped.load(std::sync::atomic::Ordering::SeqCst));
+
+        // A client disconnect drops the response body stream
+        drop(events);
+        assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+    }
 }
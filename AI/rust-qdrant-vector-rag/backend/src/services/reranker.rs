@@ -0,0 +1,214 @@
+use crate::clients::azure_openai::{AzureOpenAIClient, ChatMessage};
+use crate::models::{SearchResult, ServiceError};
+use crate::services::keyword_index::tokenize;
+use async_trait::async_trait;
+use std::collections::HashSet;
+use tracing::debug;
+
+/// Second-stage ranking of retrieved chunks
+///
+/// Retrieval over-fetches candidates; a reranker scores each candidate
+/// against the question and keeps the best ones for the context.
+#[async_trait]
+pub trait Reranker: Send + Sync {
+    /// Short name used in logs
+    fn name(&self) -> &'static str;
+
+    /// Reorders candidates by relevance to the question and keeps the top
+    /// `top_n`, recording each kept result's score in `rerank_score`
+    async fn rerank(&self, question: &str, candidates: Vec<SearchResult>, top_n: usize) -> Result<Vec<SearchResult>, ServiceError>;
+}
+
+/// Reranks by the share of question terms that appear in each chunk
+///
+/// Cheap and deterministic, so it works offline and in tests.
+#[derive(Debug, Default, Clone)]
+pub struct LexicalOverlapReranker;
+
+impl LexicalOverlapReranker {
+    pub fn new() -> Self { Self }
+}
+
+#[async_trait]
+impl Reranker for LexicalOverlapReranker {
+    fn name(&self) -> &'static str { "lexical" }
+
+    async fn rerank(&self, question: &str, candidates: Vec<SearchResult>, top_n: usize) -> Result<Vec<SearchResult>, ServiceError> {
+        let question_terms: HashSet<String> = tokenize(question).into_iter().collect();
+
+        let scores = candidates
+            .iter()
+            .map(|candidate| {
+                if question_terms.is_empty() {
+                    return 0.0;
+                }
+                let chunk_terms: HashSet<String> = tokenize(&candidate.chunk.content).into_iter().collect();
+                question_terms.intersection(&chunk_terms).count() as f32 / question_terms.len() as f32
+            })
+            .collect();
+
+        Ok(apply_scores(candidates, scores, top_n))
+    }
+}
+
+/// Reranks by asking the chat model to grade each candidate
+pub struct LlmReranker {
+    azure_client: AzureOpenAIClient,
+    max_passage_chars: usize,
+}
+
+impl LlmReranker {
+    pub fn new(azure_client: AzureOpenAIClient) -> Self {
+        Self {
+            azure_client,
+            max_passage_chars: 800,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_max_passage_chars(mut self, max_passage_chars: usize) -> Self {
+        self.max_passage_chars = max_passage_chars;
+        self
+    }
+
+    /// Creates the grading prompt listing every candidate passage
+    fn create_prompt(&self, question: &str, candidates: &[SearchResult]) -> Vec<ChatMessage> {
+        let mut passages = String::new();
+        for (i, candidate) in candidates.iter().enumerate() {
+            let passage: String = candidate.chunk.content.chars().take(self.max_passage_chars).collect();
+            passages.push_str(&format!("[{}] {}\n\n", i + 1, passage.replace('\n', " ")));
+        }
+
+        vec![
+            ChatMessage {
+                role: "system".to_string(),
+                content: "You grade how useful passages are for answering a question. Rate each passage from 0 (irrelevant) to 10 (directly answers \
+                          the question). Respond with only a JSON array of numbers, one per passage, in the given order."
+                    .to_string(),
+            },
+            ChatMessage {
+                role: "user".to_string(),
+                content: format!("Question: {}\n\nPassages:\n{}", question, passages),
+            },
+        ]
+    }
+}
+
+#[async_trait]
+impl Reranker for LlmReranker {
+    fn name(&self) -> &'static str { "llm" }
+
+    async fn rerank(&self, question: &str, candidates: Vec<SearchResult>, top_n: usize) -> Result<Vec<SearchResult>, ServiceError> {
+        if candidates.is_empty() {
+            return Ok(candidates);
+        }
+
+        let messages = self.create_prompt(question, &candidates);
+        let max_tokens = (candidates.len() * 4 + 16) as u32;
+
+        let response = self.azure_client.generate_chat_completion(messages, Some(max_tokens), Some(0.0)).await?;
+        debug!("LlmReranker: grades {}", response.trim());
+
+        let scores = parse_scores(&response, candidates.len())?;
+        Ok(apply_scores(candidates, scores, top_n))
+    }
+}
+
+/// Parses the model's JSON array of 0-10 grades into scores in 0.0-1.0
+pub fn parse_scores(response: &str, expected: usize) -> Result<Vec<f32>, ServiceError> {
+    let (Some(start), Some(end)) = (response.find('['), response.rfind(']')) else {
+        return Err(ServiceError::external_api("Reranker response did not contain a JSON array"));
+    };
+    if end < start {
+        return Err(ServiceError::external_api("Reranker response did not contain a JSON array"));
+    }
+
+    let grades: Vec<f32> =
+        serde_json::from_str(&response[start ..= end]).map_err(|e| ServiceError::external_api(format!("Failed to parse reranker grades: {}", e)))?;
+
+    if grades.len() != expected {
+        return Err(ServiceError::external_api(format!(
+            "Reranker returned {} grades for {} passages",
+            grades.len(),
+            expected
+        )));
+    }
+
+    Ok(grades.into_iter().map(|grade| (grade / 10.0).clamp(0.0, 1.0)).collect())
+}
+
+/// Attaches scores, sorts by them (keeping retrieval order for ties) and
+/// keeps the best `top_n`
+fn apply_scores(candidates: Vec<SearchResult>, scores: Vec<f32>, top_n: usize) -> Vec<SearchResult> {
+    let mut results: Vec<SearchResult> = candidates
+        .into_iter()
+        .zip(scores)
+        .map(|(mut candidate, score)| {
+            candidate.rerank_score = Some(score);
+            candidate
+        })
+        .collect();
+
+    results.sort_by(|a, b| b.rerank_score.partial_cmp(&a.rerank_score).unwrap_or(std::cmp::Ordering::Equal));
+    results.truncate(top_n);
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ChunkMetadata, ChunkType, DocumentChunk};
+    use chrono::Utc;
+
+    fn create_test_result(id: &str, content: &str, score: f32) -> SearchResult {
+        let chunk = DocumentChunk {
+            id: id.to_string(),
+            document_id: "doc".to_string(),
+            content: content.to_string(),
+            metadata: ChunkMetadata::new("test.md".to_string(), 0, ChunkType::Text),
+            embedding: None,
+            created_at: Utc::now(),
+        };
+        SearchResult::new(chunk, score)
+    }
+
+    #[tokio::test]
+    async fn test_lexical_reranker_reorders_candidates() {
+        let reranker = LexicalOverlapReranker::new();
+        let candidates = vec![
+            create_test_result("a", "General overview of the service architecture", 0.95),
+            create_test_result("b", "Chunk overlap controls how many characters neighbouring chunks share", 0.85),
+            create_test_result("c", "Unrelated deployment notes", 0.8),
+        ];
+
+        let results = reranker.rerank("How does chunk overlap work?", candidates, 2).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].chunk.id, "b");
+        assert_eq!(results[0].relevance_score, 0.85, "Retrieval score should be preserved");
+        assert!(results[0].rerank_score.unwrap() > results[1].rerank_score.unwrap());
+        assert_eq!(results[1].chunk.id, "a", "Ties should keep retrieval order");
+    }
+
+    #[tokio::test]
+    async fn test_lexical_reranker_empty_candidates() {
+        let reranker = LexicalOverlapReranker::new();
+
+        let results = reranker.rerank("question", Vec::new(), 5).await.unwrap();
+
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_parse_scores() {
+        let scores = parse_scores("Grades: [8, 2.5, 10]", 3).unwrap();
+        assert_eq!(scores, vec![0.8, 0.25, 1.0]);
+
+        // Out-of-range grades are clamped
+        assert_eq!(parse_scores("[12, -1]", 2).unwrap(), vec![1.0, 0.0]);
+
+        assert!(matches!(parse_scores("[1, 2]", 3), Err(ServiceError::ExternalAPI(_))));
+        assert!(parse_scores("no grades", 1).is_err());
+        assert!(parse_scores("] [", 1).is_err());
+    }
+}
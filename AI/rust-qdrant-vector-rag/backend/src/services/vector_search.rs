@@ -1,6 +1,8 @@
 use crate::models::{DocumentChunk, SearchResult, ServiceError};
 use crate::repository::VectorRepository;
+use crate::services::keyword_index::KeywordIndex;
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, info};
 
@@ -9,6 +11,15 @@ use tracing::{debug, info};
 pub trait VectorSearchService: Send + Sync {
     async fn search_similar(&self, query_embedding: Vec<f32>, limit: usize) -> Result<Vec<SearchResult>, ServiceError>;
     async fn search_similar_with_threshold(&self, query_embedding: Vec<f32>, limit: usize, score_threshold: f32) -> Result<Vec<SearchResult>, ServiceError>;
+    async fn keyword_search(&self, query_text: &str, limit: usize) -> Result<Vec<SearchResult>, ServiceError>;
+    async fn hybrid_search(
+        &self,
+        query_text: &str,
+        query_embedding: Vec<f32>,
+        limit: usize,
+        score_threshold: f32,
+        weights: FusionWeights,
+    ) -> Result<Vec<SearchResult>, ServiceError>;
     async fn store_embeddings(&self, chunks: Vec<DocumentChunk>) -> Result<(), ServiceError>;
     async fn delete_document_embeddings(&self, document_id: &str) -> Result<(), ServiceError>;
     async fn get_collection_stats(&self) -> Result<VectorCollectionStats, ServiceError>;
@@ -23,8 +34,55 @@ pub struct VectorCollectionStats {
     pub collection_status: String,
 }
 
+/// Weights for reciprocal rank fusion of the vector and keyword rankings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FusionWeights {
+    pub vector_weight: f32,
+    pub keyword_weight: f32,
+    /// Rank offset `k` in `weight / (k + rank)`; larger values flatten the
+    /// advantage of top-ranked results
+    pub rrf_k: f32,
+}
+
+impl Default for FusionWeights {
+    fn default() -> Self {
+        Self {
+            vector_weight: 1.0,
+            keyword_weight: 1.0,
+            rrf_k: 60.0,
+        }
+    }
+}
+
+impl FusionWeights {
+    /// Validates fusion weights
+    pub fn validate(&self) -> Result<(), ServiceError> {
+        if self.vector_weight < 0.0 || self.keyword_weight < 0.0 {
+            return Err(ServiceError::validation("vector_weight and keyword_weight must not be negative"));
+        }
+
+        if self.vector_weight + self.keyword_weight <= 0.0 {
+            return Err(ServiceError::validation("vector_weight and keyword_weight cannot both be 0"));
+        }
+
+        if self.rrf_k <= 0.0 {
+            return Err(ServiceError::validation("rrf_k must be greater than 0"));
+        }
+
+        Ok(())
+    }
+}
+
+/// How many candidates each ranking contributes per requested result in
+/// hybrid search
+const HYBRID_CANDIDATE_FACTOR: usize = 4;
+
+/// Raw BM25 score that maps to a keyword relevance of 0.5
+const KEYWORD_SCORE_SATURATION: f32 = 3.0;
+
 pub struct VectorSearchServiceImpl {
     vector_repository: Arc<dyn VectorRepository>,
+    keyword_index: Arc<KeywordIndex>,
     #[allow(dead_code)]
     default_score_threshold: f32,
     max_search_limit: usize,
@@ -34,6 +92,7 @@ impl VectorSearchServiceImpl {
     pub fn new(vector_repository: Arc<dyn VectorRepository>) -> Self {
         Self {
             vector_repository,
+            keyword_index: Arc::new(KeywordIndex::new()),
             default_score_threshold: 0.7, // Default similarity threshold
             max_search_limit: 100,        // Maximum number of results to return
         }
@@ -43,11 +102,53 @@ impl VectorSearchServiceImpl {
     pub fn with_config(vector_repository: Arc<dyn VectorRepository>, default_score_threshold: f32, max_search_limit: usize) -> Self {
         Self {
             vector_repository,
+            keyword_index: Arc::new(KeywordIndex::new()),
             default_score_threshold,
             max_search_limit,
         }
     }
 
+    /// Shares a keyword index between service instances so it is not rebuilt
+    /// for every request
+    pub fn with_keyword_index(mut self, keyword_index: Arc<KeywordIndex>) -> Self {
+        self.keyword_index = keyword_index;
+        self
+    }
+
+    /// Loads the keyword index from the repository on first use, e.g. after
+    /// a restart
+    ///
+    /// Later writes keep the shared index current themselves: through
+    /// `store_embeddings`, or in the upload and document handlers. Those
+    /// writes can land before the first search, so this checks whether the
+    /// index was synced rather than whether it is empty.
+    async fn sync_keyword_index(&self) -> Result<(), ServiceError> {
+        if self.keyword_index.is_synced().await {
+            return Ok(());
+        }
+
+        let collection_info = self
+            .vector_repository
+            .get_collection_info()
+            .await
+            .map_err(|e| ServiceError::vector_search(format!("Failed to get collection info: {}", e)))?;
+
+        if collection_info.points_count.unwrap_or(0) == 0 {
+            self.keyword_index.mark_synced().await;
+            return Ok(());
+        }
+
+        let chunks = self
+            .vector_repository
+            .list_chunks()
+            .await
+            .map_err(|e| ServiceError::vector_search(format!("Failed to load chunks for keyword index: {}", e)))?;
+
+        info!("VectorSearchService: rebuilding keyword index with {} chunks", chunks.len());
+        self.keyword_index.rebuild(chunks).await;
+        Ok(())
+    }
+
     /// Validates search parameters
     fn validate_search_params(&self, query_embedding: &[f32], limit: usize) -> Result<(), ServiceError> {
         if query_embedding.is_empty() {
@@ -176,6 +277,76 @@ impl VectorSearchService for VectorSearchServiceImpl {
         Ok(filtered_results)
     }
 
+    async fn keyword_search(&self, query_text: &str, limit: usize) -> Result<Vec<SearchResult>, ServiceError> {
+        debug!("VectorSearchService: keyword search with limit {}", limit);
+
+        if query_text.trim().is_empty() {
+            return Err(ServiceError::validation("Query text cannot be empty"));
+        }
+
+        if limit == 0 || limit > self.max_search_limit {
+            return Err(ServiceError::validation(format!(
+                "Search limit must be between 1 and {}, got: {}",
+                self.max_search_limit, limit
+            )));
+        }
+
+        self.sync_keyword_index().await?;
+
+        let mut results = self.keyword_index.search(query_text, limit).await;
+        normalize_keyword_scores(&mut results);
+
+        info!("VectorSearchService: found {} keyword matches", results.len());
+        Ok(results)
+    }
+
+    async fn hybrid_search(
+        &self,
+        query_text: &str,
+        query_embedding: Vec<f32>,
+        limit: usize,
+        score_threshold: f32,
+        weights: FusionWeights,
+    ) -> Result<Vec<SearchResult>, ServiceError> {
+        debug!(
+            "VectorSearchService: hybrid search with limit {}, threshold {} and weights {:?}",
+            limit, score_threshold, weights
+        );
+
+        if query_text.trim().is_empty() {
+            return Err(ServiceError::validation("Query text cannot be empty"));
+        }
+
+        self.validate_search_params(&query_embedding, limit)?;
+        self.validate_score_threshold(score_threshold)?;
+        weights.validate()?;
+
+        // Over-fetch from both rankings so fusion can promote chunks that
+        // only one of them ranks highly
+        let candidate_limit = (limit * HYBRID_CANDIDATE_FACTOR).min(self.max_search_limit);
+
+        let vector_results = self.search_similar_with_threshold(query_embedding, candidate_limit, score_threshold).await?;
+
+        self.sync_keyword_index().await?;
+        let mut keyword_results = self.keyword_index.search(query_text, candidate_limit).await;
+        normalize_keyword_scores(&mut keyword_results);
+
+        debug!(
+            "VectorSearchService: fusing {} vector and {} keyword candidates",
+            vector_results.len(),
+            keyword_results.len()
+        );
+
+        let results = reciprocal_rank_fusion(
+            vec![(vector_results, weights.vector_weight), (keyword_results, weights.keyword_weight)],
+            weights.rrf_k,
+            limit,
+        );
+
+        info!("VectorSearchService: hybrid search returned {} chunks", results.len());
+        Ok(results)
+    }
+
     async fn store_embeddings(&self, chunks: Vec<DocumentChunk>) -> Result<(), ServiceError> {
         debug!("VectorSearchService: storing {} chunks with embeddings", chunks.len());
 
@@ -206,6 +377,8 @@ impl VectorSearchService for VectorSearchServiceImpl {
             .await
             .map_err(|e| ServiceError::vector_search(format!("Failed to store embeddings: {}", e)))?;
 
+        self.keyword_index.insert(&chunks).await;
+
         info!("VectorSearchService: successfully stored {} chunks", chunks.len());
         Ok(())
     }
@@ -222,6 +395,8 @@ impl VectorSearchService for VectorSearchServiceImpl {
             .await
             .map_err(|e| ServiceError::vector_search(format!("Failed to delete document embeddings: {}", e)))?;
 
+        self.keyword_index.remove_document(document_id).await;
+
         info!("VectorSearchService: successfully deleted embeddings for document ID: {}", document_id);
         Ok(())
     }
@@ -250,6 +425,66 @@ impl VectorSearchService for VectorSearchServiceImpl {
     }
 }
 
+/// Maps raw BM25 scores onto [0, 1)
+///
+/// BM25 scores are unbounded; a fixed scale keeps a weak best match from
+/// looking like a confident one.
+fn normalize_keyword_scores(results: &mut [SearchResult]) {
+    for result in results {
+        result.relevance_score /= result.relevance_score + KEYWORD_SCORE_SATURATION;
+    }
+}
+
+/// Fuses several rankings with weighted reciprocal rank fusion
+///
+/// Each chunk scores `weight / (rrf_k + rank)` per ranking it appears in
+/// (rank starting at 1). Fused scores are divided by the best achievable
+/// score, so a chunk ranked first everywhere gets 1.0. A chunk found by
+/// only one ranking tops out well below that, so the best underlying score
+/// is kept in `match_score` for the confidence estimate.
+pub fn reciprocal_rank_fusion(rankings: Vec<(Vec<SearchResult>, f32)>, rrf_k: f32, limit: usize) -> Vec<SearchResult> {
+    let max_score: f32 = rankings.iter().map(|(_, weight)| weight / (rrf_k + 1.0)).sum();
+    if max_score <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut fused: HashMap<String, SearchResult> = HashMap::new();
+    for (results, weight) in rankings {
+        for (rank, result) in results.into_iter().enumerate() {
+            let contribution = weight / (rrf_k + rank as f32 + 1.0);
+            let match_score = result.match_score();
+            fused
+                .entry(result.chunk.id.clone())
+                .and_modify(|existing| {
+                    existing.relevance_score += contribution;
+                    existing.match_score = Some(existing.match_score().max(match_score));
+                })
+                .or_insert_with(|| {
+                    let mut fused_result = SearchResult::new(result.chunk, contribution);
+                    fused_result.match_score = Some(match_score);
+                    fused_result
+                });
+        }
+    }
+
+    let mut results: Vec<SearchResult> = fused
+        .into_values()
+        .map(|mut result| {
+            result.relevance_score = (result.relevance_score / max_score).min(1.0);
+            result
+        })
+        .collect();
+
+    results.sort_by(|a, b| {
+        b.relevance_score
+            .partial_cmp(&a.relevance_score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.chunk.id.cmp(&b.chunk.id))
+    });
+    results.truncate(limit);
+    results
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -336,6 +571,11 @@ mod tests {
             Ok(results)
         }
 
+        async fn list_chunks(&self) -> Result<Vec<DocumentChunk>, ServiceError> {
+            let stored = self.stored_chunks.lock().await;
+            Ok(stored.values().flatten().cloned().collect())
+        }
+
         async fn get_chunks_by_document_id(&self, document_id: &str) -> Result<Vec<DocumentChunk>, ServiceError> {
             let stored = self.stored_chunks.lock().await;
             Ok(stored.get(document_id).cloned().unwrap_or_default())
@@ -353,6 +593,10 @@ mod tests {
 
         async fn delete_chunk(&self, _chunk_id: &str) -> Result<(), ServiceError> { Ok(()) }
 
+        async fn delete_chunks(&self, _chunk_ids: &[String]) -> Result<(), ServiceError> { Ok(()) }
+
+        async fn find_chunk_by_content_hash(&self, _source_file: &str, _content_hash: &str) -> Result<Option<DocumentChunk>, ServiceError> { Ok(None) }
+
         async fn get_collection_info(&self) -> Result<qdrant_client::qdrant::CollectionInfo, ServiceError> {
             if self.should_fail {
                 return Err(self.fail_with_error.clone().unwrap_or_else(|| ServiceError::database("Mock info failure")));
@@ -682,4 +926,155 @@ mod tests {
         assert!(results[1].relevance_score >= results[2].relevance_score, "Should be sorted by score");
         assert_eq!(results[0].relevance_score, 0.95, "Highest score should be first");
     }
+
+    #[test]
+    fn test_reciprocal_rank_fusion() {
+        let shared = create_test_search_result("In both rankings", 0.9);
+        let vector_only = create_test_search_result("Vector only", 0.8);
+        let keyword_only = create_test_search_result("Keyword only", 12.0);
+
+        let results = reciprocal_rank_fusion(
+            vec![
+                (vec![shared.clone(), vector_only.clone()], 1.0),
+                (vec![shared.clone(), keyword_only.clone()], 1.0),
+            ],
+            60.0,
+            10,
+        );
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].chunk.id, shared.chunk.id);
+        assert!((results[0].relevance_score - 1.0).abs() < 1e-6, "Top in every ranking should score 1.0");
+        assert!(results[1].relevance_score < 0.6);
+
+        // Weighting the keyword ranking lets its exclusive hit beat the vector
+        // one
+        let results = reciprocal_rank_fusion(vec![(vec![vector_only.clone()], 0.5), (vec![keyword_only.clone()], 2.0)], 60.0, 1);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].chunk.id, keyword_only.chunk.id);
+    }
+
+    #[tokio::test]
+    async fn test_keyword_search_indexes_stored_chunks() {
+        let mock_repo = Arc::new(MockVectorRepository::new());
+
+        // Chunks written directly to the repository, e.g. by the document
+        // service
+        mock_repo
+            .store_chunks(vec![
+                create_test_chunk("doc1", "General notes about configuring the service", vec![0.1; 384]),
+                create_test_chunk("doc2", "Set EMBED_BATCH_SIZE to tune embedding throughput", vec![0.1; 384]),
+            ])
+            .await
+            .unwrap();
+
+        let service = VectorSearchServiceImpl::new(mock_repo.clone());
+        let results = service.keyword_search("EMBED_BATCH_SIZE", 5).await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].chunk.document_id, "doc2");
+        let score = results[0].relevance_score;
+        assert!(
+            score > 0.0 && score < 0.5,
+            "A single match in a tiny corpus should not look confident, got {}",
+            score
+        );
+
+        // Deleting through the service keeps the index in sync
+        service.delete_document_embeddings("doc2").await.unwrap();
+        assert!(service.keyword_search("EMBED_BATCH_SIZE", 5).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_keyword_search_after_upload_finds_older_documents() {
+        let mock_repo = Arc::new(MockVectorRepository::new());
+
+        // Stored before a restart, so the new index has not seen it
+        mock_repo
+            .store_chunks(vec![create_test_chunk("old", "Set QDRANT_MAX_RETRIES to control retries", vec![0.1; 384])])
+            .await
+            .unwrap();
+
+        // An upload lands before the first search and fills the shared index
+        let keyword_index = Arc::new(KeywordIndex::new());
+        let new_chunk = create_test_chunk("new", "Uploads are split into overlapping chunks", vec![0.1; 384]);
+        mock_repo.store_chunks(vec![new_chunk.clone()]).await.unwrap();
+        keyword_index.insert(&[new_chunk]).await;
+
+        let service = VectorSearchServiceImpl::new(mock_repo).with_keyword_index(keyword_index);
+        let results = service.keyword_search("QDRANT_MAX_RETRIES", 5).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].chunk.document_id, "old");
+        assert_eq!(service.keyword_search("overlapping chunks", 5).await.unwrap()[0].chunk.document_id, "new");
+    }
+
+    #[tokio::test]
+    async fn test_hybrid_search_promotes_exact_keyword_match() {
+        let mock_repo = Arc::new(MockVectorRepository::new());
+        let service = VectorSearchServiceImpl::new(mock_repo.clone());
+
+        let general = create_test_chunk("doc1", "Error handling overview for the upload pipeline", vec![0.1; 384]);
+        let related = create_test_chunk("doc1", "Retry behaviour when uploads fail", vec![0.1; 384]);
+        let exact = create_test_chunk("doc2", "ERR_CHUNK_TOO_LARGE is returned when a chunk exceeds the limit", vec![0.1; 384]);
+        service.store_embeddings(vec![general.clone(), related.clone(), exact.clone()]).await.unwrap();
+
+        // Vector search ranks the exact match last
+        mock_repo
+            .set_search_results(vec![
+                SearchResult::new(general.clone(), 0.92),
+                SearchResult::new(related.clone(), 0.9),
+                SearchResult::new(exact.clone(), 0.88),
+            ])
+            .await;
+
+        let results = service
+            .hybrid_search("What does ERR_CHUNK_TOO_LARGE mean?", vec![0.5; 384], 2, 0.7, FusionWeights::default())
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].chunk.id, exact.id, "Keyword hit should be fused to the top");
+        assert!(results[0].relevance_score > results[1].relevance_score);
+        assert!(results.iter().all(|r| r.relevance_score > 0.0 && r.relevance_score <= 1.0));
+    }
+
+    #[tokio::test]
+    async fn test_hybrid_search_keeps_keyword_score_of_keyword_only_hit() {
+        let mock_repo = Arc::new(MockVectorRepository::new());
+        let service = VectorSearchServiceImpl::new(mock_repo.clone());
+
+        let general = create_test_chunk("doc1", "Error handling overview for the upload pipeline", vec![0.1; 384]);
+        let exact = create_test_chunk("doc2", "ERR_CHUNK_TOO_LARGE is returned when a chunk exceeds the limit", vec![0.1; 384]);
+        service.store_embeddings(vec![general.clone(), exact.clone()]).await.unwrap();
+
+        // Only the keyword ranking finds the exact match
+        mock_repo.set_search_results(vec![SearchResult::new(general.clone(), 0.9)]).await;
+
+        let keyword_score = service.keyword_search("ERR_CHUNK_TOO_LARGE", 5).await.unwrap()[0].relevance_score;
+        let results = service
+            .hybrid_search("ERR_CHUNK_TOO_LARGE", vec![0.5; 384], 2, 0.7, FusionWeights::default())
+            .await
+            .unwrap();
+
+        let fused_exact = results.iter().find(|r| r.chunk.id == exact.id).unwrap();
+        assert!(fused_exact.relevance_score <= 0.5 + f32::EPSILON);
+        assert_eq!(fused_exact.match_score, Some(keyword_score));
+        let fused_general = results.iter().find(|r| r.chunk.id == general.id).unwrap();
+        assert_eq!(fused_general.match_score, Some(0.9));
+    }
+
+    #[tokio::test]
+    async fn test_hybrid_search_invalid_weights() {
+        let mock_repo = Arc::new(MockVectorRepository::new());
+        let service = VectorSearchServiceImpl::new(mock_repo);
+
+        let weights = FusionWeights {
+            vector_weight: 0.0,
+            keyword_weight: 0.0,
+            rrf_k: 60.0,
+        };
+        let result = service.hybrid_search("question", vec![0.5; 384], 5, 0.7, weights).await;
+
+        assert!(matches!(result.unwrap_err(), ServiceError::Validation(_)));
+    }
 }